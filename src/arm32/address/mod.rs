@@ -19,19 +19,78 @@
 // fero General Public License along with Pollex.
 // If not, see <https://www.gnu.org/licenses/>.
 
-use crate::arm32::{Register, Shifter};
+use crate::arm32::{
+	Indexing,
+	Register,
+	Shifter,
+	Sign,
+};
 
 use core::fmt::{Display, Formatter};
 
 /// An address operand.
+///
+/// Addresses are formed from a base register and an offset, which is either an immediate, a register, or a shifted register:
+///
+/// ```as
+/// LDR r0, [r1, #4]
+/// LDR r0, [r1, -r2]
+/// LDR r0, [r1, r2, LSL #2]
+/// ```
+///
+/// The shifter of a scaled register offset must use an immediate shift (or `RRX`).
+/// Register offsets carry an explicit [`Sign`], whereas immediate offsets are simply negative.
+///
+/// Each address also has an [`Indexing`] mode, which determines if -- and when -- the computed address is written back to the base register.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Address {
-	ImmediateOffset { base: Register, source: i32 },
+	ImmediateOffset { base: Register, source: i32, indexing: Indexing },
 
-	RegisterOffset { base: Register, source: Register },
+	RegisterOffset { base: Register, source: Register, sign: Sign, indexing: Indexing },
 
-	ScaledRegisterOffset { base: Register, source: Register, shift: Shifter },
+	ScaledRegisterOffset { base: Register, source: Shifter, sign: Sign, indexing: Indexing },
+}
+
+impl Address {
+	/// Creates a new address from a lone base register.
+	///
+	/// This is identical to creating an [`ImmediateOffset`](Address::ImmediateOffset) instance with `source: 0x0` and `indexing: Indexing::Offset`.
+	#[inline(always)]
+	#[must_use]
+	pub const fn from_register(base: Register) -> Self {
+		Self::ImmediateOffset { base, source: 0x0, indexing: Indexing::Offset }
+	}
+
+	/// Retrieves the base register.
+	#[inline]
+	#[must_use]
+	pub const fn base(self) -> Register {
+		match self {
+			| Self::ImmediateOffset { base, .. }
+			| Self::RegisterOffset { base, .. }
+			| Self::ScaledRegisterOffset { base, .. }
+			=> base,
+		}
+	}
+
+	/// Retrieves the indexing mode.
+	#[inline]
+	#[must_use]
+	pub const fn indexing(self) -> Indexing {
+		match self {
+			| Self::ImmediateOffset { indexing, .. }
+			| Self::RegisterOffset { indexing, .. }
+			| Self::ScaledRegisterOffset { indexing, .. }
+			=> indexing,
+		}
+	}
 
+	/// Checks if the address writes back to its base register.
+	///
+	/// See [`Indexing::has_writeback`].
+	#[inline(always)]
+	#[must_use]
+	pub const fn has_writeback(self) -> bool { self.indexing().has_writeback() }
 }
 
 impl Display for Address {
@@ -39,14 +98,35 @@ impl Display for Address {
 		use Address::*;
 
 		match *self {
-			ImmediateOffset { base, source }
+			ImmediateOffset { base, source: 0x0, indexing: Indexing::Offset }
+			=> write!(f, "[{base}]"),
+
+			ImmediateOffset { base, source, indexing: Indexing::Offset }
 			=> write!(f, "[{base}, #{source}]"),
 
-			RegisterOffset { base, source }
-			=> write!(f, "[{base}, {source}]"),
+			ImmediateOffset { base, source, indexing: Indexing::PreIndexed }
+			=> write!(f, "[{base}, #{source}]!"),
+
+			ImmediateOffset { base, source, indexing: Indexing::PostIndexed }
+			=> write!(f, "[{base}], #{source}"),
+
+			RegisterOffset { base, source, sign, indexing: Indexing::Offset }
+			=> write!(f, "[{base}, {sign}{source}]"),
+
+			RegisterOffset { base, source, sign, indexing: Indexing::PreIndexed }
+			=> write!(f, "[{base}, {sign}{source}]!"),
+
+			RegisterOffset { base, source, sign, indexing: Indexing::PostIndexed }
+			=> write!(f, "[{base}], {sign}{source}"),
+
+			ScaledRegisterOffset { base, source, sign, indexing: Indexing::Offset }
+			=> write!(f, "[{base}, {sign}{source}]"),
+
+			ScaledRegisterOffset { base, source, sign, indexing: Indexing::PreIndexed }
+			=> write!(f, "[{base}, {sign}{source}]!"),
 
-			ScaledRegisterOffset { base, source, shift }
-			=> write!(f, "[{base}, {source}, {shift}]"),
+			ScaledRegisterOffset { base, source, sign, indexing: Indexing::PostIndexed }
+			=> write!(f, "[{base}], {sign}{source}"),
 		}
 	}
 }
@@ -0,0 +1,89 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Pollex.
+//
+// Pollex is free software: you can redistribute it
+// and/or modify it under the terms of the GNU Af-
+// fero General Public License as published by the
+// Free Software Foundation, either version 3 of
+// the License, or (at your option) any later ver-
+// sion.
+//
+// Pollex is distributed in the hope that it will
+// be useful, but WITHOUT ANY WARRANTY; without
+// even the implied warranty of MERCHANTABILITY or
+// FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// Affero General Public License for more details.
+//
+// You should have received a copy of the GNU Af-
+// fero General Public License along with Pollex.
+// If not, see <https://www.gnu.org/licenses/>.
+
+use core::fmt::{Display, Formatter};
+use core::mem::transmute;
+
+/// A memory barrier option.
+///
+/// Barriers (`DMB`, `DSB`, and `ISB`) take an option that limits the shareability domain and the kinds of accesses they order:
+///
+/// ```as
+/// DMB ISH
+/// DSB ST
+/// ```
+///
+/// The `ST` variants only wait for stores.
+/// `ISB` only supports [`Sy`](BarrierOption::Sy).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u8)]
+pub enum BarrierOption {
+	OshSt = 0b0010,
+	Osh   = 0b0011,
+	NshSt = 0b0110,
+	Nsh   = 0b0111,
+	IshSt = 0b1010,
+	Ish   = 0b1011,
+	St    = 0b1110,
+	Sy    = 0b1111,
+}
+
+impl BarrierOption {
+	/// Converts the provided byte into a barrier option.
+	/// If the byte's value is not a valid option, [`None`] is returned.
+	#[inline]
+	#[must_use]
+	pub const fn from_u8(value: u8) -> Option<Self> {
+		match value {
+			| 0b0010
+			| 0b0011
+			| 0b0110
+			| 0b0111
+			| 0b1010
+			| 0b1011
+			| 0b1110
+			| 0b1111
+			=> Some(unsafe { transmute::<u8, Self>(value) }),
+
+			_ => None,
+		}
+	}
+}
+
+impl Display for BarrierOption {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+		use BarrierOption::*;
+
+		let name = match *self {
+			OshSt => "OSHST",
+			Osh   => "OSH",
+			NshSt => "NSHST",
+			Nsh   => "NSH",
+			IshSt => "ISHST",
+			Ish   => "ISH",
+			St    => "ST",
+			Sy    => "SY",
+		};
+
+		write!(f, "{name}")
+	}
+}
@@ -0,0 +1,73 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Pollex.
+//
+// Pollex is free software: you can redistribute it
+// and/or modify it under the terms of the GNU Af-
+// fero General Public License as published by the
+// Free Software Foundation, either version 3 of
+// the License, or (at your option) any later ver-
+// sion.
+//
+// Pollex is distributed in the hope that it will
+// be useful, but WITHOUT ANY WARRANTY; without
+// even the implied warranty of MERCHANTABILITY or
+// FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// Affero General Public License for more details.
+//
+// You should have received a copy of the GNU Af-
+// fero General Public License along with Pollex.
+// If not, see <https://www.gnu.org/licenses/>.
+
+use core::fmt::{Display, Formatter};
+
+/// A block addressing mode.
+///
+/// This denotes the order in which load/store multiple instructions access memory relative to the base register:
+///
+/// ```as
+/// LDMIA r0, {r1, r2} ; Increment after
+/// LDMIB r0, {r1, r2} ; Increment before
+/// LDMDA r0, {r1, r2} ; Decrement after
+/// LDMDB r0, {r1, r2} ; Decrement before
+/// ```
+///
+/// Registers are always transferred in ascending order, with the lowest register using the lowest address.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BlockMode {
+	IncrementAfter,
+
+	IncrementBefore,
+
+	DecrementAfter,
+
+	DecrementBefore,
+}
+
+impl BlockMode {
+	/// Checks if the base address is incremented.
+	#[inline(always)]
+	#[must_use]
+	pub const fn is_increment(self) -> bool { matches!(self, Self::IncrementAfter | Self::IncrementBefore) }
+
+	/// Checks if the base address is adjusted before the first access.
+	#[inline(always)]
+	#[must_use]
+	pub const fn is_before(self) -> bool { matches!(self, Self::IncrementBefore | Self::DecrementBefore) }
+}
+
+impl Display for BlockMode {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+		use BlockMode::*;
+
+		let suffix = match *self {
+			IncrementAfter  => "IA",
+			IncrementBefore => "IB",
+			DecrementAfter  => "DA",
+			DecrementBefore => "DB",
+		};
+
+		write!(f, "{suffix}")
+	}
+}
@@ -0,0 +1,209 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Pollex.
+//
+// Pollex is free software: you can redistribute it
+// and/or modify it under the terms of the GNU Af-
+// fero General Public License as published by the
+// Free Software Foundation, either version 3 of
+// the License, or (at your option) any later ver-
+// sion.
+//
+// Pollex is distributed in the hope that it will
+// be useful, but WITHOUT ANY WARRANTY; without
+// even the implied warranty of MERCHANTABILITY or
+// FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// Affero General Public License for more details.
+//
+// You should have received a copy of the GNU Af-
+// fero General Public License along with Pollex.
+// If not, see <https://www.gnu.org/licenses/>.
+
+use alloc::borrow::ToOwned;
+
+use crate::Error;
+use crate::arm32::CoprocessorRegister;
+
+use core::fmt::Display;
+use core::mem::transmute;
+use core::str::FromStr;
+
+/// An Arm coprocessor.
+///
+/// Coprocessors are addressed by their number, from `p0` to `p15`.
+/// Some numbers are reserved by the architecture, e.g.:
+///
+/// * `p10` and `p11` are used by the floating-point and vector extensions.
+/// * `p14` is used for debugging and trace.
+/// * `p15` is the system control coprocessor (caches, memory management, etc.).
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[repr(u8)]
+pub enum Coprocessor {
+	P0  = 0b0000,
+	P1  = 0b0001,
+	P2  = 0b0010,
+	P3  = 0b0011,
+	P4  = 0b0100,
+	P5  = 0b0101,
+	P6  = 0b0110,
+	P7  = 0b0111,
+	P8  = 0b1000,
+	P9  = 0b1001,
+	P10 = 0b1010,
+	P11 = 0b1011,
+	P12 = 0b1100,
+	P13 = 0b1101,
+	P14 = 0b1110,
+	P15 = 0b1111,
+}
+
+impl Coprocessor {
+	/// Converts the provided byte into a coprocessor identifier.
+	/// If the byte's value is not a valid identifier, [`None`] is returned.
+	///
+	/// This conversion is valid for all `4`-bit values.
+	#[inline]
+	#[must_use]
+	pub const fn from_u8(value: u8) -> Option<Self> {
+		if value <= 0b1111 {
+			Some(unsafe { transmute::<u8, Self>(value) })
+		} else {
+			None
+		}
+	}
+
+	/// Looks up the name of a well-known coprocessor register.
+	///
+	/// Registers are identified by the operands of `MCR` and `MRC`.
+	/// Currently, only common registers of the system control coprocessor (`p15`) are known; for any other combination, [`None`] is returned.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use pollex::arm32::{Coprocessor, CoprocessorRegister};
+	///
+	/// let name = Coprocessor::P15.register_name(0x0, CoprocessorRegister::C1, CoprocessorRegister::C0, 0x0);
+	/// assert_eq!(name, Some("SCTLR"));
+	/// ```
+	#[must_use]
+	pub const fn register_name(self, opcode1: u8, crn: CoprocessorRegister, crm: CoprocessorRegister, opcode2: u8) -> Option<&'static str> {
+		if !matches!(self, Self::P15) { return None };
+
+		let name = match (opcode1, crn as u8, crm as u8, opcode2) {
+			(0x0, 0x0, 0x0, 0x0) => "MIDR",
+			(0x0, 0x0, 0x0, 0x1) => "CTR",
+			(0x0, 0x0, 0x0, 0x2) => "TCMTR",
+			(0x0, 0x0, 0x0, 0x3) => "TLBTR",
+			(0x0, 0x0, 0x0, 0x5) => "MPIDR",
+			(0x0, 0x0, 0x0, 0x6) => "REVIDR",
+			(0x1, 0x0, 0x0, 0x0) => "CCSIDR",
+			(0x1, 0x0, 0x0, 0x1) => "CLIDR",
+			(0x1, 0x0, 0x0, 0x7) => "AIDR",
+			(0x2, 0x0, 0x0, 0x0) => "CSSELR",
+			(0x0, 0x1, 0x0, 0x0) => "SCTLR",
+			(0x0, 0x1, 0x0, 0x1) => "ACTLR",
+			(0x0, 0x1, 0x0, 0x2) => "CPACR",
+			(0x0, 0x1, 0x1, 0x0) => "SCR",
+			(0x0, 0x1, 0x1, 0x1) => "SDER",
+			(0x0, 0x1, 0x1, 0x2) => "NSACR",
+			(0x0, 0x2, 0x0, 0x0) => "TTBR0",
+			(0x0, 0x2, 0x0, 0x1) => "TTBR1",
+			(0x0, 0x2, 0x0, 0x2) => "TTBCR",
+			(0x0, 0x3, 0x0, 0x0) => "DACR",
+			(0x0, 0x5, 0x0, 0x0) => "DFSR",
+			(0x0, 0x5, 0x0, 0x1) => "IFSR",
+			(0x0, 0x6, 0x0, 0x0) => "DFAR",
+			(0x0, 0x6, 0x0, 0x2) => "IFAR",
+			(0x0, 0x7, 0x0, 0x4) => "WFI",
+			(0x0, 0x7, 0x1, 0x0) => "ICIALLUIS",
+			(0x0, 0x7, 0x1, 0x6) => "BPIALLIS",
+			(0x0, 0x7, 0x4, 0x0) => "PAR",
+			(0x0, 0x7, 0x5, 0x0) => "ICIALLU",
+			(0x0, 0x7, 0x5, 0x1) => "ICIMVAU",
+			(0x0, 0x7, 0x5, 0x4) => "CP15ISB",
+			(0x0, 0x7, 0x5, 0x6) => "BPIALL",
+			(0x0, 0x7, 0x5, 0x7) => "BPIMVA",
+			(0x0, 0x7, 0x6, 0x1) => "DCIMVAC",
+			(0x0, 0x7, 0x6, 0x2) => "DCISW",
+			(0x0, 0x7, 0xA, 0x1) => "DCCMVAC",
+			(0x0, 0x7, 0xA, 0x2) => "DCCSW",
+			(0x0, 0x7, 0xA, 0x4) => "CP15DSB",
+			(0x0, 0x7, 0xA, 0x5) => "CP15DMB",
+			(0x0, 0x7, 0xB, 0x1) => "DCCMVAU",
+			(0x0, 0x7, 0xE, 0x1) => "DCCIMVAC",
+			(0x0, 0x7, 0xE, 0x2) => "DCCISW",
+			(0x0, 0x8, 0x3, 0x0) => "TLBIALLIS",
+			(0x0, 0x8, 0x7, 0x0) => "TLBIALL",
+			(0x0, 0x8, 0x7, 0x1) => "TLBIMVA",
+			(0x0, 0x8, 0x7, 0x2) => "TLBIASID",
+			(0x0, 0xA, 0x2, 0x0) => "PRRR",
+			(0x0, 0xA, 0x2, 0x1) => "NMRR",
+			(0x0, 0xC, 0x0, 0x0) => "VBAR",
+			(0x0, 0xC, 0x0, 0x1) => "MVBAR",
+			(0x0, 0xC, 0x1, 0x0) => "ISR",
+			(0x0, 0xD, 0x0, 0x0) => "FCSEIDR",
+			(0x0, 0xD, 0x0, 0x1) => "CONTEXTIDR",
+			(0x0, 0xD, 0x0, 0x2) => "TPIDRURW",
+			(0x0, 0xD, 0x0, 0x3) => "TPIDRURO",
+			(0x0, 0xD, 0x0, 0x4) => "TPIDRPRW",
+
+			_ => return None,
+		};
+
+		Some(name)
+	}
+}
+
+impl Display for Coprocessor {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		use Coprocessor::*;
+
+		match *self {
+			P0     => write!(f, "p0"),
+			P1     => write!(f, "p1"),
+			P2     => write!(f, "p2"),
+			P3     => write!(f, "p3"),
+			P4     => write!(f, "p4"),
+			P5     => write!(f, "p5"),
+			P6     => write!(f, "p6"),
+			P7     => write!(f, "p7"),
+			P8     => write!(f, "p8"),
+			P9     => write!(f, "p9"),
+			P10    => write!(f, "p10"),
+			P11    => write!(f, "p11"),
+			P12    => write!(f, "p12"),
+			P13    => write!(f, "p13"),
+			P14    => write!(f, "p14"),
+			P15    => write!(f, "p15"),
+		}
+	}
+}
+
+impl FromStr for Coprocessor {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		use Coprocessor::*;
+
+		match s.to_lowercase().as_str() {
+			"p0"  => Ok(P0),
+			"p1"  => Ok(P1),
+			"p2"  => Ok(P2),
+			"p3"  => Ok(P3),
+			"p4"  => Ok(P4),
+			"p5"  => Ok(P5),
+			"p6"  => Ok(P6),
+			"p7"  => Ok(P7),
+			"p8"  => Ok(P8),
+			"p9"  => Ok(P9),
+			"p10" => Ok(P10),
+			"p11" => Ok(P11),
+			"p12" => Ok(P12),
+			"p13" => Ok(P13),
+			"p14" => Ok(P14),
+			"p15" => Ok(P15),
+
+			_ => Err(Error::UnknownRegister(s.to_owned()))
+		}
+	}
+}
@@ -0,0 +1,124 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Pollex.
+//
+// Pollex is free software: you can redistribute it
+// and/or modify it under the terms of the GNU Af-
+// fero General Public License as published by the
+// Free Software Foundation, either version 3 of
+// the License, or (at your option) any later ver-
+// sion.
+//
+// Pollex is distributed in the hope that it will
+// be useful, but WITHOUT ANY WARRANTY; without
+// even the implied warranty of MERCHANTABILITY or
+// FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// Affero General Public License for more details.
+//
+// You should have received a copy of the GNU Af-
+// fero General Public License along with Pollex.
+// If not, see <https://www.gnu.org/licenses/>.
+
+use alloc::borrow::ToOwned;
+
+use crate::Error;
+
+use core::fmt::Display;
+use core::mem::transmute;
+use core::str::FromStr;
+
+/// A coprocessor register.
+///
+/// These are the `CRd`, `CRn`, and `CRm` operands of coprocessor instructions, from `c0` to `c15`.
+/// Their meaning is entirely up to the coprocessor in question.
+/// See also [`Coprocessor::register_name`](crate::arm32::Coprocessor::register_name).
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[repr(u8)]
+pub enum CoprocessorRegister {
+	C0  = 0b0000,
+	C1  = 0b0001,
+	C2  = 0b0010,
+	C3  = 0b0011,
+	C4  = 0b0100,
+	C5  = 0b0101,
+	C6  = 0b0110,
+	C7  = 0b0111,
+	C8  = 0b1000,
+	C9  = 0b1001,
+	C10 = 0b1010,
+	C11 = 0b1011,
+	C12 = 0b1100,
+	C13 = 0b1101,
+	C14 = 0b1110,
+	C15 = 0b1111,
+}
+
+impl CoprocessorRegister {
+	/// Converts the provided byte into a register identifier.
+	/// If the byte's value is not a valid identifier, [`None`] is returned.
+	///
+	/// This conversion is valid for all `4`-bit values.
+	#[inline]
+	#[must_use]
+	pub const fn from_u8(value: u8) -> Option<Self> {
+		if value <= 0b1111 {
+			Some(unsafe { transmute::<u8, Self>(value) })
+		} else {
+			None
+		}
+	}
+}
+
+impl Display for CoprocessorRegister {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		use CoprocessorRegister::*;
+
+		match *self {
+			C0     => write!(f, "c0"),
+			C1     => write!(f, "c1"),
+			C2     => write!(f, "c2"),
+			C3     => write!(f, "c3"),
+			C4     => write!(f, "c4"),
+			C5     => write!(f, "c5"),
+			C6     => write!(f, "c6"),
+			C7     => write!(f, "c7"),
+			C8     => write!(f, "c8"),
+			C9     => write!(f, "c9"),
+			C10    => write!(f, "c10"),
+			C11    => write!(f, "c11"),
+			C12    => write!(f, "c12"),
+			C13    => write!(f, "c13"),
+			C14    => write!(f, "c14"),
+			C15    => write!(f, "c15"),
+		}
+	}
+}
+
+impl FromStr for CoprocessorRegister {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		use CoprocessorRegister::*;
+
+		match s.to_lowercase().as_str() {
+			"c0"  => Ok(C0),
+			"c1"  => Ok(C1),
+			"c2"  => Ok(C2),
+			"c3"  => Ok(C3),
+			"c4"  => Ok(C4),
+			"c5"  => Ok(C5),
+			"c6"  => Ok(C6),
+			"c7"  => Ok(C7),
+			"c8"  => Ok(C8),
+			"c9"  => Ok(C9),
+			"c10" => Ok(C10),
+			"c11" => Ok(C11),
+			"c12" => Ok(C12),
+			"c13" => Ok(C13),
+			"c14" => Ok(C14),
+			"c15" => Ok(C15),
+
+			_ => Err(Error::UnknownRegister(s.to_owned()))
+		}
+	}
+}
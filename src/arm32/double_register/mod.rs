@@ -0,0 +1,171 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Pollex.
+//
+// Pollex is free software: you can redistribute it
+// and/or modify it under the terms of the GNU Af-
+// fero General Public License as published by the
+// Free Software Foundation, either version 3 of
+// the License, or (at your option) any later ver-
+// sion.
+//
+// Pollex is distributed in the hope that it will
+// be useful, but WITHOUT ANY WARRANTY; without
+// even the implied warranty of MERCHANTABILITY or
+// FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// Affero General Public License for more details.
+//
+// You should have received a copy of the GNU Af-
+// fero General Public License along with Pollex.
+// If not, see <https://www.gnu.org/licenses/>.
+
+use alloc::borrow::ToOwned;
+
+use crate::Error;
+
+use core::fmt::Display;
+use core::mem::transmute;
+use core::str::FromStr;
+
+/// A double-precision floating-point register.
+///
+/// These are the 64-bit registers of the VFP extension, from `d0` to `d31`.
+/// Note that `d16` through `d31` are only available with VFPv3-D32 (or Advanced SIMD).
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[repr(u8)]
+pub enum DoubleRegister {
+	D0  = 0b00000,
+	D1  = 0b00001,
+	D2  = 0b00010,
+	D3  = 0b00011,
+	D4  = 0b00100,
+	D5  = 0b00101,
+	D6  = 0b00110,
+	D7  = 0b00111,
+	D8  = 0b01000,
+	D9  = 0b01001,
+	D10 = 0b01010,
+	D11 = 0b01011,
+	D12 = 0b01100,
+	D13 = 0b01101,
+	D14 = 0b01110,
+	D15 = 0b01111,
+	D16 = 0b10000,
+	D17 = 0b10001,
+	D18 = 0b10010,
+	D19 = 0b10011,
+	D20 = 0b10100,
+	D21 = 0b10101,
+	D22 = 0b10110,
+	D23 = 0b10111,
+	D24 = 0b11000,
+	D25 = 0b11001,
+	D26 = 0b11010,
+	D27 = 0b11011,
+	D28 = 0b11100,
+	D29 = 0b11101,
+	D30 = 0b11110,
+	D31 = 0b11111,
+}
+
+impl DoubleRegister {
+	/// Converts the provided byte into a register identifier.
+	/// If the byte's value is not a valid identifier, [`None`] is returned.
+	///
+	/// This conversion is valid for all `5`-bit values.
+	#[inline]
+	#[must_use]
+	pub const fn from_u8(value: u8) -> Option<Self> {
+		if value <= 0b11111 {
+			Some(unsafe { transmute::<u8, Self>(value) })
+		} else {
+			None
+		}
+	}
+}
+
+impl Display for DoubleRegister {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		use DoubleRegister::*;
+
+		match *self {
+			D0     => write!(f, "d0"),
+			D1     => write!(f, "d1"),
+			D2     => write!(f, "d2"),
+			D3     => write!(f, "d3"),
+			D4     => write!(f, "d4"),
+			D5     => write!(f, "d5"),
+			D6     => write!(f, "d6"),
+			D7     => write!(f, "d7"),
+			D8     => write!(f, "d8"),
+			D9     => write!(f, "d9"),
+			D10    => write!(f, "d10"),
+			D11    => write!(f, "d11"),
+			D12    => write!(f, "d12"),
+			D13    => write!(f, "d13"),
+			D14    => write!(f, "d14"),
+			D15    => write!(f, "d15"),
+			D16    => write!(f, "d16"),
+			D17    => write!(f, "d17"),
+			D18    => write!(f, "d18"),
+			D19    => write!(f, "d19"),
+			D20    => write!(f, "d20"),
+			D21    => write!(f, "d21"),
+			D22    => write!(f, "d22"),
+			D23    => write!(f, "d23"),
+			D24    => write!(f, "d24"),
+			D25    => write!(f, "d25"),
+			D26    => write!(f, "d26"),
+			D27    => write!(f, "d27"),
+			D28    => write!(f, "d28"),
+			D29    => write!(f, "d29"),
+			D30    => write!(f, "d30"),
+			D31    => write!(f, "d31"),
+		}
+	}
+}
+
+impl FromStr for DoubleRegister {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		use DoubleRegister::*;
+
+		match s.to_lowercase().as_str() {
+			"d0"  => Ok(D0),
+			"d1"  => Ok(D1),
+			"d2"  => Ok(D2),
+			"d3"  => Ok(D3),
+			"d4"  => Ok(D4),
+			"d5"  => Ok(D5),
+			"d6"  => Ok(D6),
+			"d7"  => Ok(D7),
+			"d8"  => Ok(D8),
+			"d9"  => Ok(D9),
+			"d10" => Ok(D10),
+			"d11" => Ok(D11),
+			"d12" => Ok(D12),
+			"d13" => Ok(D13),
+			"d14" => Ok(D14),
+			"d15" => Ok(D15),
+			"d16" => Ok(D16),
+			"d17" => Ok(D17),
+			"d18" => Ok(D18),
+			"d19" => Ok(D19),
+			"d20" => Ok(D20),
+			"d21" => Ok(D21),
+			"d22" => Ok(D22),
+			"d23" => Ok(D23),
+			"d24" => Ok(D24),
+			"d25" => Ok(D25),
+			"d26" => Ok(D26),
+			"d27" => Ok(D27),
+			"d28" => Ok(D28),
+			"d29" => Ok(D29),
+			"d30" => Ok(D30),
+			"d31" => Ok(D31),
+
+			_ => Err(Error::UnknownRegister(s.to_owned()))
+		}
+	}
+}
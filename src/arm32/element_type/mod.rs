@@ -0,0 +1,168 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Pollex.
+//
+// Pollex is free software: you can redistribute it
+// and/or modify it under the terms of the GNU Af-
+// fero General Public License as published by the
+// Free Software Foundation, either version 3 of
+// the License, or (at your option) any later ver-
+// sion.
+//
+// Pollex is distributed in the hope that it will
+// be useful, but WITHOUT ANY WARRANTY; without
+// even the implied warranty of MERCHANTABILITY or
+// FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// Affero General Public License for more details.
+//
+// You should have received a copy of the GNU Af-
+// fero General Public License along with Pollex.
+// If not, see <https://www.gnu.org/licenses/>.
+
+use core::fmt::{Display, Formatter};
+
+/// An element type of an Advanced SIMD instruction.
+///
+/// This is the data type qualifier of the instruction (e.g. the `.I16` in `VADD.I16`).
+/// Besides the typed integers (`I`), signed integers (`S`), unsigned integers (`U`), floats (`F`), and polynomials (`P`), instructions that only care about the size of their elements use the untyped variants.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ElementType {
+	I8,
+	I16,
+	I32,
+	I64,
+
+	S8,
+	S16,
+	S32,
+	S64,
+
+	U8,
+	U16,
+	U32,
+	U64,
+
+	F32,
+
+	P8,
+
+	Untyped8,
+	Untyped16,
+	Untyped32,
+	Untyped64,
+}
+
+impl ElementType {
+	/// Gets the size of the element in bits.
+	#[inline]
+	#[must_use]
+	pub const fn size(self) -> u32 {
+		use ElementType::*;
+
+		match self {
+			I8  | S8  | U8  | P8  | Untyped8  => 0x8,
+			I16 | S16 | U16       | Untyped16 => 0x10,
+			I32 | S32 | U32 | F32 | Untyped32 => 0x20,
+			I64 | S64 | U64       | Untyped64 => 0x40,
+		}
+	}
+
+	/// Checks if the element is a signed integer.
+	#[inline(always)]
+	#[must_use]
+	pub const fn is_signed(self) -> bool { matches!(self, Self::S8 | Self::S16 | Self::S32 | Self::S64) }
+
+	/// Checks if the element is an unsigned integer.
+	#[inline(always)]
+	#[must_use]
+	pub const fn is_unsigned(self) -> bool { matches!(self, Self::U8 | Self::U16 | Self::U32 | Self::U64) }
+
+	/// Checks if the element is any integer, i.e. either typed, signed, or unsigned.
+	#[inline(always)]
+	#[must_use]
+	pub const fn is_integer(self) -> bool { matches!(self, Self::I8 | Self::I16 | Self::I32 | Self::I64) || self.is_signed() || self.is_unsigned() }
+
+	/// Checks if the element is a float.
+	#[inline(always)]
+	#[must_use]
+	pub const fn is_float(self) -> bool { matches!(self, Self::F32) }
+
+	/// Constructs a typed integer element of the given size.
+	///
+	/// If the size is not any of `8`, `16`, `32`, or `64`, [`None`] is returned.
+	#[inline]
+	#[must_use]
+	pub const fn integer(size: u32) -> Option<Self> {
+		match size {
+			0x8  => Some(Self::I8),
+			0x10 => Some(Self::I16),
+			0x20 => Some(Self::I32),
+			0x40 => Some(Self::I64),
+			_    => None,
+		}
+	}
+
+	/// Constructs a signed or unsigned integer element of the given size.
+	///
+	/// If the size is not any of `8`, `16`, `32`, or `64`, [`None`] is returned.
+	#[inline]
+	#[must_use]
+	pub const fn signed(size: u32, unsigned: bool) -> Option<Self> {
+		match (size, unsigned) {
+			(0x8,  false) => Some(Self::S8),
+			(0x10, false) => Some(Self::S16),
+			(0x20, false) => Some(Self::S32),
+			(0x40, false) => Some(Self::S64),
+			(0x8,  true)  => Some(Self::U8),
+			(0x10, true)  => Some(Self::U16),
+			(0x20, true)  => Some(Self::U32),
+			(0x40, true)  => Some(Self::U64),
+			_             => None,
+		}
+	}
+
+	/// Constructs an untyped element of the given size.
+	///
+	/// If the size is not any of `8`, `16`, `32`, or `64`, [`None`] is returned.
+	#[inline]
+	#[must_use]
+	pub const fn untyped(size: u32) -> Option<Self> {
+		match size {
+			0x8  => Some(Self::Untyped8),
+			0x10 => Some(Self::Untyped16),
+			0x20 => Some(Self::Untyped32),
+			0x40 => Some(Self::Untyped64),
+			_    => None,
+		}
+	}
+}
+
+impl Display for ElementType {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+		use ElementType::*;
+
+		let name = match *self {
+			I8        => "I8",
+			I16       => "I16",
+			I32       => "I32",
+			I64       => "I64",
+			S8        => "S8",
+			S16       => "S16",
+			S32       => "S32",
+			S64       => "S64",
+			U8        => "U8",
+			U16       => "U16",
+			U32       => "U32",
+			U64       => "U64",
+			F32       => "F32",
+			P8        => "P8",
+			Untyped8  => "8",
+			Untyped16 => "16",
+			Untyped32 => "32",
+			Untyped64 => "64",
+		};
+
+		write!(f, "{name}")
+	}
+}
@@ -0,0 +1,57 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Pollex.
+//
+// Pollex is free software: you can redistribute it
+// and/or modify it under the terms of the GNU Af-
+// fero General Public License as published by the
+// Free Software Foundation, either version 3 of
+// the License, or (at your option) any later ver-
+// sion.
+//
+// Pollex is distributed in the hope that it will
+// be useful, but WITHOUT ANY WARRANTY; without
+// even the implied warranty of MERCHANTABILITY or
+// FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// Affero General Public License for more details.
+//
+// You should have received a copy of the GNU Af-
+// fero General Public License along with Pollex.
+// If not, see <https://www.gnu.org/licenses/>.
+
+use core::fmt::{Display, Formatter};
+
+/// A data endianness.
+///
+/// Data accesses may be switched between little- and big-endian using `SETEND`:
+///
+/// ```as
+/// SETEND BE
+/// ```
+///
+/// Instruction fetches are not affected.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[repr(u8)]
+pub enum Endianness {
+	Little = 0b0,
+	Big    = 0b1,
+}
+
+impl Endianness {
+	/// Checks if the endianness is [`Big`](Endianness::Big).
+	#[inline(always)]
+	#[must_use]
+	pub const fn is_big(self) -> bool { matches!(self, Self::Big) }
+}
+
+impl Display for Endianness {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+		let name = match *self {
+			Self::Little => "LE",
+			Self::Big    => "BE",
+		};
+
+		write!(f, "{name}")
+	}
+}
@@ -118,6 +118,27 @@ define_flag! {
 	doc:    "A B flag.\n\nThis indicates binary operations on some memory instructions.\n"
 }
 
+define_flag! {
+	vis:    pub,
+	name:   Eflag,
+	symbol: "E",
+	doc:    "An E flag.\n\nThis indicates raising exceptions on quiet NaNs on floating-point comparisons.\n"
+}
+
+define_flag! {
+	vis:    pub,
+	name:   Lflag,
+	symbol: "L",
+	doc:    "An L flag.\n\nThis indicates long transfers on coprocessor memory instructions.\n"
+}
+
+define_flag! {
+	vis:    pub,
+	name:   Rflag,
+	symbol: "R",
+	doc:    "An R flag.\n\nThis indicates rounding by the `FPSCR` rounding mode on floating-point-to-integer conversions.\n"
+}
+
 define_flag! {
 	vis:    pub,
 	name:   Sflag,
@@ -131,3 +152,10 @@ define_flag! {
 	symbol: "T",
 	doc:    "An T flag.\n\nThis indicates translation on some memory instructions.\n"
 }
+
+define_flag! {
+	vis:    pub,
+	name:   Wflag,
+	symbol: "!",
+	doc:    "A W flag.\n\nThis indicates writeback of the base register on some memory instructions.\n"
+}
@@ -0,0 +1,122 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Pollex.
+//
+// Pollex is free software: you can redistribute it
+// and/or modify it under the terms of the GNU Af-
+// fero General Public License as published by the
+// Free Software Foundation, either version 3 of
+// the License, or (at your option) any later ver-
+// sion.
+//
+// Pollex is distributed in the hope that it will
+// be useful, but WITHOUT ANY WARRANTY; without
+// even the implied warranty of MERCHANTABILITY or
+// FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// Affero General Public License for more details.
+//
+// You should have received a copy of the GNU Af-
+// fero General Public License along with Pollex.
+// If not, see <https://www.gnu.org/licenses/>.
+
+use core::fmt::{Display, Formatter};
+
+/// A VFPv3 floating-point immediate.
+///
+/// `VMOV` can only load immediates of the form ±*n* × 2<sup>*e*</sup>, with *n* in `16..=31` and *e* in `-7..=0` (relative to a mantissa of `16`).
+/// These are encoded as eight bits, `abcdefgh`, which this type stores directly.
+/// The value may be expanded to both single and double precision.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct FloatImmediate(u8);
+
+impl FloatImmediate {
+	/// Constructs a new immediate from its encoding.
+	#[inline(always)]
+	#[must_use]
+	pub const fn new(value: u8) -> Self { Self(value) }
+
+	/// Converts a single-precision value to an immediate.
+	///
+	/// If the value cannot be encoded, [`None`] is returned.
+	#[must_use]
+	pub const fn from_f32(value: f32) -> Option<Self> {
+		let bits = value.to_bits();
+
+		let b = bits >> 0x1D & 0b1;
+
+		let exponent = bits >> 0x19 & 0b11111;
+		let expected = if b == 0b1 { 0b11111 } else { 0b00000 };
+
+		if bits & 0x0007FFFF != 0x0 || exponent != expected || bits >> 0x1E & 0b1 == b {
+			return None;
+		}
+
+		let value = (bits >> 0x18 & 0b10000000) | (bits >> 0x13 & 0b01111111);
+		Some(Self(value as u8))
+	}
+
+	/// Converts a double-precision value to an immediate.
+	///
+	/// If the value cannot be encoded, [`None`] is returned.
+	#[must_use]
+	pub const fn from_f64(value: f64) -> Option<Self> {
+		let bits = value.to_bits();
+
+		let b = bits >> 0x3D & 0b1;
+
+		let exponent = bits >> 0x36 & 0b11111111;
+		let expected = if b == 0b1 { 0b11111111 } else { 0b00000000 };
+
+		if bits & 0x0000FFFFFFFFFFFF != 0x0 || exponent != expected || bits >> 0x3E & 0b1 == b {
+			return None;
+		}
+
+		let value = (bits >> 0x38 & 0b10000000) | (bits >> 0x30 & 0b01111111);
+		Some(Self(value as u8))
+	}
+
+	/// Retrieves the encoding of the immediate.
+	#[inline(always)]
+	#[must_use]
+	pub const fn to_u8(self) -> u8 { self.0 }
+
+	/// Expands the immediate to single precision.
+	#[must_use]
+	pub const fn to_f32(self) -> f32 {
+		let value = self.0 as u32;
+
+		let b = value >> 0x6 & 0b1;
+
+		let mut bits = (value & 0b10000000) << 0x18;
+		bits |= (b ^ 0b1) << 0x1E;
+		if b == 0b1 { bits |= 0b11111 << 0x19 };
+		bits |= (value & 0b00111111) << 0x13;
+
+		f32::from_bits(bits)
+	}
+
+	/// Expands the immediate to double precision.
+	#[must_use]
+	pub const fn to_f64(self) -> f64 {
+		let value = self.0 as u64;
+
+		let b = value >> 0x6 & 0b1;
+
+		let mut bits = (value & 0b10000000) << 0x38;
+		bits |= (b ^ 0b1) << 0x3E;
+		if b == 0b1 { bits |= 0b11111111 << 0x36 };
+		bits |= (value & 0b00111111) << 0x30;
+
+		f64::from_bits(bits)
+	}
+}
+
+impl Display for FloatImmediate {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+		// Every immediate is exactly representable in
+		// both precisions, so we can always use `f64`.
+		write!(f, "{:?}", self.to_f64())
+	}
+}
@@ -0,0 +1,55 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Pollex.
+//
+// Pollex is free software: you can redistribute it
+// and/or modify it under the terms of the GNU Af-
+// fero General Public License as published by the
+// Free Software Foundation, either version 3 of
+// the License, or (at your option) any later ver-
+// sion.
+//
+// Pollex is distributed in the hope that it will
+// be useful, but WITHOUT ANY WARRANTY; without
+// even the implied warranty of MERCHANTABILITY or
+// FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// Affero General Public License for more details.
+//
+// You should have received a copy of the GNU Af-
+// fero General Public License along with Pollex.
+// If not, see <https://www.gnu.org/licenses/>.
+
+/// An indexing mode of an address.
+///
+/// Addresses can either be used as-is (*offset* addressing), or they can write the computed address back to the base register.
+/// The latter is done either before (*pre-indexed*) or after (*post-indexed*) the memory access:
+///
+/// ```as
+/// LDR r0, [r1, #4]  ; Offset
+/// LDR r0, [r1, #4]! ; Pre-indexed
+/// LDR r0, [r1], #4  ; Post-indexed
+/// ```
+///
+/// Thus, both pre- and post-indexing imply writeback.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Indexing {
+	Offset,
+
+	PreIndexed,
+
+	PostIndexed,
+}
+
+impl Indexing {
+	/// Checks if the indexing mode writes the address back to the base register.
+	#[inline(always)]
+	#[must_use]
+	pub const fn has_writeback(self) -> bool { !matches!(self, Self::Offset) }
+
+	/// Checks if the offset is applied before the memory access.
+	///
+	/// This is true for both offset and pre-indexed addressing.
+	#[inline(always)]
+	#[must_use]
+	pub const fn is_pre_indexed(self) -> bool { !matches!(self, Self::PostIndexed) }
+}
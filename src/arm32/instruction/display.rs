@@ -19,7 +19,16 @@
 // fero General Public License along with Pollex.
 // If not, see <https://www.gnu.org/licenses/>.
 
-use crate::arm32::{Sflag, Instruction, Shifter};
+use crate::arm32::{
+	BlockMode,
+	Instruction,
+	Predicate,
+	Register,
+	Sflag,
+	Shifter,
+	VfpRegister,
+	Wflag,
+};
 
 use core::fmt::Display;
 
@@ -60,6 +69,21 @@ impl Display for Instruction {
 				s,
 			} => write!(f, "BIC{predicate}{s} {destination}, {base}, {source}"),
 
+			BitFieldClear {
+				predicate,
+				destination,
+				lsb,
+				width,
+			} => write!(f, "BFC{predicate} {destination}, #{lsb}, #{width}"),
+
+			BitFieldInsert {
+				predicate,
+				destination,
+				source,
+				lsb,
+				width,
+			} => write!(f, "BFI{predicate} {destination}, {source}, #{lsb}, #{width}"),
+
 			Branch {
 				predicate,
 				immediate,
@@ -83,6 +107,20 @@ impl Display for Instruction {
 			Breakpoint {
 				immediate } => write!(f, "BKPT #{immediate}"),
 
+			ChangeProcessorMode { mode } => write!(f, "CPS #{}", mode as u8),
+
+			ClearExclusive => write!(f, "CLREX"),
+
+			CoprocessorDataProcessing {
+				predicate,
+				coprocessor,
+				opcode1,
+				crd,
+				crn,
+				crm,
+				opcode2,
+			} => write!(f, "CDP{predicate} {coprocessor}, {opcode1}, {crd}, {crn}, {crm}, {opcode2}"),
+
 			CountLeadingZeroes {
 				predicate,
 				destination,
@@ -95,12 +133,46 @@ impl Display for Instruction {
 				rhs,
 			} => write!(f, "CMP{predicate} {lhs}, {rhs}"),
 
+			CompareBranchNonZero {
+				source,
+				immediate,
+			} => write!(f, "CBNZ {source}, #{immediate}"),
+
+			CompareBranchZero {
+				source,
+				immediate,
+			} => write!(f, "CBZ {source}, #{immediate}"),
+
 			CompareNegated {
 				predicate,
 				lhs,
 				rhs,
 			} => write!(f, "CMN{predicate} {lhs}, {rhs}"),
 
+			DataMemoryBarrier { option } => write!(f, "DMB {option}"),
+
+			DataSynchronisationBarrier { option } => write!(f, "DSB {option}"),
+
+			DisableInterrupts {
+				mask,
+				mode,
+			} => {
+				write!(f, "CPSID {mask}")?;
+				if let Some(mode) = mode { write!(f, ", #{}", mode as u8)? };
+
+				Ok(())
+			},
+
+			EnableInterrupts {
+				mask,
+				mode,
+			} => {
+				write!(f, "CPSIE {mask}")?;
+				if let Some(mode) = mode { write!(f, ", #{}", mode as u8)? };
+
+				Ok(())
+			},
+
 			ExclusiveOr {
 				predicate,
 				destination,
@@ -109,6 +181,175 @@ impl Display for Instruction {
 				s,
 			} => write!(f, "EOR{predicate}{s} {destination}, {base}, {source}"),
 
+			FloatAbsolute {
+				predicate,
+				destination,
+				source,
+			} => write!(f, "VABS{predicate}.{} {destination}, {source}", precision(destination)),
+
+			FloatAdd {
+				predicate,
+				destination,
+				base,
+				source,
+			} => write!(f, "VADD{predicate}.{} {destination}, {base}, {source}", precision(destination)),
+
+			FloatCompare {
+				predicate,
+				lhs,
+				rhs,
+				e,
+			} => {
+				write!(f, "VCMP{e}{predicate}.{} {lhs}, ", precision(lhs))?;
+
+				match rhs {
+					Some(rhs) => write!(f, "{rhs}"),
+					None      => write!(f, "#0.0"),
+				}
+			},
+
+			FloatConvert {
+				predicate,
+				destination,
+				source,
+			} => write!(f, "VCVT{predicate}.{}.{} {destination}, {source}", precision(destination), precision(source)),
+
+			FloatDivide {
+				predicate,
+				destination,
+				base,
+				source,
+			} => write!(f, "VDIV{predicate}.{} {destination}, {base}, {source}", precision(destination)),
+
+			FloatLoad {
+				predicate,
+				register,
+				address,
+			} => write!(f, "VLDR{predicate} {register}, {address}"),
+
+			FloatLoadMultiple {
+				predicate,
+				base:      Register::Sp,
+				first,
+				count,
+				mode:      BlockMode::IncrementAfter,
+				w:         Wflag::On,
+			} => {
+				write!(f, "VPOP{predicate} ")?;
+				write_vfp_list(f, first, count)
+			},
+
+			FloatLoadMultiple {
+				predicate,
+				base,
+				first,
+				count,
+				mode,
+				w,
+			} => {
+				write!(f, "VLDM{mode}{predicate} {base}{w}, ")?;
+				write_vfp_list(f, first, count)
+			},
+
+			FloatMove {
+				predicate,
+				destination,
+				source,
+			} => write!(f, "VMOV{predicate}.{} {destination}, {source}", precision(destination)),
+
+			FloatMoveImmediate {
+				predicate,
+				destination,
+				immediate,
+			} => write!(f, "VMOV{predicate}.{} {destination}, #{immediate}", precision(destination)),
+
+			FloatMultiply {
+				predicate,
+				destination,
+				base,
+				source,
+			} => write!(f, "VMUL{predicate}.{} {destination}, {base}, {source}", precision(destination)),
+
+			FloatNegate {
+				predicate,
+				destination,
+				source,
+			} => write!(f, "VNEG{predicate}.{} {destination}, {source}", precision(destination)),
+
+			FloatSquareRoot {
+				predicate,
+				destination,
+				source,
+			} => write!(f, "VSQRT{predicate}.{} {destination}, {source}", precision(destination)),
+
+			FloatStore {
+				predicate,
+				register,
+				address,
+			} => write!(f, "VSTR{predicate} {register}, {address}"),
+
+			FloatStoreMultiple {
+				predicate,
+				base:      Register::Sp,
+				first,
+				count,
+				mode:      BlockMode::DecrementBefore,
+				w:         Wflag::On,
+			} => {
+				write!(f, "VPUSH{predicate} ")?;
+				write_vfp_list(f, first, count)
+			},
+
+			FloatStoreMultiple {
+				predicate,
+				base,
+				first,
+				count,
+				mode,
+				w,
+			} => {
+				write!(f, "VSTM{mode}{predicate} {base}{w}, ")?;
+				write_vfp_list(f, first, count)
+			},
+
+			FloatSubtract {
+				predicate,
+				destination,
+				base,
+				source,
+			} => write!(f, "VSUB{predicate}.{} {destination}, {base}, {source}", precision(destination)),
+
+			FloatToSigned {
+				predicate,
+				destination,
+				source,
+				r,
+			} => write!(f, "VCVT{r}{predicate}.S32.{} {destination}, {source}", precision(source)),
+
+			FloatToUnsigned {
+				predicate,
+				destination,
+				source,
+				r,
+			} => write!(f, "VCVT{r}{predicate}.U32.{} {destination}, {source}", precision(source)),
+
+			IfThen {
+				predicate,
+				mask,
+			} => {
+				write!(f, "IT")?;
+
+				// The block ends just before the lowest set bit.
+				for bit in (mask.trailing_zeros() + 0x1..0x4).rev() {
+					write!(f, "{}", if mask & (0x1 << bit) != 0x0 { "T" } else { "E" })?;
+				}
+
+				match predicate {
+					Predicate::Always => write!(f, " AL"),
+					predicate         => write!(f, " {predicate}"),
+				}
+			},
+
 			InclusiveOr {
 				predicate,
 				destination,
@@ -117,6 +358,16 @@ impl Display for Instruction {
 				s,
 			} => write!(f, "ORR{predicate}{s} {destination}, {base}, {source}"),
 
+			InclusiveOrNot {
+				predicate,
+				destination,
+				base,
+				source,
+				s,
+			} => write!(f, "ORN{predicate}{s} {destination}, {base}, {source}"),
+
+			InstructionSynchronisationBarrier { option } => write!(f, "ISB {option}"),
+
 			Load {
 				predicate,
 				register,
@@ -125,6 +376,87 @@ impl Display for Instruction {
 				t,
 			} => write!(f, "LDR{predicate}{b}{t} {register}, {address}"),
 
+			LoadCoprocessor {
+				predicate,
+				coprocessor,
+				crd,
+				address,
+				l,
+			} => write!(f, "LDC{predicate}{l} {coprocessor}, {crd}, {address}"),
+
+			LoadDoubleword {
+				predicate,
+				first,
+				second,
+				address,
+			} => write!(f, "LDR{predicate}D {first}, {second}, {address}"),
+
+			LoadExclusive {
+				predicate,
+				register,
+				address,
+			} => write!(f, "LDREX{predicate} {register}, {address}"),
+
+			LoadExclusiveByte {
+				predicate,
+				register,
+				address,
+			} => write!(f, "LDREXB{predicate} {register}, {address}"),
+
+			LoadExclusiveDoubleword {
+				predicate,
+				first,
+				second,
+				address,
+			} => write!(f, "LDREXD{predicate} {first}, {second}, {address}"),
+
+			LoadExclusiveHalfword {
+				predicate,
+				register,
+				address,
+			} => write!(f, "LDREXH{predicate} {register}, {address}"),
+
+			LoadHalfword {
+				predicate,
+				register,
+				address,
+			} => write!(f, "LDR{predicate}H {register}, {address}"),
+
+			LoadMultiple {
+				predicate,
+				base:      Register::Sp,
+				registers,
+				mode:      BlockMode::IncrementAfter,
+				w:         Wflag::On,
+				s:         Sflag::Off,
+			} => write!(f, "POP{predicate} {registers}"),
+
+			LoadMultiple {
+				predicate,
+				base,
+				registers,
+				mode,
+				w,
+				s,
+			} => {
+				write!(f, "LDM{predicate}{mode} {base}{w}, {registers}")?;
+				if s.is_on() { write!(f, "^")? };
+
+				Ok(())
+			},
+
+			LoadSignedByte {
+				predicate,
+				register,
+				address,
+			} => write!(f, "LDR{predicate}SB {register}, {address}"),
+
+			LoadSignedHalfword {
+				predicate,
+				register,
+				address,
+			} => write!(f, "LDR{predicate}SH {register}, {address}"),
+
 			Move {
 				predicate,
 				destination,
@@ -174,6 +506,13 @@ impl Display for Instruction {
 				s,
 			} => write!(f, "LSR{predicate}{s} {destination}, {source}, {shift}"),
 
+			Move {
+				predicate,
+				destination,
+				source:      Shifter::RotateRightExtend { source },
+				s,
+			} => write!(f, "RRX{predicate}{s} {destination}, {source}"),
+
 			Move {
 				predicate,
 				destination,
@@ -195,6 +534,67 @@ impl Display for Instruction {
 				s,
 			} => write!(f, "MOV{predicate}{s} {destination}, {source}"),
 
+			MoveFromCoprocessor {
+				predicate,
+				coprocessor,
+				opcode1,
+				register,
+				crn,
+				crm,
+				opcode2,
+			} => {
+				write!(f, "MRC{predicate} {coprocessor}, {opcode1}, {register}, {crn}, {crm}, {opcode2}")?;
+
+				if let Some(name) = coprocessor.register_name(opcode1, crn, crm, opcode2) { write!(f, " ; {name}")? };
+
+				Ok(())
+			},
+
+			MoveFromCoprocessorDouble {
+				predicate,
+				coprocessor,
+				opcode1,
+				first,
+				second,
+				crm,
+			} => write!(f, "MRRC{predicate} {coprocessor}, {opcode1}, {first}, {second}, {crm}"),
+
+			MoveFromFloat {
+				predicate,
+				destination,
+				source,
+			} => write!(f, "VMOV{predicate} {destination}, {source}"),
+
+			MoveFromFloatDouble {
+				predicate,
+				low,
+				high,
+				source,
+			} => write!(f, "VMOV{predicate} {low}, {high}, {source}"),
+
+			MoveFromFloatStatus {
+				predicate,
+				destination: Register::Pc,
+			} => write!(f, "VMRS{predicate} APSR_nzcv, FPSCR"),
+
+			MoveFromFloatStatus {
+				predicate,
+				destination,
+			} => write!(f, "VMRS{predicate} {destination}, FPSCR"),
+
+			MoveFromScalar {
+				predicate,
+				element,
+				destination,
+				source,
+			} => write!(f, "VMOV{predicate}.{element} {destination}, {source}"),
+
+			MoveFromStatusRegister {
+				predicate,
+				destination,
+				source,
+			} => write!(f, "MRS{predicate} {destination}, {source}"),
+
 			MoveNot {
 				predicate,
 				destination,
@@ -202,6 +602,75 @@ impl Display for Instruction {
 				s,
 			} => write!(f, "MVN{predicate}{s} {destination}, {source}"),
 
+			MoveToCoprocessor {
+				predicate,
+				coprocessor,
+				opcode1,
+				register,
+				crn,
+				crm,
+				opcode2,
+			} => {
+				write!(f, "MCR{predicate} {coprocessor}, {opcode1}, {register}, {crn}, {crm}, {opcode2}")?;
+
+				if let Some(name) = coprocessor.register_name(opcode1, crn, crm, opcode2) { write!(f, " ; {name}")? };
+
+				Ok(())
+			},
+
+			MoveToCoprocessorDouble {
+				predicate,
+				coprocessor,
+				opcode1,
+				first,
+				second,
+				crm,
+			} => write!(f, "MCRR{predicate} {coprocessor}, {opcode1}, {first}, {second}, {crm}"),
+
+			MoveToFloat {
+				predicate,
+				destination,
+				source,
+			} => write!(f, "VMOV{predicate} {destination}, {source}"),
+
+			MoveToFloatDouble {
+				predicate,
+				destination,
+				low,
+				high,
+			} => write!(f, "VMOV{predicate} {destination}, {low}, {high}"),
+
+			MoveToFloatStatus {
+				predicate,
+				source,
+			} => write!(f, "VMSR{predicate} FPSCR, {source}"),
+
+			MoveToScalar {
+				predicate,
+				element,
+				destination,
+				source,
+			} => write!(f, "VMOV{predicate}.{element} {destination}, {source}"),
+
+			MoveToStatusRegister {
+				predicate,
+				destination,
+				fields,
+				source,
+			} => write!(f, "MSR{predicate} {destination}_{fields}, {source}"),
+
+			MoveTop {
+				predicate,
+				destination,
+				immediate,
+			} => write!(f, "MOVT{predicate} {destination}, #{immediate}"),
+
+			MoveWide {
+				predicate,
+				destination,
+				immediate,
+			} => write!(f, "MOVW{predicate} {destination}, #{immediate}"),
+
 			Multiply {
 				predicate,
 				destination,
@@ -215,9 +684,44 @@ impl Display for Instruction {
 				destination,
 				base,
 				source,
-				shift,
+				accumulator,
 				s,
-			} => write!(f, "MLA{predicate}{s} {destination}, {base}, {source}, {shift}"),
+			} => write!(f, "MLA{predicate}{s} {destination}, {base}, {source}, {accumulator}"),
+
+			MultiplySubtract {
+				predicate,
+				destination,
+				base,
+				source,
+				accumulator,
+			} => write!(f, "MLS{predicate} {destination}, {base}, {source}, {accumulator}"),
+
+			NoOperation { predicate } => write!(f, "NOP{predicate}"),
+
+			PackHalfword {
+				predicate,
+				destination,
+				base,
+				source: source @ Shifter::ArithmeticShiftRightImmediate { .. },
+			} => write!(f, "PKHTB{predicate} {destination}, {base}, {source}"),
+
+			PackHalfword {
+				predicate,
+				destination,
+				base,
+				source,
+			} => write!(f, "PKHBT{predicate} {destination}, {base}, {source}"),
+
+			ParallelArithmetic {
+				predicate,
+				prefix,
+				operation,
+				destination,
+				base,
+				source,
+			} => write!(f, "{prefix}{operation}{predicate} {destination}, {base}, {source}"),
+
+			PreloadData { address } => write!(f, "PLD {address}"),
 
 			Reverse {
 				predicate,
@@ -225,6 +729,24 @@ impl Display for Instruction {
 				source,
 			} => write!(f, "REV{predicate} {destination}, {source}"),
 
+			ReverseBits {
+				predicate,
+				destination,
+				source,
+			} => write!(f, "RBIT{predicate} {destination}, {source}"),
+
+			ReversePackedHalfword {
+				predicate,
+				destination,
+				source,
+			} => write!(f, "REV16{predicate} {destination}, {source}"),
+
+			ReverseSignedHalfword {
+				predicate,
+				destination,
+				source,
+			} => write!(f, "REVSH{predicate} {destination}, {source}"),
+
 			ReverseSubtract {
 				predicate,
 				destination,
@@ -256,17 +778,170 @@ impl Display for Instruction {
 				source,
 			} => write!(f, "QADD{predicate} {destination}, {base}, {source}"),
 
-			SaturatingSubtract {
+			SaturatingDoubleAdd {
 				predicate,
 				destination,
 				base,
 				source,
-			} => write!(f, "QSUB{predicate} {destination}, {base}, {source}"),
+			} => write!(f, "QDADD{predicate} {destination}, {base}, {source}"),
 
-			SoftwareInterrupt {
+			SaturatingDoubleSubtract {
 				predicate,
-				immediate,
-			} => write!(f, "SWI{predicate} #{immediate}"),
+				destination,
+				base,
+				source,
+			} => write!(f, "QDSUB{predicate} {destination}, {base}, {source}"),
+
+			SaturatingSubtract {
+				predicate,
+				destination,
+				base,
+				source,
+			} => write!(f, "QSUB{predicate} {destination}, {base}, {source}"),
+
+			Select {
+				predicate,
+				destination,
+				base,
+				source,
+			} => write!(f, "SEL{predicate} {destination}, {base}, {source}"),
+
+			SendEvent { predicate } => write!(f, "SEV{predicate}"),
+
+			SetEndianness { endianness } => write!(f, "SETEND {endianness}"),
+
+			SignExtendAddByte {
+				predicate,
+				destination,
+				base,
+				source,
+				rotation,
+			} => {
+				write!(f, "SXTAB{predicate} {destination}, {base}, {source}")?;
+				if rotation != 0x0 { write!(f, ", ROR #{rotation}")? };
+
+				Ok(())
+			},
+
+			SignExtendAddByte16 {
+				predicate,
+				destination,
+				base,
+				source,
+				rotation,
+			} => {
+				write!(f, "SXTAB16{predicate} {destination}, {base}, {source}")?;
+				if rotation != 0x0 { write!(f, ", ROR #{rotation}")? };
+
+				Ok(())
+			},
+
+			SignExtendAddHalfword {
+				predicate,
+				destination,
+				base,
+				source,
+				rotation,
+			} => {
+				write!(f, "SXTAH{predicate} {destination}, {base}, {source}")?;
+				if rotation != 0x0 { write!(f, ", ROR #{rotation}")? };
+
+				Ok(())
+			},
+
+			SignExtendByte {
+				predicate,
+				destination,
+				source,
+				rotation,
+			} => {
+				write!(f, "SXTB{predicate} {destination}, {source}")?;
+				if rotation != 0x0 { write!(f, ", ROR #{rotation}")? };
+
+				Ok(())
+			},
+
+			SignExtendByte16 {
+				predicate,
+				destination,
+				source,
+				rotation,
+			} => {
+				write!(f, "SXTB16{predicate} {destination}, {source}")?;
+				if rotation != 0x0 { write!(f, ", ROR #{rotation}")? };
+
+				Ok(())
+			},
+
+			SignExtendHalfword {
+				predicate,
+				destination,
+				source,
+				rotation,
+			} => {
+				write!(f, "SXTH{predicate} {destination}, {source}")?;
+				if rotation != 0x0 { write!(f, ", ROR #{rotation}")? };
+
+				Ok(())
+			},
+
+			SignedBitFieldExtract {
+				predicate,
+				destination,
+				source,
+				lsb,
+				width,
+			} => write!(f, "SBFX{predicate} {destination}, {source}, #{lsb}, #{width}"),
+
+			SignedDivide {
+				predicate,
+				destination,
+				base,
+				source,
+			} => write!(f, "SDIV{predicate} {destination}, {base}, {source}"),
+
+			SignedMultiplyAccumulateLong {
+				predicate,
+				destination_low,
+				destination_high,
+				base,
+				source,
+				s,
+			} => write!(f, "SMLAL{predicate}{s} {destination_low}, {destination_high}, {base}, {source}"),
+
+			SignedMultiplyLong {
+				predicate,
+				destination_low,
+				destination_high,
+				base,
+				source,
+				s,
+			} => write!(f, "SMULL{predicate}{s} {destination_low}, {destination_high}, {base}, {source}"),
+
+			SignedSaturate {
+				predicate,
+				destination,
+				immediate,
+				source,
+			} => write!(f, "SSAT{predicate} {destination}, #{immediate}, {source}"),
+
+			SignedSaturate16 {
+				predicate,
+				destination,
+				immediate,
+				source,
+			} => write!(f, "SSAT16{predicate} {destination}, #{immediate}, {source}"),
+
+			SignedToFloat {
+				predicate,
+				destination,
+				source,
+			} => write!(f, "VCVT{predicate}.{}.S32 {destination}, {source}", precision(destination)),
+
+			SoftwareInterrupt {
+				predicate,
+				immediate,
+			} => write!(f, "SWI{predicate} #{immediate}"),
 
 			Store {
 				predicate,
@@ -276,6 +951,79 @@ impl Display for Instruction {
 				t,
 			} => write!(f, "STR{predicate}{b}{t} {register}, {address}"),
 
+			StoreCoprocessor {
+				predicate,
+				coprocessor,
+				crd,
+				address,
+				l,
+			} => write!(f, "STC{predicate}{l} {coprocessor}, {crd}, {address}"),
+
+			StoreDoubleword {
+				predicate,
+				first,
+				second,
+				address,
+			} => write!(f, "STR{predicate}D {first}, {second}, {address}"),
+
+			StoreExclusive {
+				predicate,
+				status,
+				register,
+				address,
+			} => write!(f, "STREX{predicate} {status}, {register}, {address}"),
+
+			StoreExclusiveByte {
+				predicate,
+				status,
+				register,
+				address,
+			} => write!(f, "STREXB{predicate} {status}, {register}, {address}"),
+
+			StoreExclusiveDoubleword {
+				predicate,
+				status,
+				first,
+				second,
+				address,
+			} => write!(f, "STREXD{predicate} {status}, {first}, {second}, {address}"),
+
+			StoreExclusiveHalfword {
+				predicate,
+				status,
+				register,
+				address,
+			} => write!(f, "STREXH{predicate} {status}, {register}, {address}"),
+
+			StoreHalfword {
+				predicate,
+				register,
+				address,
+			} => write!(f, "STR{predicate}H {register}, {address}"),
+
+			StoreMultiple {
+				predicate,
+				base:      Register::Sp,
+				registers,
+				mode:      BlockMode::DecrementBefore,
+				w:         Wflag::On,
+				s:         Sflag::Off,
+			} => write!(f, "PUSH{predicate} {registers}"),
+
+			StoreMultiple {
+				predicate,
+				base,
+				registers,
+				mode,
+				w,
+				s,
+			} => {
+				write!(f, "STM{predicate}{mode} {base}{w}, {registers}")?;
+				if s.is_on() { write!(f, "^")? };
+
+				Ok(())
+			},
+
 			Subtract {
 				predicate,
 				destination,
@@ -295,9 +1043,51 @@ impl Display for Instruction {
 			Swap {
 				predicate,
 				register,
+				source,
 				address,
 				b,
-			} => write!(f, "SWP{predicate}{b} {register}, {address}"),
+			} => write!(f, "SWP{predicate}{b} {register}, {source}, {address}"),
+
+			UnsignedBitFieldExtract {
+				predicate,
+				destination,
+				source,
+				lsb,
+				width,
+			} => write!(f, "UBFX{predicate} {destination}, {source}, #{lsb}, #{width}"),
+
+			UnsignedDivide {
+				predicate,
+				destination,
+				base,
+				source,
+			} => write!(f, "UDIV{predicate} {destination}, {base}, {source}"),
+
+			UnsignedMultiplyAccumulateAccumulateLong {
+				predicate,
+				destination_low,
+				destination_high,
+				base,
+				source,
+			} => write!(f, "UMAAL{predicate} {destination_low}, {destination_high}, {base}, {source}"),
+
+			UnsignedMultiplyAccumulateLong {
+				predicate,
+				destination_low,
+				destination_high,
+				base,
+				source,
+				s,
+			} => write!(f, "UMLAL{predicate}{s} {destination_low}, {destination_high}, {base}, {source}"),
+
+			UnsignedMultiplyLong {
+				predicate,
+				destination_low,
+				destination_high,
+				base,
+				source,
+				s,
+			} => write!(f, "UMULL{predicate}{s} {destination_low}, {destination_high}, {base}, {source}"),
 
 			UnsignedSaturate {
 				predicate,
@@ -306,6 +1096,46 @@ impl Display for Instruction {
 				source,
 			} => write!(f, "USAT{predicate} {destination}, #{immediate}, {source}"),
 
+			UnsignedSaturate16 {
+				predicate,
+				destination,
+				immediate,
+				source,
+			} => write!(f, "USAT16{predicate} {destination}, #{immediate}, {source}"),
+
+			UnsignedSumAbsoluteDifferences {
+				predicate,
+				destination,
+				base,
+				source,
+			} => write!(f, "USAD8{predicate} {destination}, {base}, {source}"),
+
+			UnsignedSumAbsoluteDifferencesAccumulate {
+				predicate,
+				destination,
+				base,
+				source,
+				accumulator,
+			} => write!(f, "USADA8{predicate} {destination}, {base}, {source}, {accumulator}"),
+
+			UnsignedToFloat {
+				predicate,
+				destination,
+				source,
+			} => write!(f, "VCVT{predicate}.{}.U32 {destination}, {source}", precision(destination)),
+
+			TableBranchByte {
+				predicate,
+				base,
+				index,
+			} => write!(f, "TBB{predicate} [{base}, {index}]"),
+
+			TableBranchHalfword {
+				predicate,
+				base,
+				index,
+			} => write!(f, "TBH{predicate} [{base}, {index}, LSL #1]"),
+
 			Test {
 				predicate,
 				lhs,
@@ -317,6 +1147,362 @@ impl Display for Instruction {
 				lhs,
 				rhs,
 			} => write!(f, "TEQ{predicate} {lhs}, {rhs}"),
+
+			VectorAbsolute {
+				element,
+				destination,
+				source,
+			} => write!(f, "VABS.{element} {destination}, {source}"),
+
+			VectorAbsoluteDifference {
+				element,
+				destination,
+				base,
+				source,
+			} => write!(f, "VABD.{element} {destination}, {base}, {source}"),
+
+			VectorAdd {
+				element,
+				destination,
+				base,
+				source,
+			} => write!(f, "VADD.{element} {destination}, {base}, {source}"),
+
+			VectorAnd {
+				destination,
+				base,
+				source,
+			} => write!(f, "VAND {destination}, {base}, {source}"),
+
+			VectorBitClear {
+				destination,
+				base,
+				source,
+			} => write!(f, "VBIC {destination}, {base}, {source}"),
+
+			VectorCompareEqual {
+				element,
+				destination,
+				base,
+				source,
+			} => write!(f, "VCEQ.{element} {destination}, {base}, {source}"),
+
+			VectorCompareGreaterThan {
+				element,
+				destination,
+				base,
+				source,
+			} => write!(f, "VCGT.{element} {destination}, {base}, {source}"),
+
+			VectorCompareGreaterThanOrEqual {
+				element,
+				destination,
+				base,
+				source,
+			} => write!(f, "VCGE.{element} {destination}, {base}, {source}"),
+
+			VectorDuplicate {
+				predicate,
+				element,
+				destination,
+				source,
+			} => write!(f, "VDUP{predicate}.{element} {destination}, {source}"),
+
+			VectorDuplicateScalar {
+				element,
+				destination,
+				source,
+			} => write!(f, "VDUP.{element} {destination}, {source}"),
+
+			VectorExclusiveOr {
+				destination,
+				base,
+				source,
+			} => write!(f, "VEOR {destination}, {base}, {source}"),
+
+			VectorExtract {
+				destination,
+				base,
+				source,
+				index,
+			} => write!(f, "VEXT.8 {destination}, {base}, {source}, #{index}"),
+
+			VectorInclusiveOr {
+				destination,
+				base,
+				source,
+			} => {
+				if base == source {
+					write!(f, "VMOV {destination}, {source}")
+				} else {
+					write!(f, "VORR {destination}, {base}, {source}")
+				}
+			},
+
+			VectorLoadStructure {
+				interleave,
+				element,
+				first,
+				count,
+				spacing,
+				address,
+			} => {
+				write!(f, "VLD{interleave}.{element} {{")?;
+
+				for i in 0x0..count {
+					if i != 0x0 { write!(f, ", ")? };
+					write!(f, "d{}", u32::from(first as u8) + u32::from(i) * u32::from(spacing))?;
+				}
+
+				write!(f, "}}, {address}")
+			},
+
+			VectorMaximum {
+				element,
+				destination,
+				base,
+				source,
+			} => write!(f, "VMAX.{element} {destination}, {base}, {source}"),
+
+			VectorMinimum {
+				element,
+				destination,
+				base,
+				source,
+			} => write!(f, "VMIN.{element} {destination}, {base}, {source}"),
+
+			VectorMultiply {
+				element,
+				destination,
+				base,
+				source,
+			} => write!(f, "VMUL.{element} {destination}, {base}, {source}"),
+
+			VectorMultiplyAccumulate {
+				element,
+				destination,
+				base,
+				source,
+			} => write!(f, "VMLA.{element} {destination}, {base}, {source}"),
+
+			VectorMultiplyScalar {
+				element,
+				destination,
+				base,
+				source,
+			} => write!(f, "VMUL.{element} {destination}, {base}, {source}"),
+
+			VectorMultiplySubtract {
+				element,
+				destination,
+				base,
+				source,
+			} => write!(f, "VMLS.{element} {destination}, {base}, {source}"),
+
+			VectorNegate {
+				element,
+				destination,
+				source,
+			} => write!(f, "VNEG.{element} {destination}, {source}"),
+
+			VectorNot {
+				destination,
+				source,
+			} => write!(f, "VMVN {destination}, {source}"),
+
+			VectorReverse16 {
+				element,
+				destination,
+				source,
+			} => write!(f, "VREV16.{element} {destination}, {source}"),
+
+			VectorReverse32 {
+				element,
+				destination,
+				source,
+			} => write!(f, "VREV32.{element} {destination}, {source}"),
+
+			VectorReverse64 {
+				element,
+				destination,
+				source,
+			} => write!(f, "VREV64.{element} {destination}, {source}"),
+
+			VectorSaturatingAdd {
+				element,
+				destination,
+				base,
+				source,
+			} => write!(f, "VQADD.{element} {destination}, {base}, {source}"),
+
+			VectorSaturatingSubtract {
+				element,
+				destination,
+				base,
+				source,
+			} => write!(f, "VQSUB.{element} {destination}, {base}, {source}"),
+
+			VectorShiftLeft {
+				element,
+				destination,
+				source,
+				shift,
+			} => write!(f, "VSHL.{element} {destination}, {source}, #{shift}"),
+
+			VectorShiftRight {
+				element,
+				destination,
+				source,
+				shift,
+			} => write!(f, "VSHR.{element} {destination}, {source}, #{shift}"),
+
+			VectorStoreStructure {
+				interleave,
+				element,
+				first,
+				count,
+				spacing,
+				address,
+			} => {
+				write!(f, "VST{interleave}.{element} {{")?;
+
+				for i in 0x0..count {
+					if i != 0x0 { write!(f, ", ")? };
+					write!(f, "d{}", u32::from(first as u8) + u32::from(i) * u32::from(spacing))?;
+				}
+
+				write!(f, "}}, {address}")
+			},
+
+			VectorSubtract {
+				element,
+				destination,
+				base,
+				source,
+			} => write!(f, "VSUB.{element} {destination}, {base}, {source}"),
+
+			VectorSwap {
+				destination,
+				source,
+			} => write!(f, "VSWP {destination}, {source}"),
+
+			VectorTranspose {
+				element,
+				destination,
+				source,
+			} => write!(f, "VTRN.{element} {destination}, {source}"),
+
+			VectorUnzip {
+				element,
+				destination,
+				source,
+			} => write!(f, "VUZP.{element} {destination}, {source}"),
+
+			VectorZip {
+				element,
+				destination,
+				source,
+			} => write!(f, "VZIP.{element} {destination}, {source}"),
+
+			WaitForEvent { predicate } => write!(f, "WFE{predicate}"),
+
+			WaitForInterrupt { predicate } => write!(f, "WFI{predicate}"),
+
+			Yield { predicate } => write!(f, "YIELD{predicate}"),
+
+			ZeroExtendAddByte {
+				predicate,
+				destination,
+				base,
+				source,
+				rotation,
+			} => {
+				write!(f, "UXTAB{predicate} {destination}, {base}, {source}")?;
+				if rotation != 0x0 { write!(f, ", ROR #{rotation}")? };
+
+				Ok(())
+			},
+
+			ZeroExtendAddByte16 {
+				predicate,
+				destination,
+				base,
+				source,
+				rotation,
+			} => {
+				write!(f, "UXTAB16{predicate} {destination}, {base}, {source}")?;
+				if rotation != 0x0 { write!(f, ", ROR #{rotation}")? };
+
+				Ok(())
+			},
+
+			ZeroExtendAddHalfword {
+				predicate,
+				destination,
+				base,
+				source,
+				rotation,
+			} => {
+				write!(f, "UXTAH{predicate} {destination}, {base}, {source}")?;
+				if rotation != 0x0 { write!(f, ", ROR #{rotation}")? };
+
+				Ok(())
+			},
+
+			ZeroExtendByte {
+				predicate,
+				destination,
+				source,
+				rotation,
+			} => {
+				write!(f, "UXTB{predicate} {destination}, {source}")?;
+				if rotation != 0x0 { write!(f, ", ROR #{rotation}")? };
+
+				Ok(())
+			},
+
+			ZeroExtendByte16 {
+				predicate,
+				destination,
+				source,
+				rotation,
+			} => {
+				write!(f, "UXTB16{predicate} {destination}, {source}")?;
+				if rotation != 0x0 { write!(f, ", ROR #{rotation}")? };
+
+				Ok(())
+			},
+
+			ZeroExtendHalfword {
+				predicate,
+				destination,
+				source,
+				rotation,
+			} => {
+				write!(f, "UXTH{predicate} {destination}, {source}")?;
+				if rotation != 0x0 { write!(f, ", ROR #{rotation}")? };
+
+				Ok(())
+			},
 		}
 	}
 }
+
+/// Gets the data type suffix of a floating-point register.
+#[inline]
+#[must_use]
+const fn precision(register: VfpRegister) -> &'static str {
+	if register.is_double() { "F64" } else { "F32" }
+}
+
+/// Writes a list of consecutive floating-point registers.
+fn write_vfp_list(f: &mut core::fmt::Formatter, first: VfpRegister, count: u8) -> core::fmt::Result {
+	if count > 0x1 {
+		let prefix = if first.is_double() { 'd' } else { 's' };
+		let last   = u32::from(first.to_u8()) + u32::from(count) - 0x1;
+
+		write!(f, "{{{first}-{prefix}{last}}}")
+	} else {
+		write!(f, "{{{first}}}")
+	}
+}
@@ -19,38 +19,1210 @@
 // fero General Public License along with Pollex.
 // If not, see <https://www.gnu.org/licenses/>.
 
-use crate::Error;
+use super::mnemonic::{
+	parse_predicate,
+	split_if_then,
+	split_mnemonic,
+	split_parallel,
+	DataType,
+};
+use super::operands::{is_immediate, is_shift, Operands};
+
+use crate::{assert_or_err, Error, Result};
 use crate::arm32::{
+	Address,
+	BarrierOption,
+	Bflag,
+	BlockMode,
+	DoubleRegister,
+	Eflag,
+	ElementType,
+	Endianness,
+	Indexing,
 	Instruction,
+	InterruptMask,
+	Lflag,
 	Predicate,
+	ProcessorMode,
 	Register,
-	Shifter,
+	Rflag,
 	Sflag,
+	Shifter,
+	Sign,
+	SingleRegister,
+	StatusFields,
+	Tflag,
+	VectorRegister,
+	VfpRegister,
+	Wflag,
 };
 
+use alloc::borrow::ToOwned;
+use alloc::vec::Vec;
 use core::str::FromStr;
 
 impl FromStr for Instruction {
 	type Err = Error;
 
-	fn from_str(s: &str) -> Result<Self, Self::Err> {
-		use Instruction::*;
+	/// Parses an instruction in the unified assembler language.
+	///
+	/// Every mnemonic emitted by [`Display`](core::fmt::Display) is accepted, as are the pre-UAL orderings of suffixes and condition codes (e.g. both `LDRBEQ` and `LDREQB`).
+	/// Mnemonics are case-insensitive, and comments (starting with `;`) are ignored.
+	///
+	/// # Errors
+	///
+	/// Unknown mnemonics yield [`UnknownMnemonic`](Error::UnknownMnemonic), and unknown registers yield [`UnknownRegister`](Error::UnknownRegister).
+	/// Malformed operands yield other errors, depending on the kind of operand.
+	fn from_str(s: &str) -> Result<Self> {
+		// Comments run until the end of the line.
+		let s = s.split_once(';').map_or(s, |(s, _)| s).trim();
+
+		let (mnemonic, operands) = s.split_once(char::is_whitespace).unwrap_or((s, ""));
+
+		let mut operands = Operands::new(operands)?;
+
+		let instruction = parse_instruction(mnemonic, &mut operands)?;
+		operands.finish()?;
+
+		Ok(instruction)
+	}
+}
 
-		// Temporary to make doc tests work:
-		match s {
-			"CPY r0, r1" => Ok(Move {
-				predicate:   Predicate::Always,
-				destination: Register::R0,
-				source:      Shifter::from_register(Register::R1),
-				s:           Sflag::On,
-			}),
+/// Parses an instruction from its mnemonic and operands.
+fn parse_instruction(text: &str, operands: &mut Operands) -> Result<Instruction> {
+	let upper = text.to_uppercase();
 
-			"BX lr" => Ok(BranchExchange {
-				predicate: Predicate::Always,
-				source:    Register::Lr,
-			}),
+	let mut parts = upper.split('.');
+	let name      = parts.next().unwrap_or_default();
 
-			_ => todo!(),
+	// Width qualifiers carry no meaning here.
+	let types = parts
+		.filter(|&part| part != "W" && part != "N")
+		.map(|part| DataType::parse(part).ok_or_else(|| Error::UnknownMnemonic(text.to_owned())))
+		.collect::<Result<Vec<_>>>()?;
+
+	if !name.starts_with('V') && !types.is_empty() { return Err(Error::UnknownMnemonic(text.to_owned())) };
+
+	if let Some(mask) = split_if_then(name) {
+		let predicate = parse_predicate(&operands.next_item()?.to_uppercase())
+			.ok_or(Error::IllegalPredicate { reason: "unknown condition code" })?;
+
+		return Ok(Instruction::IfThen { predicate, mask });
+	}
+
+	if let Some((name, suffix, predicate)) = split_mnemonic(name) {
+		if name.starts_with('V') {
+			parse_vector(text, name, suffix, predicate, &types, operands)
+		} else {
+			parse_core(text, name, suffix, predicate, operands)
 		}
+	} else if let Some((prefix, operation, predicate)) = split_parallel(name) {
+		let (destination, base, source) = three_registers(operands)?;
+
+		Ok(Instruction::ParallelArithmetic { predicate, prefix, operation, destination, base, source })
+	} else {
+		Err(Error::UnknownMnemonic(text.to_owned()))
+	}
+}
+
+/// Parses an Arm (i.e. non-VFP, non-Advanced SIMD) instruction.
+fn parse_core(
+	text:      &str,
+	name:      &str,
+	suffix:    &str,
+	predicate: Predicate,
+	operands:  &mut Operands,
+) -> Result<Instruction> {
+	use Instruction::*;
+
+	let s = Sflag::from(suffix == "S");
+
+	let instruction = match name {
+		"ADC" => {
+			let (destination, base, source) = data_processing(operands)?;
+			AddCarry { predicate, destination, base, source, s }
+		},
+
+		"ADD" => {
+			let (destination, base, source) = data_processing(operands)?;
+			Add { predicate, destination, base, source, s }
+		},
+
+		"AND" => {
+			let (destination, base, source) = data_processing(operands)?;
+			And { predicate, destination, base, source, s }
+		},
+
+		"ASR" | "LSL" | "LSR" | "ROR" => {
+			let destination = operands.parse()?;
+			let source      = if operands.remaining() >= 0x2 { operands.parse()? } else { destination };
+			let source      = operands.shift(name, source)?;
+
+			Move { predicate, destination, source, s }
+		},
+
+		"B" => Branch { predicate, immediate: operands.immediate()? },
+
+		"BFC" => {
+			let destination = operands.parse()?;
+			let lsb         = operands.immediate()?;
+			let width       = operands.immediate()?;
+
+			BitFieldClear { predicate, destination, lsb, width }
+		},
+
+		"BFI" => {
+			let destination = operands.parse()?;
+			let source      = operands.parse()?;
+			let lsb         = operands.immediate()?;
+			let width       = operands.immediate()?;
+
+			BitFieldInsert { predicate, destination, source, lsb, width }
+		},
+
+		"BIC" => {
+			let (destination, base, source) = data_processing(operands)?;
+			BitClear { predicate, destination, base, source, s }
+		},
+
+		"BKPT" => {
+			unconditional(predicate)?;
+			Breakpoint { immediate: operands.immediate()? }
+		},
+
+		"BL" => BranchLink { predicate, source: operands.immediate()? },
+
+		"BLX" => BranchLinkExchange { predicate, source: operands.shifter()? },
+
+		"BX" => BranchExchange { predicate, source: operands.parse()? },
+
+		"CBNZ" | "CBZ" => {
+			unconditional(predicate)?;
+
+			let source    = operands.parse()?;
+			let immediate = operands.immediate()?;
+
+			if name == "CBNZ" {
+				CompareBranchNonZero { source, immediate }
+			} else {
+				CompareBranchZero { source, immediate }
+			}
+		},
+
+		"CDP" => {
+			let coprocessor = operands.parse()?;
+			let opcode1     = operands.immediate()?;
+			let crd         = operands.parse()?;
+			let crn         = operands.parse()?;
+			let crm         = operands.parse()?;
+			let opcode2     = optional_opcode(operands)?;
+
+			CoprocessorDataProcessing { predicate, coprocessor, opcode1, crd, crn, crm, opcode2 }
+		},
+
+		"CLREX" => {
+			unconditional(predicate)?;
+			ClearExclusive
+		},
+
+		"CLZ" => {
+			let [destination, source] = registers(operands)?;
+			CountLeadingZeroes { predicate, destination, source }
+		},
+
+		"CMN" => CompareNegated { predicate, lhs: operands.parse()?, rhs: operands.shifter()? },
+
+		"CMP" => Compare { predicate, lhs: operands.parse()?, rhs: operands.shifter()? },
+
+		"CPS" => {
+			unconditional(predicate)?;
+			ChangeProcessorMode { mode: processor_mode(operands)? }
+		},
+
+		"CPSID" | "CPSIE" => {
+			unconditional(predicate)?;
+
+			let mask = interrupt_mask(operands.next_item()?)?;
+			let mode = if operands.remaining() != 0x0 { Some(processor_mode(operands)?) } else { None };
+
+			if name == "CPSID" {
+				DisableInterrupts { mask, mode }
+			} else {
+				EnableInterrupts { mask, mode }
+			}
+		},
+
+		"CPY" => {
+			let [destination, source] = registers(operands)?;
+			Move { predicate, destination, source: Shifter::from_register(source), s: Sflag::Off }
+		},
+
+		"DMB" => {
+			unconditional(predicate)?;
+			DataMemoryBarrier { option: barrier_option(operands)? }
+		},
+
+		"DSB" => {
+			unconditional(predicate)?;
+			DataSynchronisationBarrier { option: barrier_option(operands)? }
+		},
+
+		"EOR" => {
+			let (destination, base, source) = data_processing(operands)?;
+			ExclusiveOr { predicate, destination, base, source, s }
+		},
+
+		"ISB" => {
+			unconditional(predicate)?;
+			InstructionSynchronisationBarrier { option: barrier_option(operands)? }
+		},
+
+		"LDC" | "STC" => {
+			let coprocessor = operands.parse()?;
+			let crd         = operands.parse()?;
+			let address     = operands.address()?;
+			let l           = Lflag::from(suffix == "L");
+
+			if name == "LDC" {
+				LoadCoprocessor { predicate, coprocessor, crd, address, l }
+			} else {
+				StoreCoprocessor { predicate, coprocessor, crd, address, l }
+			}
+		},
+
+		"LDM" | "STM" => {
+			let (base, w)        = operands.register_with_writeback()?;
+			let (registers, s)   = operands.register_list()?;
+			let mode             = block_mode(suffix, name == "LDM");
+
+			if name == "LDM" {
+				LoadMultiple { predicate, base, registers, mode, w, s }
+			} else {
+				StoreMultiple { predicate, base, registers, mode, w, s }
+			}
+		},
+
+		"LDR" | "STR" if suffix == "D" => {
+			let [first, second] = registers(operands)?;
+			let address         = operands.address()?;
+
+			if name == "LDR" {
+				LoadDoubleword { predicate, first, second, address }
+			} else {
+				StoreDoubleword { predicate, first, second, address }
+			}
+		},
+
+		"LDR" | "STR" => {
+			let register = operands.parse()?;
+			let address  = operands.address()?;
+
+			let b = Bflag::from(suffix.contains('B') && !suffix.contains('S'));
+			let t = Tflag::from(suffix.contains('T'));
+
+			match (name, suffix) {
+				("LDR", "H")  => LoadHalfword { predicate, register, address },
+				("LDR", "SB") => LoadSignedByte { predicate, register, address },
+				("LDR", "SH") => LoadSignedHalfword { predicate, register, address },
+				("LDR", _)    => Load { predicate, register, address, b, t },
+				(_,     "H")  => StoreHalfword { predicate, register, address },
+				_             => Store { predicate, register, address, b, t },
+			}
+		},
+
+		"LDREX" | "LDREXB" | "LDREXH" => {
+			let register = operands.parse()?;
+			let address  = operands.address()?;
+
+			match name {
+				"LDREXB" => LoadExclusiveByte { predicate, register, address },
+				"LDREXH" => LoadExclusiveHalfword { predicate, register, address },
+				_        => LoadExclusive { predicate, register, address },
+			}
+		},
+
+		"LDREXD" => {
+			let [first, second] = registers(operands)?;
+			let address         = operands.address()?;
+
+			LoadExclusiveDoubleword { predicate, first, second, address }
+		},
+
+		"MCR" | "MRC" => {
+			let coprocessor = operands.parse()?;
+			let opcode1     = operands.immediate()?;
+			let register    = operands.parse()?;
+			let crn         = operands.parse()?;
+			let crm         = operands.parse()?;
+			let opcode2     = optional_opcode(operands)?;
+
+			if name == "MCR" {
+				MoveToCoprocessor { predicate, coprocessor, opcode1, register, crn, crm, opcode2 }
+			} else {
+				MoveFromCoprocessor { predicate, coprocessor, opcode1, register, crn, crm, opcode2 }
+			}
+		},
+
+		"MCRR" | "MRRC" => {
+			let coprocessor     = operands.parse()?;
+			let opcode1         = operands.immediate()?;
+			let [first, second] = registers(operands)?;
+			let crm             = operands.parse()?;
+
+			if name == "MCRR" {
+				MoveToCoprocessorDouble { predicate, coprocessor, opcode1, first, second, crm }
+			} else {
+				MoveFromCoprocessorDouble { predicate, coprocessor, opcode1, first, second, crm }
+			}
+		},
+
+		"MLA" => {
+			let [destination, base, source, accumulator] = registers(operands)?;
+			MultiplyAccumulate { predicate, destination, base, source, accumulator, s }
+		},
+
+		"MLS" => {
+			let [destination, base, source, accumulator] = registers(operands)?;
+			MultiplySubtract { predicate, destination, base, source, accumulator }
+		},
+
+		"MOV" => Move { predicate, destination: operands.parse()?, source: operands.shifter()?, s },
+
+		"MOVT" => MoveTop { predicate, destination: operands.parse()?, immediate: operands.immediate()? },
+
+		"MOVW" => MoveWide { predicate, destination: operands.parse()?, immediate: operands.immediate()? },
+
+		"MRS" => {
+			let destination      = operands.parse()?;
+			let (source, fields) = operands.status_register()?;
+
+			assert_or_err!(fields.is_none(), Error::IllegalInstruction { reason: "MRS does not take a field mask" });
+
+			MoveFromStatusRegister { predicate, destination, source }
+		},
+
+		"MSR" => {
+			let (destination, fields) = operands.status_register()?;
+			let source                = operands.shifter()?;
+
+			// Legacy assemblers default to the control and
+			// flags fields.
+			let fields = fields.unwrap_or(StatusFields::CONTROL | StatusFields::FLAGS);
+
+			MoveToStatusRegister { predicate, destination, fields, source }
+		},
+
+		"MUL" => {
+			let (destination, base, source) = three_registers(operands)?;
+			Multiply { predicate, destination, base, source, s }
+		},
+
+		"MVN" => MoveNot { predicate, destination: operands.parse()?, source: operands.shifter()?, s },
+
+		"NEG" => {
+			let [destination, base] = registers(operands)?;
+			ReverseSubtract { predicate, destination, base, source: Shifter::Immediate(0x0), s }
+		},
+
+		"NOP" => NoOperation { predicate },
+
+		"ORR" => {
+			let (destination, base, source) = data_processing(operands)?;
+			InclusiveOr { predicate, destination, base, source, s }
+		},
+
+		"ORN" => {
+			let (destination, base, source) = data_processing(operands)?;
+			InclusiveOrNot { predicate, destination, base, source, s }
+		},
+
+		"PKHBT" | "PKHTB" => {
+			let [destination, base] = registers(operands)?;
+			let source              = operands.shifter()?;
+
+			PackHalfword { predicate, destination, base, source }
+		},
+
+		"PLD" => {
+			unconditional(predicate)?;
+			PreloadData { address: operands.address()? }
+		},
+
+		"POP" | "PUSH" => {
+			let (registers, s) = operands.register_list()?;
+
+			assert_or_err!(s.is_off(), Error::IllegalFlag { reason: "stack transfers cannot access user registers" });
+
+			if name == "POP" {
+				LoadMultiple { predicate, base: Register::Sp, registers, mode: BlockMode::IncrementAfter, w: Wflag::On, s }
+			} else {
+				StoreMultiple { predicate, base: Register::Sp, registers, mode: BlockMode::DecrementBefore, w: Wflag::On, s }
+			}
+		},
+
+		"QADD" | "QDADD" | "QDSUB" | "QSUB" | "SDIV" | "SEL" | "UDIV" | "USAD8" => {
+			let (destination, base, source) = three_registers(operands)?;
+
+			match name {
+				"QADD"  => SaturatingAdd { predicate, destination, base, source },
+				"QDADD" => SaturatingDoubleAdd { predicate, destination, base, source },
+				"QDSUB" => SaturatingDoubleSubtract { predicate, destination, base, source },
+				"QSUB"  => SaturatingSubtract { predicate, destination, base, source },
+				"SDIV"  => SignedDivide { predicate, destination, base, source },
+				"SEL"   => Select { predicate, destination, base, source },
+				"UDIV"  => UnsignedDivide { predicate, destination, base, source },
+				_       => UnsignedSumAbsoluteDifferences { predicate, destination, base, source },
+			}
+		},
+
+		"RBIT" | "REV" | "REV16" | "REVSH" => {
+			let [destination, source] = registers(operands)?;
+
+			match name {
+				"RBIT"  => ReverseBits { predicate, destination, source },
+				"REV"   => Reverse { predicate, destination, source },
+				"REV16" => ReversePackedHalfword { predicate, destination, source },
+				_       => ReverseSignedHalfword { predicate, destination, source },
+			}
+		},
+
+		"RRX" => {
+			let [destination, source] = registers(operands)?;
+			Move { predicate, destination, source: Shifter::RotateRightExtend { source }, s }
+		},
+
+		"RSB" => {
+			let (destination, base, source) = data_processing(operands)?;
+			ReverseSubtract { predicate, destination, base, source, s }
+		},
+
+		"RSC" => {
+			let (destination, base, source) = data_processing(operands)?;
+			ReverseSubtractCarry { predicate, destination, base, source, s }
+		},
+
+		"SBC" => {
+			let (destination, base, source) = data_processing(operands)?;
+			SubtractCarry { predicate, destination, base, source, s }
+		},
+
+		"SBFX" | "UBFX" => {
+			let [destination, source] = registers(operands)?;
+			let lsb                   = operands.immediate()?;
+			let width                 = operands.immediate()?;
+
+			if name == "SBFX" {
+				SignedBitFieldExtract { predicate, destination, source, lsb, width }
+			} else {
+				UnsignedBitFieldExtract { predicate, destination, source, lsb, width }
+			}
+		},
+
+		"SETEND" => {
+			unconditional(predicate)?;
+
+			let endianness = match operands.next_item()?.to_uppercase().as_str() {
+				"LE" => Endianness::Little,
+				"BE" => Endianness::Big,
+
+				_ => return Err(Error::IllegalInstruction { reason: "unknown endianness" }),
+			};
+
+			SetEndianness { endianness }
+		},
+
+		"SEV" => SendEvent { predicate },
+
+		"SMLAL" | "SMULL" | "UMAAL" | "UMLAL" | "UMULL" => {
+			let [destination_low, destination_high, base, source] = registers(operands)?;
+
+			match name {
+				"SMLAL" => SignedMultiplyAccumulateLong { predicate, destination_low, destination_high, base, source, s },
+				"SMULL" => SignedMultiplyLong { predicate, destination_low, destination_high, base, source, s },
+				"UMAAL" => UnsignedMultiplyAccumulateAccumulateLong { predicate, destination_low, destination_high, base, source },
+				"UMLAL" => UnsignedMultiplyAccumulateLong { predicate, destination_low, destination_high, base, source, s },
+				_       => UnsignedMultiplyLong { predicate, destination_low, destination_high, base, source, s },
+			}
+		},
+
+		"SSAT" | "USAT" => {
+			let destination = operands.parse()?;
+			let immediate   = operands.immediate()?;
+			let source      = operands.shifter()?;
+
+			if name == "SSAT" {
+				SignedSaturate { predicate, destination, immediate, source }
+			} else {
+				UnsignedSaturate { predicate, destination, immediate, source }
+			}
+		},
+
+		"SSAT16" | "USAT16" => {
+			let destination = operands.parse()?;
+			let immediate   = operands.immediate()?;
+			let source      = operands.parse()?;
+
+			if name == "SSAT16" {
+				SignedSaturate16 { predicate, destination, immediate, source }
+			} else {
+				UnsignedSaturate16 { predicate, destination, immediate, source }
+			}
+		},
+
+		"STREX" | "STREXB" | "STREXH" => {
+			let [status, register] = registers(operands)?;
+			let address            = operands.address()?;
+
+			match name {
+				"STREXB" => StoreExclusiveByte { predicate, status, register, address },
+				"STREXH" => StoreExclusiveHalfword { predicate, status, register, address },
+				_        => StoreExclusive { predicate, status, register, address },
+			}
+		},
+
+		"STREXD" => {
+			let [status, first, second] = registers(operands)?;
+			let address                 = operands.address()?;
+
+			StoreExclusiveDoubleword { predicate, status, first, second, address }
+		},
+
+		"SUB" => {
+			let (destination, base, source) = data_processing(operands)?;
+			Subtract { predicate, destination, base, source, s }
+		},
+
+		"SVC" | "SWI" => SoftwareInterrupt { predicate, immediate: operands.immediate()? },
+
+		"SWP" => {
+			let [register, source] = registers(operands)?;
+			let address            = operands.address()?;
+
+			Swap { predicate, register, source, address, b: Bflag::from(suffix == "B") }
+		},
+
+		"SXTAB" | "SXTAB16" | "SXTAH" | "UXTAB" | "UXTAB16" | "UXTAH" => {
+			let [destination, base, source] = registers(operands)?;
+			let rotation                    = operands.rotation()?;
+
+			match name {
+				"SXTAB"   => SignExtendAddByte { predicate, destination, base, source, rotation },
+				"SXTAB16" => SignExtendAddByte16 { predicate, destination, base, source, rotation },
+				"SXTAH"   => SignExtendAddHalfword { predicate, destination, base, source, rotation },
+				"UXTAB"   => ZeroExtendAddByte { predicate, destination, base, source, rotation },
+				"UXTAB16" => ZeroExtendAddByte16 { predicate, destination, base, source, rotation },
+				_         => ZeroExtendAddHalfword { predicate, destination, base, source, rotation },
+			}
+		},
+
+		"SXTB" | "SXTB16" | "SXTH" | "UXTB" | "UXTB16" | "UXTH" => {
+			let [destination, source] = registers(operands)?;
+			let rotation              = operands.rotation()?;
+
+			match name {
+				"SXTB"   => SignExtendByte { predicate, destination, source, rotation },
+				"SXTB16" => SignExtendByte16 { predicate, destination, source, rotation },
+				"SXTH"   => SignExtendHalfword { predicate, destination, source, rotation },
+				"UXTB"   => ZeroExtendByte { predicate, destination, source, rotation },
+				"UXTB16" => ZeroExtendByte16 { predicate, destination, source, rotation },
+				_        => ZeroExtendHalfword { predicate, destination, source, rotation },
+			}
+		},
+
+		"TBB" | "TBH" => {
+			// `TBH` scales its index by two.
+			let (
+				| ("TBB", Address::RegisterOffset { base, source: index, sign: Sign::Positive, indexing: Indexing::Offset })
+				| ("TBH", Address::ScaledRegisterOffset { base, source: Shifter::LogicalShiftLeftImmediate { source: index, shift: 0x1 }, sign: Sign::Positive, indexing: Indexing::Offset })
+			) = (name, operands.address()?) else {
+				return Err(Error::IllegalInstruction { reason: "malformed table" });
+			};
+
+			if name == "TBB" {
+				TableBranchByte { predicate, base, index }
+			} else {
+				TableBranchHalfword { predicate, base, index }
+			}
+		},
+
+		"TEQ" => TestEquivalence { predicate, lhs: operands.parse()?, rhs: operands.shifter()? },
+
+		"TST" => Test { predicate, lhs: operands.parse()?, rhs: operands.shifter()? },
+
+		"USADA8" => {
+			let [destination, base, source, accumulator] = registers(operands)?;
+			UnsignedSumAbsoluteDifferencesAccumulate { predicate, destination, base, source, accumulator }
+		},
+
+		"WFE" => WaitForEvent { predicate },
+
+		"WFI" => WaitForInterrupt { predicate },
+
+		"YIELD" => Yield { predicate },
+
+		_ => return Err(Error::UnknownMnemonic(text.to_owned())),
+	};
+
+	Ok(instruction)
+}
+
+/// Parses a VFP or Advanced SIMD instruction.
+fn parse_vector(
+	text:      &str,
+	name:      &str,
+	suffix:    &str,
+	predicate: Predicate,
+	types:     &[DataType],
+	operands:  &mut Operands,
+) -> Result<Instruction> {
+	use Instruction::*;
+
+	let float = is_float(types, operands);
+
+	let instruction = match name {
+		"VABS" | "VNEG" | "VSQRT" if float || name == "VSQRT" => {
+			let (destination, source) = float_registers(types, operands)?;
+
+			match name {
+				"VABS" => FloatAbsolute { predicate, destination, source },
+				"VNEG" => FloatNegate { predicate, destination, source },
+				_      => FloatSquareRoot { predicate, destination, source },
+			}
+		},
+
+		"VADD" | "VDIV" | "VMUL" | "VSUB" if float || name == "VDIV" => {
+			let (destination, base) = float_registers(types, operands)?;
+			let source              = operands.parse()?;
+
+			check_precision(destination.is_double(), &[source])?;
+
+			match name {
+				"VADD" => FloatAdd { predicate, destination, base, source },
+				"VDIV" => FloatDivide { predicate, destination, base, source },
+				"VMUL" => FloatMultiply { predicate, destination, base, source },
+				_      => FloatSubtract { predicate, destination, base, source },
+			}
+		},
+
+		"VCMP" => {
+			let double = precision(types)?;
+			let lhs    = operands.parse()?;
+
+			let rhs = if operands.peek().is_some_and(is_immediate) {
+				assert_or_err!(
+					operands.float_immediate()?.is_none(),
+					Error::IllegalImmediate { reason: "floating-point comparisons can only be made against zero" }
+				);
+
+				None
+			} else {
+				Some(operands.parse()?)
+			};
+
+			check_precision(double, &[lhs])?;
+			check_precision(double, rhs.as_slice())?;
+
+			FloatCompare { predicate, lhs, rhs, e: Eflag::from(suffix == "E") }
+		},
+
+		"VCVT" => parse_convert(predicate, Rflag::from(suffix == "R"), types, operands)?,
+
+		"VDUP" => {
+			let element     = element(types)?;
+			let destination = operands.parse()?;
+
+			if operands.peek().is_some_and(|item| item.contains('[')) {
+				unconditional(predicate)?;
+				VectorDuplicateScalar { element, destination, source: operands.scalar()? }
+			} else {
+				VectorDuplicate { predicate, element, destination, source: operands.parse()? }
+			}
+		},
+
+		"VLD1" | "VLD2" | "VLD3" | "VLD4" | "VST1" | "VST2" | "VST3" | "VST4" => {
+			unconditional(predicate)?;
+
+			let interleave              = name.as_bytes()[0x3] - b'0';
+			let element                 = element(types)?;
+			let (first, count, spacing) = operands.vector_list()?;
+			let address                 = operands.structure_address()?;
+
+			if name.starts_with("VLD") {
+				VectorLoadStructure { interleave, element, first, count, spacing, address }
+			} else {
+				VectorStoreStructure { interleave, element, first, count, spacing, address }
+			}
+		},
+
+		"VLDM" | "VSTM" => {
+			let (base, w)      = operands.register_with_writeback()?;
+			let (first, count) = operands.vfp_list()?;
+			let mode           = block_mode(suffix, name == "VLDM");
+
+			if name == "VLDM" {
+				FloatLoadMultiple { predicate, base, first, count, mode, w }
+			} else {
+				FloatStoreMultiple { predicate, base, first, count, mode, w }
+			}
+		},
+
+		"VLDR" => FloatLoad { predicate, register: operands.parse()?, address: operands.address()? },
+
+		"VMOV" => parse_move(predicate, types, operands)?,
+
+		"VMRS" => {
+			let destination = operands.next_item()?;
+
+			let destination = if destination.eq_ignore_ascii_case("APSR_nzcv") {
+				Register::Pc
+			} else {
+				destination.parse()?
+			};
+
+			fpscr(operands.next_item()?)?;
+
+			MoveFromFloatStatus { predicate, destination }
+		},
+
+		"VMSR" => {
+			fpscr(operands.next_item()?)?;
+
+			MoveToFloatStatus { predicate, source: operands.parse()? }
+		},
+
+		"VMUL" if operands.peek_nth(0x2).is_some_and(|item| item.contains('[')) => {
+			unconditional(predicate)?;
+
+			let element     = element(types)?;
+			let destination = operands.parse()?;
+			let base        = operands.parse()?;
+			let source      = operands.scalar()?;
+
+			VectorMultiplyScalar { element, destination, base, source }
+		},
+
+		"VPOP" | "VPUSH" => {
+			let (first, count) = operands.vfp_list()?;
+
+			if name == "VPOP" {
+				FloatLoadMultiple { predicate, base: Register::Sp, first, count, mode: BlockMode::IncrementAfter, w: Wflag::On }
+			} else {
+				FloatStoreMultiple { predicate, base: Register::Sp, first, count, mode: BlockMode::DecrementBefore, w: Wflag::On }
+			}
+		},
+
+		"VSTR" => FloatStore { predicate, register: operands.parse()?, address: operands.address()? },
+
+		"VAND" | "VBIC" | "VEOR" | "VORR" => {
+			unconditional(predicate)?;
+
+			let [destination, base, source] = vector_registers(operands)?;
+
+			match name {
+				"VAND" => VectorAnd { destination, base, source },
+				"VBIC" => VectorBitClear { destination, base, source },
+				"VEOR" => VectorExclusiveOr { destination, base, source },
+				_      => VectorInclusiveOr { destination, base, source },
+			}
+		},
+
+		"VABD" | "VADD" | "VCEQ" | "VCGE" | "VCGT" | "VMAX" | "VMIN" | "VMLA" | "VMLS" | "VMUL" | "VQADD" | "VQSUB" | "VSUB" => {
+			unconditional(predicate)?;
+
+			let element                     = element(types)?;
+			let [destination, base, source] = vector_registers(operands)?;
+
+			match name {
+				"VABD"  => VectorAbsoluteDifference { element, destination, base, source },
+				"VADD"  => VectorAdd { element, destination, base, source },
+				"VCEQ"  => VectorCompareEqual { element, destination, base, source },
+				"VCGE"  => VectorCompareGreaterThanOrEqual { element, destination, base, source },
+				"VCGT"  => VectorCompareGreaterThan { element, destination, base, source },
+				"VMAX"  => VectorMaximum { element, destination, base, source },
+				"VMIN"  => VectorMinimum { element, destination, base, source },
+				"VMLA"  => VectorMultiplyAccumulate { element, destination, base, source },
+				"VMLS"  => VectorMultiplySubtract { element, destination, base, source },
+				"VMUL"  => VectorMultiply { element, destination, base, source },
+				"VQADD" => VectorSaturatingAdd { element, destination, base, source },
+				"VQSUB" => VectorSaturatingSubtract { element, destination, base, source },
+				_       => VectorSubtract { element, destination, base, source },
+			}
+		},
+
+		"VMVN" | "VSWP" => {
+			unconditional(predicate)?;
+
+			let [destination, source] = vector_registers(operands)?;
+
+			if name == "VMVN" {
+				VectorNot { destination, source }
+			} else {
+				VectorSwap { destination, source }
+			}
+		},
+
+		"VABS" | "VNEG" | "VREV16" | "VREV32" | "VREV64" | "VTRN" | "VUZP" | "VZIP" => {
+			unconditional(predicate)?;
+
+			let element               = element(types)?;
+			let [destination, source] = vector_registers(operands)?;
+
+			match name {
+				"VABS"   => VectorAbsolute { element, destination, source },
+				"VNEG"   => VectorNegate { element, destination, source },
+				"VREV16" => VectorReverse16 { element, destination, source },
+				"VREV32" => VectorReverse32 { element, destination, source },
+				"VREV64" => VectorReverse64 { element, destination, source },
+				"VTRN"   => VectorTranspose { element, destination, source },
+				"VUZP"   => VectorUnzip { element, destination, source },
+				_        => VectorZip { element, destination, source },
+			}
+		},
+
+		"VSHL" | "VSHR" => {
+			unconditional(predicate)?;
+
+			let element               = element(types)?;
+			let [destination, source] = vector_registers(operands)?;
+			let shift                 = operands.immediate()?;
+
+			if name == "VSHL" {
+				VectorShiftLeft { element, destination, source, shift }
+			} else {
+				VectorShiftRight { element, destination, source, shift }
+			}
+		},
+
+		"VEXT" => {
+			unconditional(predicate)?;
+
+			assert_or_err!(element(types)?.size() == 0x8, Error::IllegalInstruction { reason: "VEXT only operates on bytes" });
+
+			let [destination, base, source] = vector_registers(operands)?;
+			let index                       = operands.immediate()?;
+
+			VectorExtract { destination, base, source, index }
+		},
+
+		_ => return Err(Error::UnknownMnemonic(text.to_owned())),
+	};
+
+	Ok(instruction)
+}
+
+/// Parses a `VCVT` instruction.
+///
+/// The kind of conversion is given by the pair of data types.
+fn parse_convert(predicate: Predicate, r: Rflag, types: &[DataType], operands: &mut Operands) -> Result<Instruction> {
+	use Instruction::*;
+
+	const S32: DataType = DataType::Element(ElementType::S32);
+	const U32: DataType = DataType::Element(ElementType::U32);
+
+	let instruction = match *types {
+		[target @ (S32 | U32), from] => {
+			let double      = precision(&[from])?;
+			let destination = operands.parse::<SingleRegister>()?;
+			let source      = operands.parse()?;
+
+			check_precision(double, &[source])?;
+
+			if target == S32 {
+				FloatToSigned { predicate, destination, source, r }
+			} else {
+				FloatToUnsigned { predicate, destination, source, r }
+			}
+		},
+
+		[to, from @ (S32 | U32)] => {
+			assert_or_err!(r.is_off(), Error::IllegalFlag { reason: "only conversions to integers can round using the FPSCR" });
+
+			let double      = precision(&[to])?;
+			let destination = operands.parse()?;
+			let source      = operands.parse::<SingleRegister>()?;
+
+			check_precision(double, &[destination])?;
+
+			if from == S32 {
+				SignedToFloat { predicate, destination, source }
+			} else {
+				UnsignedToFloat { predicate, destination, source }
+			}
+		},
+
+		[to, from] => {
+			assert_or_err!(r.is_off(), Error::IllegalFlag { reason: "only conversions to integers can round using the FPSCR" });
+
+			let to_double   = precision(&[to])?;
+			let from_double = precision(&[from])?;
+
+			assert_or_err!(to_double != from_double, Error::IllegalInstruction { reason: "conversion between identical precisions" });
+
+			let destination = operands.parse()?;
+			let source      = operands.parse()?;
+
+			check_precision(to_double, &[destination])?;
+			check_precision(from_double, &[source])?;
+
+			FloatConvert { predicate, destination, source }
+		},
+
+		_ => return Err(Error::IllegalInstruction { reason: "conversions take exactly two data types" }),
+	};
+
+	Ok(instruction)
+}
+
+/// Parses a `VMOV` instruction.
+///
+/// The kind of move is given by the kinds of the operands.
+fn parse_move(predicate: Predicate, types: &[DataType], operands: &mut Operands) -> Result<Instruction> {
+	use Instruction::*;
+
+	let is_core = |item: Option<&str>| item.is_some_and(|item| item.parse::<Register>().is_ok());
+
+	let first  = operands.peek();
+	let second = operands.peek_nth(0x1);
+
+	let instruction = if first.is_some_and(|item| item.contains('[')) {
+		let element     = element(types)?;
+		let destination = operands.scalar()?;
+		let source      = operands.parse()?;
+
+		MoveToScalar { predicate, element, destination, source }
+	} else if second.is_some_and(|item| item.contains('[')) {
+		let element     = element(types)?;
+		let destination = operands.parse()?;
+		let source      = operands.scalar()?;
+
+		MoveFromScalar { predicate, element, destination, source }
+	} else if operands.remaining() == 0x3 {
+		if is_core(first) {
+			let [low, high] = registers(operands)?;
+			let source      = operands.parse()?;
+
+			MoveFromFloatDouble { predicate, low, high, source }
+		} else {
+			let destination = operands.parse()?;
+			let [low, high] = registers(operands)?;
+
+			MoveToFloatDouble { predicate, destination, low, high }
+		}
+	} else if is_core(first) {
+		MoveFromFloat { predicate, destination: operands.parse()?, source: operands.parse()? }
+	} else if is_core(second) {
+		MoveToFloat { predicate, destination: operands.parse()?, source: operands.parse()? }
+	} else if second.is_some_and(is_immediate) {
+		let double      = precision(types)?;
+		let destination = operands.parse()?;
+
+		let immediate = operands
+			.float_immediate()?
+			.ok_or(Error::IllegalImmediate { reason: "value cannot be encoded as a floating-point immediate" })?;
+
+		check_precision(double, &[destination])?;
+
+		FloatMoveImmediate { predicate, destination, immediate }
+	} else if is_float(types, operands) {
+		let (destination, source) = float_registers(types, operands)?;
+
+		FloatMove { predicate, destination, source }
+	} else {
+		unconditional(predicate)?;
+
+		let [destination, source] = vector_registers(operands)?;
+
+		VectorInclusiveOr { destination, base: source, source }
+	};
+
+	Ok(instruction)
+}
+
+/// Parses the operands of a data-processing instruction.
+///
+/// The base register may be omitted, in which case the destination register is used in its place.
+fn data_processing(operands: &mut Operands) -> Result<(Register, Register, Shifter)> {
+	let destination = operands.parse()?;
+
+	let base = if operands.remaining() >= 0x2 && !operands.peek_nth(0x1).is_some_and(is_shift) {
+		operands.parse()?
+	} else {
+		destination
+	};
+
+	let source = operands.shifter()?;
+
+	Ok((destination, base, source))
+}
+
+/// Parses three registers, of which the first may be omitted.
+///
+/// An omitted destination register is the same as the base register.
+fn three_registers(operands: &mut Operands) -> Result<(Register, Register, Register)> {
+	let destination = operands.parse()?;
+	let base        = if operands.remaining() >= 0x2 { operands.parse()? } else { destination };
+	let source      = operands.parse()?;
+
+	Ok((destination, base, source))
+}
+
+/// Parses a fixed number of general-purpose registers.
+fn registers<const N: usize>(operands: &mut Operands) -> Result<[Register; N]> {
+	let mut registers = [Register::R0; N];
+	for register in &mut registers { *register = operands.parse()? }
+
+	Ok(registers)
+}
+
+/// Parses a fixed number of Advanced SIMD registers.
+fn vector_registers<const N: usize>(operands: &mut Operands) -> Result<[VectorRegister; N]> {
+	let mut registers = [VectorRegister::Double(DoubleRegister::D0); N];
+	for register in &mut registers { *register = operands.parse()? }
+
+	Ok(registers)
+}
+
+/// Parses the destination and source registers of a VFP instruction.
+fn float_registers(types: &[DataType], operands: &mut Operands) -> Result<(VfpRegister, VfpRegister)> {
+	let double      = precision(types)?;
+	let destination = operands.parse()?;
+	let source      = operands.parse()?;
+
+	check_precision(double, &[destination, source])?;
+
+	Ok((destination, source))
+}
+
+/// Parses the optional second opcode of a coprocessor instruction.
+fn optional_opcode(operands: &mut Operands) -> Result<u8> {
+	if operands.remaining() == 0x0 { return Ok(0x0) };
+
+	operands.immediate()
+}
+
+/// Parses a processor mode given by its number, e.g. `#19`.
+fn processor_mode(operands: &mut Operands) -> Result<ProcessorMode> {
+	let mode = operands.immediate()?;
+
+	ProcessorMode::from_u8(mode).ok_or(Error::IllegalImmediate { reason: "unknown processor mode" })
+}
+
+/// Parses an optional barrier option.
+///
+/// A missing option defaults to `SY`.
+fn barrier_option(operands: &mut Operands) -> Result<BarrierOption> {
+	use BarrierOption::*;
+
+	let Some(item) = operands.peek() else { return Ok(Sy) };
+
+	if is_immediate(item) {
+		let option = operands.immediate()?;
+		return BarrierOption::from_u8(option).ok_or(Error::IllegalImmediate { reason: "reserved barrier option" });
+	}
+
+	let option = match operands.next_item()?.to_uppercase().as_str() {
+		"OSHST" => OshSt,
+		"OSH"   => Osh,
+		"NSHST" => NshSt,
+		"NSH"   => Nsh,
+		"ISHST" => IshSt,
+		"ISH"   => Ish,
+		"ST"    => St,
+		"SY"    => Sy,
+
+		_ => return Err(Error::IllegalInstruction { reason: "unknown barrier option" }),
+	};
+
+	Ok(option)
+}
+
+/// Parses an interrupt mask, e.g. `aif`.
+fn interrupt_mask(s: &str) -> Result<InterruptMask> {
+	let mut mask = InterruptMask::new();
+
+	for c in s.chars() {
+		mask |= match c.to_ascii_lowercase() {
+			'a' => InterruptMask::ABORT,
+			'i' => InterruptMask::IRQ,
+			'f' => InterruptMask::FIQ,
+
+			_ => return Err(Error::IllegalInstruction { reason: "malformed interrupt mask" }),
+		};
+	}
+
+	Ok(mask)
+}
+
+/// Asserts that the given operand names the `FPSCR`.
+fn fpscr(s: &str) -> Result<()> {
+	if s.eq_ignore_ascii_case("FPSCR") {
+		Ok(())
+	} else {
+		Err(Error::UnknownRegister(s.to_owned()))
+	}
+}
+
+/// Maps the suffix of a block transfer to its mode.
+///
+/// The stack-oriented suffixes denote opposite modes for loads and stores.
+fn block_mode(suffix: &str, load: bool) -> BlockMode {
+	use BlockMode::*;
+
+	match (suffix, load) {
+		("IB", _) | ("ED", true) | ("FA", false) => IncrementBefore,
+		("DA", _) | ("FA", true) | ("ED", false) => DecrementAfter,
+		("DB", _) | ("EA", true) | ("FD", false) => DecrementBefore,
+
+		_ => IncrementAfter,
+	}
+}
+
+/// Asserts that the instruction isn't conditional.
+fn unconditional(predicate: Predicate) -> Result<()> {
+	assert_or_err!(predicate == Predicate::Always, Error::IllegalPredicate { reason: "instruction cannot be conditional" });
+
+	Ok(())
+}
+
+/// Retrieves the sole element type.
+fn element(types: &[DataType]) -> Result<ElementType> {
+	match *types {
+		[DataType::Element(element)] => Ok(element),
+
+		_ => Err(Error::IllegalInstruction { reason: "expected exactly one element type" }),
+	}
+}
+
+/// Checks if the given data type denotes double precision.
+fn precision(types: &[DataType]) -> Result<bool> {
+	match *types {
+		[DataType::F64] => Ok(true),
+
+		[DataType::Element(ElementType::F32)] => Ok(false),
+
+		_ => Err(Error::IllegalInstruction { reason: "expected exactly one floating-point data type" }),
+	}
+}
+
+/// Asserts that the given registers are of the given precision.
+fn check_precision(double: bool, registers: &[VfpRegister]) -> Result<()> {
+	assert_or_err!(
+		registers.iter().all(|register| register.is_double() == double),
+		Error::IllegalRegister { reason: "register does not match data type" }
+	);
+
+	Ok(())
+}
+
+/// Checks if the instruction is a VFP (as opposed to an Advanced SIMD) instruction.
+///
+/// Only `F32` is shared between the two, in which case the kind of the first register decides.
+fn is_float(types: &[DataType], operands: &Operands) -> bool {
+	match *types {
+		[DataType::F64] => true,
+
+		[DataType::Element(ElementType::F32)] => operands.peek().is_some_and(|item| item.starts_with(['s', 'S'])),
+
+		_ => false,
 	}
-}
\ No newline at end of file
+}
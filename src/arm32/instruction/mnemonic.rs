@@ -0,0 +1,382 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Pollex.
+//
+// Pollex is free software: you can redistribute it
+// and/or modify it under the terms of the GNU Af-
+// fero General Public License as published by the
+// Free Software Foundation, either version 3 of
+// the License, or (at your option) any later ver-
+// sion.
+//
+// Pollex is distributed in the hope that it will
+// be useful, but WITHOUT ANY WARRANTY; without
+// even the implied warranty of MERCHANTABILITY or
+// FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// Affero General Public License for more details.
+//
+// You should have received a copy of the GNU Af-
+// fero General Public License along with Pollex.
+// If not, see <https://www.gnu.org/licenses/>.
+
+use crate::arm32::{
+	ElementType,
+	ParallelOperation,
+	ParallelPrefix,
+	Predicate,
+};
+
+/// The suffixes of mnemonics that take none.
+const NONE: &[&str] = &[""];
+
+/// The suffixes of mnemonics that take an S flag.
+const S: &[&str] = &["", "S"];
+
+/// The suffixes of block transfers.
+///
+/// The stack-oriented names (`FD`, `FA`, `ED`, `EA`) are resolved by the caller, as their meaning depends on the direction of the transfer.
+const MODES: &[&str] = &["", "IA", "IB", "DA", "DB", "FD", "FA", "ED", "EA"];
+
+/// The suffixes of floating-point block transfers.
+const VFP_MODES: &[&str] = &["", "IA", "DB", "FD", "EA"];
+
+/// The mnemonics and their permitted suffixes.
+///
+/// Suffixes may be written either before or after the condition code.
+/// This accepts both the pre-UAL syntax (e.g. `LDREQB`) and the unified syntax (e.g. `LDRBEQ`).
+const MNEMONICS: &[(&str, &[&str])] = &[
+	("ADC",     S),
+	("ADD",     S),
+	("AND",     S),
+	("ASR",     S),
+	("B",       NONE),
+	("BFC",     NONE),
+	("BFI",     NONE),
+	("BIC",     S),
+	("BKPT",    NONE),
+	("BL",      NONE),
+	("BLX",     NONE),
+	("BX",      NONE),
+	("CBNZ",    NONE),
+	("CBZ",     NONE),
+	("CDP",     NONE),
+	("CLREX",   NONE),
+	("CLZ",     NONE),
+	("CMN",     NONE),
+	("CMP",     NONE),
+	("CPS",     NONE),
+	("CPSID",   NONE),
+	("CPSIE",   NONE),
+	("CPY",     NONE),
+	("DMB",     NONE),
+	("DSB",     NONE),
+	("EOR",     S),
+	("ISB",     NONE),
+	("LDC",     &["", "L"]),
+	("LDM",     MODES),
+	("LDR",     &["", "B", "T", "BT", "H", "SB", "SH", "D"]),
+	("LDREX",   NONE),
+	("LDREXB",  NONE),
+	("LDREXD",  NONE),
+	("LDREXH",  NONE),
+	("LSL",     S),
+	("LSR",     S),
+	("MCR",     NONE),
+	("MCRR",    NONE),
+	("MLA",     S),
+	("MLS",     NONE),
+	("MOV",     S),
+	("MOVT",    NONE),
+	("MOVW",    NONE),
+	("MRC",     NONE),
+	("MRRC",    NONE),
+	("MRS",     NONE),
+	("MSR",     NONE),
+	("MUL",     S),
+	("MVN",     S),
+	("NEG",     S),
+	("NOP",     NONE),
+	("ORN",     S),
+	("ORR",     S),
+	("PKHBT",   NONE),
+	("PKHTB",   NONE),
+	("PLD",     NONE),
+	("POP",     NONE),
+	("PUSH",    NONE),
+	("QADD",    NONE),
+	("QDADD",   NONE),
+	("QDSUB",   NONE),
+	("QSUB",    NONE),
+	("RBIT",    NONE),
+	("REV",     NONE),
+	("REV16",   NONE),
+	("REVSH",   NONE),
+	("ROR",     S),
+	("RRX",     S),
+	("RSB",     S),
+	("RSC",     S),
+	("SBC",     S),
+	("SBFX",    NONE),
+	("SDIV",    NONE),
+	("SEL",     NONE),
+	("SETEND",  NONE),
+	("SEV",     NONE),
+	("SMLAL",   S),
+	("SMULL",   S),
+	("SSAT",    NONE),
+	("SSAT16",  NONE),
+	("STC",     &["", "L"]),
+	("STM",     MODES),
+	("STR",     &["", "B", "T", "BT", "H", "D"]),
+	("STREX",   NONE),
+	("STREXB",  NONE),
+	("STREXD",  NONE),
+	("STREXH",  NONE),
+	("SUB",     S),
+	("SVC",     NONE),
+	("SWI",     NONE),
+	("SWP",     &["", "B"]),
+	("SXTAB",   NONE),
+	("SXTAB16", NONE),
+	("SXTAH",   NONE),
+	("SXTB",    NONE),
+	("SXTB16",  NONE),
+	("SXTH",    NONE),
+	("TBB",     NONE),
+	("TBH",     NONE),
+	("TEQ",     NONE),
+	("TST",     NONE),
+	("UBFX",    NONE),
+	("UDIV",    NONE),
+	("UMAAL",   NONE),
+	("UMLAL",   S),
+	("UMULL",   S),
+	("USAD8",   NONE),
+	("USADA8",  NONE),
+	("USAT",    NONE),
+	("USAT16",  NONE),
+	("UXTAB",   NONE),
+	("UXTAB16", NONE),
+	("UXTAH",   NONE),
+	("UXTB",    NONE),
+	("UXTB16",  NONE),
+	("UXTH",    NONE),
+	("VABD",    NONE),
+	("VABS",    NONE),
+	("VADD",    NONE),
+	("VAND",    NONE),
+	("VBIC",    NONE),
+	("VCEQ",    NONE),
+	("VCGE",    NONE),
+	("VCGT",    NONE),
+	("VCMP",    &["", "E"]),
+	("VCVT",    &["", "R"]),
+	("VDIV",    NONE),
+	("VDUP",    NONE),
+	("VEOR",    NONE),
+	("VEXT",    NONE),
+	("VLD1",    NONE),
+	("VLD2",    NONE),
+	("VLD3",    NONE),
+	("VLD4",    NONE),
+	("VLDM",    VFP_MODES),
+	("VLDR",    NONE),
+	("VMAX",    NONE),
+	("VMIN",    NONE),
+	("VMLA",    NONE),
+	("VMLS",    NONE),
+	("VMOV",    NONE),
+	("VMRS",    NONE),
+	("VMSR",    NONE),
+	("VMUL",    NONE),
+	("VMVN",    NONE),
+	("VNEG",    NONE),
+	("VORR",    NONE),
+	("VPOP",    NONE),
+	("VPUSH",   NONE),
+	("VQADD",   NONE),
+	("VQSUB",   NONE),
+	("VREV16",  NONE),
+	("VREV32",  NONE),
+	("VREV64",  NONE),
+	("VSHL",    NONE),
+	("VSHR",    NONE),
+	("VSQRT",   NONE),
+	("VST1",    NONE),
+	("VST2",    NONE),
+	("VST3",    NONE),
+	("VST4",    NONE),
+	("VSTM",    VFP_MODES),
+	("VSTR",    NONE),
+	("VSUB",    NONE),
+	("VSWP",    NONE),
+	("VTRN",    NONE),
+	("VUZP",    NONE),
+	("VZIP",    NONE),
+	("WFE",     NONE),
+	("WFI",     NONE),
+	("YIELD",   NONE),
+];
+
+/// The prefixes of parallel arithmetic.
+///
+/// Longer prefixes are listed first, so that e.g. `SH` isn't mistaken for `S`.
+const PARALLEL_PREFIXES: [(&str, ParallelPrefix); 0x6] = [
+	("SH", ParallelPrefix::SignedHalving),
+	("UQ", ParallelPrefix::UnsignedSaturating),
+	("UH", ParallelPrefix::UnsignedHalving),
+	("S",  ParallelPrefix::Signed),
+	("Q",  ParallelPrefix::Saturating),
+	("U",  ParallelPrefix::Unsigned),
+];
+
+/// The operations of parallel arithmetic, including their unified names.
+const PARALLEL_OPERATIONS: [(&str, ParallelOperation); 0x8] = [
+	("ADD16",   ParallelOperation::Add16),
+	("ADDSUBX", ParallelOperation::AddSubtractExchange),
+	("ASX",     ParallelOperation::AddSubtractExchange),
+	("SUBADDX", ParallelOperation::SubtractAddExchange),
+	("SAX",     ParallelOperation::SubtractAddExchange),
+	("SUB16",   ParallelOperation::Subtract16),
+	("ADD8",    ParallelOperation::Add8),
+	("SUB8",    ParallelOperation::Subtract8),
+];
+
+/// A data type of a floating-point or Advanced SIMD instruction, e.g. the `F32` in `VADD.F32`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(in super) enum DataType {
+	Element(ElementType),
+
+	/// The double-precision type, which has no Advanced SIMD counterpart.
+	F64,
+}
+
+impl DataType {
+	/// Parses an (uppercase) data type.
+	#[must_use]
+	pub(in super) fn parse(s: &str) -> Option<Self> {
+		use ElementType::*;
+
+		let element = match s {
+			"I8"  => I8,
+			"I16" => I16,
+			"I32" => I32,
+			"I64" => I64,
+			"S8"  => S8,
+			"S16" => S16,
+			"S32" => S32,
+			"S64" => S64,
+			"U8"  => U8,
+			"U16" => U16,
+			"U32" => U32,
+			"U64" => U64,
+			"F32" => F32,
+			"P8"  => P8,
+			"8"   => Untyped8,
+			"16"  => Untyped16,
+			"32"  => Untyped32,
+			"64"  => Untyped64,
+
+			"F64" => return Some(Self::F64),
+
+			_ => return None,
+		};
+
+		Some(Self::Element(element))
+	}
+}
+
+/// Parses an (uppercase) condition code.
+///
+/// Both the unified and the legacy names of the carry conditions are accepted.
+#[must_use]
+pub(in super) fn parse_predicate(s: &str) -> Option<Predicate> {
+	use Predicate::*;
+
+	let predicate = match s {
+		"EQ"        => Equal,
+		"NE"        => NotEqual,
+		"HS" | "CS" => HigherOrSame,
+		"LO" | "CC" => Lower,
+		"MI"        => Minus,
+		"PL"        => Plus,
+		"VS"        => Overflow,
+		"VC"        => NoOverflow,
+		"HI"        => Higher,
+		"LS"        => LowerOrSame,
+		"GE"        => GreaterThanOrEqual,
+		"LT"        => LessThan,
+		"GT"        => GreaterThan,
+		"LE"        => LessThanOrEqual,
+		"AL"        => Always,
+
+		_ => return None,
+	};
+
+	Some(predicate)
+}
+
+/// Splits the given suffix into the fixed part and an optional condition code.
+#[must_use]
+fn split_suffix(tail: &str, suffix: &str) -> Option<Predicate> {
+	if tail == suffix { return Some(Predicate::Always) };
+
+	// Try both orders.
+	tail
+		.strip_suffix(suffix)
+		.and_then(parse_predicate)
+		.or_else(|| tail.strip_prefix(suffix).and_then(parse_predicate))
+}
+
+/// Splits an (uppercase) mnemonic into its base, suffix, and predicate.
+#[must_use]
+pub(in super) fn split_mnemonic(name: &str) -> Option<(&'static str, &'static str, Predicate)> {
+	for &(base, suffixes) in MNEMONICS {
+		let Some(tail) = name.strip_prefix(base) else { continue };
+
+		for &suffix in suffixes {
+			if let Some(predicate) = split_suffix(tail, suffix) { return Some((base, suffix, predicate)) };
+		}
+	}
+
+	None
+}
+
+/// Splits an (uppercase) parallel arithmetic mnemonic, e.g. `UQADD8NE`.
+#[must_use]
+pub(in super) fn split_parallel(name: &str) -> Option<(ParallelPrefix, ParallelOperation, Predicate)> {
+	for (prefix_name, prefix) in PARALLEL_PREFIXES {
+		let Some(tail) = name.strip_prefix(prefix_name) else { continue };
+
+		for (operation_name, operation) in PARALLEL_OPERATIONS {
+			let Some(tail) = tail.strip_prefix(operation_name) else { continue };
+
+			if let Some(predicate) = split_suffix(tail, "") { return Some((prefix, operation, predicate)) };
+		}
+	}
+
+	None
+}
+
+/// Parses an (uppercase) if-then mnemonic, e.g. `ITTE`, into its mask.
+///
+/// See [`IfThen`](crate::arm32::Instruction::IfThen) for the format of the mask.
+#[must_use]
+pub(in super) fn split_if_then(name: &str) -> Option<u8> {
+	let tail = name.strip_prefix("IT")?;
+
+	if tail.len() > 0x3 { return None };
+
+	let mut mask = 0b1000 >> tail.len();
+
+	for (index, c) in tail.chars().enumerate() {
+		match c {
+			'T' => mask |= 0b1000 >> index,
+			'E' => { },
+
+			_ => return None,
+		}
+	}
+
+	Some(mask)
+}
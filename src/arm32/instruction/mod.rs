@@ -21,15 +21,41 @@
 
 mod display;
 mod from_str;
+mod mnemonic;
+mod operands;
 
 use crate::arm32::{
 	Address,
+	BarrierOption,
 	Bflag,
+	BlockMode,
+	Coprocessor,
+	CoprocessorRegister,
+	DoubleRegister,
+	Eflag,
+	ElementType,
+	Endianness,
+	FloatImmediate,
+	InterruptMask,
+	Lflag,
+	ParallelOperation,
+	ParallelPrefix,
 	Predicate,
+	ProcessorMode,
+	Rflag,
 	Sflag,
 	Register,
+	RegisterList,
+	Scalar,
 	Shifter,
-	Tflag
+	SingleRegister,
+	StatusFields,
+	StatusRegister,
+	StructureAddress,
+	Tflag,
+	VectorRegister,
+	VfpRegister,
+	Wflag,
 };
 
 /// An Arm32 instruction.
@@ -51,6 +77,111 @@ use crate::arm32::{
 ///
 /// Also note that not all operands can be encoded in Arm instruction sets.
 /// Even the largest immediates usually have a limit at `24` significant figures.
+///
+/// # Memory transfers
+///
+/// Words and bytes are transferred using [`Load`](Instruction::Load) and [`Store`](Instruction::Store).
+/// Halfwords, signed bytes, and doublewords instead use dedicated variants (e.g. [`LoadHalfword`](Instruction::LoadHalfword)).
+/// These use a more restricted addressing mode, with only `8`-bit immediate offsets and unshifted register offsets.
+///
+/// Doubleword transfers take two registers: `first` is transferred from/to the address itself and `second` from/to the following word.
+/// On Arm, `first` must be even-numbered and `second` must be the one immediately after it.
+///
+/// Multiple registers are transferred using [`LoadMultiple`](Instruction::LoadMultiple) and [`StoreMultiple`](Instruction::StoreMultiple).
+/// The `w` flag writes the final address back to `base`, and the `s` flag (`^` in assembly) selects the user-mode registers, or -- for loads including `pc` -- restores `CPSR` from `SPSR`.
+/// `PUSH` and `POP` are provided as synonyms of `STMDB sp!` and `LDMIA sp!`, respectively.
+///
+/// [`PreloadData`](Instruction::PreloadData) (`PLD`) hints that the memory at `address` will soon be accessed, and only takes unindexed addresses.
+///
+/// # Branches
+///
+/// Branch targets are given as addresses, which are encoded relative to the address of the branch itself.
+/// The Thumb-only [`CompareBranchZero`](Instruction::CompareBranchZero) (`CBZ`) and [`CompareBranchNonZero`](Instruction::CompareBranchNonZero) (`CBNZ`) can only branch forwards by up to (126) bytes, and cannot be used within if-then blocks.
+///
+/// The table branches [`TableBranchByte`](Instruction::TableBranchByte) (`TBB`) and [`TableBranchHalfword`](Instruction::TableBranchHalfword) (`TBH`) load the `index`th entry of the table at `base`, and branch forwards by twice its value.
+/// These are likewise only available on Thumb.
+///
+/// # Exclusive access
+///
+/// Exclusive transfers (e.g. [`LoadExclusive`](Instruction::LoadExclusive)) only take a base register as their address on Arm.
+/// Thumb additionally allows word-aligned immediate offsets of up to (1020) for word transfers.
+/// Exclusive stores write their result (`0` on success, `1` on failure) to `status`.
+///
+/// # Extensions
+///
+/// The extending instructions (e.g. [`SignExtendByte`](Instruction::SignExtendByte)) first rotate their source right by `rotation`, which must be any of `0`, `8`, `16`, or `24`.
+/// The `16` variants extend two bytes (at bits `0` and `16`) into two halfwords, and the `Add` variants add the extended value to `base`.
+///
+/// [`PackHalfword`](Instruction::PackHalfword) uses a left shift of `source` to denote `PKHBT` and an arithmetic right shift to denote `PKHTB`.
+///
+/// # Bitfields
+///
+/// Bitfields are denoted by their least significant bit (`lsb`) and their `width`.
+/// The former must be at most (31), and the latter must be at least (1) and fit within the remaining bits.
+///
+/// # Saturation
+///
+/// The saturating instructions clamp their results to the range of a signed or unsigned integer.
+/// [`SignedSaturate`](Instruction::SignedSaturate) and [`UnsignedSaturate`](Instruction::UnsignedSaturate) saturate to `immediate` bits, which must be in the ranges (1) to (32) and (0) to (31), respectively.
+/// Their source may only be shifted by an immediate `LSL` or `ASR`.
+/// The `16` variants saturate two halfwords separately, using the ranges (1) to (16) and (0) to (15).
+///
+/// The doubling variants (e.g. [`SaturatingDoubleAdd`](Instruction::SaturatingDoubleAdd)) saturate twice the value of `source` before adding it to (or subtracting it from) `base`.
+///
+/// # Status registers
+///
+/// Status registers are read using [`MoveFromStatusRegister`](Instruction::MoveFromStatusRegister) and written using [`MoveToStatusRegister`](Instruction::MoveToStatusRegister).
+/// The latter only writes the fields selected by its mask, and its source must be either an immediate or an unshifted register.
+///
+/// # If-then blocks
+///
+/// On Thumb, [`IfThen`](Instruction::IfThen) (`IT`) predicates the (up to four) instructions following it.
+/// The first of these uses `predicate` itself, whilst bits (3) to (1) of `mask` each denote one of the remaining ones: set bits select `predicate` (*then*) and cleared bits its inverse (*else*).
+/// The block ends just before the lowest set bit of `mask`, so e.g. `ITTE EQ` is denoted by `0b1010` and a lone `IT EQ` by `0b1000`.
+///
+/// The instructions within a block still carry their own predicates, which must match those given by the block.
+/// See [`InstructionCodec`](crate::arm32::InstructionCodec) for more information.
+///
+/// # Processor state
+///
+/// Interrupts are masked using [`DisableInterrupts`](Instruction::DisableInterrupts) (`CPSID`) and unmasked using [`EnableInterrupts`](Instruction::EnableInterrupts) (`CPSIE`).
+/// Both require a non-empty mask and may additionally change the processor mode, which can also be done on its own using [`ChangeProcessorMode`](Instruction::ChangeProcessorMode) (`CPS`).
+/// These instructions are unconditional, and Thumb can only change the mode using its wide encodings.
+///
+/// The hints (e.g. [`WaitForInterrupt`](Instruction::WaitForInterrupt)) behave as no-ops on cores that do not implement them.
+///
+/// # Coprocessors
+///
+/// Coprocessor instructions take the coprocessor number along with opcodes and registers whose meaning is defined by the coprocessor itself.
+/// `opcode1` is at most `3` bits wide for [`MoveToCoprocessor`](Instruction::MoveToCoprocessor) and [`MoveFromCoprocessor`](Instruction::MoveFromCoprocessor), and `4` bits otherwise; `opcode2` is always at most `3` bits wide.
+///
+/// The memory transfers [`LoadCoprocessor`](Instruction::LoadCoprocessor) and [`StoreCoprocessor`](Instruction::StoreCoprocessor) only accept word-aligned immediate offsets of up to (1020).
+///
+/// # Floating-point
+///
+/// VFP instructions (e.g. [`FloatAdd`](Instruction::FloatAdd)) take [`VfpRegister`] operands, whose kind selects between single and double precision.
+/// All of these operands must be of the same precision, except for those of conversions.
+/// [`FloatCompare`](Instruction::FloatCompare) compares against `0.0` if `rhs` is [`None`].
+///
+/// [`FloatLoad`](Instruction::FloatLoad) and [`FloatStore`](Instruction::FloatStore) only accept word-aligned immediate offsets of up to (1020).
+/// [`FloatLoadMultiple`](Instruction::FloatLoadMultiple) and [`FloatStoreMultiple`](Instruction::FloatStoreMultiple) transfer `count` consecutive registers starting at `first`.
+/// Only increment-after and decrement-before (with writeback) are supported, and `VPUSH` and `VPOP` are provided as synonyms of `VSTMDB sp!` and `VLDMIA sp!`.
+///
+/// # Advanced SIMD
+///
+/// Advanced SIMD (NEON) instructions (e.g. [`VectorAdd`](Instruction::VectorAdd)) take [`VectorRegister`] operands, whose kind selects between double and quad registers.
+/// These operands must all be of the same kind, and the `element` type must be one supported by the instruction in question.
+/// Most of these instructions are unconditional on Arm and thus have no predicate.
+///
+/// The structure transfers [`VectorLoadStructure`](Instruction::VectorLoadStructure) and [`VectorStoreStructure`](Instruction::VectorStoreStructure) (`VLDn` and `VSTn`, with *n* being `interleave`) transfer `count` registers, starting at `first` and spaced by `spacing`.
+///
+/// # Multiplications
+///
+/// Multiplications take their factors in `base` and `source` (`Rm` and `Rs` in Arm terminology).
+/// Long multiplications yield a `64`-bit product split across `destination_low` and `destination_high`, which the accumulating variants also add to.
+///
+/// Older cores (before Armv6) cannot use `base` as a destination.
+/// This is only checked if enabled (see [`set_pre_armv6`](crate::arm32::InstructionCodec::set_pre_armv6)).
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Instruction {
 	Add {
@@ -85,6 +216,21 @@ pub enum Instruction {
 		s:           Sflag,
 	},
 
+	BitFieldClear {
+		predicate:   Predicate,
+		destination: Register,
+		lsb:         u32,
+		width:       u32,
+	},
+
+	BitFieldInsert {
+		predicate:   Predicate,
+		destination: Register,
+		source:      Register,
+		lsb:         u32,
+		width:       u32,
+	},
+
 	Branch {
 		predicate: Predicate,
 		immediate: u32,
@@ -109,6 +255,22 @@ pub enum Instruction {
 		immediate: u32,
 	},
 
+	ChangeProcessorMode {
+		mode: ProcessorMode,
+	},
+
+	ClearExclusive,
+
+	CoprocessorDataProcessing {
+		predicate:   Predicate,
+		coprocessor: Coprocessor,
+		opcode1:     u8,
+		crd:         CoprocessorRegister,
+		crn:         CoprocessorRegister,
+		crm:         CoprocessorRegister,
+		opcode2:     u8,
+	},
+
 	CountLeadingZeroes {
 		predicate:   Predicate,
 		destination: Register,
@@ -121,12 +283,40 @@ pub enum Instruction {
 		rhs:       Shifter,
 	},
 
+	CompareBranchNonZero {
+		source:    Register,
+		immediate: u32,
+	},
+
+	CompareBranchZero {
+		source:    Register,
+		immediate: u32,
+	},
+
 	CompareNegated {
 		predicate: Predicate,
 		lhs:       Register,
 		rhs:       Shifter,
 	},
 
+	DataMemoryBarrier {
+		option: BarrierOption,
+	},
+
+	DataSynchronisationBarrier {
+		option: BarrierOption,
+	},
+
+	DisableInterrupts {
+		mask: InterruptMask,
+		mode: Option<ProcessorMode>,
+	},
+
+	EnableInterrupts {
+		mask: InterruptMask,
+		mode: Option<ProcessorMode>,
+	},
+
 	ExclusiveOr {
 		predicate:   Predicate,
 		destination: Register,
@@ -135,6 +325,126 @@ pub enum Instruction {
 		s:           Sflag,
 	},
 
+	FloatAbsolute {
+		predicate:   Predicate,
+		destination: VfpRegister,
+		source:      VfpRegister,
+	},
+
+	FloatAdd {
+		predicate:   Predicate,
+		destination: VfpRegister,
+		base:        VfpRegister,
+		source:      VfpRegister,
+	},
+
+	FloatCompare {
+		predicate: Predicate,
+		lhs:       VfpRegister,
+		rhs:       Option<VfpRegister>,
+		e:         Eflag,
+	},
+
+	FloatConvert {
+		predicate:   Predicate,
+		destination: VfpRegister,
+		source:      VfpRegister,
+	},
+
+	FloatDivide {
+		predicate:   Predicate,
+		destination: VfpRegister,
+		base:        VfpRegister,
+		source:      VfpRegister,
+	},
+
+	FloatLoad {
+		predicate: Predicate,
+		register:  VfpRegister,
+		address:   Address,
+	},
+
+	FloatLoadMultiple {
+		predicate: Predicate,
+		base:      Register,
+		first:     VfpRegister,
+		count:     u8,
+		mode:      BlockMode,
+		w:         Wflag,
+	},
+
+	FloatMove {
+		predicate:   Predicate,
+		destination: VfpRegister,
+		source:      VfpRegister,
+	},
+
+	FloatMoveImmediate {
+		predicate:   Predicate,
+		destination: VfpRegister,
+		immediate:   FloatImmediate,
+	},
+
+	FloatMultiply {
+		predicate:   Predicate,
+		destination: VfpRegister,
+		base:        VfpRegister,
+		source:      VfpRegister,
+	},
+
+	FloatNegate {
+		predicate:   Predicate,
+		destination: VfpRegister,
+		source:      VfpRegister,
+	},
+
+	FloatSquareRoot {
+		predicate:   Predicate,
+		destination: VfpRegister,
+		source:      VfpRegister,
+	},
+
+	FloatStore {
+		predicate: Predicate,
+		register:  VfpRegister,
+		address:   Address,
+	},
+
+	FloatStoreMultiple {
+		predicate: Predicate,
+		base:      Register,
+		first:     VfpRegister,
+		count:     u8,
+		mode:      BlockMode,
+		w:         Wflag,
+	},
+
+	FloatSubtract {
+		predicate:   Predicate,
+		destination: VfpRegister,
+		base:        VfpRegister,
+		source:      VfpRegister,
+	},
+
+	FloatToSigned {
+		predicate:   Predicate,
+		destination: SingleRegister,
+		source:      VfpRegister,
+		r:           Rflag,
+	},
+
+	FloatToUnsigned {
+		predicate:   Predicate,
+		destination: SingleRegister,
+		source:      VfpRegister,
+		r:           Rflag,
+	},
+
+	IfThen {
+		predicate: Predicate,
+		mask:      u8,
+	},
+
 	InclusiveOr {
 		predicate:   Predicate,
 		destination: Register,
@@ -143,6 +453,18 @@ pub enum Instruction {
 		s:           Sflag,
 	},
 
+	InclusiveOrNot {
+		predicate:   Predicate,
+		destination: Register,
+		base:        Register,
+		source:      Shifter,
+		s:           Sflag,
+	},
+
+	InstructionSynchronisationBarrier {
+		option: BarrierOption,
+	},
+
 	Load {
 		predicate: Predicate,
 		register:  Register,
@@ -151,6 +473,73 @@ pub enum Instruction {
 		t:         Tflag,
 	},
 
+	LoadCoprocessor {
+		predicate:   Predicate,
+		coprocessor: Coprocessor,
+		crd:         CoprocessorRegister,
+		address:     Address,
+		l:           Lflag,
+	},
+
+	LoadDoubleword {
+		predicate: Predicate,
+		first:     Register,
+		second:    Register,
+		address:   Address,
+	},
+
+	LoadExclusive {
+		predicate: Predicate,
+		register:  Register,
+		address:   Address,
+	},
+
+	LoadExclusiveByte {
+		predicate: Predicate,
+		register:  Register,
+		address:   Address,
+	},
+
+	LoadExclusiveDoubleword {
+		predicate: Predicate,
+		first:     Register,
+		second:    Register,
+		address:   Address,
+	},
+
+	LoadExclusiveHalfword {
+		predicate: Predicate,
+		register:  Register,
+		address:   Address,
+	},
+
+	LoadHalfword {
+		predicate: Predicate,
+		register:  Register,
+		address:   Address,
+	},
+
+	LoadMultiple {
+		predicate: Predicate,
+		base:      Register,
+		registers: RegisterList,
+		mode:      BlockMode,
+		w:         Wflag,
+		s:         Sflag,
+	},
+
+	LoadSignedByte {
+		predicate: Predicate,
+		register:  Register,
+		address:   Address,
+	},
+
+	LoadSignedHalfword {
+		predicate: Predicate,
+		register:  Register,
+		address:   Address,
+	},
+
 	Move {
 		predicate:   Predicate,
 		destination: Register,
@@ -158,118 +547,1127 @@ pub enum Instruction {
 		s:           Sflag,
 	},
 
-	MoveNot {
+	MoveFromCoprocessor {
 		predicate:   Predicate,
-		destination: Register,
-		source:      Shifter,
-		s:           Sflag,
+		coprocessor: Coprocessor,
+		opcode1:     u8,
+		register:    Register,
+		crn:         CoprocessorRegister,
+		crm:         CoprocessorRegister,
+		opcode2:     u8,
 	},
 
-	Multiply {
+	MoveFromCoprocessorDouble {
+		predicate:   Predicate,
+		coprocessor: Coprocessor,
+		opcode1:     u8,
+		first:       Register,
+		second:      Register,
+		crm:         CoprocessorRegister,
+	},
+
+	MoveFromFloat {
 		predicate:   Predicate,
 		destination: Register,
-		base:        Register,
-		source:      Register,
-		s:           Sflag,
+		source:      SingleRegister,
 	},
 
-	MultiplyAccumulate {
+	MoveFromFloatDouble {
+		predicate: Predicate,
+		low:       Register,
+		high:      Register,
+		source:    DoubleRegister,
+	},
+
+	MoveFromFloatStatus {
 		predicate:   Predicate,
 		destination: Register,
-		base:        Register,
-		source:      Register,
-		shift:       Register,
-		s:           Sflag,
 	},
 
-	Reverse {
+	MoveFromScalar {
 		predicate:   Predicate,
+		element:     ElementType,
 		destination: Register,
-		source:      Register,
+		source:      Scalar,
 	},
 
-	ReverseSubtract {
+	MoveFromStatusRegister {
 		predicate:   Predicate,
 		destination: Register,
-		base:        Register,
-		source:      Shifter,
-		s:           Sflag,
+		source:      StatusRegister,
 	},
 
-	ReverseSubtractCarry {
+	MoveNot {
 		predicate:   Predicate,
 		destination: Register,
-		base:        Register,
 		source:      Shifter,
 		s:           Sflag,
 	},
 
-	SaturatingAdd {
+	MoveToCoprocessor {
 		predicate:   Predicate,
-		destination: Register,
-		base:        Register,
-		source:      Register,
+		coprocessor: Coprocessor,
+		opcode1:     u8,
+		register:    Register,
+		crn:         CoprocessorRegister,
+		crm:         CoprocessorRegister,
+		opcode2:     u8,
 	},
 
-	SaturatingSubtract {
+	MoveToCoprocessorDouble {
 		predicate:   Predicate,
-		destination: Register,
-		base:        Register,
+		coprocessor: Coprocessor,
+		opcode1:     u8,
+		first:       Register,
+		second:      Register,
+		crm:         CoprocessorRegister,
+	},
+
+	MoveToFloat {
+		predicate:   Predicate,
+		destination: SingleRegister,
 		source:      Register,
 	},
 
-	SoftwareInterrupt {
-		predicate: Predicate,
-		immediate: u32,
+	MoveToFloatDouble {
+		predicate:   Predicate,
+		destination: DoubleRegister,
+		low:         Register,
+		high:        Register,
 	},
 
-	Store {
+	MoveToFloatStatus {
 		predicate: Predicate,
-		register:  Register,
-		address:   Address,
-		b:         Bflag,
-		t:         Tflag,
+		source:    Register,
 	},
 
-	Subtract {
+	MoveToScalar {
 		predicate:   Predicate,
-		destination: Register,
-		base:        Register,
-		source:      Shifter,
-		s:           Sflag,
+		element:     ElementType,
+		destination: Scalar,
+		source:      Register,
 	},
 
-	SubtractCarry {
+	MoveToStatusRegister {
 		predicate:   Predicate,
-		destination: Register,
-		base:        Register,
+		destination: StatusRegister,
+		fields:      StatusFields,
 		source:      Shifter,
-		s:           Sflag,
 	},
 
-	Swap {
-		predicate: Predicate,
-		register:  Register,
-		address:   Address,
-		b:         Bflag,
+	MoveTop {
+		predicate:   Predicate,
+		destination: Register,
+		immediate:   u32,
 	},
 
-	UnsignedSaturate {
+	MoveWide {
 		predicate:   Predicate,
 		destination: Register,
 		immediate:   u32,
-		source:      Shifter,
 	},
 
-	Test {
-		predicate: Predicate,
-		lhs:       Register,
-		rhs:       Shifter,
+	Multiply {
+		predicate:   Predicate,
+		destination: Register,
+		base:        Register,
+		source:      Register,
+		s:           Sflag,
 	},
 
-	TestEquivalence {
+	MultiplyAccumulate {
+		predicate:   Predicate,
+		destination: Register,
+		base:        Register,
+		source:      Register,
+		accumulator: Register,
+		s:           Sflag,
+	},
+
+	MultiplySubtract {
+		predicate:   Predicate,
+		destination: Register,
+		base:        Register,
+		source:      Register,
+		accumulator: Register,
+	},
+
+	NoOperation {
+		predicate: Predicate,
+	},
+
+	PackHalfword {
+		predicate:   Predicate,
+		destination: Register,
+		base:        Register,
+		source:      Shifter,
+	},
+
+	ParallelArithmetic {
+		predicate:   Predicate,
+		prefix:      ParallelPrefix,
+		operation:   ParallelOperation,
+		destination: Register,
+		base:        Register,
+		source:      Register,
+	},
+
+	PreloadData {
+		address: Address,
+	},
+
+	Reverse {
+		predicate:   Predicate,
+		destination: Register,
+		source:      Register,
+	},
+
+	ReverseBits {
+		predicate:   Predicate,
+		destination: Register,
+		source:      Register,
+	},
+
+	ReversePackedHalfword {
+		predicate:   Predicate,
+		destination: Register,
+		source:      Register,
+	},
+
+	ReverseSignedHalfword {
+		predicate:   Predicate,
+		destination: Register,
+		source:      Register,
+	},
+
+	ReverseSubtract {
+		predicate:   Predicate,
+		destination: Register,
+		base:        Register,
+		source:      Shifter,
+		s:           Sflag,
+	},
+
+	ReverseSubtractCarry {
+		predicate:   Predicate,
+		destination: Register,
+		base:        Register,
+		source:      Shifter,
+		s:           Sflag,
+	},
+
+	SaturatingAdd {
+		predicate:   Predicate,
+		destination: Register,
+		base:        Register,
+		source:      Register,
+	},
+
+	SaturatingDoubleAdd {
+		predicate:   Predicate,
+		destination: Register,
+		base:        Register,
+		source:      Register,
+	},
+
+	SaturatingDoubleSubtract {
+		predicate:   Predicate,
+		destination: Register,
+		base:        Register,
+		source:      Register,
+	},
+
+	SaturatingSubtract {
+		predicate:   Predicate,
+		destination: Register,
+		base:        Register,
+		source:      Register,
+	},
+
+	Select {
+		predicate:   Predicate,
+		destination: Register,
+		base:        Register,
+		source:      Register,
+	},
+
+	SendEvent {
+		predicate: Predicate,
+	},
+
+	SetEndianness {
+		endianness: Endianness,
+	},
+
+	SignExtendAddByte {
+		predicate:   Predicate,
+		destination: Register,
+		base:        Register,
+		source:      Register,
+		rotation:    u32,
+	},
+
+	SignExtendAddByte16 {
+		predicate:   Predicate,
+		destination: Register,
+		base:        Register,
+		source:      Register,
+		rotation:    u32,
+	},
+
+	SignExtendAddHalfword {
+		predicate:   Predicate,
+		destination: Register,
+		base:        Register,
+		source:      Register,
+		rotation:    u32,
+	},
+
+	SignExtendByte {
+		predicate:   Predicate,
+		destination: Register,
+		source:      Register,
+		rotation:    u32,
+	},
+
+	SignExtendByte16 {
+		predicate:   Predicate,
+		destination: Register,
+		source:      Register,
+		rotation:    u32,
+	},
+
+	SignExtendHalfword {
+		predicate:   Predicate,
+		destination: Register,
+		source:      Register,
+		rotation:    u32,
+	},
+
+	SignedBitFieldExtract {
+		predicate:   Predicate,
+		destination: Register,
+		source:      Register,
+		lsb:         u32,
+		width:       u32,
+	},
+
+	SignedDivide {
+		predicate:   Predicate,
+		destination: Register,
+		base:        Register,
+		source:      Register,
+	},
+
+	SignedMultiplyAccumulateLong {
+		predicate:        Predicate,
+		destination_low:  Register,
+		destination_high: Register,
+		base:             Register,
+		source:           Register,
+		s:                Sflag,
+	},
+
+	SignedMultiplyLong {
+		predicate:        Predicate,
+		destination_low:  Register,
+		destination_high: Register,
+		base:             Register,
+		source:           Register,
+		s:                Sflag,
+	},
+
+	SignedSaturate {
+		predicate:   Predicate,
+		destination: Register,
+		immediate:   u32,
+		source:      Shifter,
+	},
+
+	SignedSaturate16 {
+		predicate:   Predicate,
+		destination: Register,
+		immediate:   u32,
+		source:      Register,
+	},
+
+	SignedToFloat {
+		predicate:   Predicate,
+		destination: VfpRegister,
+		source:      SingleRegister,
+	},
+
+	SoftwareInterrupt {
+		predicate: Predicate,
+		immediate: u32,
+	},
+
+	Store {
+		predicate: Predicate,
+		register:  Register,
+		address:   Address,
+		b:         Bflag,
+		t:         Tflag,
+	},
+
+	StoreCoprocessor {
+		predicate:   Predicate,
+		coprocessor: Coprocessor,
+		crd:         CoprocessorRegister,
+		address:     Address,
+		l:           Lflag,
+	},
+
+	StoreDoubleword {
+		predicate: Predicate,
+		first:     Register,
+		second:    Register,
+		address:   Address,
+	},
+
+	StoreExclusive {
+		predicate: Predicate,
+		status:    Register,
+		register:  Register,
+		address:   Address,
+	},
+
+	StoreExclusiveByte {
+		predicate: Predicate,
+		status:    Register,
+		register:  Register,
+		address:   Address,
+	},
+
+	StoreExclusiveDoubleword {
+		predicate: Predicate,
+		status:    Register,
+		first:     Register,
+		second:    Register,
+		address:   Address,
+	},
+
+	StoreExclusiveHalfword {
+		predicate: Predicate,
+		status:    Register,
+		register:  Register,
+		address:   Address,
+	},
+
+	StoreHalfword {
+		predicate: Predicate,
+		register:  Register,
+		address:   Address,
+	},
+
+	StoreMultiple {
+		predicate: Predicate,
+		base:      Register,
+		registers: RegisterList,
+		mode:      BlockMode,
+		w:         Wflag,
+		s:         Sflag,
+	},
+
+	Subtract {
+		predicate:   Predicate,
+		destination: Register,
+		base:        Register,
+		source:      Shifter,
+		s:           Sflag,
+	},
+
+	SubtractCarry {
+		predicate:   Predicate,
+		destination: Register,
+		base:        Register,
+		source:      Shifter,
+		s:           Sflag,
+	},
+
+	Swap {
+		predicate: Predicate,
+		register:  Register,
+		source:    Register,
+		address:   Address,
+		b:         Bflag,
+	},
+
+	UnsignedBitFieldExtract {
+		predicate:   Predicate,
+		destination: Register,
+		source:      Register,
+		lsb:         u32,
+		width:       u32,
+	},
+
+	UnsignedDivide {
+		predicate:   Predicate,
+		destination: Register,
+		base:        Register,
+		source:      Register,
+	},
+
+	UnsignedMultiplyAccumulateAccumulateLong {
+		predicate:        Predicate,
+		destination_low:  Register,
+		destination_high: Register,
+		base:             Register,
+		source:           Register,
+	},
+
+	UnsignedMultiplyAccumulateLong {
+		predicate:        Predicate,
+		destination_low:  Register,
+		destination_high: Register,
+		base:             Register,
+		source:           Register,
+		s:                Sflag,
+	},
+
+	UnsignedMultiplyLong {
+		predicate:        Predicate,
+		destination_low:  Register,
+		destination_high: Register,
+		base:             Register,
+		source:           Register,
+		s:                Sflag,
+	},
+
+	UnsignedSaturate {
+		predicate:   Predicate,
+		destination: Register,
+		immediate:   u32,
+		source:      Shifter,
+	},
+
+	UnsignedSaturate16 {
+		predicate:   Predicate,
+		destination: Register,
+		immediate:   u32,
+		source:      Register,
+	},
+
+	UnsignedSumAbsoluteDifferences {
+		predicate:   Predicate,
+		destination: Register,
+		base:        Register,
+		source:      Register,
+	},
+
+	UnsignedSumAbsoluteDifferencesAccumulate {
+		predicate:   Predicate,
+		destination: Register,
+		base:        Register,
+		source:      Register,
+		accumulator: Register,
+	},
+
+	UnsignedToFloat {
+		predicate:   Predicate,
+		destination: VfpRegister,
+		source:      SingleRegister,
+	},
+
+	TableBranchByte {
+		predicate: Predicate,
+		base:      Register,
+		index:     Register,
+	},
+
+	TableBranchHalfword {
+		predicate: Predicate,
+		base:      Register,
+		index:     Register,
+	},
+
+	Test {
 		predicate: Predicate,
 		lhs:       Register,
 		rhs:       Shifter,
 	},
+
+	TestEquivalence {
+		predicate: Predicate,
+		lhs:       Register,
+		rhs:       Shifter,
+	},
+
+	VectorAbsolute {
+		element:     ElementType,
+		destination: VectorRegister,
+		source:      VectorRegister,
+	},
+
+	VectorAbsoluteDifference {
+		element:     ElementType,
+		destination: VectorRegister,
+		base:        VectorRegister,
+		source:      VectorRegister,
+	},
+
+	VectorAdd {
+		element:     ElementType,
+		destination: VectorRegister,
+		base:        VectorRegister,
+		source:      VectorRegister,
+	},
+
+	VectorAnd {
+		destination: VectorRegister,
+		base:        VectorRegister,
+		source:      VectorRegister,
+	},
+
+	VectorBitClear {
+		destination: VectorRegister,
+		base:        VectorRegister,
+		source:      VectorRegister,
+	},
+
+	VectorCompareEqual {
+		element:     ElementType,
+		destination: VectorRegister,
+		base:        VectorRegister,
+		source:      VectorRegister,
+	},
+
+	VectorCompareGreaterThan {
+		element:     ElementType,
+		destination: VectorRegister,
+		base:        VectorRegister,
+		source:      VectorRegister,
+	},
+
+	VectorCompareGreaterThanOrEqual {
+		element:     ElementType,
+		destination: VectorRegister,
+		base:        VectorRegister,
+		source:      VectorRegister,
+	},
+
+	VectorDuplicate {
+		predicate:   Predicate,
+		element:     ElementType,
+		destination: VectorRegister,
+		source:      Register,
+	},
+
+	VectorDuplicateScalar {
+		element:     ElementType,
+		destination: VectorRegister,
+		source:      Scalar,
+	},
+
+	VectorExclusiveOr {
+		destination: VectorRegister,
+		base:        VectorRegister,
+		source:      VectorRegister,
+	},
+
+	VectorExtract {
+		destination: VectorRegister,
+		base:        VectorRegister,
+		source:      VectorRegister,
+		index:       u8,
+	},
+
+	VectorInclusiveOr {
+		destination: VectorRegister,
+		base:        VectorRegister,
+		source:      VectorRegister,
+	},
+
+	VectorLoadStructure {
+		interleave: u8,
+		element:    ElementType,
+		first:      DoubleRegister,
+		count:      u8,
+		spacing:    u8,
+		address:    StructureAddress,
+	},
+
+	VectorMaximum {
+		element:     ElementType,
+		destination: VectorRegister,
+		base:        VectorRegister,
+		source:      VectorRegister,
+	},
+
+	VectorMinimum {
+		element:     ElementType,
+		destination: VectorRegister,
+		base:        VectorRegister,
+		source:      VectorRegister,
+	},
+
+	VectorMultiply {
+		element:     ElementType,
+		destination: VectorRegister,
+		base:        VectorRegister,
+		source:      VectorRegister,
+	},
+
+	VectorMultiplyAccumulate {
+		element:     ElementType,
+		destination: VectorRegister,
+		base:        VectorRegister,
+		source:      VectorRegister,
+	},
+
+	VectorMultiplyScalar {
+		element:     ElementType,
+		destination: VectorRegister,
+		base:        VectorRegister,
+		source:      Scalar,
+	},
+
+	VectorMultiplySubtract {
+		element:     ElementType,
+		destination: VectorRegister,
+		base:        VectorRegister,
+		source:      VectorRegister,
+	},
+
+	VectorNegate {
+		element:     ElementType,
+		destination: VectorRegister,
+		source:      VectorRegister,
+	},
+
+	VectorNot {
+		destination: VectorRegister,
+		source:      VectorRegister,
+	},
+
+	VectorReverse16 {
+		element:     ElementType,
+		destination: VectorRegister,
+		source:      VectorRegister,
+	},
+
+	VectorReverse32 {
+		element:     ElementType,
+		destination: VectorRegister,
+		source:      VectorRegister,
+	},
+
+	VectorReverse64 {
+		element:     ElementType,
+		destination: VectorRegister,
+		source:      VectorRegister,
+	},
+
+	VectorSaturatingAdd {
+		element:     ElementType,
+		destination: VectorRegister,
+		base:        VectorRegister,
+		source:      VectorRegister,
+	},
+
+	VectorSaturatingSubtract {
+		element:     ElementType,
+		destination: VectorRegister,
+		base:        VectorRegister,
+		source:      VectorRegister,
+	},
+
+	VectorShiftLeft {
+		element:     ElementType,
+		destination: VectorRegister,
+		source:      VectorRegister,
+		shift:       u32,
+	},
+
+	VectorShiftRight {
+		element:     ElementType,
+		destination: VectorRegister,
+		source:      VectorRegister,
+		shift:       u32,
+	},
+
+	VectorStoreStructure {
+		interleave: u8,
+		element:    ElementType,
+		first:      DoubleRegister,
+		count:      u8,
+		spacing:    u8,
+		address:    StructureAddress,
+	},
+
+	VectorSubtract {
+		element:     ElementType,
+		destination: VectorRegister,
+		base:        VectorRegister,
+		source:      VectorRegister,
+	},
+
+	VectorSwap {
+		destination: VectorRegister,
+		source:      VectorRegister,
+	},
+
+	VectorTranspose {
+		element:     ElementType,
+		destination: VectorRegister,
+		source:      VectorRegister,
+	},
+
+	VectorUnzip {
+		element:     ElementType,
+		destination: VectorRegister,
+		source:      VectorRegister,
+	},
+
+	VectorZip {
+		element:     ElementType,
+		destination: VectorRegister,
+		source:      VectorRegister,
+	},
+
+	WaitForEvent {
+		predicate: Predicate,
+	},
+
+	WaitForInterrupt {
+		predicate: Predicate,
+	},
+
+	Yield {
+		predicate: Predicate,
+	},
+
+	ZeroExtendAddByte {
+		predicate:   Predicate,
+		destination: Register,
+		base:        Register,
+		source:      Register,
+		rotation:    u32,
+	},
+
+	ZeroExtendAddByte16 {
+		predicate:   Predicate,
+		destination: Register,
+		base:        Register,
+		source:      Register,
+		rotation:    u32,
+	},
+
+	ZeroExtendAddHalfword {
+		predicate:   Predicate,
+		destination: Register,
+		base:        Register,
+		source:      Register,
+		rotation:    u32,
+	},
+
+	ZeroExtendByte {
+		predicate:   Predicate,
+		destination: Register,
+		source:      Register,
+		rotation:    u32,
+	},
+
+	ZeroExtendByte16 {
+		predicate:   Predicate,
+		destination: Register,
+		source:      Register,
+		rotation:    u32,
+	},
+
+	ZeroExtendHalfword {
+		predicate:   Predicate,
+		destination: Register,
+		source:      Register,
+		rotation:    u32,
+	},
+}
+
+impl Instruction {
+	/// Retrieves the predicate of the instruction.
+	///
+	/// Unconditional instructions (including [`IfThen`](Instruction::IfThen)) yield [`Always`](Predicate::Always).
+	#[must_use]
+	pub const fn predicate(self) -> Predicate {
+		use Instruction::*;
+
+		match self {
+			Add { predicate, .. }
+			| AddCarry { predicate, .. }
+			| And { predicate, .. }
+			| BitClear { predicate, .. }
+			| BitFieldClear { predicate, .. }
+			| BitFieldInsert { predicate, .. }
+			| Branch { predicate, .. }
+			| BranchExchange { predicate, .. }
+			| BranchLink { predicate, .. }
+			| BranchLinkExchange { predicate, .. }
+			| CoprocessorDataProcessing { predicate, .. }
+			| CountLeadingZeroes { predicate, .. }
+			| Compare { predicate, .. }
+			| CompareNegated { predicate, .. }
+			| ExclusiveOr { predicate, .. }
+			| FloatAbsolute { predicate, .. }
+			| FloatAdd { predicate, .. }
+			| FloatCompare { predicate, .. }
+			| FloatConvert { predicate, .. }
+			| FloatDivide { predicate, .. }
+			| FloatLoad { predicate, .. }
+			| FloatLoadMultiple { predicate, .. }
+			| FloatMove { predicate, .. }
+			| FloatMoveImmediate { predicate, .. }
+			| FloatMultiply { predicate, .. }
+			| FloatNegate { predicate, .. }
+			| FloatSquareRoot { predicate, .. }
+			| FloatStore { predicate, .. }
+			| FloatStoreMultiple { predicate, .. }
+			| FloatSubtract { predicate, .. }
+			| FloatToSigned { predicate, .. }
+			| FloatToUnsigned { predicate, .. }
+			| InclusiveOr { predicate, .. }
+			| InclusiveOrNot { predicate, .. }
+			| Load { predicate, .. }
+			| LoadCoprocessor { predicate, .. }
+			| LoadDoubleword { predicate, .. }
+			| LoadExclusive { predicate, .. }
+			| LoadExclusiveByte { predicate, .. }
+			| LoadExclusiveDoubleword { predicate, .. }
+			| LoadExclusiveHalfword { predicate, .. }
+			| LoadHalfword { predicate, .. }
+			| LoadMultiple { predicate, .. }
+			| LoadSignedByte { predicate, .. }
+			| LoadSignedHalfword { predicate, .. }
+			| Move { predicate, .. }
+			| MoveFromCoprocessor { predicate, .. }
+			| MoveFromCoprocessorDouble { predicate, .. }
+			| MoveFromFloat { predicate, .. }
+			| MoveFromFloatDouble { predicate, .. }
+			| MoveFromFloatStatus { predicate, .. }
+			| MoveFromScalar { predicate, .. }
+			| MoveFromStatusRegister { predicate, .. }
+			| MoveNot { predicate, .. }
+			| MoveToCoprocessor { predicate, .. }
+			| MoveToCoprocessorDouble { predicate, .. }
+			| MoveToFloat { predicate, .. }
+			| MoveToFloatDouble { predicate, .. }
+			| MoveToFloatStatus { predicate, .. }
+			| MoveToScalar { predicate, .. }
+			| MoveToStatusRegister { predicate, .. }
+			| MoveTop { predicate, .. }
+			| MoveWide { predicate, .. }
+			| Multiply { predicate, .. }
+			| MultiplyAccumulate { predicate, .. }
+			| MultiplySubtract { predicate, .. }
+			| NoOperation { predicate, .. }
+			| PackHalfword { predicate, .. }
+			| ParallelArithmetic { predicate, .. }
+			| Reverse { predicate, .. }
+			| ReverseBits { predicate, .. }
+			| ReversePackedHalfword { predicate, .. }
+			| ReverseSignedHalfword { predicate, .. }
+			| ReverseSubtract { predicate, .. }
+			| ReverseSubtractCarry { predicate, .. }
+			| SaturatingAdd { predicate, .. }
+			| SaturatingDoubleAdd { predicate, .. }
+			| SaturatingDoubleSubtract { predicate, .. }
+			| SaturatingSubtract { predicate, .. }
+			| Select { predicate, .. }
+			| SendEvent { predicate, .. }
+			| SignExtendAddByte { predicate, .. }
+			| SignExtendAddByte16 { predicate, .. }
+			| SignExtendAddHalfword { predicate, .. }
+			| SignExtendByte { predicate, .. }
+			| SignExtendByte16 { predicate, .. }
+			| SignExtendHalfword { predicate, .. }
+			| SignedBitFieldExtract { predicate, .. }
+			| SignedDivide { predicate, .. }
+			| SignedMultiplyAccumulateLong { predicate, .. }
+			| SignedMultiplyLong { predicate, .. }
+			| SignedSaturate { predicate, .. }
+			| SignedSaturate16 { predicate, .. }
+			| SignedToFloat { predicate, .. }
+			| SoftwareInterrupt { predicate, .. }
+			| Store { predicate, .. }
+			| StoreCoprocessor { predicate, .. }
+			| StoreDoubleword { predicate, .. }
+			| StoreExclusive { predicate, .. }
+			| StoreExclusiveByte { predicate, .. }
+			| StoreExclusiveDoubleword { predicate, .. }
+			| StoreExclusiveHalfword { predicate, .. }
+			| StoreHalfword { predicate, .. }
+			| StoreMultiple { predicate, .. }
+			| Subtract { predicate, .. }
+			| SubtractCarry { predicate, .. }
+			| Swap { predicate, .. }
+			| UnsignedBitFieldExtract { predicate, .. }
+			| UnsignedDivide { predicate, .. }
+			| UnsignedMultiplyAccumulateAccumulateLong { predicate, .. }
+			| UnsignedMultiplyAccumulateLong { predicate, .. }
+			| UnsignedMultiplyLong { predicate, .. }
+			| UnsignedSaturate { predicate, .. }
+			| UnsignedSaturate16 { predicate, .. }
+			| UnsignedSumAbsoluteDifferences { predicate, .. }
+			| UnsignedSumAbsoluteDifferencesAccumulate { predicate, .. }
+			| UnsignedToFloat { predicate, .. }
+			| TableBranchByte { predicate, .. }
+			| TableBranchHalfword { predicate, .. }
+			| Test { predicate, .. }
+			| TestEquivalence { predicate, .. }
+			| VectorDuplicate { predicate, .. }
+			| WaitForEvent { predicate, .. }
+			| WaitForInterrupt { predicate, .. }
+			| Yield { predicate, .. }
+			| ZeroExtendAddByte { predicate, .. }
+			| ZeroExtendAddByte16 { predicate, .. }
+			| ZeroExtendAddHalfword { predicate, .. }
+			| ZeroExtendByte { predicate, .. }
+			| ZeroExtendByte16 { predicate, .. }
+			| ZeroExtendHalfword { predicate, .. } => predicate,
+
+			_ => Predicate::Always,
+		}
+	}
+
+	/// Sets the predicate of the instruction.
+	///
+	/// This has no effect on unconditional instructions.
+	pub const fn set_predicate(&mut self, value: Predicate) {
+		use Instruction::*;
+
+		match *self {
+			Add { ref mut predicate, .. }
+			| AddCarry { ref mut predicate, .. }
+			| And { ref mut predicate, .. }
+			| BitClear { ref mut predicate, .. }
+			| BitFieldClear { ref mut predicate, .. }
+			| BitFieldInsert { ref mut predicate, .. }
+			| Branch { ref mut predicate, .. }
+			| BranchExchange { ref mut predicate, .. }
+			| BranchLink { ref mut predicate, .. }
+			| BranchLinkExchange { ref mut predicate, .. }
+			| CoprocessorDataProcessing { ref mut predicate, .. }
+			| CountLeadingZeroes { ref mut predicate, .. }
+			| Compare { ref mut predicate, .. }
+			| CompareNegated { ref mut predicate, .. }
+			| ExclusiveOr { ref mut predicate, .. }
+			| FloatAbsolute { ref mut predicate, .. }
+			| FloatAdd { ref mut predicate, .. }
+			| FloatCompare { ref mut predicate, .. }
+			| FloatConvert { ref mut predicate, .. }
+			| FloatDivide { ref mut predicate, .. }
+			| FloatLoad { ref mut predicate, .. }
+			| FloatLoadMultiple { ref mut predicate, .. }
+			| FloatMove { ref mut predicate, .. }
+			| FloatMoveImmediate { ref mut predicate, .. }
+			| FloatMultiply { ref mut predicate, .. }
+			| FloatNegate { ref mut predicate, .. }
+			| FloatSquareRoot { ref mut predicate, .. }
+			| FloatStore { ref mut predicate, .. }
+			| FloatStoreMultiple { ref mut predicate, .. }
+			| FloatSubtract { ref mut predicate, .. }
+			| FloatToSigned { ref mut predicate, .. }
+			| FloatToUnsigned { ref mut predicate, .. }
+			| InclusiveOr { ref mut predicate, .. }
+			| InclusiveOrNot { ref mut predicate, .. }
+			| Load { ref mut predicate, .. }
+			| LoadCoprocessor { ref mut predicate, .. }
+			| LoadDoubleword { ref mut predicate, .. }
+			| LoadExclusive { ref mut predicate, .. }
+			| LoadExclusiveByte { ref mut predicate, .. }
+			| LoadExclusiveDoubleword { ref mut predicate, .. }
+			| LoadExclusiveHalfword { ref mut predicate, .. }
+			| LoadHalfword { ref mut predicate, .. }
+			| LoadMultiple { ref mut predicate, .. }
+			| LoadSignedByte { ref mut predicate, .. }
+			| LoadSignedHalfword { ref mut predicate, .. }
+			| Move { ref mut predicate, .. }
+			| MoveFromCoprocessor { ref mut predicate, .. }
+			| MoveFromCoprocessorDouble { ref mut predicate, .. }
+			| MoveFromFloat { ref mut predicate, .. }
+			| MoveFromFloatDouble { ref mut predicate, .. }
+			| MoveFromFloatStatus { ref mut predicate, .. }
+			| MoveFromScalar { ref mut predicate, .. }
+			| MoveFromStatusRegister { ref mut predicate, .. }
+			| MoveNot { ref mut predicate, .. }
+			| MoveToCoprocessor { ref mut predicate, .. }
+			| MoveToCoprocessorDouble { ref mut predicate, .. }
+			| MoveToFloat { ref mut predicate, .. }
+			| MoveToFloatDouble { ref mut predicate, .. }
+			| MoveToFloatStatus { ref mut predicate, .. }
+			| MoveToScalar { ref mut predicate, .. }
+			| MoveToStatusRegister { ref mut predicate, .. }
+			| MoveTop { ref mut predicate, .. }
+			| MoveWide { ref mut predicate, .. }
+			| Multiply { ref mut predicate, .. }
+			| MultiplyAccumulate { ref mut predicate, .. }
+			| MultiplySubtract { ref mut predicate, .. }
+			| NoOperation { ref mut predicate, .. }
+			| PackHalfword { ref mut predicate, .. }
+			| ParallelArithmetic { ref mut predicate, .. }
+			| Reverse { ref mut predicate, .. }
+			| ReverseBits { ref mut predicate, .. }
+			| ReversePackedHalfword { ref mut predicate, .. }
+			| ReverseSignedHalfword { ref mut predicate, .. }
+			| ReverseSubtract { ref mut predicate, .. }
+			| ReverseSubtractCarry { ref mut predicate, .. }
+			| SaturatingAdd { ref mut predicate, .. }
+			| SaturatingDoubleAdd { ref mut predicate, .. }
+			| SaturatingDoubleSubtract { ref mut predicate, .. }
+			| SaturatingSubtract { ref mut predicate, .. }
+			| Select { ref mut predicate, .. }
+			| SendEvent { ref mut predicate, .. }
+			| SignExtendAddByte { ref mut predicate, .. }
+			| SignExtendAddByte16 { ref mut predicate, .. }
+			| SignExtendAddHalfword { ref mut predicate, .. }
+			| SignExtendByte { ref mut predicate, .. }
+			| SignExtendByte16 { ref mut predicate, .. }
+			| SignExtendHalfword { ref mut predicate, .. }
+			| SignedBitFieldExtract { ref mut predicate, .. }
+			| SignedDivide { ref mut predicate, .. }
+			| SignedMultiplyAccumulateLong { ref mut predicate, .. }
+			| SignedMultiplyLong { ref mut predicate, .. }
+			| SignedSaturate { ref mut predicate, .. }
+			| SignedSaturate16 { ref mut predicate, .. }
+			| SignedToFloat { ref mut predicate, .. }
+			| SoftwareInterrupt { ref mut predicate, .. }
+			| Store { ref mut predicate, .. }
+			| StoreCoprocessor { ref mut predicate, .. }
+			| StoreDoubleword { ref mut predicate, .. }
+			| StoreExclusive { ref mut predicate, .. }
+			| StoreExclusiveByte { ref mut predicate, .. }
+			| StoreExclusiveDoubleword { ref mut predicate, .. }
+			| StoreExclusiveHalfword { ref mut predicate, .. }
+			| StoreHalfword { ref mut predicate, .. }
+			| StoreMultiple { ref mut predicate, .. }
+			| Subtract { ref mut predicate, .. }
+			| SubtractCarry { ref mut predicate, .. }
+			| Swap { ref mut predicate, .. }
+			| UnsignedBitFieldExtract { ref mut predicate, .. }
+			| UnsignedDivide { ref mut predicate, .. }
+			| UnsignedMultiplyAccumulateAccumulateLong { ref mut predicate, .. }
+			| UnsignedMultiplyAccumulateLong { ref mut predicate, .. }
+			| UnsignedMultiplyLong { ref mut predicate, .. }
+			| UnsignedSaturate { ref mut predicate, .. }
+			| UnsignedSaturate16 { ref mut predicate, .. }
+			| UnsignedSumAbsoluteDifferences { ref mut predicate, .. }
+			| UnsignedSumAbsoluteDifferencesAccumulate { ref mut predicate, .. }
+			| UnsignedToFloat { ref mut predicate, .. }
+			| TableBranchByte { ref mut predicate, .. }
+			| TableBranchHalfword { ref mut predicate, .. }
+			| Test { ref mut predicate, .. }
+			| TestEquivalence { ref mut predicate, .. }
+			| VectorDuplicate { ref mut predicate, .. }
+			| WaitForEvent { ref mut predicate, .. }
+			| WaitForInterrupt { ref mut predicate, .. }
+			| Yield { ref mut predicate, .. }
+			| ZeroExtendAddByte { ref mut predicate, .. }
+			| ZeroExtendAddByte16 { ref mut predicate, .. }
+			| ZeroExtendAddHalfword { ref mut predicate, .. }
+			| ZeroExtendByte { ref mut predicate, .. }
+			| ZeroExtendByte16 { ref mut predicate, .. }
+			| ZeroExtendHalfword { ref mut predicate, .. } => *predicate = value,
+
+			_ => { }
+		}
+	}
 }
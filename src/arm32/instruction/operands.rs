@@ -0,0 +1,578 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Pollex.
+//
+// Pollex is free software: you can redistribute it
+// and/or modify it under the terms of the GNU Af-
+// fero General Public License as published by the
+// Free Software Foundation, either version 3 of
+// the License, or (at your option) any later ver-
+// sion.
+//
+// Pollex is distributed in the hope that it will
+// be useful, but WITHOUT ANY WARRANTY; without
+// even the implied warranty of MERCHANTABILITY or
+// FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// Affero General Public License for more details.
+//
+// You should have received a copy of the GNU Af-
+// fero General Public License along with Pollex.
+// If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{assert_or_err, Error, Result};
+use crate::arm32::{
+	Address,
+	DoubleRegister,
+	FloatImmediate,
+	Indexing,
+	Register,
+	RegisterList,
+	Scalar,
+	Sflag,
+	Shifter,
+	Sign,
+	StatusFields,
+	StatusRegister,
+	StructureAddress,
+	VfpRegister,
+	Wflag,
+};
+
+use alloc::borrow::ToOwned;
+use alloc::vec::Vec;
+use core::str::FromStr;
+
+/// The operands of an assembly instruction.
+///
+/// The operand string is split at every comma that isn't enclosed in brackets or braces.
+/// Composite operands -- such as shifters and post-indexed addresses -- thus span several items, which the respective methods consume together.
+#[derive(Clone, Debug)]
+pub(in super) struct Operands<'a> {
+	items: Vec<&'a str>,
+	index: usize,
+}
+
+impl<'a> Operands<'a> {
+	/// Splits the given operand string.
+	pub(in super) fn new(s: &'a str) -> Result<Self> {
+		let s = s.trim();
+
+		let mut items = Vec::new();
+
+		if !s.is_empty() {
+			let mut depth = 0x0_u32;
+			let mut start = 0x0;
+
+			for (index, c) in s.char_indices() {
+				match c {
+					'[' | '{' => depth += 0x1,
+
+					']' | '}' => {
+						depth = depth
+							.checked_sub(0x1)
+							.ok_or(Error::IllegalInstruction { reason: "unbalanced brackets" })?;
+					},
+
+					',' if depth == 0x0 => {
+						items.push(s[start..index].trim());
+						start = index + 0x1;
+					},
+
+					_ => { },
+				}
+			}
+
+			assert_or_err!(depth == 0x0, Error::IllegalInstruction { reason: "unbalanced brackets" });
+
+			items.push(s[start..].trim());
+		}
+
+		assert_or_err!(!items.contains(&""), Error::IllegalInstruction { reason: "empty operand" });
+
+		Ok(Self { items, index: 0x0 })
+	}
+
+	/// Retrieves the number of remaining items.
+	#[inline(always)]
+	#[must_use]
+	pub(in super) const fn remaining(&self) -> usize { self.items.len() - self.index }
+
+	/// Retrieves the `n`th remaining item without consuming it.
+	#[inline]
+	#[must_use]
+	pub(in super) fn peek_nth(&self, n: usize) -> Option<&'a str> { self.items.get(self.index + n).copied() }
+
+	/// Retrieves the next item without consuming it.
+	#[inline(always)]
+	#[must_use]
+	pub(in super) fn peek(&self) -> Option<&'a str> { self.peek_nth(0x0) }
+
+	/// Consumes the next item.
+	pub(in super) fn next_item(&mut self) -> Result<&'a str> {
+		let item = self.peek().ok_or(Error::IllegalInstruction { reason: "missing operand" })?;
+		self.index += 0x1;
+
+		Ok(item)
+	}
+
+	/// Asserts that every item has been consumed.
+	pub(in super) const fn finish(&self) -> Result<()> {
+		assert_or_err!(self.remaining() == 0x0, Error::IllegalInstruction { reason: "too many operands" });
+
+		Ok(())
+	}
+
+	/// Parses the next item using [`FromStr`].
+	///
+	/// This is used for registers, which all report [`UnknownRegister`](Error::UnknownRegister) on failure.
+	pub(in super) fn parse<T: FromStr<Err = Error>>(&mut self) -> Result<T> { self.next_item()?.parse() }
+
+	/// Parses a register optionally followed by a `!` writeback marker.
+	pub(in super) fn register_with_writeback(&mut self) -> Result<(Register, Wflag)> {
+		let (register, writeback) = strip_marker(self.next_item()?, '!');
+
+		Ok((register.parse()?, Wflag::from(writeback)))
+	}
+
+	/// Parses an integer immediate.
+	///
+	/// The `#` prefix is optional.
+	pub(in super) fn immediate<T: TryFrom<i64>>(&mut self) -> Result<T> {
+		let value = parse_integer(self.next_item()?)?;
+
+		T::try_from(value).map_err(|_| Error::IllegalImmediate { reason: "immediate out of range" })
+	}
+
+	/// Parses a floating-point immediate.
+	///
+	/// Zero is not representable and yields [`None`].
+	pub(in super) fn float_immediate(&mut self) -> Result<Option<FloatImmediate>> {
+		let item = self.next_item()?;
+		let item = item.strip_prefix('#').unwrap_or(item).trim_start();
+
+		let value: f64 = item
+			.parse()
+			.map_err(|_| Error::IllegalImmediate { reason: "malformed floating-point literal" })?;
+
+		// Positive and negative zero only differ in the
+		// sign bit.
+		if value.to_bits() << 0x1 == 0x0 { return Ok(None) };
+
+		FloatImmediate::from_f64(value)
+			.map(Some)
+			.ok_or(Error::IllegalImmediate { reason: "value cannot be encoded as a floating-point immediate" })
+	}
+
+	/// Parses a shifter operand.
+	///
+	/// This is either an immediate or a register, the latter optionally followed by a shift.
+	pub(in super) fn shifter(&mut self) -> Result<Shifter> {
+		let item = self.next_item()?;
+
+		if is_immediate(item) {
+			let value = parse_integer(item)?;
+			let value = u32::try_from(value).map_err(|_| Error::IllegalImmediate { reason: "immediate out of range" })?;
+
+			return Ok(Shifter::Immediate(value));
+		}
+
+		let source = item.parse()?;
+
+		match self.peek() {
+			Some(shift) if is_shift(shift) => {
+				self.index += 0x1;
+				parse_shift(source, shift)
+			},
+
+			_ => Ok(Shifter::from_register(source)),
+		}
+	}
+
+	/// Parses the amount of a shift of the given register by the given operator, e.g. `ASR`.
+	///
+	/// This is used by the shift instructions, which take their amount as a separate operand.
+	pub(in super) fn shift(&mut self, operator: &str, source: Register) -> Result<Shifter> {
+		let amount = self.next_item()?;
+		make_shift(operator, source, amount)
+	}
+
+	/// Parses an optional `ROR` rotation as used by the extend instructions.
+	///
+	/// A missing rotation yields `0`.
+	pub(in super) fn rotation(&mut self) -> Result<u32> {
+		let Some(item) = self.peek() else { return Ok(0x0) };
+		self.index += 0x1;
+
+		match parse_shift(Register::R0, item)? {
+			Shifter::RotateRightImmediate { shift, .. } => Ok(shift),
+
+			_ => Err(Error::IllegalShifter { reason: "only immediate rotations are permitted" }),
+		}
+	}
+
+	/// Parses an address.
+	///
+	/// If the brackets only contain the base register, every remaining item is taken as a post-indexed offset.
+	pub(in super) fn address(&mut self) -> Result<Address> {
+		let (item, pre_indexed) = strip_marker(self.next_item()?, '!');
+
+		let indexing = if pre_indexed { Indexing::PreIndexed } else { Indexing::Offset };
+
+		let inner = item
+			.strip_prefix('[')
+			.and_then(|item| item.strip_suffix(']'))
+			.ok_or(Error::IllegalInstruction { reason: "malformed address" })?;
+
+		let mut parts = inner.split(',').map(str::trim);
+		let base: Register = parts.next().unwrap_or_default().parse()?;
+
+		let offset: Vec<&str> = parts.collect();
+
+		if offset.is_empty() && indexing == Indexing::Offset && self.remaining() != 0x0 {
+			let offset = &self.items[self.index..];
+			self.index = self.items.len();
+
+			return parse_offset(base, offset, Indexing::PostIndexed);
+		}
+
+		parse_offset(base, &offset, indexing)
+	}
+
+	/// Parses the address of a structure transfer.
+	///
+	/// A following register is taken as the post-increment.
+	pub(in super) fn structure_address(&mut self) -> Result<StructureAddress> {
+		let (item, post_indexed) = strip_marker(self.next_item()?, '!');
+
+		let inner = item
+			.strip_prefix('[')
+			.and_then(|item| item.strip_suffix(']'))
+			.ok_or(Error::IllegalInstruction { reason: "malformed address" })?;
+
+		let (base, alignment) = match inner.split_once(':') {
+			Some((base, alignment)) => {
+				let alignment = parse_integer(alignment)?;
+				let alignment = u32::try_from(alignment).map_err(|_| Error::IllegalImmediate { reason: "alignment out of range" })?;
+
+				(base.trim().parse()?, alignment)
+			},
+
+			None => (inner.trim().parse()?, 0x0),
+		};
+
+		let address = if post_indexed {
+			StructureAddress::PostIndexed { base, alignment }
+		} else if self.remaining() != 0x0 {
+			StructureAddress::RegisterPostIndexed { base, alignment, source: self.parse()? }
+		} else {
+			StructureAddress::Offset { base, alignment }
+		};
+
+		Ok(address)
+	}
+
+	/// Parses a list of general-purpose registers.
+	///
+	/// The list may be followed by `^`, which is returned as an S flag.
+	pub(in super) fn register_list(&mut self) -> Result<(RegisterList, Sflag)> {
+		let (item, user) = strip_marker(self.next_item()?, '^');
+		let s            = Sflag::from(user);
+
+		let mut list = RegisterList::new();
+
+		for (first, last) in parse_list::<Register>(item)? {
+			assert_or_err!(first as u8 <= last as u8, Error::IllegalInstruction { reason: "descending register range" });
+
+			for register in (first as u8..=last as u8).filter_map(Register::from_u8) { list.insert(register); }
+		}
+
+		Ok((list, s))
+	}
+
+	/// Parses a list of consecutive floating-point registers.
+	///
+	/// The first register and the number of registers are returned.
+	pub(in super) fn vfp_list(&mut self) -> Result<(VfpRegister, u8)> {
+		let ranges = parse_list::<VfpRegister>(self.next_item()?)?;
+
+		let Some(&(first, _)) = ranges.first() else {
+			return Err(Error::IllegalInstruction { reason: "empty register list" });
+		};
+
+		let mut next = first.to_u8();
+
+		for (start, end) in ranges {
+			assert_or_err!(
+				start.is_double() == first.is_double() && end.is_double() == first.is_double(),
+				Error::IllegalRegister { reason: "register list mixes precisions" }
+			);
+
+			assert_or_err!(
+				start.to_u8() == next && end.to_u8() >= start.to_u8(),
+				Error::IllegalInstruction { reason: "register list is not consecutive" }
+			);
+
+			next = end.to_u8() + 0x1;
+		}
+
+		Ok((first, next - first.to_u8()))
+	}
+
+	/// Parses a list of evenly spaced double registers.
+	///
+	/// The first register, the number of registers, and the spacing are returned.
+	pub(in super) fn vector_list(&mut self) -> Result<(DoubleRegister, u8, u8)> {
+		let ranges = parse_list::<DoubleRegister>(self.next_item()?)?;
+
+		let Some(&(first, _)) = ranges.first() else {
+			return Err(Error::IllegalInstruction { reason: "empty register list" });
+		};
+
+		let mut registers = Vec::new();
+
+		for (start, end) in ranges {
+			assert_or_err!(start as u8 <= end as u8, Error::IllegalInstruction { reason: "descending register range" });
+
+			registers.extend(start as u8..=end as u8);
+		}
+
+		let count   = u8::try_from(registers.len()).map_err(|_| Error::IllegalInstruction { reason: "register list too long" })?;
+		let spacing = registers.get(0x1).map_or(0x1, |&second| second.wrapping_sub(first as u8));
+
+		assert_or_err!(
+			matches!(spacing, 0x1 | 0x2) && registers.windows(0x2).all(|pair| pair[0x1].wrapping_sub(pair[0x0]) == spacing),
+			Error::IllegalInstruction { reason: "register list is not evenly spaced" }
+		);
+
+		Ok((first, count, spacing))
+	}
+
+	/// Parses a scalar, e.g. `d1[3]`.
+	pub(in super) fn scalar(&mut self) -> Result<Scalar> {
+		let item = self.next_item()?;
+
+		let (register, index) = item
+			.strip_suffix(']')
+			.and_then(|item| item.split_once('['))
+			.ok_or(Error::IllegalInstruction { reason: "malformed scalar" })?;
+
+		let index = parse_integer(index)?;
+		let index = u8::try_from(index).map_err(|_| Error::IllegalImmediate { reason: "scalar index out of range" })?;
+
+		Ok(Scalar::new(register.trim().parse()?, index))
+	}
+
+	/// Parses a status register and its optional field mask, e.g. `CPSR_fc`.
+	pub(in super) fn status_register(&mut self) -> Result<(StatusRegister, Option<StatusFields>)> {
+		let item = self.next_item()?;
+
+		let (name, fields) = match item.split_once('_') {
+			Some((name, fields)) => (name, Some(fields)),
+			None                 => (item, None),
+		};
+
+		let register = if name.eq_ignore_ascii_case("CPSR") || name.eq_ignore_ascii_case("APSR") {
+			StatusRegister::Cpsr
+		} else if name.eq_ignore_ascii_case("SPSR") {
+			StatusRegister::Spsr
+		} else {
+			return Err(Error::UnknownRegister(item.to_owned()));
+		};
+
+		let Some(fields) = fields else { return Ok((register, None)) };
+
+		// The APSR uses the names of the flags instead.
+		let fields = if fields.eq_ignore_ascii_case("nzcvq") {
+			StatusFields::FLAGS
+		} else if fields.eq_ignore_ascii_case("g") {
+			StatusFields::STATUS
+		} else if fields.eq_ignore_ascii_case("nzcvqg") {
+			StatusFields::FLAGS | StatusFields::STATUS
+		} else {
+			let mut mask = StatusFields::new();
+
+			for c in fields.chars() {
+				mask |= match c.to_ascii_lowercase() {
+					'c' => StatusFields::CONTROL,
+					'x' => StatusFields::EXTENSION,
+					's' => StatusFields::STATUS,
+					'f' => StatusFields::FLAGS,
+
+					_ => return Err(Error::UnknownRegister(item.to_owned())),
+				};
+			}
+
+			mask
+		};
+
+		Ok((register, Some(fields)))
+	}
+}
+
+/// Checks if the given operand is an immediate.
+#[must_use]
+pub(in super) fn is_immediate(s: &str) -> bool {
+	// Signs are also used by register offsets.
+	let digits = s.strip_prefix(['-', '+']).unwrap_or(s);
+
+	s.starts_with('#') || digits.starts_with(|c: char| c.is_ascii_digit())
+}
+
+/// Checks if the given operand is a shift, e.g. `LSL #2`.
+#[must_use]
+pub(in super) fn is_shift(s: &str) -> bool {
+	let operator = s.split_whitespace().next().unwrap_or_default();
+
+	["LSL", "LSR", "ASR", "ROR", "RRX"]
+		.iter()
+		.any(|name| operator.eq_ignore_ascii_case(name))
+}
+
+/// Strips a trailing marker, e.g. `!`, from the given operand.
+#[must_use]
+fn strip_marker(s: &str, marker: char) -> (&str, bool) {
+	s.strip_suffix(marker).map_or((s, false), |s| (s.trim_end(), true))
+}
+
+/// Strips a leading sign from the given operand.
+///
+/// The returned flag is set if the sign is negative.
+#[must_use]
+fn strip_sign(s: &str) -> (bool, &str) {
+	s.strip_prefix('-').map_or_else(|| (false, s.strip_prefix('+').unwrap_or(s)), |s| (true, s))
+}
+
+/// Parses an integer literal.
+///
+/// Literals may be given in decimal, hexadecimal (`0x`), or binary (`0b`), optionally prefixed by `#` and a sign.
+pub(in super) fn parse_integer(s: &str) -> Result<i64> {
+	let s = s.strip_prefix('#').unwrap_or(s).trim();
+
+	let (negative, s) = strip_sign(s);
+
+	let (radix, digits) = [("0x", 0x10), ("0X", 0x10), ("0b", 0x2), ("0B", 0x2)]
+		.into_iter()
+		.find_map(|(prefix, radix)| s.strip_prefix(prefix).map(|digits| (radix, digits)))
+		.unwrap_or((0xA, s));
+
+	// `from_str_radix` accepts a sign of its own.
+	assert_or_err!(
+		digits.starts_with(|c: char| c.is_ascii_alphanumeric()),
+		Error::IllegalImmediate { reason: "malformed integer literal" }
+	);
+
+	let value = u64::from_str_radix(digits, radix)
+		.map_err(|_| Error::IllegalImmediate { reason: "malformed integer literal" })?;
+
+	let value = i64::try_from(value).map_err(|_| Error::IllegalImmediate { reason: "immediate out of range" })?;
+
+	Ok(if negative { -value } else { value })
+}
+
+/// Parses a shift of the given register, e.g. `LSL #2` or `ROR r3`.
+fn parse_shift(source: Register, s: &str) -> Result<Shifter> {
+	let (operator, amount) = match s.split_once(char::is_whitespace) {
+		Some((operator, amount)) => (operator, amount.trim()),
+		None                     => (s, ""),
+	};
+
+	if operator.eq_ignore_ascii_case("RRX") {
+		assert_or_err!(amount.is_empty(), Error::IllegalShifter { reason: "RRX does not take a shift amount" });
+
+		return Ok(Shifter::RotateRightExtend { source });
+	}
+
+	make_shift(operator, source, amount)
+}
+
+/// Constructs a shift of the given register by either an immediate or a register amount.
+fn make_shift(operator: &str, source: Register, amount: &str) -> Result<Shifter> {
+	use Shifter::*;
+
+	let shifter = if is_immediate(amount) {
+		let shift = parse_integer(amount)?;
+		let shift = u32::try_from(shift).map_err(|_| Error::IllegalImmediate { reason: "shift amount out of range" })?;
+
+		match operator.to_ascii_uppercase().as_str() {
+			"ASR" => ArithmeticShiftRightImmediate { source, shift },
+			"LSL" => LogicalShiftLeftImmediate { source, shift },
+			"LSR" => LogicalShiftRightImmediate { source, shift },
+			"ROR" => RotateRightImmediate { source, shift },
+
+			_ => return Err(Error::IllegalShifter { reason: "unknown shift operator" }),
+		}
+	} else {
+		let shift = amount.parse()?;
+
+		match operator.to_ascii_uppercase().as_str() {
+			"ASR" => ArithmeticShiftRightRegister { source, shift },
+			"LSL" => LogicalShiftLeftRegister { source, shift },
+			"LSR" => LogicalShiftRightRegister { source, shift },
+			"ROR" => RotateRightRegister { source, shift },
+
+			_ => return Err(Error::IllegalShifter { reason: "unknown shift operator" }),
+		}
+	};
+
+	Ok(shifter)
+}
+
+/// Parses the offset of an address.
+fn parse_offset(base: Register, offset: &[&str], indexing: Indexing) -> Result<Address> {
+	let address = match *offset {
+		[] => Address::ImmediateOffset { base, source: 0x0, indexing },
+
+		[source] if is_immediate(source) => {
+			let source = parse_integer(source)?;
+			let source = i32::try_from(source).map_err(|_| Error::IllegalImmediate { reason: "offset out of range" })?;
+
+			Address::ImmediateOffset { base, source, indexing }
+		},
+
+		[source, ref shift @ ..] => {
+			let (negative, source) = strip_sign(source);
+			let sign               = Sign::from(!negative);
+
+			let source = source.trim_start().parse()?;
+
+			match *shift {
+				[] => Address::RegisterOffset { base, source, sign, indexing },
+
+				[shift] => Address::ScaledRegisterOffset { base, source: parse_shift(source, shift)?, sign, indexing },
+
+				_ => return Err(Error::IllegalInstruction { reason: "malformed address" }),
+			}
+		},
+	};
+
+	Ok(address)
+}
+
+/// Parses a braced list of registers into its ranges.
+///
+/// Lone registers are returned as ranges of themselves.
+fn parse_list<T: Copy + FromStr<Err = Error>>(s: &str) -> Result<Vec<(T, T)>> {
+	let inner = s
+		.strip_prefix('{')
+		.and_then(|s| s.strip_suffix('}'))
+		.ok_or(Error::IllegalInstruction { reason: "malformed register list" })?
+		.trim();
+
+	if inner.is_empty() { return Ok(Vec::new()) };
+
+	inner
+		.split(',')
+		.map(|item| {
+			let item = item.trim();
+
+			match item.split_once('-') {
+				Some((first, last)) => Ok((first.trim().parse()?, last.trim().parse()?)),
+
+				None => {
+					let register = item.parse()?;
+					Ok((register, register))
+				},
+			}
+		})
+		.collect()
+}
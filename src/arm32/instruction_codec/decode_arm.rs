@@ -0,0 +1,918 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Pollex.
+//
+// Pollex is free software: you can redistribute it
+// and/or modify it under the terms of the GNU Af-
+// fero General Public License as published by the
+// Free Software Foundation, either version 3 of
+// the License, or (at your option) any later ver-
+// sion.
+//
+// Pollex is distributed in the hope that it will
+// be useful, but WITHOUT ANY WARRANTY; without
+// even the implied warranty of MERCHANTABILITY or
+// FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// Affero General Public License for more details.
+//
+// You should have received a copy of the GNU Af-
+// fero General Public License along with Pollex.
+// If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Error, Result};
+use crate::arm32::{
+	Address,
+	ArmOpcode,
+	BarrierOption,
+	Bflag,
+	BlockMode,
+	Coprocessor,
+	CoprocessorRegister,
+	Endianness,
+	Indexing,
+	Instruction,
+	InstructionCodec,
+	InterruptMask,
+	Lflag,
+	ParallelOperation,
+	ParallelPrefix,
+	Predicate,
+	ProcessorMode,
+	Register,
+	RegisterList,
+	Sflag,
+	Shifter,
+	Sign,
+	StatusFields,
+	StatusRegister,
+	Tflag,
+	Wflag,
+};
+
+use core::num::Wrapping;
+
+use super::register_at;
+use super::decode_neon::decode_neon;
+use super::decode_vfp::decode_vfp;
+
+/// Extracts the even register at the given bit position together with the one following it.
+///
+/// This is used by doubleword transfers.
+/// The first register cannot be `lr`, as the second would then be `pc`.
+#[inline]
+const fn register_pair_at(opcode: u32, shift: u32) -> Result<(Register, Register)> {
+	let first = ((opcode >> shift) & 0b1111) as u8;
+	if first & 0b1 != 0b0 || first == Register::Lr as u8 { return Err(Error::InvalidOpcode) };
+
+	Ok((Register::from_u8(first).unwrap(), Register::from_u8(first + 0x1).unwrap()))
+}
+
+/// Extracts the coprocessor register at the given bit position.
+#[inline(always)]
+#[must_use]
+const fn coprocessor_register_at(opcode: u32, shift: u32) -> CoprocessorRegister {
+	CoprocessorRegister::from_u8(((opcode >> shift) & 0b1111) as u8).unwrap()
+}
+
+/// Decodes an immediate shift of the given register.
+///
+/// This is the inverse of the immediate shifts of `add_shifter`.
+/// Null shifts on `LSR` and `ASR` denote shifts of (32), and `ROR #0` denotes `RRX`.
+#[must_use]
+pub(in super) const fn decode_shift_immediate(source: Register, code: u32, shift: u32) -> Shifter {
+	use Shifter::*;
+
+	match (code, shift) {
+		(0b00, shift) => LogicalShiftLeftImmediate { source, shift },
+
+		(0b01, 0x0) => LogicalShiftRightImmediate { source, shift: 0x20 },
+
+		(0b01, shift) => LogicalShiftRightImmediate { source, shift },
+
+		(0b10, 0x0) => ArithmeticShiftRightImmediate { source, shift: 0x20 },
+
+		(0b10, shift) => ArithmeticShiftRightImmediate { source, shift },
+
+		(_, 0x0) => RotateRightExtend { source },
+
+		(_, shift) => RotateRightImmediate { source, shift },
+	}
+}
+
+/// Decodes the shifter operand of a data-processing instruction.
+///
+/// This is the inverse of `add_shifter`.
+/// Immediates are rotated back into their full value.
+#[must_use]
+const fn decode_shifter(opcode: u32) -> Shifter {
+	use Shifter::*;
+
+	if opcode & 0b00000010_00000000_00000000_00000000 != 0x0 {
+		let source = opcode & 0b00000000_00000000_00000000_11111111;
+		let rotate = (opcode >> 0x8) & 0b1111;
+
+		return Immediate(source.rotate_right(rotate * 0x2));
+	}
+
+	let source = register_at(opcode, 0x0);
+	let code   = (opcode >> 0x5) & 0b11;
+
+	if opcode & 0b00000000_00000000_00000000_00010000 == 0x0 {
+		return decode_shift_immediate(source, code, (opcode >> 0x7) & 0b11111);
+	}
+
+	let shift = register_at(opcode, 0x8);
+
+	match code {
+		0b00 => LogicalShiftLeftRegister { source, shift },
+
+		0b01 => LogicalShiftRightRegister { source, shift },
+
+		0b10 => ArithmeticShiftRightRegister { source, shift },
+
+		_ => RotateRightRegister { source, shift },
+	}
+}
+
+/// Decodes a data-processing instruction.
+///
+/// The unused destination field of comparisons and base field of moves must be zero.
+fn decode_data_processing(opcode: u32, predicate: Predicate) -> Result<Instruction> {
+	use Instruction::*;
+
+	let destination = register_at(opcode, 0xC);
+	let base        = register_at(opcode, 0x10);
+	let source      = decode_shifter(opcode);
+
+	let s = Sflag::from(opcode & 0b00000000_00010000_00000000_00000000 != 0x0);
+
+	let code = (opcode >> 0x15) & 0b1111;
+
+	match code {
+		0b1000..=0b1011 if opcode & 0b00000000_00000000_11110000_00000000 != 0x0 => return Err(Error::InvalidOpcode),
+
+		0b1101 | 0b1111 if opcode & 0b00000000_00001111_00000000_00000000 != 0x0 => return Err(Error::InvalidOpcode),
+
+		_ => { }
+	}
+
+	let instruction = match code {
+		0b0000 => And { predicate, destination, base, source, s },
+
+		0b0001 => ExclusiveOr { predicate, destination, base, source, s },
+
+		0b0010 => Subtract { predicate, destination, base, source, s },
+
+		0b0011 => ReverseSubtract { predicate, destination, base, source, s },
+
+		0b0100 => Add { predicate, destination, base, source, s },
+
+		0b0101 => AddCarry { predicate, destination, base, source, s },
+
+		0b0110 => SubtractCarry { predicate, destination, base, source, s },
+
+		0b0111 => ReverseSubtractCarry { predicate, destination, base, source, s },
+
+		0b1000 => Test { predicate, lhs: base, rhs: source },
+
+		0b1001 => TestEquivalence { predicate, lhs: base, rhs: source },
+
+		0b1010 => Compare { predicate, lhs: base, rhs: source },
+
+		0b1011 => CompareNegated { predicate, lhs: base, rhs: source },
+
+		0b1100 => InclusiveOr { predicate, destination, base, source, s },
+
+		0b1101 => Move { predicate, destination, source, s },
+
+		0b1110 => BitClear { predicate, destination, base, source, s },
+
+		_ => MoveNot { predicate, destination, source, s },
+	};
+
+	Ok(instruction)
+}
+
+/// Decodes a multiplication.
+///
+/// The first factor (`Rm`) is decoded as `base`, and the second (`Rs`) as `source`.
+/// The unused accumulator field of `MUL` must be zero.
+fn decode_multiply(opcode: u32, predicate: Predicate) -> Result<Instruction> {
+	use Instruction::*;
+
+	let destination = register_at(opcode, 0x10);
+	let accumulator = register_at(opcode, 0xC);
+	let base        = register_at(opcode, 0x0);
+	let source      = register_at(opcode, 0x8);
+
+	let destination_low  = accumulator;
+	let destination_high = destination;
+
+	let s = Sflag::from(opcode & 0b00000000_00010000_00000000_00000000 != 0x0);
+
+	let instruction = match ((opcode >> 0x15) & 0b111, s) {
+		(0b000, s) if accumulator == Register::R0 => Multiply { predicate, destination, base, source, s },
+
+		(0b001, s) => MultiplyAccumulate { predicate, destination, base, source, accumulator, s },
+
+		(0b010, Sflag::Off) => UnsignedMultiplyAccumulateAccumulateLong { predicate, destination_low, destination_high, base, source },
+
+		(0b011, Sflag::Off) => MultiplySubtract { predicate, destination, base, source, accumulator },
+
+		(0b100, s) => UnsignedMultiplyLong { predicate, destination_low, destination_high, base, source, s },
+
+		(0b101, s) => UnsignedMultiplyAccumulateLong { predicate, destination_low, destination_high, base, source, s },
+
+		(0b110, s) => SignedMultiplyLong { predicate, destination_low, destination_high, base, source, s },
+
+		(0b111, s) => SignedMultiplyAccumulateLong { predicate, destination_low, destination_high, base, source, s },
+
+		_ => return Err(Error::InvalidOpcode),
+	};
+
+	Ok(instruction)
+}
+
+/// Decodes an exclusive transfer.
+///
+/// The unused source field of loads must be all ones.
+fn decode_exclusive(opcode: u32, predicate: Predicate) -> Result<Instruction> {
+	use Instruction::*;
+
+	let address = Address::from_register(register_at(opcode, 0x10));
+
+	let l = opcode & 0b00000000_00010000_00000000_00000000 != 0x0;
+
+	let instruction = if l {
+		if opcode & 0b1111 != 0b1111 { return Err(Error::InvalidOpcode) };
+
+		let register = register_at(opcode, 0xC);
+
+		match (opcode >> 0x15) & 0b11 {
+			0b00 => LoadExclusive { predicate, register, address },
+
+			0b01 => {
+				let (first, second) = register_pair_at(opcode, 0xC)?;
+				LoadExclusiveDoubleword { predicate, first, second, address }
+			}
+
+			0b10 => LoadExclusiveByte { predicate, register, address },
+
+			_ => LoadExclusiveHalfword { predicate, register, address },
+		}
+	} else {
+		let status   = register_at(opcode, 0xC);
+		let register = register_at(opcode, 0x0);
+
+		match (opcode >> 0x15) & 0b11 {
+			0b00 => StoreExclusive { predicate, status, register, address },
+
+			0b01 => {
+				let (first, second) = register_pair_at(opcode, 0x0)?;
+				StoreExclusiveDoubleword { predicate, status, first, second, address }
+			}
+
+			0b10 => StoreExclusiveByte { predicate, status, register, address },
+
+			_ => StoreExclusiveHalfword { predicate, status, register, address },
+		}
+	};
+
+	Ok(instruction)
+}
+
+/// Decodes a miscellaneous instruction (i.e. status register moves, exchanges, `CLZ`, saturating arithmetic, and `BKPT`).
+///
+/// The unused register fields must be all ones, and any remaining unused bits must be zero.
+fn decode_misc(opcode: u32, predicate: Predicate) -> Result<Instruction> {
+	use Instruction::*;
+
+	macro_rules! match_bits {
+		($mask:expr, $value:expr) => {{
+			opcode & $mask == $value
+		}};
+	}
+
+	let destination = register_at(opcode, 0xC);
+	let base        = register_at(opcode, 0x0);
+	let source      = register_at(opcode, 0x10);
+
+	let r = opcode & 0b00000000_01000000_00000000_00000000 != 0x0;
+
+	let instruction = match ((opcode >> 0x4) & 0b1111, (opcode >> 0x15) & 0b11) {
+		(0b0000, 0b00 | 0b10) if match_bits!(0b00000000_00001111_00001111_11111111, 0b00000000_00001111_00000000_00000000) => MoveFromStatusRegister { predicate, destination, source: StatusRegister::from(r) },
+
+		(0b0000, 0b01 | 0b11) if match_bits!(0b00000000_00000000_11111111_00000000, 0b00000000_00000000_11110000_00000000) => {
+			let fields = StatusFields::from_u8(((opcode >> 0x10) & 0b1111) as u8);
+			if fields.is_empty() { return Err(Error::InvalidOpcode) };
+
+			MoveToStatusRegister {
+				predicate,
+				destination: StatusRegister::from(r),
+				fields,
+				source:      Shifter::from_register(base),
+			}
+		}
+
+		(0b0001, 0b01) if match_bits!(0b00000000_00001111_11111111_00000000, 0b00000000_00001111_11111111_00000000) => BranchExchange { predicate, source: base },
+
+		(0b0001, 0b11) if match_bits!(0b00000000_00001111_00001111_00000000, 0b00000000_00001111_00001111_00000000) => CountLeadingZeroes { predicate, destination, source: base },
+
+		(0b0011, 0b01) if match_bits!(0b00000000_00001111_11111111_00000000, 0b00000000_00001111_11111111_00000000) => BranchLinkExchange { predicate, source: Shifter::from_register(base) },
+
+		(0b0101, _) if !match_bits!(0b00000000_00000000_00001111_00000000, 0b00000000_00000000_00000000_00000000) => return Err(Error::InvalidOpcode),
+
+		(0b0101, 0b00) => SaturatingAdd { predicate, destination, base, source },
+
+		(0b0101, 0b01) => SaturatingSubtract { predicate, destination, base, source },
+
+		(0b0101, 0b10) => SaturatingDoubleAdd { predicate, destination, base, source },
+
+		(0b0101, 0b11) => SaturatingDoubleSubtract { predicate, destination, base, source },
+
+		(0b0111, 0b01) if predicate == Predicate::Always => Breakpoint { immediate: (opcode >> 0x4) & 0b11111111_11110000 | opcode & 0b1111 },
+
+		_ => return Err(Error::InvalidOpcode),
+	};
+
+	Ok(instruction)
+}
+
+/// Decodes the address of a single data transfer.
+///
+/// On success, the address is returned together with the T flag (which is encoded as the W bit of post-indexed transfers).
+fn decode_transfer_address(opcode: u32) -> (Address, Tflag) {
+	let base = register_at(opcode, 0x10);
+
+	let p = opcode & 0b00000001_00000000_00000000_00000000 != 0x0;
+	let u = opcode & 0b00000000_10000000_00000000_00000000 != 0x0;
+	let w = opcode & 0b00000000_00100000_00000000_00000000 != 0x0;
+
+	let (indexing, t) = match (p, w) {
+		(false, w) => (Indexing::PostIndexed, Tflag::from(w)),
+
+		(true, false) => (Indexing::Offset, Tflag::Off),
+
+		(true, true) => (Indexing::PreIndexed, Tflag::Off),
+	};
+
+	let address = if opcode & 0b00000010_00000000_00000000_00000000 == 0x0 {
+		let source = (opcode & 0b00000000_00000000_00001111_11111111) as i32;
+
+		Address::ImmediateOffset {
+			base,
+			source: if u { source } else { -source },
+			indexing,
+		}
+	} else {
+		let source = register_at(opcode, 0x0);
+		let code   = (opcode & 0b00000000_00000000_00000000_01100000) >> 0x5;
+		let shift  = (opcode & 0b00000000_00000000_00001111_10000000) >> 0x7;
+
+		let sign = Sign::from(u);
+
+		match decode_shift_immediate(source, code, shift) {
+			Shifter::LogicalShiftLeftImmediate { shift: 0x0, .. } => Address::RegisterOffset { base, source, sign, indexing },
+
+			source => Address::ScaledRegisterOffset { base, source, sign, indexing },
+		}
+	};
+
+	(address, t)
+}
+
+/// Decodes the address of a miscellaneous transfer (i.e. of halfwords, signed bytes, or doublewords).
+fn decode_misc_address(opcode: u32) -> Result<Address> {
+	let base = register_at(opcode, 0x10);
+
+	let p = opcode & 0b00000001_00000000_00000000_00000000 != 0x0;
+	let u = opcode & 0b00000000_10000000_00000000_00000000 != 0x0;
+	let w = opcode & 0b00000000_00100000_00000000_00000000 != 0x0;
+
+	let indexing = match (p, w) {
+		(false, false) => Indexing::PostIndexed,
+
+		(true, false) => Indexing::Offset,
+
+		(true, true) => Indexing::PreIndexed,
+
+		// Unprivileged transfers are not supported.
+		(false, true) => return Err(Error::InvalidOpcode),
+	};
+
+	let address = if opcode & 0b00000000_01000000_00000000_00000000 != 0x0 {
+		let source = (opcode & 0b00000000_00000000_00000000_00001111) | (opcode & 0b00000000_00000000_00001111_00000000) >> 0x4;
+		let source = source as i32;
+
+		Address::ImmediateOffset {
+			base,
+			source: if u { source } else { -source },
+			indexing,
+		}
+	} else {
+		if opcode & 0b00000000_00000000_00001111_00000000 != 0x0 { return Err(Error::InvalidOpcode) };
+
+		Address::RegisterOffset {
+			base,
+			source: register_at(opcode, 0x0),
+			sign:   Sign::from(u),
+			indexing,
+		}
+	};
+
+	Ok(address)
+}
+
+/// Decodes the address of a coprocessor data transfer.
+const fn decode_coprocessor_address(opcode: u32) -> Result<Address> {
+	let p = opcode & 0b00000001_00000000_00000000_00000000 != 0x0;
+	let u = opcode & 0b00000000_10000000_00000000_00000000 != 0x0;
+	let w = opcode & 0b00000000_00100000_00000000_00000000 != 0x0;
+
+	let indexing = match (p, w) {
+		(false, true) => Indexing::PostIndexed,
+
+		(true, false) => Indexing::Offset,
+
+		(true, true) => Indexing::PreIndexed,
+
+		// Unindexed transfers are not supported.
+		(false, false) => return Err(Error::InvalidOpcode),
+	};
+
+	let source = ((opcode & 0b00000000_00000000_00000000_11111111) << 0x2) as i32;
+
+	let address = Address::ImmediateOffset {
+		base:   register_at(opcode, 0x10),
+		source: if u { source } else { -source },
+		indexing,
+	};
+
+	Ok(address)
+}
+
+/// Decodes an instruction of the coprocessor space.
+///
+/// This includes the floating-point instructions and the Advanced SIMD transfers, all of which are encoded identically on Arm and Thumb.
+pub(in super) fn decode_coprocessor(opcode: u32, predicate: Predicate) -> Result<Instruction> {
+	use Instruction::*;
+
+	macro_rules! match_bits {
+		($mask:expr, $value:expr) => {{
+			opcode & $mask == $value
+		}};
+	}
+
+	let instruction = if match_bits!(0b00001111_00000000_00001111_00010000, 0b00001110_00000000_00001011_00010000) {
+		decode_neon(opcode)?
+	} else if (match_bits!(0b00001110_00000000_00000000_00000000, 0b00001100_00000000_00000000_00000000) || match_bits!(0b00001111_00000000_00000000_00000000, 0b00001110_00000000_00000000_00000000))
+		&& match_bits!(0b00000000_00000000_00001110_00000000, 0b00000000_00000000_00001010_00000000)
+	{
+		decode_vfp(opcode, predicate)?
+	} else if match_bits!(0b00001111_00000000_00000000_00010000, 0b00001110_00000000_00000000_00010000) {
+		let coprocessor = Coprocessor::from_u8(((opcode >> 0x8) & 0b1111) as u8).unwrap();
+		let opcode1     = ((opcode >> 0x15) & 0b111) as u8;
+		let register    = register_at(opcode, 0xC);
+		let crn         = coprocessor_register_at(opcode, 0x10);
+		let crm         = coprocessor_register_at(opcode, 0x0);
+		let opcode2     = ((opcode >> 0x5) & 0b111) as u8;
+
+		if opcode & 0b00000000_00010000_00000000_00000000 != 0x0 {
+			MoveFromCoprocessor { predicate, coprocessor, opcode1, register, crn, crm, opcode2 }
+		} else {
+			MoveToCoprocessor { predicate, coprocessor, opcode1, register, crn, crm, opcode2 }
+		}
+	} else if match_bits!(0b00001111_00000000_00000000_00010000, 0b00001110_00000000_00000000_00000000) {
+		CoprocessorDataProcessing {
+			predicate,
+			coprocessor: Coprocessor::from_u8(((opcode >> 0x8) & 0b1111) as u8).unwrap(),
+			opcode1:     ((opcode >> 0x14) & 0b1111) as u8,
+			crd:         coprocessor_register_at(opcode, 0xC),
+			crn:         coprocessor_register_at(opcode, 0x10),
+			crm:         coprocessor_register_at(opcode, 0x0),
+			opcode2:     ((opcode >> 0x5) & 0b111) as u8,
+		}
+	} else if match_bits!(0b00001111_11100000_00000000_00000000, 0b00001100_01000000_00000000_00000000) {
+		let coprocessor = Coprocessor::from_u8(((opcode >> 0x8) & 0b1111) as u8).unwrap();
+		let opcode1     = ((opcode >> 0x4) & 0b1111) as u8;
+		let first       = register_at(opcode, 0xC);
+		let second      = register_at(opcode, 0x10);
+		let crm         = coprocessor_register_at(opcode, 0x0);
+
+		if opcode & 0b00000000_00010000_00000000_00000000 != 0x0 {
+			MoveFromCoprocessorDouble { predicate, coprocessor, opcode1, first, second, crm }
+		} else {
+			MoveToCoprocessorDouble { predicate, coprocessor, opcode1, first, second, crm }
+		}
+	} else if match_bits!(0b00001110_00000000_00000000_00000000, 0b00001100_00000000_00000000_00000000) {
+		let coprocessor = Coprocessor::from_u8(((opcode >> 0x8) & 0b1111) as u8).unwrap();
+		let crd         = coprocessor_register_at(opcode, 0xC);
+		let address     = decode_coprocessor_address(opcode)?;
+
+		let l = Lflag::from(opcode & 0b00000000_01000000_00000000_00000000 != 0x0);
+
+		if opcode & 0b00000000_00010000_00000000_00000000 != 0x0 {
+			LoadCoprocessor { predicate, coprocessor, crd, address, l }
+		} else {
+			StoreCoprocessor { predicate, coprocessor, crd, address, l }
+		}
+	} else {
+		return Err(Error::InvalidOpcode);
+	};
+
+	Ok(instruction)
+}
+
+impl InstructionCodec {
+	/// Decodes a `24`-bit word offset from `PC` into the absolute branch target.
+	///
+	/// This is the inverse of `encode_branch_offset`.
+	#[must_use]
+	fn decode_branch_target(&self, opcode: u32) -> u32 {
+		// Sign-extend the offset and multiply it by four.
+		let offset = ((opcode << 0x8) as i32 >> 0x6) as u32;
+
+		(self.address + Wrapping(0x8) + Wrapping(offset)).0
+	}
+
+	/// Decodes the given Arm opcode.
+	///
+	/// Branch offsets are decoded relative to the codec's address, yielding absolute targets.
+	///
+	/// # Errors
+	///
+	/// If the provided opcode is invalid (i.e. does not match any known pattern, or is unpredictable), an [InvalidOpcode](crate::Error::InvalidOpcode) error is returned.
+	pub fn decode_arm(&mut self, opcode: ArmOpcode) -> Result<Instruction> {
+		use Instruction::*;
+
+		let opcode = opcode.to_u32();
+
+		macro_rules! match_bits {
+			($mask:expr, $value:expr) => {{
+				opcode & $mask == $value
+			}};
+		}
+
+		let Some(predicate) = Predicate::from_u8((opcode >> 0x1C) as u8) else {
+			// The condition code `0b1111` is used by the
+			// unconditional instructions.
+
+			let instruction = if match_bits!(0b11111111_11110001_11111110_00100000, 0b11110001_00000000_00000000_00000000) {
+				let mask = InterruptMask::from_u8(((opcode >> 0x6) & 0b111) as u8);
+
+				let mode = if opcode & 0b00000000_00000010_00000000_00000000 != 0x0 {
+					Some(ProcessorMode::from_u8((opcode & 0b11111) as u8).ok_or(Error::InvalidOpcode)?)
+				} else {
+					None
+				};
+
+				match ((opcode >> 0x12) & 0b11, mode) {
+					(0b10, mode) if !mask.is_empty() => EnableInterrupts { mask, mode },
+
+					(0b11, mode) if !mask.is_empty() => DisableInterrupts { mask, mode },
+
+					(0b00, Some(mode)) if mask.is_empty() => ChangeProcessorMode { mode },
+
+					_ => return Err(Error::InvalidOpcode),
+				}
+			} else if match_bits!(0b11111111_11111111_11111101_11111111, 0b11110001_00000001_00000000_00000000) {
+				let endianness = if opcode & 0b00000000_00000000_00000010_00000000 != 0x0 {
+					Endianness::Big
+				} else {
+					Endianness::Little
+				};
+
+				SetEndianness { endianness }
+			} else if opcode == 0b11110101_01111111_11110000_00011111 {
+				ClearExclusive
+			} else if match_bits!(0b11111111_11111111_11111111_11000000, 0b11110101_01111111_11110000_01000000) {
+				let option = BarrierOption::from_u8((opcode & 0b1111) as u8).ok_or(Error::InvalidOpcode)?;
+
+				match (opcode >> 0x4) & 0b11 {
+					0b00 => DataSynchronisationBarrier { option },
+
+					0b01 => DataMemoryBarrier { option },
+
+					0b10 => InstructionSynchronisationBarrier { option },
+
+					_ => return Err(Error::InvalidOpcode),
+				}
+			} else if match_bits!(0b11111101_01110000_11110000_00000000, 0b11110101_01010000_11110000_00000000) {
+				// Register offsets are followed by a null bit.
+				if match_bits!(0b00000010_00000000_00000000_00010000, 0b00000010_00000000_00000000_00010000) { return Err(Error::InvalidOpcode) };
+
+				PreloadData { address: decode_transfer_address(opcode).0 }
+			} else if match_bits!(0b11111110_00000000_00000000_00000000, 0b11111010_00000000_00000000_00000000) {
+				// The H bit holds the halfword of the target.
+				let h      = (opcode >> 0x18) & 0b1;
+				let target = (Wrapping(self.decode_branch_target(opcode)) + Wrapping(h << 0x1)).0;
+
+				BranchLinkExchange { predicate: Predicate::Always, source: Shifter::Immediate(target) }
+			} else {
+				decode_neon(opcode)?
+			};
+
+			self.check_encodable(instruction, Self::encode_arm)?;
+
+			self.address += Wrapping(ArmOpcode::SIZE);
+			return Ok(instruction);
+		};
+
+		let instruction = if match_bits!(0b00001111_10110000_00001111_11110000, 0b00000001_00000000_00000000_10010000) {
+			Swap {
+				predicate,
+				register: register_at(opcode, 0xC),
+				source:   register_at(opcode, 0x0),
+				address:  Address::from_register(register_at(opcode, 0x10)),
+				b:        Bflag::from(opcode & 0b00000000_01000000_00000000_00000000 != 0x0),
+			}
+		} else if match_bits!(0b00001111_10000000_00001111_11110000, 0b00000001_10000000_00001111_10010000) {
+			decode_exclusive(opcode, predicate)?
+		} else if match_bits!(0b00001111_00000000_00000000_11110000, 0b00000000_00000000_00000000_10010000) {
+			decode_multiply(opcode, predicate)?
+		} else if match_bits!(0b00001110_00000000_00000000_10010000, 0b00000000_00000000_00000000_10010000)
+			&& !match_bits!(0b00000000_00000000_00000000_01100000, 0b00000000_00000000_00000000_00000000)
+		{
+			let register = register_at(opcode, 0xC);
+			let address  = decode_misc_address(opcode)?;
+
+			let l    = opcode & 0b00000000_00010000_00000000_00000000 != 0x0;
+			let code = (opcode & 0b00000000_00000000_00000000_01100000) >> 0x5;
+
+			match (l, code) {
+				(false, 0b01) => StoreHalfword { predicate, register, address },
+
+				(false, _) => {
+					let (first, second) = register_pair_at(opcode, 0xC)?;
+
+					if code == 0b10 {
+						LoadDoubleword { predicate, first, second, address }
+					} else {
+						StoreDoubleword { predicate, first, second, address }
+					}
+				}
+
+				(true, 0b01) => LoadHalfword { predicate, register, address },
+
+				(true, 0b10) => LoadSignedByte { predicate, register, address },
+
+				(true, _) => LoadSignedHalfword { predicate, register, address },
+			}
+		} else if match_bits!(0b00001111_10010000_00000000_00000000, 0b00000001_00000000_00000000_00000000) {
+			decode_misc(opcode, predicate)?
+		} else if match_bits!(0b00001111_10010000_00000000_00000000, 0b00000011_00000000_00000000_00000000) {
+			let destination = register_at(opcode, 0xC);
+			let immediate   = (opcode >> 0x4) & 0b11110000_00000000 | opcode & 0b00001111_11111111;
+
+			match (opcode >> 0x15) & 0b11 {
+				0b00 => MoveWide { predicate, destination, immediate },
+
+				0b10 => MoveTop { predicate, destination, immediate },
+
+				_ => {
+					let fields = StatusFields::from_u8(((opcode >> 0x10) & 0b1111) as u8);
+
+					if !fields.is_empty() {
+						if !match_bits!(0b00000000_00000000_11110000_00000000, 0b00000000_00000000_11110000_00000000) { return Err(Error::InvalidOpcode) };
+
+						MoveToStatusRegister {
+							predicate,
+							destination: StatusRegister::from(opcode & 0b00000000_01000000_00000000_00000000 != 0x0),
+							fields,
+							source:      decode_shifter(opcode),
+						}
+					} else if match_bits!(0b00000000_01000000_11111111_11111000, 0b00000000_00000000_11110000_00000000) {
+						match opcode & 0b111 {
+							0b000 => NoOperation { predicate },
+
+							0b001 => Yield { predicate },
+
+							0b010 => WaitForEvent { predicate },
+
+							0b011 => WaitForInterrupt { predicate },
+
+							0b100 => SendEvent { predicate },
+
+							_ => return Err(Error::InvalidOpcode),
+						}
+					} else {
+						return Err(Error::InvalidOpcode);
+					}
+				}
+			}
+		} else if match_bits!(0b00001100_00000000_00000000_00000000, 0b00000000_00000000_00000000_00000000)
+			&& !match_bits!(0b00000010_00000000_00000000_10010000, 0b00000000_00000000_00000000_10010000)
+		{
+			decode_data_processing(opcode, predicate)?
+		} else if match_bits!(0b00001100_00000000_00000000_00000000, 0b00000100_00000000_00000000_00000000)
+			&& !match_bits!(0b00000010_00000000_00000000_00010000, 0b00000010_00000000_00000000_00010000)
+		{
+			let register = register_at(opcode, 0xC);
+			let (address, t) = decode_transfer_address(opcode);
+
+			let b = Bflag::from(opcode & 0b00000000_01000000_00000000_00000000 != 0x0);
+			let l = opcode & 0b00000000_00010000_00000000_00000000 != 0x0;
+
+			if l {
+				Load {
+					predicate,
+					register,
+					address,
+					b,
+					t,
+				}
+			} else {
+				Store {
+					predicate,
+					register,
+					address,
+					b,
+					t,
+				}
+			}
+		} else if match_bits!(0b00001111_10000000_00001111_00010000, 0b00000110_00000000_00001111_00010000) {
+			ParallelArithmetic {
+				predicate,
+				prefix:      ParallelPrefix::from_u8(((opcode >> 0x14) & 0b111) as u8).ok_or(Error::InvalidOpcode)?,
+				operation:   ParallelOperation::from_u8(((opcode >> 0x5) & 0b111) as u8).ok_or(Error::InvalidOpcode)?,
+				destination: register_at(opcode, 0xC),
+				base:        register_at(opcode, 0x10),
+				source:      register_at(opcode, 0x0),
+			}
+		} else if match_bits!(0b00001111_11110000_00000000_00110000, 0b00000110_10000000_00000000_00010000) {
+			let source = register_at(opcode, 0x0);
+			let shift  = (opcode >> 0x7) & 0b11111;
+
+			let source = if opcode & 0b00000000_00000000_00000000_01000000 != 0x0 {
+				Shifter::ArithmeticShiftRightImmediate { source, shift: if shift == 0x0 { 0x20 } else { shift } }
+			} else {
+				Shifter::LogicalShiftLeftImmediate { source, shift }
+			};
+
+			PackHalfword {
+				predicate,
+				destination: register_at(opcode, 0xC),
+				base:        register_at(opcode, 0x10),
+				source,
+			}
+		} else if match_bits!(0b00001111_11110000_00001111_11110000, 0b00000110_10000000_00001111_10110000) {
+			Select {
+				predicate,
+				destination: register_at(opcode, 0xC),
+				base:        register_at(opcode, 0x10),
+				source:      register_at(opcode, 0x0),
+			}
+		} else if match_bits!(0b00001111_10100000_00000000_00110000, 0b00000110_10100000_00000000_00010000) {
+			let destination = register_at(opcode, 0xC);
+			let immediate   = (opcode >> 0x10) & 0b11111;
+			let source      = decode_shift_immediate(register_at(opcode, 0x0), (opcode >> 0x5) & 0b10, (opcode >> 0x7) & 0b11111);
+
+			if opcode & 0b00000000_01000000_00000000_00000000 != 0x0 {
+				UnsignedSaturate { predicate, destination, immediate, source }
+			} else {
+				SignedSaturate { predicate, destination, immediate: immediate + 0x1, source }
+			}
+		} else if match_bits!(0b00001111_10110000_00001111_11110000, 0b00000110_10100000_00001111_00110000) {
+			let destination = register_at(opcode, 0xC);
+			let immediate   = (opcode >> 0x10) & 0b1111;
+			let source      = register_at(opcode, 0x0);
+
+			if opcode & 0b00000000_01000000_00000000_00000000 != 0x0 {
+				UnsignedSaturate16 { predicate, destination, immediate, source }
+			} else {
+				SignedSaturate16 { predicate, destination, immediate: immediate + 0x1, source }
+			}
+		} else if match_bits!(0b00001111_10111111_00001111_01110000, 0b00000110_10111111_00001111_00110000) {
+			let destination = register_at(opcode, 0xC);
+			let source      = register_at(opcode, 0x0);
+
+			match (opcode & 0b00000000_01000000_00000000_00000000 != 0x0, opcode & 0b00000000_00000000_00000000_10000000 != 0x0) {
+				(false, false) => Reverse { predicate, destination, source },
+
+				(false, true) => ReversePackedHalfword { predicate, destination, source },
+
+				(true, false) => ReverseBits { predicate, destination, source },
+
+				(true, true) => ReverseSignedHalfword { predicate, destination, source },
+			}
+		} else if match_bits!(0b00001111_11010000_11110000_11110000, 0b00000111_00010000_11110000_00010000) {
+			let destination = register_at(opcode, 0x10);
+			let base        = register_at(opcode, 0x0);
+			let source      = register_at(opcode, 0x8);
+
+			if opcode & 0b00000000_00100000_00000000_00000000 != 0x0 {
+				UnsignedDivide { predicate, destination, base, source }
+			} else {
+				SignedDivide { predicate, destination, base, source }
+			}
+		} else if match_bits!(0b00001111_11100000_00000000_01110000, 0b00000111_11000000_00000000_00010000) {
+			let destination = register_at(opcode, 0xC);
+			let lsb         = (opcode >> 0x7) & 0b11111;
+			let msb         = (opcode >> 0x10) & 0b11111;
+
+			if msb < lsb { return Err(Error::InvalidOpcode) };
+			let width = msb - lsb + 0x1;
+
+			match register_at(opcode, 0x0) {
+				Register::Pc => BitFieldClear { predicate, destination, lsb, width },
+
+				source => BitFieldInsert { predicate, destination, source, lsb, width },
+			}
+		} else if match_bits!(0b00001111_10100000_00000000_01110000, 0b00000111_10100000_00000000_01010000) {
+			let destination = register_at(opcode, 0xC);
+			let source      = register_at(opcode, 0x0);
+			let lsb         = (opcode >> 0x7) & 0b11111;
+			let width       = ((opcode >> 0x10) & 0b11111) + 0x1;
+
+			if lsb + width > 0x20 { return Err(Error::InvalidOpcode) };
+
+			if opcode & 0b00000000_01000000_00000000_00000000 != 0x0 {
+				UnsignedBitFieldExtract { predicate, destination, source, lsb, width }
+			} else {
+				SignedBitFieldExtract { predicate, destination, source, lsb, width }
+			}
+		} else if match_bits!(0b00001111_10000000_00000011_11110000, 0b00000110_10000000_00000000_01110000) {
+			let destination = register_at(opcode, 0xC);
+			let source      = register_at(opcode, 0x0);
+			let rotation    = ((opcode >> 0xA) & 0b11) << 0x3;
+
+			match ((opcode >> 0x14) & 0b111, register_at(opcode, 0x10)) {
+				(0b000, Register::Pc) => SignExtendByte16 { predicate, destination, source, rotation },
+
+				(0b000, base) => SignExtendAddByte16 { predicate, destination, base, source, rotation },
+
+				(0b010, Register::Pc) => SignExtendByte { predicate, destination, source, rotation },
+
+				(0b010, base) => SignExtendAddByte { predicate, destination, base, source, rotation },
+
+				(0b011, Register::Pc) => SignExtendHalfword { predicate, destination, source, rotation },
+
+				(0b011, base) => SignExtendAddHalfword { predicate, destination, base, source, rotation },
+
+				(0b100, Register::Pc) => ZeroExtendByte16 { predicate, destination, source, rotation },
+
+				(0b100, base) => ZeroExtendAddByte16 { predicate, destination, base, source, rotation },
+
+				(0b110, Register::Pc) => ZeroExtendByte { predicate, destination, source, rotation },
+
+				(0b110, base) => ZeroExtendAddByte { predicate, destination, base, source, rotation },
+
+				(0b111, Register::Pc) => ZeroExtendHalfword { predicate, destination, source, rotation },
+
+				(0b111, base) => ZeroExtendAddHalfword { predicate, destination, base, source, rotation },
+
+				_ => return Err(Error::InvalidOpcode),
+			}
+		} else if match_bits!(0b00001111_11110000_00000000_11110000, 0b00000111_10000000_00000000_00010000) {
+			let destination = register_at(opcode, 0x10);
+			let base        = register_at(opcode, 0x0);
+			let source      = register_at(opcode, 0x8);
+
+			match register_at(opcode, 0xC) {
+				Register::Pc => UnsignedSumAbsoluteDifferences { predicate, destination, base, source },
+
+				accumulator => UnsignedSumAbsoluteDifferencesAccumulate { predicate, destination, base, source, accumulator },
+			}
+		} else if match_bits!(0b00001110_00000000_00000000_00000000, 0b00001000_00000000_00000000_00000000) {
+			let base      = register_at(opcode, 0x10);
+			let registers = RegisterList::from_u16(opcode as u16);
+
+			if registers.is_empty() { return Err(Error::InvalidOpcode) };
+
+			let mode = match (opcode & 0b00000001_00000000_00000000_00000000 != 0x0, opcode & 0b00000000_10000000_00000000_00000000 != 0x0) {
+				(false, true)  => BlockMode::IncrementAfter,
+				(true,  true)  => BlockMode::IncrementBefore,
+				(false, false) => BlockMode::DecrementAfter,
+				(true,  false) => BlockMode::DecrementBefore,
+			};
+
+			let w = Wflag::from(opcode & 0b00000000_00100000_00000000_00000000 != 0x0);
+			let s = Sflag::from(opcode & 0b00000000_01000000_00000000_00000000 != 0x0);
+
+			if opcode & 0b00000000_00010000_00000000_00000000 != 0x0 {
+				LoadMultiple { predicate, base, registers, mode, w, s }
+			} else {
+				StoreMultiple { predicate, base, registers, mode, w, s }
+			}
+		} else if match_bits!(0b00001110_00000000_00000000_00000000, 0b00001010_00000000_00000000_00000000) {
+			let target = self.decode_branch_target(opcode);
+
+			if opcode & 0b00000001_00000000_00000000_00000000 != 0x0 {
+				BranchLink { predicate, source: target }
+			} else {
+				Branch { predicate, immediate: target }
+			}
+		} else if match_bits!(0b00001111_00000000_00000000_00000000, 0b00001111_00000000_00000000_00000000) {
+			SoftwareInterrupt { predicate, immediate: opcode & 0b00000000_11111111_11111111_11111111 }
+		} else if match_bits!(0b00001100_00000000_00000000_00000000, 0b00001100_00000000_00000000_00000000) {
+			decode_coprocessor(opcode, predicate)?
+		} else {
+			return Err(Error::InvalidOpcode);
+		};
+
+		self.check_encodable(instruction, Self::encode_arm)?;
+
+		self.address += Wrapping(ArmOpcode::SIZE);
+		Ok(instruction)
+	}
+}
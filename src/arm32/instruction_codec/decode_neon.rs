@@ -0,0 +1,457 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Pollex.
+//
+// Pollex is free software: you can redistribute it
+// and/or modify it under the terms of the GNU Af-
+// fero General Public License as published by the
+// Free Software Foundation, either version 3 of
+// the License, or (at your option) any later ver-
+// sion.
+//
+// Pollex is distributed in the hope that it will
+// be useful, but WITHOUT ANY WARRANTY; without
+// even the implied warranty of MERCHANTABILITY or
+// FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// Affero General Public License for more details.
+//
+// You should have received a copy of the GNU Af-
+// fero General Public License along with Pollex.
+// If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Error, Result};
+use crate::arm32::{
+	DoubleRegister,
+	ElementType,
+	Instruction,
+	Predicate,
+	Register,
+	Scalar,
+	StructureAddress,
+	VectorRegister,
+};
+
+use super::register_at;
+
+/// Joins the four-bit and one-bit fields of an Advanced SIMD register.
+///
+/// Quad registers must be even-numbered in the fields.
+fn join_register(opcode: u32, shift: u32, bit: u32, quad: bool) -> Result<VectorRegister> {
+	let value = ((opcode >> bit) & 0b1) << 0x4 | ((opcode >> shift) & 0b1111);
+
+	VectorRegister::from_double_index(value as u8, quad).ok_or(Error::InvalidOpcode)
+}
+
+/// Extracts the Advanced SIMD register in the `Vd` field.
+#[inline(always)]
+fn vd(opcode: u32, quad: bool) -> Result<VectorRegister> { join_register(opcode, 0xC, 0x16, quad) }
+
+/// Extracts the Advanced SIMD register in the `Vn` field.
+#[inline(always)]
+fn vn(opcode: u32, quad: bool) -> Result<VectorRegister> { join_register(opcode, 0x10, 0x7, quad) }
+
+/// Extracts the Advanced SIMD register in the `Vm` field.
+#[inline(always)]
+fn vm(opcode: u32, quad: bool) -> Result<VectorRegister> { join_register(opcode, 0x0, 0x5, quad) }
+
+/// Extracts the double register in the `Vm` field.
+#[inline]
+const fn double_vm(opcode: u32) -> DoubleRegister {
+	let value = ((opcode >> 0x5) & 0b1) << 0x4 | (opcode & 0b1111);
+
+	DoubleRegister::from_u8(value as u8).unwrap()
+}
+
+/// Decodes the `opc1` and `opc2` fields of scalar transfers.
+///
+/// On success, the element size is returned together with the scalar.
+const fn decode_scalar_index(opcode: u32) -> Result<(u32, Scalar)> {
+	let field = ((opcode >> 0x15) & 0b11) << 0x2 | ((opcode >> 0x5) & 0b11);
+
+	let (size, index) = if field & 0b1000 != 0x0 {
+		(0x8, field & 0b111)
+	} else if field & 0b0001 != 0x0 {
+		(0x10, field >> 0x1)
+	} else if field & 0b0010 == 0x0 {
+		(0x20, field >> 0x2)
+	} else {
+		return Err(Error::InvalidOpcode);
+	};
+
+	let register = DoubleRegister::from_u8((((opcode >> 0x7) & 0b1) << 0x4 | ((opcode >> 0x10) & 0b1111)) as u8).unwrap();
+
+	Ok((size, Scalar::new(register, index as u8)))
+}
+
+/// Decodes an Advanced SIMD instruction with three registers of the same length.
+fn decode_neon_three_same(opcode: u32) -> Result<Instruction> {
+	use Instruction::*;
+
+	let quad = opcode & 0b00000000_00000000_00000000_01000000 != 0x0;
+
+	let destination = vd(opcode, quad)?;
+	let base        = vn(opcode, quad)?;
+	let source      = vm(opcode, quad)?;
+
+	let code = (opcode >> 0x8) & 0b1111;
+	let b    = opcode & 0b00000000_00000000_00000000_00010000 != 0x0;
+	let u    = opcode & 0b00000001_00000000_00000000_00000000 != 0x0;
+	let size = 0x8 << ((opcode >> 0x14) & 0b11);
+
+	if code >= 0b1101 {
+		// Half-precision floats are not supported.
+		if opcode & 0b00000000_00010000_00000000_00000000 != 0x0 { return Err(Error::InvalidOpcode) };
+
+		let element = ElementType::F32;
+		let op      = opcode & 0b00000000_00100000_00000000_00000000 != 0x0;
+
+		let instruction = match (code, b, u, op) {
+			(0b1101, false, false, false) => VectorAdd { element, destination, base, source },
+			(0b1101, false, false, true)  => VectorSubtract { element, destination, base, source },
+			(0b1101, false, true,  true)  => VectorAbsoluteDifference { element, destination, base, source },
+			(0b1101, true,  false, false) => VectorMultiplyAccumulate { element, destination, base, source },
+			(0b1101, true,  false, true)  => VectorMultiplySubtract { element, destination, base, source },
+			(0b1101, true,  true,  false) => VectorMultiply { element, destination, base, source },
+			(0b1110, false, false, false) => VectorCompareEqual { element, destination, base, source },
+			(0b1110, false, true,  false) => VectorCompareGreaterThanOrEqual { element, destination, base, source },
+			(0b1110, false, true,  true)  => VectorCompareGreaterThan { element, destination, base, source },
+			(0b1111, false, false, false) => VectorMaximum { element, destination, base, source },
+			(0b1111, false, false, true)  => VectorMinimum { element, destination, base, source },
+
+			_ => return Err(Error::InvalidOpcode),
+		};
+
+		return Ok(instruction);
+	}
+
+	if code == 0b0001 && b {
+		let instruction = match (u, size) {
+			(false, 0x8)  => VectorAnd { destination, base, source },
+			(false, 0x10) => VectorBitClear { destination, base, source },
+			(false, 0x20) => VectorInclusiveOr { destination, base, source },
+			(true,  0x8)  => VectorExclusiveOr { destination, base, source },
+
+			_ => return Err(Error::InvalidOpcode),
+		};
+
+		return Ok(instruction);
+	}
+
+	// All of the following except the saturating ones
+	// only permit elements of up to 32 bits.
+	if size == 0x40 && code != 0b0000 && code != 0b0010 && code != 0b1000 { return Err(Error::InvalidOpcode) };
+
+	let signed  = ElementType::signed(size, u).unwrap();
+	let integer = ElementType::integer(size).unwrap();
+
+	let instruction = match (code, b, u) {
+		(0b0000, true, _) => VectorSaturatingAdd { element: signed, destination, base, source },
+
+		(0b0010, true, _) => VectorSaturatingSubtract { element: signed, destination, base, source },
+
+		(0b0011, false, _) => VectorCompareGreaterThan { element: signed, destination, base, source },
+
+		(0b0011, true, _) => VectorCompareGreaterThanOrEqual { element: signed, destination, base, source },
+
+		(0b0110, false, _) => VectorMaximum { element: signed, destination, base, source },
+
+		(0b0110, true, _) => VectorMinimum { element: signed, destination, base, source },
+
+		(0b0111, false, _) => VectorAbsoluteDifference { element: signed, destination, base, source },
+
+		(0b1000, false, false) => VectorAdd { element: integer, destination, base, source },
+
+		(0b1000, false, true) => VectorSubtract { element: integer, destination, base, source },
+
+		(0b1000, true, true) if size != 0x40 => VectorCompareEqual { element: integer, destination, base, source },
+
+		(0b1001, false, false) => VectorMultiplyAccumulate { element: integer, destination, base, source },
+
+		(0b1001, false, true) => VectorMultiplySubtract { element: integer, destination, base, source },
+
+		(0b1001, true, false) => VectorMultiply { element: integer, destination, base, source },
+
+		(0b1001, true, true) if size == 0x8 => VectorMultiply { element: ElementType::P8, destination, base, source },
+
+		_ => return Err(Error::InvalidOpcode),
+	};
+
+	Ok(instruction)
+}
+
+/// Decodes an Advanced SIMD instruction with two registers and miscellaneous operations.
+fn decode_neon_two_misc(opcode: u32) -> Result<Instruction> {
+	use Instruction::*;
+
+	let quad = opcode & 0b00000000_00000000_00000000_01000000 != 0x0;
+
+	let destination = vd(opcode, quad)?;
+	let source      = vm(opcode, quad)?;
+
+	let size = 0x8 << ((opcode >> 0x12) & 0b11);
+
+	let element = ElementType::untyped(size).unwrap();
+	let signed  = ElementType::signed(size, false).unwrap();
+
+	let instruction = match ((opcode >> 0x10) & 0b11, (opcode >> 0x7) & 0b1111) {
+		(0b00, 0b0000) if size <= 0x20 => VectorReverse64 { element, destination, source },
+
+		(0b00, 0b0001) if size <= 0x10 => VectorReverse32 { element, destination, source },
+
+		(0b00, 0b0010) if size == 0x8 => VectorReverse16 { element, destination, source },
+
+		(0b00, 0b1011) if size == 0x8 => VectorNot { destination, source },
+
+		(0b01, 0b0110) if size <= 0x20 => VectorAbsolute { element: signed, destination, source },
+
+		(0b01, 0b0111) if size <= 0x20 => VectorNegate { element: signed, destination, source },
+
+		(0b01, 0b1110) if size == 0x20 => VectorAbsolute { element: ElementType::F32, destination, source },
+
+		(0b01, 0b1111) if size == 0x20 => VectorNegate { element: ElementType::F32, destination, source },
+
+		(0b10, 0b0000) if size == 0x8 => VectorSwap { destination, source },
+
+		(0b10, 0b0001) if size <= 0x20 => VectorTranspose { element, destination, source },
+
+		(0b10, 0b0010) if size < 0x20 || size == 0x20 && quad => VectorUnzip { element, destination, source },
+
+		(0b10, 0b0011) if size < 0x20 || size == 0x20 && quad => VectorZip { element, destination, source },
+
+		_ => return Err(Error::InvalidOpcode),
+	};
+
+	Ok(instruction)
+}
+
+/// Decodes an Advanced SIMD shift by immediate.
+fn decode_neon_shift(opcode: u32) -> Result<Instruction> {
+	use Instruction::*;
+
+	let quad = opcode & 0b00000000_00000000_00000000_01000000 != 0x0;
+
+	let destination = vd(opcode, quad)?;
+	let source      = vm(opcode, quad)?;
+
+	let immediate = (opcode >> 0x10) & 0b111111;
+	let u         = opcode & 0b00000001_00000000_00000000_00000000 != 0x0;
+
+	// The element size is given by the highest set bit
+	// of the L bit and the immediate.
+	let size = if opcode & 0b00000000_00000000_00000000_10000000 != 0x0 {
+		0x40
+	} else if immediate & 0b100000 != 0x0 {
+		0x20
+	} else if immediate & 0b010000 != 0x0 {
+		0x10
+	} else if immediate & 0b001000 != 0x0 {
+		0x8
+	} else {
+		return Err(Error::InvalidOpcode);
+	};
+
+	// The immediate of 64-bit shifts is not offset by
+	// the element size.
+	let offset = if size == 0x40 { 0x0 } else { size };
+
+	let instruction = match ((opcode >> 0x8) & 0b1111, u) {
+		(0b0101, false) => VectorShiftLeft {
+			element: ElementType::integer(size).unwrap(),
+			destination,
+			source,
+			shift: immediate - offset,
+		},
+
+		(0b0000, u) => VectorShiftRight {
+			element: ElementType::signed(size, u).unwrap(),
+			destination,
+			source,
+			shift: size + offset - immediate,
+		},
+
+		_ => return Err(Error::InvalidOpcode),
+	};
+
+	Ok(instruction)
+}
+
+/// Decodes an Advanced SIMD multiple structure transfer.
+fn decode_neon_structure(opcode: u32) -> Result<Instruction> {
+	use Instruction::*;
+
+	let (interleave, count, spacing, max_alignment) = match (opcode >> 0x8) & 0b1111 {
+		0b0111 => (0x1, 0x1, 0x1, 0x40),
+		0b1010 => (0x1, 0x2, 0x1, 0x80),
+		0b0110 => (0x1, 0x3, 0x1, 0x40),
+		0b0010 => (0x1, 0x4, 0x1, 0x100),
+		0b1000 => (0x2, 0x2, 0x1, 0x80),
+		0b1001 => (0x2, 0x2, 0x2, 0x80),
+		0b0011 => (0x2, 0x4, 0x1, 0x100),
+		0b0100 => (0x3, 0x3, 0x1, 0x40),
+		0b0101 => (0x3, 0x3, 0x2, 0x40),
+		0b0000 => (0x4, 0x4, 0x1, 0x100),
+		0b0001 => (0x4, 0x4, 0x2, 0x100),
+
+		_ => return Err(Error::InvalidOpcode),
+	};
+
+	let size      = 0x8 << ((opcode >> 0x6) & 0b11);
+	let alignment = match (opcode >> 0x4) & 0b11 {
+		0b00 => 0x0,
+		code => 0x20 << code,
+	};
+
+	if size == 0x40 && interleave != 0x1 || alignment > max_alignment { return Err(Error::InvalidOpcode) };
+
+	let first = u32::from(join_register(opcode, 0xC, 0x16, false)?.to_double_index());
+	if first + (count - 0x1) * spacing > 0x1F { return Err(Error::InvalidOpcode) };
+
+	let base = register_at(opcode, 0x10);
+	if base == Register::Pc { return Err(Error::InvalidOpcode) };
+
+	let address = match register_at(opcode, 0x0) {
+		Register::Pc => StructureAddress::Offset { base, alignment },
+
+		Register::Sp => StructureAddress::PostIndexed { base, alignment },
+
+		source => StructureAddress::RegisterPostIndexed { base, alignment, source },
+	};
+
+	let interleave = interleave as u8;
+	let element    = ElementType::untyped(size).unwrap();
+	let first      = DoubleRegister::from_u8(first as u8).unwrap();
+	let count      = count as u8;
+	let spacing    = spacing as u8;
+
+	let instruction = if opcode & 0b00000000_00100000_00000000_00000000 != 0x0 {
+		VectorLoadStructure { interleave, element, first, count, spacing, address }
+	} else {
+		VectorStoreStructure { interleave, element, first, count, spacing, address }
+	};
+
+	Ok(instruction)
+}
+
+/// Decodes an Advanced SIMD instruction.
+///
+/// The opcode must be given in its Arm encoding.
+/// This includes the transfers between Arm registers and scalars (or vectors), which are conditional and reside in the coprocessor space of `p11`.
+pub(in super) fn decode_neon(opcode: u32) -> Result<Instruction> {
+	use Instruction::*;
+
+	macro_rules! match_bits {
+		($mask:expr, $value:expr) => {{
+			opcode & $mask == $value
+		}};
+	}
+
+	let instruction = if match_bits!(0b11111110_10000000_00000000_00000000, 0b11110010_00000000_00000000_00000000) {
+		decode_neon_three_same(opcode)?
+	} else if match_bits!(0b11111111_10110000_00000000_00010000, 0b11110010_10110000_00000000_00000000) {
+		let quad = opcode & 0b00000000_00000000_00000000_01000000 != 0x0;
+		let index = (opcode >> 0x8) & 0b1111;
+
+		if !quad && index >= 0x8 { return Err(Error::InvalidOpcode) };
+
+		VectorExtract {
+			destination: vd(opcode, quad)?,
+			base:        vn(opcode, quad)?,
+			source:      vm(opcode, quad)?,
+			index:       index as u8,
+		}
+	} else if match_bits!(0b11111111_10110000_00001000_00010000, 0b11110011_10110000_00000000_00000000) {
+		decode_neon_two_misc(opcode)?
+	} else if match_bits!(0b11111111_10110000_00001111_10010000, 0b11110011_10110000_00001100_00000000) {
+		let field = (opcode >> 0x10) & 0b1111;
+		if field.trailing_zeros() >= 0x3 { return Err(Error::InvalidOpcode) };
+
+		let size = 0x8 << field.trailing_zeros();
+
+		VectorDuplicateScalar {
+			element:     ElementType::untyped(size).unwrap(),
+			destination: vd(opcode, opcode & 0b00000000_00000000_00000000_01000000 != 0x0)?,
+			source:      Scalar::new(double_vm(opcode), (field >> (field.trailing_zeros() + 0x1)) as u8),
+		}
+	} else if match_bits!(0b11111110_10000000_00000000_00010000, 0b11110010_10000000_00000000_00010000) {
+		decode_neon_shift(opcode)?
+	} else if match_bits!(0b11111110_10000000_00001110_01010000, 0b11110010_10000000_00001000_01000000) {
+		let quad  = opcode & 0b00000001_00000000_00000000_00000000 != 0x0;
+		let float = opcode & 0b00000000_00000000_00000001_00000000 != 0x0;
+		let size  = 0x8 << ((opcode >> 0x14) & 0b11);
+
+		let element = match (float, size) {
+			(false, 0x10) => ElementType::I16,
+			(false, 0x20) => ElementType::I32,
+			(true,  0x20) => ElementType::F32,
+
+			_ => return Err(Error::InvalidOpcode),
+		};
+
+		let source = if size == 0x10 {
+			Scalar::new(DoubleRegister::from_u8((opcode & 0b111) as u8).unwrap(), (((opcode >> 0x5) & 0b1) << 0x1 | (opcode >> 0x3) & 0b1) as u8)
+		} else {
+			Scalar::new(DoubleRegister::from_u8((opcode & 0b1111) as u8).unwrap(), ((opcode >> 0x5) & 0b1) as u8)
+		};
+
+		VectorMultiplyScalar {
+			element,
+			destination: vd(opcode, quad)?,
+			base:        vn(opcode, quad)?,
+			source,
+		}
+	} else if match_bits!(0b11111111_10010000_00000000_00000000, 0b11110100_00000000_00000000_00000000) {
+		decode_neon_structure(opcode)?
+	} else if opcode >> 0x1C == 0b1111 {
+		return Err(Error::InvalidOpcode);
+	} else {
+		let predicate = Predicate::from_u8((opcode >> 0x1C) as u8).unwrap();
+
+		if match_bits!(0b00001111_10010000_00001111_01011111, 0b00001110_10000000_00001011_00010000) {
+			let element = match (opcode >> 0x16 & 0b1, opcode >> 0x5 & 0b1) {
+				(0b0, 0b0) => ElementType::Untyped32,
+				(0b0, 0b1) => ElementType::Untyped16,
+				(0b1, 0b0) => ElementType::Untyped8,
+
+				_ => return Err(Error::InvalidOpcode),
+			};
+
+			VectorDuplicate {
+				predicate,
+				element,
+				destination: vn(opcode, opcode & 0b00000000_00100000_00000000_00000000 != 0x0)?,
+				source:      register_at(opcode, 0xC),
+			}
+		} else if match_bits!(0b00001111_10010000_00001111_00011111, 0b00001110_00000000_00001011_00010000) {
+			let (size, destination) = decode_scalar_index(opcode)?;
+
+			MoveToScalar {
+				predicate,
+				element: ElementType::untyped(size).unwrap(),
+				destination,
+				source:  register_at(opcode, 0xC),
+			}
+		} else if match_bits!(0b00001111_00010000_00001111_00011111, 0b00001110_00010000_00001011_00010000) {
+			let (size, source) = decode_scalar_index(opcode)?;
+			let u = opcode & 0b00000000_10000000_00000000_00000000 != 0x0;
+
+			let element = if size == 0x20 {
+				if u { return Err(Error::InvalidOpcode) };
+
+				ElementType::Untyped32
+			} else {
+				ElementType::signed(size, u).unwrap()
+			};
+
+			MoveFromScalar {
+				predicate,
+				element,
+				destination: register_at(opcode, 0xC),
+				source,
+			}
+		} else {
+			return Err(Error::InvalidOpcode);
+		}
+	};
+
+	Ok(instruction)
+}
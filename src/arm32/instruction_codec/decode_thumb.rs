@@ -19,111 +19,297 @@
 // fero General Public License along with Pollex.
 // If not, see <https://www.gnu.org/licenses/>.
 
-use crate::Result;
+use crate::{Error, Result};
 use crate::arm32::{
+	Address,
+	Bflag,
+	BlockMode,
+	Endianness,
+	Indexing,
 	Instruction,
 	InstructionCodec,
+	InterruptMask,
 	Predicate,
 	Register,
+	RegisterList,
 	Sflag,
 	Shifter,
+	Sign,
+	Tflag,
 	ThumbOpcode,
+	Wflag,
 };
 
 use core::num::Wrapping;
 
+use super::decode_if_then_state;
+use super::decode_thumb_wide::decode_thumb_wide;
+
+/// Extracts the low register at the given bit position.
+#[inline(always)]
+#[must_use]
+const fn low_register_at(opcode: u16, shift: u32) -> Register {
+	Register::from_u8(((opcode >> shift) & 0b111) as u8).unwrap()
+}
+
+/// Constructs a word or byte transfer.
+#[inline]
+#[must_use]
+const fn decode_transfer(l: bool, register: Register, address: Address, b: Bflag) -> Instruction {
+	if l {
+		Instruction::Load {
+			predicate: Predicate::Always,
+			register,
+			address,
+			b,
+			t:         Tflag::Off,
+		}
+	} else {
+		Instruction::Store {
+			predicate: Predicate::Always,
+			register,
+			address,
+			b,
+			t:         Tflag::Off,
+		}
+	}
+}
+
+/// Decodes a two-register data-processing instruction.
+///
+/// All of these set the flags, and those with a destination also use it as their base.
+#[must_use]
+const fn decode_data_processing(opcode: u16) -> Instruction {
+	use Instruction::*;
+
+	let predicate   = Predicate::Always;
+	let destination = low_register_at(opcode, 0x0);
+	let register    = low_register_at(opcode, 0x3);
+
+	let base   = destination;
+	let source = Shifter::from_register(register);
+	let s      = Sflag::On;
+
+	match (opcode >> 0x6) & 0b1111 {
+		0b0000 => And { predicate, destination, base, source, s },
+
+		0b0001 => ExclusiveOr { predicate, destination, base, source, s },
+
+		0b0010 => Move { predicate, destination, source: Shifter::LogicalShiftLeftRegister { source: destination, shift: register }, s },
+
+		0b0011 => Move { predicate, destination, source: Shifter::LogicalShiftRightRegister { source: destination, shift: register }, s },
+
+		0b0100 => Move { predicate, destination, source: Shifter::ArithmeticShiftRightRegister { source: destination, shift: register }, s },
+
+		0b0101 => AddCarry { predicate, destination, base, source, s },
+
+		0b0110 => SubtractCarry { predicate, destination, base, source, s },
+
+		0b0111 => Move { predicate, destination, source: Shifter::RotateRightRegister { source: destination, shift: register }, s },
+
+		0b1000 => Test { predicate, lhs: destination, rhs: source },
+
+		0b1001 => ReverseSubtract { predicate, destination, base: register, source: Shifter::Immediate(0x0), s },
+
+		0b1010 => Compare { predicate, lhs: destination, rhs: source },
+
+		0b1011 => CompareNegated { predicate, lhs: destination, rhs: source },
+
+		0b1100 => InclusiveOr { predicate, destination, base, source, s },
+
+		0b1101 => Multiply { predicate, destination, base, source: register, s },
+
+		0b1110 => BitClear { predicate, destination, base, source, s },
+
+		_ => MoveNot { predicate, destination, source, s },
+	}
+}
+
+/// Decodes a miscellaneous instruction (i.e. one prefixed by `0b1011`).
+fn decode_misc(opcode: u16) -> Result<Instruction> {
+	use Instruction::*;
+
+	macro_rules! match_bits {
+		($mask:expr, $value:expr) => {{
+			opcode & $mask == $value
+		}};
+	}
+
+	let predicate = Predicate::Always;
+
+	let instruction = if match_bits!(0b11111111_00000000, 0b10110000_00000000) {
+		let source = Shifter::Immediate(u32::from(opcode & 0b00000000_01111111) * 0x4);
+
+		if opcode & 0b00000000_10000000 != 0x0 {
+			Subtract { predicate, destination: Register::Sp, base: Register::Sp, source, s: Sflag::Off }
+		} else {
+			Add { predicate, destination: Register::Sp, base: Register::Sp, source, s: Sflag::Off }
+		}
+	} else if match_bits!(0b11111111_00000000, 0b10110010_00000000) {
+		let destination = low_register_at(opcode, 0x0);
+		let source      = low_register_at(opcode, 0x3);
+		let rotation    = 0x0;
+
+		match (opcode >> 0x6) & 0b11 {
+			0b00 => SignExtendHalfword { predicate, destination, source, rotation },
+
+			0b01 => SignExtendByte { predicate, destination, source, rotation },
+
+			0b10 => ZeroExtendHalfword { predicate, destination, source, rotation },
+
+			_ => ZeroExtendByte { predicate, destination, source, rotation },
+		}
+	} else if match_bits!(0b11110110_00000000, 0b10110100_00000000) {
+		let l = opcode & 0b00001000_00000000 != 0x0;
+
+		// `PUSH` may additionally store `lr` and `POP` may
+		// load `pc`.
+		let extra = if l { Register::Pc } else { Register::Lr };
+
+		let mut registers = RegisterList::from_u16(opcode & 0b00000000_11111111);
+		if opcode & 0b00000001_00000000 != 0x0 { registers.insert(extra); }
+
+		if registers.is_empty() { return Err(Error::InvalidOpcode) };
+
+		let base = Register::Sp;
+		let w    = Wflag::On;
+		let s    = Sflag::Off;
+
+		if l {
+			LoadMultiple { predicate, base, registers, mode: BlockMode::IncrementAfter, w, s }
+		} else {
+			StoreMultiple { predicate, base, registers, mode: BlockMode::DecrementBefore, w, s }
+		}
+	} else if match_bits!(0b11111111_11110111, 0b10110110_01010000) {
+		let endianness = if opcode & 0b00000000_00001000 != 0x0 { Endianness::Big } else { Endianness::Little };
+
+		SetEndianness { endianness }
+	} else if match_bits!(0b11111111_11101000, 0b10110110_01100000) {
+		let mask = InterruptMask::from_u8((opcode & 0b00000000_00000111) as u8);
+		if mask.is_empty() { return Err(Error::InvalidOpcode) };
+
+		if opcode & 0b00000000_00010000 != 0x0 {
+			DisableInterrupts { mask, mode: None }
+		} else {
+			EnableInterrupts { mask, mode: None }
+		}
+	} else if match_bits!(0b11111111_00000000, 0b10111010_00000000) {
+		let destination = low_register_at(opcode, 0x0);
+		let source      = low_register_at(opcode, 0x3);
+
+		match (opcode >> 0x6) & 0b11 {
+			0b00 => Reverse { predicate, destination, source },
+
+			0b01 => ReversePackedHalfword { predicate, destination, source },
+
+			0b11 => ReverseSignedHalfword { predicate, destination, source },
+
+			_ => return Err(Error::InvalidOpcode),
+		}
+	} else if match_bits!(0b11111111_00000000, 0b10111110_00000000) {
+		Breakpoint { immediate: u32::from(opcode & 0b00000000_11111111) }
+	} else if match_bits!(0b11111111_00001111, 0b10111111_00000000) {
+		match (opcode >> 0x4) & 0b1111 {
+			0b0000 => NoOperation { predicate },
+
+			0b0001 => Yield { predicate },
+
+			0b0010 => WaitForEvent { predicate },
+
+			0b0011 => WaitForInterrupt { predicate },
+
+			0b0100 => SendEvent { predicate },
+
+			_ => return Err(Error::InvalidOpcode),
+		}
+	} else if match_bits!(0b11111111_00000000, 0b10111111_00000000) {
+		decode_if_then_state(opcode as u8)?
+	} else {
+		return Err(Error::InvalidOpcode);
+	};
+
+	Ok(instruction)
+}
+
 impl InstructionCodec {
 	/// Decodes the given Thumb opcode.
 	///
+	/// Branch offsets are decoded relative to the codec's address, yielding absolute targets.
+	/// Wide (32-bit) opcodes are instead decoded using [`decode_thumb_wide`](InstructionCodec::decode_thumb_wide).
+	///
 	/// # Errors
 	///
-	/// If the provided opcode is invalid (i.e. does not match any known pattern), an [InvalidOpcode](crate::Error::InvalidOpcode) error is returned.
+	/// If the provided opcode is invalid (i.e. does not match any known pattern, or is unpredictable), an [InvalidOpcode](crate::Error::InvalidOpcode) error is returned.
 	pub fn decode_thumb(&mut self, opcode: ThumbOpcode) -> Result<Instruction> {
 		use Instruction::*;
 
 		let opcode = opcode.to_u16();
 
 		macro_rules! match_bits {
-			($mask:expr) => {{
-				opcode & $mask == $mask
+			($mask:expr, $value:expr) => {{
+				opcode & $mask == $value
 			}};
 		}
 
-		let instruction = if match_bits!(0b11010000_00000000) {
-			let predicate = (opcode & 0b00001111_00000000).wrapping_shr(0x8) as u8;
-			let immediate = u32::from(opcode & 0b00000000_11111111);
+		let instruction = if match_bits!(0b11111000_00000000, 0b00011000_00000000) {
+			let predicate   = Predicate::Always;
+			let destination = low_register_at(opcode, 0x0);
+			let base        = low_register_at(opcode, 0x3);
 
-			if predicate == 0b1111 {
-				SoftwareInterrupt {
-					predicate: Predicate::Always,
-					immediate,
-				}
+			let source = if opcode & 0b00000100_00000000 != 0x0 {
+				Shifter::Immediate(u32::from((opcode >> 0x6) & 0b111))
 			} else {
-				let predicate = Predicate::from_u8(predicate).unwrap();
+				Shifter::from_register(low_register_at(opcode, 0x6))
+			};
 
-				let mut immediate = (immediate.wrapping_shl(0x18) as i32).wrapping_shl(0x17) as u32;
-				immediate = immediate.wrapping_add(self.address.0).wrapping_add(0x4);
+			let s = Sflag::On;
 
-				Branch {
-					predicate,
-					immediate,
-				}
-			}
-		} else if match_bits!(0b00011000_00000000) {
-			let destination = Register::from_u8((opcode & 0b00000000_00000111) as u8).unwrap();
-			let base        = Register::from_u8((opcode & 0b00000000_00111000).wrapping_shr(0x3) as u8).unwrap();
-
-			let subtract = opcode & 0b00000010_00000000 != 0x0;
-
-			let immediate_source = opcode & 0b00000100_00000000 != 0x0;
-			if immediate_source {
-				let source = u32::from((opcode & 0b00000001_11000000).wrapping_shr(0x6));
-
-				if subtract {
-					Subtract {
-						predicate:   Predicate::Always,
-						destination,
-						base,
-						source:      Shifter::Immediate(source),
-						s:           Sflag::On,
-					}
-				} else {
-					Add {
-						predicate:   Predicate::Always,
-						destination,
-						base,
-						source:      Shifter::Immediate(source),
-						s:           Sflag::On,
-					}
-				}
+			if opcode & 0b00000010_00000000 != 0x0 {
+				Subtract { predicate, destination, base, source, s }
 			} else {
-				// register source
-
-				let source = Register::from_u8((opcode & 0b00000001_11000000).wrapping_shr(0x6) as u8).unwrap();
-
-				if subtract {
-					Subtract {
-						predicate:   Predicate::Always,
-						destination,
-						base,
-						source:      Shifter::from_register(source),
-						s:           Sflag::On,
-					}
-				} else {
-					Add {
-						predicate:   Predicate::Always,
-						destination,
-						base,
-						source:      Shifter::from_register(source),
-						s:           Sflag::On,
-					}
-				}
+				Add { predicate, destination, base, source, s }
+			}
+		} else if match_bits!(0b11100000_00000000, 0b00000000_00000000) {
+			let destination = low_register_at(opcode, 0x0);
+			let register    = low_register_at(opcode, 0x3);
+
+			let shift = u32::from((opcode >> 0x6) & 0b11111);
+
+			// Right shifts encode a shift of (32) as null.
+			let source = match ((opcode >> 0xB) & 0b11, shift) {
+				(0b00, _) => Shifter::LogicalShiftLeftImmediate { source: register, shift },
+
+				(0b01, 0x0) => Shifter::LogicalShiftRightImmediate { source: register, shift: 0x20 },
+
+				(0b01, _) => Shifter::LogicalShiftRightImmediate { source: register, shift },
+
+				(_, 0x0) => Shifter::ArithmeticShiftRightImmediate { source: register, shift: 0x20 },
+
+				_ => Shifter::ArithmeticShiftRightImmediate { source: register, shift },
+			};
+
+			Move { predicate: Predicate::Always, destination, source, s: Sflag::On }
+		} else if match_bits!(0b11100000_00000000, 0b00100000_00000000) {
+			let predicate = Predicate::Always;
+			let register  = low_register_at(opcode, 0x8);
+			let source    = Shifter::Immediate(u32::from(opcode & 0b00000000_11111111));
+			let s         = Sflag::On;
+
+			match (opcode >> 0xB) & 0b11 {
+				0b00 => Move { predicate, destination: register, source, s },
+
+				0b01 => Compare { predicate, lhs: register, rhs: source },
+
+				0b10 => Add { predicate, destination: register, base: register, source, s },
+
+				_ => Subtract { predicate, destination: register, base: register, source, s },
 			}
-		} else if match_bits!(0b01000111_00000000) {
-			let source = Register::from_u8((opcode & 0b00000000_01111000).wrapping_shr(0x3) as u8).unwrap();
-			let l      = opcode & 0b00000000_10000000 != 0x0;
+		} else if match_bits!(0b11111100_00000000, 0b01000000_00000000) {
+			decode_data_processing(opcode)
+		} else if match_bits!(0b11111111_00000111, 0b01000111_00000000) {
+			let source = Register::from_u8(((opcode >> 0x3) & 0b1111) as u8).unwrap();
 
-			if l {
+			if opcode & 0b00000000_10000000 != 0x0 {
 				BranchLinkExchange {
 					predicate: Predicate::Always,
 					source:    Shifter::from_register(source),
@@ -134,13 +320,207 @@ impl InstructionCodec {
 					source,
 				}
 			}
-		} else {
-			let _code = (opcode & 0b00011000_00000000).wrapping_shr(0xB) as u8;
+		} else if match_bits!(0b11111100_00000000, 0b01000100_00000000) {
+			// The destination is split into `H1` and `Rd`.
+
+			let predicate   = Predicate::Always;
+			let destination = Register::from_u8((((opcode >> 0x4) & 0b1000) | (opcode & 0b111)) as u8).unwrap();
+			let source      = Shifter::from_register(Register::from_u8(((opcode >> 0x3) & 0b1111) as u8).unwrap());
+			let s           = Sflag::Off;
+
+			match (opcode >> 0x8) & 0b11 {
+				0b00 => Add { predicate, destination, base: destination, source, s },
+
+				// Comparisons of two low registers use the
+				// other encoding.
+				0b01 if opcode & 0b00000000_11000000 == 0x0 => return Err(Error::InvalidOpcode),
+
+				0b01 => Compare { predicate, lhs: destination, rhs: source },
+
+				0b10 => Move { predicate, destination, source, s },
+
+				// `BX` and `BLX` must have their lowest bits
+				// cleared.
+				_ => return Err(Error::InvalidOpcode),
+			}
+		} else if match_bits!(0b11111000_00000000, 0b01001000_00000000) {
+			let register = low_register_at(opcode, 0x8);
+			let source   = i32::from(opcode & 0b00000000_11111111) * 0x4;
+
+			let address = Address::ImmediateOffset { base: Register::Pc, source, indexing: Indexing::Offset };
+
+			decode_transfer(true, register, address, Bflag::Off)
+		} else if match_bits!(0b11110010_00000000, 0b01010010_00000000) {
+			let register = low_register_at(opcode, 0x0);
+			let base     = low_register_at(opcode, 0x3);
+			let source   = low_register_at(opcode, 0x6);
+
+			let predicate = Predicate::Always;
+			let address   = Address::RegisterOffset { base, source, sign: Sign::Positive, indexing: Indexing::Offset };
+
+			match (opcode >> 0xA) & 0b11 {
+				0b00 => StoreHalfword { predicate, register, address },
+
+				0b01 => LoadSignedByte { predicate, register, address },
+
+				0b10 => LoadHalfword { predicate, register, address },
+
+				_ => LoadSignedHalfword { predicate, register, address },
+			}
+		} else if match_bits!(0b11110010_00000000, 0b01010000_00000000) {
+			let register = low_register_at(opcode, 0x0);
+			let base     = low_register_at(opcode, 0x3);
+			let source   = low_register_at(opcode, 0x6);
+
+			let address = Address::RegisterOffset { base, source, sign: Sign::Positive, indexing: Indexing::Offset };
+
+			let b = Bflag::from(opcode & 0b00000100_00000000 != 0x0);
+			let l = opcode & 0b00001000_00000000 != 0x0;
+
+			decode_transfer(l, register, address, b)
+		} else if match_bits!(0b11100000_00000000, 0b01100000_00000000) {
+			let register = low_register_at(opcode, 0x0);
+			let base     = low_register_at(opcode, 0x3);
+
+			let b = Bflag::from(opcode & 0b00010000_00000000 != 0x0);
+			let l = opcode & 0b00001000_00000000 != 0x0;
+
+			let mut source = i32::from((opcode & 0b00000111_11000000).wrapping_shr(0x6));
+			if b.is_off() { source *= 0x4 };
+
+			let address = Address::ImmediateOffset { base, source, indexing: Indexing::Offset };
+
+			decode_transfer(l, register, address, b)
+		} else if match_bits!(0b11110000_00000000, 0b10000000_00000000) {
+			let register = low_register_at(opcode, 0x0);
+			let base     = low_register_at(opcode, 0x3);
+			let source   = i32::from((opcode & 0b00000111_11000000).wrapping_shr(0x6)) * 0x2;
+
+			let predicate = Predicate::Always;
+			let address   = Address::ImmediateOffset { base, source, indexing: Indexing::Offset };
+
+			if opcode & 0b00001000_00000000 != 0x0 {
+				LoadHalfword { predicate, register, address }
+			} else {
+				StoreHalfword { predicate, register, address }
+			}
+		} else if match_bits!(0b11110000_00000000, 0b10010000_00000000) {
+			let register = low_register_at(opcode, 0x8);
+			let source   = i32::from(opcode & 0b00000000_11111111) * 0x4;
+
+			let address = Address::ImmediateOffset { base: Register::Sp, source, indexing: Indexing::Offset };
 
-			todo!();
+			let l = opcode & 0b00001000_00000000 != 0x0;
+
+			decode_transfer(l, register, address, Bflag::Off)
+		} else if match_bits!(0b11110000_00000000, 0b10100000_00000000) {
+			let destination = low_register_at(opcode, 0x8);
+			let source      = Shifter::Immediate(u32::from(opcode & 0b00000000_11111111) * 0x4);
+
+			let base = if opcode & 0b00001000_00000000 != 0x0 { Register::Sp } else { Register::Pc };
+
+			Add { predicate: Predicate::Always, destination, base, source, s: Sflag::Off }
+		} else if match_bits!(0b11110101_00000000, 0b10110001_00000000) {
+			let source = low_register_at(opcode, 0x0);
+
+			// The offset is split into `i` and `imm5`.
+			let offset    = i32::from((opcode & 0b00000000_11111000) >> 0x2 | (opcode & 0b00000010_00000000) >> 0x3);
+			let immediate = self.decode_thumb_branch_target(offset);
+
+			if opcode & 0b00001000_00000000 != 0x0 {
+				CompareBranchNonZero { source, immediate }
+			} else {
+				CompareBranchZero { source, immediate }
+			}
+		} else if match_bits!(0b11110000_00000000, 0b10110000_00000000) {
+			decode_misc(opcode)?
+		} else if match_bits!(0b11110000_00000000, 0b11000000_00000000) {
+			let base      = low_register_at(opcode, 0x8);
+			let registers = RegisterList::from_u16(opcode & 0b00000000_11111111);
+
+			if registers.is_empty() { return Err(Error::InvalidOpcode) };
+
+			let predicate = Predicate::Always;
+			let mode      = BlockMode::IncrementAfter;
+			let s         = Sflag::Off;
+
+			// Loads write back exactly when the base register
+			// is not overwritten, and stores always do.
+			if opcode & 0b00001000_00000000 != 0x0 {
+				let w = Wflag::from(!registers.contains(base));
+
+				LoadMultiple { predicate, base, registers, mode, w, s }
+			} else {
+				StoreMultiple { predicate, base, registers, mode, w: Wflag::On, s }
+			}
+		} else if match_bits!(0b11111111_00000000, 0b11011111_00000000) {
+			SoftwareInterrupt {
+				predicate: Predicate::Always,
+				immediate: u32::from(opcode & 0b00000000_11111111),
+			}
+		} else if match_bits!(0b11110000_00000000, 0b11010000_00000000) {
+			// `0b1110` is permanently undefined.
+			let predicate = Predicate::from_u8(((opcode >> 0x8) & 0b1111) as u8)
+				.filter(|&predicate| predicate != Predicate::Always)
+				.ok_or(Error::InvalidOpcode)?;
+
+			let offset = i32::from(opcode as u8 as i8) << 0x1;
+
+			Branch {
+				predicate,
+				immediate: self.decode_thumb_branch_target(offset),
+			}
+		} else if match_bits!(0b11111000_00000000, 0b11100000_00000000) {
+			// Sign-extend the eleven-bit offset.
+			let offset = (i32::from(opcode) << 0x15) >> 0x14;
+
+			Branch {
+				predicate: Predicate::Always,
+				immediate: self.decode_thumb_branch_target(offset),
+			}
+		} else {
+			return Err(Error::InvalidOpcode);
 		};
 
+		let instruction = self.apply_if_then(instruction, true)?;
+
+		self.check_encodable(instruction, Self::encode_thumb)?;
+		self.advance_if_then(instruction)?;
+
 		self.address += Wrapping(ThumbOpcode::SIZE);
 		Ok(instruction)
 	}
+
+	/// Decodes the given wide (Thumb-2) opcode.
+	///
+	/// The first halfword must be a wide prefix (see [`is_wide_prefix`](ThumbOpcode::is_wide_prefix)), and is to be given first.
+	/// This includes the `BL` pairs used by Thumb-1.
+	///
+	/// Branch offsets are decoded relative to the codec's address, yielding absolute targets.
+	///
+	/// # Errors
+	///
+	/// If the provided opcode is invalid (i.e. does not match any known pattern, or is unpredictable), an [InvalidOpcode](crate::Error::InvalidOpcode) error is returned.
+	pub fn decode_thumb_wide(&mut self, opcode: (ThumbOpcode, ThumbOpcode)) -> Result<Instruction> {
+		if !opcode.0.is_wide_prefix() { return Err(Error::InvalidOpcode) };
+
+		let wide = u32::from(opcode.0.to_u16()) << 0x10 | u32::from(opcode.1.to_u16());
+		let instruction = decode_thumb_wide(wide, self.address.0)?;
+
+		let instruction = self.apply_if_then(instruction, false)?;
+
+		self.check_encodable(instruction, Self::encode_thumb)?;
+		self.advance_if_then(instruction)?;
+
+		self.skip_halfwords(0x2);
+		Ok(instruction)
+	}
+
+	/// Decodes a halfword offset from `PC` into the absolute branch target.
+	///
+	/// On Thumb, `PC` is four bytes ahead of the current address.
+	#[must_use]
+	fn decode_thumb_branch_target(&self, offset: i32) -> u32 {
+		(self.address + Wrapping(0x4) + Wrapping(offset as u32)).0
+	}
 }
@@ -0,0 +1,823 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Pollex.
+//
+// Pollex is free software: you can redistribute it
+// and/or modify it under the terms of the GNU Af-
+// fero General Public License as published by the
+// Free Software Foundation, either version 3 of
+// the License, or (at your option) any later ver-
+// sion.
+//
+// Pollex is distributed in the hope that it will
+// be useful, but WITHOUT ANY WARRANTY; without
+// even the implied warranty of MERCHANTABILITY or
+// FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// Affero General Public License for more details.
+//
+// You should have received a copy of the GNU Af-
+// fero General Public License along with Pollex.
+// If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Error, Result};
+use crate::arm32::{
+	Address,
+	BarrierOption,
+	Bflag,
+	BlockMode,
+	Indexing,
+	Instruction,
+	InterruptMask,
+	ParallelOperation,
+	ParallelPrefix,
+	Predicate,
+	ProcessorMode,
+	Register,
+	RegisterList,
+	Sflag,
+	Shifter,
+	Sign,
+	StatusFields,
+	StatusRegister,
+	Tflag,
+	Wflag,
+};
+
+use super::register_at;
+use super::decode_arm::{decode_coprocessor, decode_shift_immediate};
+use super::decode_neon::decode_neon;
+
+/// Extracts the `12`-bit immediate split into `i`, `imm3`, and `imm8`.
+///
+/// This is used both by plain immediates and by modified immediates.
+#[inline(always)]
+#[must_use]
+const fn immediate_at(opcode: u32) -> u32 {
+	(opcode >> 0xF) & 0b00001000_00000000 | (opcode >> 0x4) & 0b00000111_00000000 | opcode & 0b00000000_11111111
+}
+
+/// Extracts the `5`-bit immediate split into `imm3` and `imm2`.
+///
+/// This is used by immediate shifts and by the least significant bit of bitfields.
+#[inline(always)]
+#[must_use]
+const fn split_immediate_at(opcode: u32) -> u32 {
+	(opcode >> 0xA) & 0b11100 | (opcode >> 0x6) & 0b00011
+}
+
+/// Decodes a branch offset split into `S`, `imm10`, `J1`, `J2`, and `imm11`.
+///
+/// This is the inverse of `add_branch_offset`.
+#[must_use]
+const fn decode_branch_offset(opcode: u32) -> i32 {
+	let s  = (opcode >> 0x1A) & 0b1;
+	let i1 = !((opcode >> 0xD) ^ s) & 0b1;
+	let i2 = !((opcode >> 0xB) ^ s) & 0b1;
+
+	let mut offset = (opcode & 0b00000111_11111111) << 0x1;
+	offset |= ((opcode >> 0x10) & 0b00000011_11111111) << 0xC;
+	offset |= i2 << 0x16;
+	offset |= i1 << 0x17;
+	offset |= s << 0x18;
+
+	// Sign-extend the `25`-bit offset.
+	(offset << 0x7) as i32 >> 0x7
+}
+
+/// Decodes the branch offset of a conditional branch.
+///
+/// This encoding has no `imm10`, but takes the condition and `imm6` in its place.
+/// Its `J1` and `J2` are likewise not inverted.
+#[must_use]
+const fn decode_conditional_branch_offset(opcode: u32) -> i32 {
+	let mut offset = (opcode & 0b00000111_11111111) << 0x1;
+	offset |= ((opcode >> 0x10) & 0b00111111) << 0xC;
+	offset |= ((opcode >> 0xD) & 0b1) << 0x12;
+	offset |= ((opcode >> 0xB) & 0b1) << 0x13;
+	offset |= ((opcode >> 0x1A) & 0b1) << 0x14;
+
+	// Sign-extend the `21`-bit offset.
+	(offset << 0xB) as i32 >> 0xB
+}
+
+/// Decodes a data-processing instruction with the given source operand.
+///
+/// This is shared by the modified-immediate and the shifted-register encodings.
+/// Compares and tests are those with `pc` as their destination, and moves are those with `pc` as their base.
+fn decode_data_processing(opcode: u32, source: Shifter) -> Result<Instruction> {
+	use Instruction::*;
+
+	let predicate   = Predicate::Always;
+	let destination = register_at(opcode, 0x8);
+	let base        = register_at(opcode, 0x10);
+
+	let s = Sflag::from(opcode & 0b00000000_00010000_00000000_00000000 != 0x0);
+
+	let compare = destination == Register::Pc && s.is_on();
+	let lhs     = base;
+	let rhs     = source;
+
+	let code = (opcode >> 0x15) & 0b1111;
+
+	// Only moves use `pc` (in place of their base).
+	if base == Register::Pc && code != 0b0010 && code != 0b0011 { return Err(Error::InvalidOpcode) };
+
+	let instruction = match (code, compare) {
+		(0b0000, true) => Test { predicate, lhs, rhs },
+
+		(0b0000, false) => And { predicate, destination, base, source, s },
+
+		(0b0001, _) => BitClear { predicate, destination, base, source, s },
+
+		(0b0010, _) if base == Register::Pc => Move { predicate, destination, source, s },
+
+		(0b0010, _) => InclusiveOr { predicate, destination, base, source, s },
+
+		(0b0011, _) if base == Register::Pc => MoveNot { predicate, destination, source, s },
+
+		(0b0011, _) => InclusiveOrNot { predicate, destination, base, source, s },
+
+		(0b0100, true) => TestEquivalence { predicate, lhs, rhs },
+
+		(0b0100, false) => ExclusiveOr { predicate, destination, base, source, s },
+
+		(0b1000, true) => CompareNegated { predicate, lhs, rhs },
+
+		(0b1000, false) => Add { predicate, destination, base, source, s },
+
+		(0b1010, _) => AddCarry { predicate, destination, base, source, s },
+
+		(0b1011, _) => SubtractCarry { predicate, destination, base, source, s },
+
+		(0b1101, true) => Compare { predicate, lhs, rhs },
+
+		(0b1101, false) => Subtract { predicate, destination, base, source, s },
+
+		(0b1110, _) => ReverseSubtract { predicate, destination, base, source, s },
+
+		_ => return Err(Error::InvalidOpcode),
+	};
+
+	Ok(instruction)
+}
+
+/// Decodes a data-processing instruction with a plain (i.e. not modified) immediate.
+///
+/// These are the `12`-bit additions and subtractions, the `16`-bit moves, saturations, and bitfield instructions.
+const fn decode_plain_immediate(opcode: u32) -> Result<Instruction> {
+	use Instruction::*;
+
+	let predicate   = Predicate::Always;
+	let destination = register_at(opcode, 0x8);
+	let base        = register_at(opcode, 0x10);
+
+	let lsb   = split_immediate_at(opcode);
+	let field = opcode & 0b00011111;
+
+	// Saturations and bitfield instructions have no `i`
+	// bit and a should-be-zero bit above the field.
+	if opcode & 0b00000001_00000000_00000000_00000000 != 0x0 && opcode & 0b00000100_00000000_00000000_00100000 != 0x0 { return Err(Error::InvalidOpcode) };
+
+	let instruction = match (opcode >> 0x14) & 0b11111 {
+		0b00000 => Add { predicate, destination, base, source: Shifter::Immediate(immediate_at(opcode)), s: Sflag::Off },
+
+		0b01010 => Subtract { predicate, destination, base, source: Shifter::Immediate(immediate_at(opcode)), s: Sflag::Off },
+
+		0b00100 => MoveWide { predicate, destination, immediate: (opcode >> 0x4) & 0b11110000_00000000 | immediate_at(opcode) },
+
+		0b01100 => MoveTop { predicate, destination, immediate: (opcode >> 0x4) & 0b11110000_00000000 | immediate_at(opcode) },
+
+		0b10010 | 0b11010 if lsb == 0x0 => {
+			if opcode & 0b00010000 != 0x0 { return Err(Error::InvalidOpcode) };
+
+			let immediate = opcode & 0b1111;
+
+			if opcode & 0b00000000_10000000_00000000_00000000 != 0x0 {
+				UnsignedSaturate16 { predicate, destination, immediate, source: base }
+			} else {
+				SignedSaturate16 { predicate, destination, immediate: immediate + 0x1, source: base }
+			}
+		}
+
+		0b10000 | 0b10010 | 0b11000 | 0b11010 => {
+			let source = decode_shift_immediate(base, (opcode >> 0x14) & 0b10, lsb);
+
+			if opcode & 0b00000000_10000000_00000000_00000000 != 0x0 {
+				UnsignedSaturate { predicate, destination, immediate: field, source }
+			} else {
+				SignedSaturate { predicate, destination, immediate: field + 0x1, source }
+			}
+		}
+
+		0b10100 | 0b11100 => {
+			let width = field + 0x1;
+			if lsb + width > 0x20 { return Err(Error::InvalidOpcode) };
+
+			if opcode & 0b00000000_10000000_00000000_00000000 != 0x0 {
+				UnsignedBitFieldExtract { predicate, destination, source: base, lsb, width }
+			} else {
+				SignedBitFieldExtract { predicate, destination, source: base, lsb, width }
+			}
+		}
+
+		0b10110 => {
+			if field < lsb { return Err(Error::InvalidOpcode) };
+			let width = field - lsb + 0x1;
+
+			match base {
+				Register::Pc => BitFieldClear { predicate, destination, lsb, width },
+
+				source => BitFieldInsert { predicate, destination, source, lsb, width },
+			}
+		}
+
+		_ => return Err(Error::InvalidOpcode),
+	};
+
+	Ok(instruction)
+}
+
+/// Decodes a miscellaneous control instruction (i.e. status register moves, hints, `CPS`, and barriers).
+///
+/// Status register moves of the M-profile (i.e. those with a special register field) are not supported.
+fn decode_misc_control(opcode: u32) -> Result<Instruction> {
+	use Instruction::*;
+
+	let predicate = Predicate::Always;
+
+	let r = opcode & 0b00000000_00010000_00000000_00000000 != 0x0;
+
+	let instruction = match (opcode >> 0x14) & 0b01111111 {
+		0b0111000 | 0b0111001 if opcode as u8 == 0x0 => {
+			let fields = StatusFields::from_u8(((opcode >> 0x8) & 0b1111) as u8);
+			if fields.is_empty() { return Err(Error::InvalidOpcode) };
+
+			MoveToStatusRegister {
+				predicate,
+				destination: StatusRegister::from(r),
+				fields,
+				source:      Shifter::from_register(register_at(opcode, 0x10)),
+			}
+		}
+
+		0b0111010 if opcode & 0b00000000_00000000_00000111_00000000 == 0x0 => {
+			match opcode & 0b11111111 {
+				0b000 => NoOperation { predicate },
+
+				0b001 => Yield { predicate },
+
+				0b010 => WaitForEvent { predicate },
+
+				0b011 => WaitForInterrupt { predicate },
+
+				0b100 => SendEvent { predicate },
+
+				_ => return Err(Error::InvalidOpcode),
+			}
+		}
+
+		0b0111010 => {
+			let mask = InterruptMask::from_u8(((opcode >> 0x5) & 0b111) as u8);
+
+			let mode = if opcode & 0b00000000_00000000_00000001_00000000 != 0x0 {
+				Some(ProcessorMode::from_u8((opcode & 0b11111) as u8).ok_or(Error::InvalidOpcode)?)
+			} else {
+				None
+			};
+
+			match ((opcode >> 0x9) & 0b11, mode) {
+				(0b10, mode) if !mask.is_empty() => EnableInterrupts { mask, mode },
+
+				(0b11, mode) if !mask.is_empty() => DisableInterrupts { mask, mode },
+
+				(0b00, Some(mode)) if mask.is_empty() => ChangeProcessorMode { mode },
+
+				_ => return Err(Error::InvalidOpcode),
+			}
+		}
+
+		0b0111011 if opcode & 0b00000000_00001111_00101111_00000000 == 0b00000000_00001111_00001111_00000000 => {
+			let option = BarrierOption::from_u8((opcode & 0b1111) as u8);
+
+			match ((opcode >> 0x4) & 0b1111, option) {
+				(0b0010, _) => ClearExclusive,
+
+				(0b0100, Some(option)) => DataSynchronisationBarrier { option },
+
+				(0b0101, Some(option)) => DataMemoryBarrier { option },
+
+				(0b0110, Some(option)) => InstructionSynchronisationBarrier { option },
+
+				_ => return Err(Error::InvalidOpcode),
+			}
+		}
+
+		0b0111110 | 0b0111111 if opcode & 0b00000000_00001111_00000000_11111111 == 0b00000000_00001111_00000000_00000000 => {
+			MoveFromStatusRegister { predicate, destination: register_at(opcode, 0x8), source: StatusRegister::from(r) }
+		}
+
+		_ => return Err(Error::InvalidOpcode),
+	};
+
+	Ok(instruction)
+}
+
+/// Decodes a doubleword or exclusive transfer, or a table branch (i.e. `TBB` or `TBH`).
+const fn decode_doubleword_transfer(opcode: u32) -> Result<Instruction> {
+	use Instruction::*;
+
+	let predicate = Predicate::Always;
+	let base      = register_at(opcode, 0x10);
+	let register  = register_at(opcode, 0xC);
+	let first     = register;
+	let second    = register_at(opcode, 0x8);
+
+	let p = opcode & 0b00000001_00000000_00000000_00000000 != 0x0;
+	let u = opcode & 0b00000000_10000000_00000000_00000000 != 0x0;
+	let w = opcode & 0b00000000_00100000_00000000_00000000 != 0x0;
+	let l = opcode & 0b00000000_00010000_00000000_00000000 != 0x0;
+
+	let source = ((opcode & 0b11111111) << 0x2) as i32;
+
+	let instruction = match (p, w) {
+		(false, false) if !u => {
+			// Loads have no second register.
+			if l && opcode & 0b00001111_00000000 != 0b00001111_00000000 { return Err(Error::InvalidOpcode) };
+
+			let address = Address::ImmediateOffset { base, source, indexing: Indexing::Offset };
+
+			if l {
+				LoadExclusive { predicate, register, address }
+			} else {
+				StoreExclusive { predicate, status: second, register, address }
+			}
+		}
+
+		(false, false) => {
+			let address = Address::from_register(base);
+			let status  = register_at(opcode, 0x0);
+
+			let op    = (opcode >> 0x4) & 0b1111;
+			let table = l && op <= 0b0001;
+
+			// Table branches only take their base and index.
+			// Of the exclusive transfers, only doubleword
+			// transfers have a second register, and only
+			// stores have a status register.
+			if table && opcode & 0b11111111_00000000 != 0b11110000_00000000 { return Err(Error::InvalidOpcode) };
+			if !table && op != 0b0111 && opcode & 0b00001111_00000000 != 0b00001111_00000000 { return Err(Error::InvalidOpcode) };
+			if !table && l && opcode & 0b00001111 != 0b00001111 { return Err(Error::InvalidOpcode) };
+
+			match (op, l) {
+				(0b0000, true) => TableBranchByte { predicate, base, index: register_at(opcode, 0x0) },
+
+				(0b0001, true) => TableBranchHalfword { predicate, base, index: register_at(opcode, 0x0) },
+
+				(0b0100, false) => StoreExclusiveByte { predicate, status, register, address },
+
+				(0b0101, false) => StoreExclusiveHalfword { predicate, status, register, address },
+
+				(0b0111, false) => StoreExclusiveDoubleword { predicate, status, first, second, address },
+
+				(0b0100, true) => LoadExclusiveByte { predicate, register, address },
+
+				(0b0101, true) => LoadExclusiveHalfword { predicate, register, address },
+
+				(0b0111, true) => LoadExclusiveDoubleword { predicate, first, second, address },
+
+				_ => return Err(Error::InvalidOpcode),
+			}
+		}
+
+		(p, w) => {
+			let indexing = match (p, w) {
+				(false, _) => Indexing::PostIndexed,
+
+				(true, false) => Indexing::Offset,
+
+				(true, true) => Indexing::PreIndexed,
+			};
+
+			let address = Address::ImmediateOffset {
+				base,
+				source: if u { source } else { -source },
+				indexing,
+			};
+
+			if l {
+				LoadDoubleword { predicate, first, second, address }
+			} else {
+				StoreDoubleword { predicate, first, second, address }
+			}
+		}
+	};
+
+	Ok(instruction)
+}
+
+/// Decodes the address of a word, byte, or halfword transfer.
+///
+/// On success, the address is returned together with the T flag.
+fn decode_transfer_address(opcode: u32, l: bool) -> Result<(Address, Tflag)> {
+	let base = register_at(opcode, 0x10);
+	let u    = opcode & 0b00000000_10000000_00000000_00000000 != 0x0;
+
+	if base == Register::Pc {
+		// Stores relative to `pc` are undefined.
+		if !l { return Err(Error::InvalidOpcode) };
+
+		let source = (opcode & 0b00001111_11111111) as i32;
+
+		let address = Address::ImmediateOffset {
+			base,
+			source: if u { source } else { -source },
+			indexing: Indexing::Offset,
+		};
+
+		return Ok((address, Tflag::Off));
+	}
+
+	if u {
+		let source = (opcode & 0b00001111_11111111) as i32;
+
+		return Ok((Address::ImmediateOffset { base, source, indexing: Indexing::Offset }, Tflag::Off));
+	}
+
+	if opcode & 0b00001000_00000000 == 0x0 {
+		if opcode & 0b00001111_11000000 != 0x0 { return Err(Error::InvalidOpcode) };
+
+		let source = register_at(opcode, 0x0);
+		let shift  = (opcode >> 0x4) & 0b11;
+		let sign   = Sign::Positive;
+
+		let address = if shift == 0x0 {
+			Address::RegisterOffset { base, source, sign, indexing: Indexing::Offset }
+		} else {
+			Address::ScaledRegisterOffset { base, source: Shifter::LogicalShiftLeftImmediate { source, shift }, sign, indexing: Indexing::Offset }
+		};
+
+		return Ok((address, Tflag::Off));
+	}
+
+	let p = opcode & 0b00000100_00000000 != 0x0;
+	let u = opcode & 0b00000010_00000000 != 0x0;
+	let w = opcode & 0b00000001_00000000 != 0x0;
+
+	let (indexing, t) = match (p, u, w) {
+		(true, true, false) => (Indexing::Offset, Tflag::On),
+
+		(true, false, false) => (Indexing::Offset, Tflag::Off),
+
+		(true, _, true) => (Indexing::PreIndexed, Tflag::Off),
+
+		(false, _, true) => (Indexing::PostIndexed, Tflag::Off),
+
+		(false, _, false) => return Err(Error::InvalidOpcode),
+	};
+
+	let source = (opcode & 0b11111111) as i32;
+
+	let address = Address::ImmediateOffset {
+		base,
+		source: if u { source } else { -source },
+		indexing,
+	};
+
+	Ok((address, t))
+}
+
+/// Decodes a word, byte, or halfword transfer.
+///
+/// Of the memory hints (i.e. byte and halfword loads to `pc`), only `PLD` is supported.
+/// Unprivileged halfword and signed transfers are not supported either.
+fn decode_transfer(opcode: u32) -> Result<Instruction> {
+	use Instruction::*;
+
+	let predicate = Predicate::Always;
+	let register  = register_at(opcode, 0xC);
+
+	let signed = opcode & 0b00000001_00000000_00000000_00000000 != 0x0;
+	let l      = opcode & 0b00000000_00010000_00000000_00000000 != 0x0;
+	let size   = (opcode >> 0x15) & 0b11;
+
+	let hint = l && size != 0b10 && register == Register::Pc;
+
+	let (address, t) = decode_transfer_address(opcode, l)?;
+
+	let instruction = match (l, signed, size, t) {
+		(true, false, 0b00, Tflag::Off) if hint && address.indexing() == Indexing::Offset => PreloadData { address },
+
+		_ if hint => return Err(Error::InvalidOpcode),
+
+		(false, false, 0b00, t) => Store { predicate, register, address, b: Bflag::On, t },
+
+		(false, false, 0b01, Tflag::Off) => StoreHalfword { predicate, register, address },
+
+		(false, false, 0b10, t) => Store { predicate, register, address, b: Bflag::Off, t },
+
+		(true, false, 0b00, t) => Load { predicate, register, address, b: Bflag::On, t },
+
+		(true, false, 0b01, Tflag::Off) => LoadHalfword { predicate, register, address },
+
+		(true, false, 0b10, t) => Load { predicate, register, address, b: Bflag::Off, t },
+
+		(true, true, 0b00, Tflag::Off) => LoadSignedByte { predicate, register, address },
+
+		(true, true, 0b01, Tflag::Off) => LoadSignedHalfword { predicate, register, address },
+
+		_ => return Err(Error::InvalidOpcode),
+	};
+
+	Ok(instruction)
+}
+
+/// Decodes a data-processing instruction with only register operands.
+///
+/// These are the register-shifted moves, extensions, parallel arithmetic, and the miscellaneous operations (e.g. `CLZ`).
+fn decode_register_data_processing(opcode: u32) -> Result<Instruction> {
+	use Instruction::*;
+
+	let predicate   = Predicate::Always;
+	let destination = register_at(opcode, 0x8);
+	let base        = register_at(opcode, 0x10);
+	let source      = register_at(opcode, 0x0);
+
+	if opcode & 0b00000000_00000000_11110000_00000000 != 0b00000000_00000000_11110000_00000000 { return Err(Error::InvalidOpcode) };
+
+	let op1 = (opcode >> 0x14) & 0b1111;
+	let op2 = (opcode >> 0x4) & 0b1111;
+
+	let instruction = match (op1, op2) {
+		(0b0000..=0b0111, 0b0000) => {
+			let shift = source;
+			let source = base;
+
+			let source = match op1 >> 0x1 {
+				0b00 => Shifter::LogicalShiftLeftRegister { source, shift },
+
+				0b01 => Shifter::LogicalShiftRightRegister { source, shift },
+
+				0b10 => Shifter::ArithmeticShiftRightRegister { source, shift },
+
+				_ => Shifter::RotateRightRegister { source, shift },
+			};
+
+			Move { predicate, destination, source, s: Sflag::from(op1 & 0b1 != 0x0) }
+		}
+
+		(0b0000..=0b0101, 0b1000..=0b1011) => {
+			let rotation = (op2 & 0b11) << 0x3;
+
+			match (op1, base) {
+				(0b0000, Register::Pc) => SignExtendHalfword { predicate, destination, source, rotation },
+
+				(0b0000, base) => SignExtendAddHalfword { predicate, destination, base, source, rotation },
+
+				(0b0001, Register::Pc) => ZeroExtendHalfword { predicate, destination, source, rotation },
+
+				(0b0001, base) => ZeroExtendAddHalfword { predicate, destination, base, source, rotation },
+
+				(0b0010, Register::Pc) => SignExtendByte16 { predicate, destination, source, rotation },
+
+				(0b0010, base) => SignExtendAddByte16 { predicate, destination, base, source, rotation },
+
+				(0b0011, Register::Pc) => ZeroExtendByte16 { predicate, destination, source, rotation },
+
+				(0b0011, base) => ZeroExtendAddByte16 { predicate, destination, base, source, rotation },
+
+				(0b0100, Register::Pc) => SignExtendByte { predicate, destination, source, rotation },
+
+				(0b0100, base) => SignExtendAddByte { predicate, destination, base, source, rotation },
+
+				(_, Register::Pc) => ZeroExtendByte { predicate, destination, source, rotation },
+
+				(_, base) => ZeroExtendAddByte { predicate, destination, base, source, rotation },
+			}
+		}
+
+		(0b1000..=0b1111, 0b0000..=0b0111) => {
+			// The operations are numbered differently from
+			// Arm, where the prefix is also placed elsewhere.
+			let operation = match op1 & 0b111 {
+				0b000 => ParallelOperation::Add8,
+				0b001 => ParallelOperation::Add16,
+				0b010 => ParallelOperation::AddSubtractExchange,
+				0b100 => ParallelOperation::Subtract8,
+				0b101 => ParallelOperation::Subtract16,
+				0b110 => ParallelOperation::SubtractAddExchange,
+
+				_ => return Err(Error::InvalidOpcode),
+			};
+
+			let prefix = ParallelPrefix::from_u8((op2 & 0b100 | ((op2 & 0b11) + 0x1)) as u8).ok_or(Error::InvalidOpcode)?;
+
+			ParallelArithmetic { predicate, prefix, operation, destination, base, source }
+		}
+
+		(0b1000..=0b1011, 0b1000..=0b1011) => {
+			match (op1 & 0b11, op2 & 0b11) {
+				(0b00, 0b00) => SaturatingAdd { predicate, destination, base: source, source: base },
+
+				(0b00, 0b01) => SaturatingDoubleAdd { predicate, destination, base: source, source: base },
+
+				(0b00, 0b10) => SaturatingSubtract { predicate, destination, base: source, source: base },
+
+				(0b00, _) => SaturatingDoubleSubtract { predicate, destination, base: source, source: base },
+
+				// The source register is encoded twice.
+				(0b01 | 0b11, _) if base != source => return Err(Error::InvalidOpcode),
+
+				(0b01, 0b00) => Reverse { predicate, destination, source },
+
+				(0b01, 0b01) => ReversePackedHalfword { predicate, destination, source },
+
+				(0b01, 0b10) => ReverseBits { predicate, destination, source },
+
+				(0b01, _) => ReverseSignedHalfword { predicate, destination, source },
+
+				(0b10, 0b00) => Select { predicate, destination, base, source },
+
+				(0b11, 0b00) => CountLeadingZeroes { predicate, destination, source },
+
+				_ => return Err(Error::InvalidOpcode),
+			}
+		}
+
+		_ => return Err(Error::InvalidOpcode),
+	};
+
+	Ok(instruction)
+}
+
+/// Decodes a multiplication, long multiplication, division, or sum of absolute differences.
+///
+/// Only the multiplications that also exist on Arm (and `MLS`) are supported.
+/// The first factor (`Rn`) is decoded as `base`, and the second (`Rm`) as `source`.
+const fn decode_multiply(opcode: u32) -> Result<Instruction> {
+	use Instruction::*;
+
+	let predicate   = Predicate::Always;
+	let destination = register_at(opcode, 0x8);
+	let accumulator = register_at(opcode, 0xC);
+	let base        = register_at(opcode, 0x10);
+	let source      = register_at(opcode, 0x0);
+
+	let destination_low  = accumulator;
+	let destination_high = destination;
+
+	let s = Sflag::Off;
+
+	let instruction = match ((opcode >> 0x14) & 0b1111, (opcode >> 0x4) & 0b1111, accumulator) {
+		(0b0000, 0b0000, Register::Pc) => Multiply { predicate, destination, base, source, s },
+
+		(0b0000, 0b0000, accumulator) => MultiplyAccumulate { predicate, destination, base, source, accumulator, s },
+
+		(0b0000, 0b0001, accumulator) => MultiplySubtract { predicate, destination, base, source, accumulator },
+
+		(0b0111, 0b0000, Register::Pc) => UnsignedSumAbsoluteDifferences { predicate, destination, base, source },
+
+		(0b0111, 0b0000, accumulator) => UnsignedSumAbsoluteDifferencesAccumulate { predicate, destination, base, source, accumulator },
+
+		(0b1000, 0b0000, _) => SignedMultiplyLong { predicate, destination_low, destination_high, base, source, s },
+
+		(0b1001, 0b1111, Register::Pc) => SignedDivide { predicate, destination, base, source },
+
+		(0b1010, 0b0000, _) => UnsignedMultiplyLong { predicate, destination_low, destination_high, base, source, s },
+
+		(0b1011, 0b1111, Register::Pc) => UnsignedDivide { predicate, destination, base, source },
+
+		(0b1100, 0b0000, _) => SignedMultiplyAccumulateLong { predicate, destination_low, destination_high, base, source, s },
+
+		(0b1110, 0b0000, _) => UnsignedMultiplyAccumulateLong { predicate, destination_low, destination_high, base, source, s },
+
+		(0b1110, 0b0110, _) => UnsignedMultiplyAccumulateAccumulateLong { predicate, destination_low, destination_high, base, source },
+
+		_ => return Err(Error::InvalidOpcode),
+	};
+
+	Ok(instruction)
+}
+
+/// Decodes the wide (Thumb-2) opcode.
+///
+/// The provided opcode has its first halfword in the upper bits, and the provided address is that of the instruction, used for branches.
+/// The floating-point, Advanced SIMD, and coprocessor instructions are decoded from their Arm encodings.
+pub(in super) fn decode_thumb_wide(opcode: u32, address: u32) -> Result<Instruction> {
+	use Instruction::*;
+
+	macro_rules! match_bits {
+		($mask:expr, $value:expr) => {{
+			opcode & $mask == $value
+		}};
+	}
+
+	let instruction = if match_bits!(0b11111110_01000000_00000000_00000000, 0b11101000_00000000_00000000_00000000) {
+		let mode = match (opcode >> 0x17) & 0b11 {
+			0b01 => BlockMode::IncrementAfter,
+
+			0b10 => BlockMode::DecrementBefore,
+
+			// `SRS` and `RFE` are not supported.
+			_ => return Err(Error::InvalidOpcode),
+		};
+
+		let predicate = Predicate::Always;
+		let base      = register_at(opcode, 0x10);
+		let registers = RegisterList::from_u16(opcode as u16);
+
+		if registers.is_empty() { return Err(Error::InvalidOpcode) };
+
+		let w = Wflag::from(opcode & 0b00000000_00100000_00000000_00000000 != 0x0);
+		let s = Sflag::Off;
+
+		if opcode & 0b00000000_00010000_00000000_00000000 != 0x0 {
+			LoadMultiple { predicate, base, registers, mode, w, s }
+		} else {
+			StoreMultiple { predicate, base, registers, mode, w, s }
+		}
+	} else if match_bits!(0b11111110_01000000_00000000_00000000, 0b11101000_01000000_00000000_00000000) {
+		decode_doubleword_transfer(opcode)?
+	} else if match_bits!(0b11111110_00000000_10000000_00000000, 0b11101010_00000000_00000000_00000000) {
+		let source = register_at(opcode, 0x0);
+		let shift  = split_immediate_at(opcode);
+
+		if match_bits!(0b00000001_11100000_00000000_00000000, 0b00000000_11000000_00000000_00000000) {
+			// The T bit selects `ASR` instead of `LSL`.
+			let source = decode_shift_immediate(source, (opcode >> 0x4) & 0b10, shift);
+
+			if opcode & 0b00000000_00010000_00000000_00010000 != 0x0 { return Err(Error::InvalidOpcode) };
+
+			PackHalfword {
+				predicate:   Predicate::Always,
+				destination: register_at(opcode, 0x8),
+				base:        register_at(opcode, 0x10),
+				source,
+			}
+		} else {
+			decode_data_processing(opcode, decode_shift_immediate(source, (opcode >> 0x4) & 0b11, shift))?
+		}
+	} else if match_bits!(0b11101111_00000000_00000000_00000000, 0b11101111_00000000_00000000_00000000) {
+		// The U bit is moved from bit `28` to bit `24`.
+		let u = (opcode >> 0x1C) & 0b1;
+
+		decode_neon(0b11110010_00000000_00000000_00000000 | u << 0x18 | opcode & 0b00000000_11111111_11111111_11111111)?
+	} else if match_bits!(0b11111100_00000000_00000000_00000000, 0b11101100_00000000_00000000_00000000) {
+		decode_coprocessor(opcode, Predicate::Always)?
+	} else if match_bits!(0b11111010_00000000_10000000_00000000, 0b11110000_00000000_00000000_00000000) {
+		decode_data_processing(opcode, Shifter::Immediate(Shifter::decode_thumb_immediate(immediate_at(opcode))))?
+	} else if match_bits!(0b11111010_00000000_10000000_00000000, 0b11110010_00000000_00000000_00000000) {
+		decode_plain_immediate(opcode)?
+	} else if match_bits!(0b11111000_00000000_11010000_00000000, 0b11110000_00000000_10000000_00000000) {
+		if match_bits!(0b00000011_10000000_00000000_00000000, 0b00000011_10000000_00000000_00000000) {
+			decode_misc_control(opcode)?
+		} else {
+			// `0b1110` and `0b1111` are used by the
+			// miscellaneous control instructions.
+			let predicate = Predicate::from_u8(((opcode >> 0x16) & 0b1111) as u8).unwrap();
+			let offset    = decode_conditional_branch_offset(opcode);
+
+			Branch {
+				predicate,
+				immediate: address.wrapping_add(0x4).wrapping_add(offset as u32),
+			}
+		}
+	} else if match_bits!(0b11111000_00000000_11010000_00000000, 0b11110000_00000000_10010000_00000000) {
+		let offset = decode_branch_offset(opcode);
+
+		Branch {
+			predicate: Predicate::Always,
+			immediate: address.wrapping_add(0x4).wrapping_add(offset as u32),
+		}
+	} else if match_bits!(0b11111000_00000000_11010000_00000000, 0b11110000_00000000_11000000_00000000) {
+		// The target must be word-aligned.
+		if opcode & 0b1 != 0x0 { return Err(Error::InvalidOpcode) };
+
+		// The offset is relative to the word-aligned `PC`.
+		let offset = decode_branch_offset(opcode);
+		let target = (address.wrapping_add(0x4) & !0b11).wrapping_add(offset as u32);
+
+		BranchLinkExchange {
+			predicate: Predicate::Always,
+			source:    Shifter::Immediate(target),
+		}
+	} else if match_bits!(0b11111000_00000000_11010000_00000000, 0b11110000_00000000_11010000_00000000) {
+		let offset = decode_branch_offset(opcode);
+
+		BranchLink {
+			predicate: Predicate::Always,
+			source:    address.wrapping_add(0x4).wrapping_add(offset as u32),
+		}
+	} else if match_bits!(0b11111111_00010000_00000000_00000000, 0b11111001_00000000_00000000_00000000) {
+		decode_neon(0b11110100_00000000_00000000_00000000 | opcode & 0b00000000_11111111_11111111_11111111)?
+	} else if match_bits!(0b11111110_00000000_00000000_00000000, 0b11111000_00000000_00000000_00000000) {
+		decode_transfer(opcode)?
+	} else if match_bits!(0b11111111_00000000_00000000_00000000, 0b11111010_00000000_00000000_00000000) {
+		decode_register_data_processing(opcode)?
+	} else if match_bits!(0b11111111_00000000_00000000_00000000, 0b11111011_00000000_00000000_00000000) {
+		decode_multiply(opcode)?
+	} else {
+		return Err(Error::InvalidOpcode);
+	};
+
+	Ok(instruction)
+}
@@ -0,0 +1,277 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Pollex.
+//
+// Pollex is free software: you can redistribute it
+// and/or modify it under the terms of the GNU Af-
+// fero General Public License as published by the
+// Free Software Foundation, either version 3 of
+// the License, or (at your option) any later ver-
+// sion.
+//
+// Pollex is distributed in the hope that it will
+// be useful, but WITHOUT ANY WARRANTY; without
+// even the implied warranty of MERCHANTABILITY or
+// FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// Affero General Public License for more details.
+//
+// You should have received a copy of the GNU Af-
+// fero General Public License along with Pollex.
+// If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Error, Result};
+use crate::arm32::{
+	Address,
+	BlockMode,
+	DoubleRegister,
+	Eflag,
+	FloatImmediate,
+	Indexing,
+	Instruction,
+	Predicate,
+	Rflag,
+	SingleRegister,
+	VfpRegister,
+	Wflag,
+};
+
+use super::register_at;
+
+/// Joins the four-bit and one-bit fields of a floating-point register.
+///
+/// This is the inverse of `split_register`.
+#[must_use]
+const fn join_register(opcode: u32, shift: u32, bit: u32, double: bool) -> VfpRegister {
+	let field = (opcode >> shift) & 0b1111;
+	let bit   = (opcode >> bit) & 0b1;
+
+	let value = if double { bit << 0x4 | field } else { field << 0x1 | bit };
+
+	match VfpRegister::from_u8(value as u8, double) {
+		Some(register) => register,
+		None           => unreachable!(),
+	}
+}
+
+/// Extracts the floating-point register in the `Vd` field.
+#[inline(always)]
+#[must_use]
+const fn vd(opcode: u32, double: bool) -> VfpRegister { join_register(opcode, 0xC, 0x16, double) }
+
+/// Extracts the floating-point register in the `Vn` field.
+#[inline(always)]
+#[must_use]
+const fn vn(opcode: u32, double: bool) -> VfpRegister { join_register(opcode, 0x10, 0x7, double) }
+
+/// Extracts the floating-point register in the `Vm` field.
+#[inline(always)]
+#[must_use]
+const fn vm(opcode: u32, double: bool) -> VfpRegister { join_register(opcode, 0x0, 0x5, double) }
+
+/// Extracts the single-precision register in the given field.
+#[inline]
+#[must_use]
+const fn single(register: VfpRegister) -> SingleRegister {
+	match register {
+		VfpRegister::Single(register) => register,
+		VfpRegister::Double(..)       => unreachable!(),
+	}
+}
+
+/// Extracts the double-precision register in the given field.
+#[inline]
+#[must_use]
+const fn double(register: VfpRegister) -> DoubleRegister {
+	match register {
+		VfpRegister::Double(register) => register,
+		VfpRegister::Single(..)       => unreachable!(),
+	}
+}
+
+/// Decodes a floating-point data-processing instruction.
+fn decode_vfp_data_processing(opcode: u32, predicate: Predicate) -> Result<Instruction> {
+	use Instruction::*;
+
+	let is_double = opcode & 0b00000000_00000000_00000001_00000000 != 0x0;
+
+	let destination = vd(opcode, is_double);
+	let base        = vn(opcode, is_double);
+	let source      = vm(opcode, is_double);
+
+	let instruction = match opcode & 0b00000000_10110000_00000000_01000000 {
+		0b00000000_00110000_00000000_00000000 => FloatAdd { predicate, destination, base, source },
+
+		0b00000000_00110000_00000000_01000000 => FloatSubtract { predicate, destination, base, source },
+
+		0b00000000_00100000_00000000_00000000 => FloatMultiply { predicate, destination, base, source },
+
+		0b00000000_10000000_00000000_00000000 => FloatDivide { predicate, destination, base, source },
+
+		0b00000000_10110000_00000000_00000000 => {
+			if opcode & 0b00000000_00000000_00000000_10100000 != 0x0 { return Err(Error::InvalidOpcode) };
+
+			let immediate = ((opcode >> 0xC) & 0b11110000) | (opcode & 0b00001111);
+
+			FloatMoveImmediate {
+				predicate,
+				destination,
+				immediate: FloatImmediate::new(immediate as u8),
+			}
+		}
+
+		0b00000000_10110000_00000000_01000000 => {
+			let opc2 = (opcode >> 0x10) & 0b1111;
+			let opc3 = opcode & 0b00000000_00000000_00000000_10000000 != 0x0;
+
+			match (opc2, opc3) {
+				(0b0000, false) => FloatMove { predicate, destination, source },
+
+				(0b0000, true) => FloatAbsolute { predicate, destination, source },
+
+				(0b0001, false) => FloatNegate { predicate, destination, source },
+
+				(0b0001, true) => FloatSquareRoot { predicate, destination, source },
+
+				(0b0100, e) => FloatCompare { predicate, lhs: destination, rhs: Some(source), e: Eflag::from(e) },
+
+				(0b0101, e) => {
+					if opcode & 0b00000000_00000000_00000000_00101111 != 0x0 { return Err(Error::InvalidOpcode) };
+
+					FloatCompare { predicate, lhs: destination, rhs: None, e: Eflag::from(e) }
+				}
+
+				(0b0111, true) => FloatConvert { predicate, destination: vd(opcode, !is_double), source },
+
+				(0b1000, signed) => {
+					let source = single(vm(opcode, false));
+
+					if signed {
+						SignedToFloat { predicate, destination, source }
+					} else {
+						UnsignedToFloat { predicate, destination, source }
+					}
+				}
+
+				(0b1100, z) => FloatToUnsigned { predicate, destination: single(vd(opcode, false)), source, r: Rflag::from(!z) },
+
+				(0b1101, z) => FloatToSigned { predicate, destination: single(vd(opcode, false)), source, r: Rflag::from(!z) },
+
+				_ => return Err(Error::InvalidOpcode),
+			}
+		}
+
+		_ => return Err(Error::InvalidOpcode),
+	};
+
+	Ok(instruction)
+}
+
+/// Decodes a floating-point memory transfer.
+fn decode_vfp_transfer(opcode: u32, predicate: Predicate) -> Result<Instruction> {
+	use Instruction::*;
+
+	let is_double = opcode & 0b00000000_00000000_00000001_00000000 != 0x0;
+
+	let base = register_at(opcode, 0x10);
+	let l    = opcode & 0b00000000_00010000_00000000_00000000 != 0x0;
+
+	let p = opcode & 0b00000001_00000000_00000000_00000000 != 0x0;
+	let u = opcode & 0b00000000_10000000_00000000_00000000 != 0x0;
+	let w = opcode & 0b00000000_00100000_00000000_00000000 != 0x0;
+
+	let immediate = opcode & 0b00000000_00000000_00000000_11111111;
+
+	let mode = match (p, u, w) {
+		(true, _, false) => {
+			let register = vd(opcode, is_double);
+
+			let source  = (immediate << 0x2) as i32;
+			let address = Address::ImmediateOffset {
+				base,
+				source: if u { source } else { -source },
+				indexing: Indexing::Offset,
+			};
+
+			let instruction = if l {
+				FloatLoad { predicate, register, address }
+			} else {
+				FloatStore { predicate, register, address }
+			};
+
+			return Ok(instruction);
+		}
+
+		(false, true, _) => BlockMode::IncrementAfter,
+
+		(true, false, true) => BlockMode::DecrementBefore,
+
+		_ => return Err(Error::InvalidOpcode),
+	};
+
+	// Odd counts of double registers denote the dep-
+	// recated `FLDMX` and `FSTMX` instructions.
+	if is_double && immediate & 0b1 != 0b0 { return Err(Error::InvalidOpcode) };
+
+	let first = vd(opcode, is_double);
+	let count = immediate >> u32::from(is_double);
+
+	if count == 0x0 || count > 0x10 && is_double || u32::from(first.to_u8()) + count > 0x20 { return Err(Error::InvalidOpcode) };
+
+	let count = count as u8;
+	let w     = Wflag::from(w);
+
+	let instruction = if l {
+		FloatLoadMultiple { predicate, base, first, count, mode, w }
+	} else {
+		FloatStoreMultiple { predicate, base, first, count, mode, w }
+	};
+
+	Ok(instruction)
+}
+
+/// Decodes a floating-point instruction.
+///
+/// The opcode must be of the coprocessor space and target either coprocessor `p10` or `p11`.
+/// As the VFP encodings are shared between Arm and Thumb, the condition is ignored and the given predicate is used instead.
+pub(in super) fn decode_vfp(opcode: u32, predicate: Predicate) -> Result<Instruction> {
+	use Instruction::*;
+
+	macro_rules! match_bits {
+		($mask:expr, $value:expr) => {{
+			opcode & $mask == $value
+		}};
+	}
+
+	let instruction = if match_bits!(0b00001111_00000000_00000000_00010000, 0b00001110_00000000_00000000_00000000) {
+		decode_vfp_data_processing(opcode, predicate)?
+	} else if match_bits!(0b00001111_11100000_00001111_01111111, 0b00001110_00000000_00001010_00010000) {
+		let register = register_at(opcode, 0xC);
+		let single   = single(vn(opcode, false));
+
+		if opcode & 0b00000000_00010000_00000000_00000000 != 0x0 {
+			MoveFromFloat { predicate, destination: register, source: single }
+		} else {
+			MoveToFloat { predicate, destination: single, source: register }
+		}
+	} else if match_bits!(0b00001111_11111111_00001111_11111111, 0b00001110_11110001_00001010_00010000) {
+		MoveFromFloatStatus { predicate, destination: register_at(opcode, 0xC) }
+	} else if match_bits!(0b00001111_11111111_00001111_11111111, 0b00001110_11100001_00001010_00010000) {
+		MoveToFloatStatus { predicate, source: register_at(opcode, 0xC) }
+	} else if match_bits!(0b00001111_11100000_00001111_11010000, 0b00001100_01000000_00001011_00010000) {
+		let low    = register_at(opcode, 0xC);
+		let high   = register_at(opcode, 0x10);
+		let source = double(vm(opcode, true));
+
+		if opcode & 0b00000000_00010000_00000000_00000000 != 0x0 {
+			MoveFromFloatDouble { predicate, low, high, source }
+		} else {
+			MoveToFloatDouble { predicate, destination: source, low, high }
+		}
+	} else if match_bits!(0b00001110_00000000_00000000_00000000, 0b00001100_00000000_00000000_00000000) {
+		decode_vfp_transfer(opcode, predicate)?
+	} else {
+		return Err(Error::InvalidOpcode);
+	};
+
+	Ok(instruction)
+}
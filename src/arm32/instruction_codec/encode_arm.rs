@@ -21,24 +21,49 @@
 
 use crate::{assert_or_err, Error, Result};
 use crate::arm32::{
+	Address,
 	ArmOpcode,
+	BlockMode,
+	Indexing,
 	Instruction,
 	InstructionCodec,
+	Predicate,
+	Register,
+	RegisterList,
 	Shifter,
+	Sflag,
+	Tflag,
+	Wflag,
 };
 
 use core::num::Wrapping;
 
+use super::check_bitfield;
+use super::encode_neon::encode_neon;
+use super::encode_vfp::encode_vfp;
+
 fn add_shifter(mut opcode: u32, shifter: Shifter) -> Result<u32> {
 	use Shifter::*;
 
 	let get_shift_code = |shifter: Shifter| match shifter {
-		LogicalShiftLeftImmediate { .. }     => 0b00,
-		LogicalShiftRightImmediate { .. }    => 0b01,
-		ArithmeticShiftRightImmediate { .. } => 0b10,
-		RotateRightImmediate { .. }          => 0b11,
+		| LogicalShiftLeftImmediate { .. }
+		| LogicalShiftLeftRegister { .. }
+		=> Ok(0b00),
+
+		| LogicalShiftRightImmediate { .. }
+		| LogicalShiftRightRegister { .. }
+		=> Ok(0b01),
+
+		| ArithmeticShiftRightImmediate { .. }
+		| ArithmeticShiftRightRegister { .. }
+		=> Ok(0b10),
+
+		| RotateRightExtend { .. }
+		| RotateRightImmediate { .. }
+		| RotateRightRegister { .. }
+		=> Ok(0b11),
 
-		_ => panic!("cannot get shifter code of `{shifter:?}`"),
+		Immediate(..) => Err(Error::IllegalShifter { reason: "immediates do not have a shift code on arm" }),
 	};
 
 	match shifter {
@@ -53,35 +78,51 @@ fn add_shifter(mut opcode: u32, shifter: Shifter) -> Result<u32> {
 		| RotateRightImmediate { source, shift }
 		=> {
 			assert_or_err!(shift != 0x0, Error::IllegalImmediate { reason: "immediate shift cannot be null on arm" });
+			assert_or_err!(shifter.is_arm_encodable(), Error::IllegalImmediate { reason: "immediate shift out of range on arm" });
 
-			let code = get_shift_code(shifter);
+			let code = get_shift_code(shifter)?;
+
+			// Shifts of (32) are encoded as null for `LSR` and
+			// `ASR`.
+			let shift = shift & 0b00000000_00000000_00000000_00011111;
 
 			opcode |= source as u32;
 			opcode |= code << 0x5;
 			opcode |= shift << 0x7;
 		}
 
-		RotateRightExtend { .. } => {
-			todo!()
+		RotateRightExtend { source } => {
+			// This is encoded as `ROR #0`.
+
+			let code = get_shift_code(shifter)?;
+
+			opcode |= source as u32;
+			opcode |= code << 0x5;
 		}
 
-		| ArithmeticShiftRightRegister { source, .. }
-		| LogicalShiftLeftRegister { source, .. }
-		| LogicalShiftRightRegister { source, .. }
-		| RotateRightRegister { source, .. }
+		| ArithmeticShiftRightRegister { source, shift }
+		| LogicalShiftLeftRegister { source, shift }
+		| LogicalShiftRightRegister { source, shift }
+		| RotateRightRegister { source, shift }
 		=> {
-			let _code = get_shift_code(shifter);
+			let destination = (opcode & 0b00000000_00000000_11110000_00000000) >> 0xC;
+			let base        = (opcode & 0b00000000_00001111_00000000_00000000) >> 0x10;
+
+			assert_or_err!(shifter.is_arm_encodable(), Error::IllegalShifter { reason: "register shifts cannot use pc on arm" });
+			assert_or_err!(destination != Register::Pc as u32, Error::IllegalShifter { reason: "register shifts cannot be used with pc as destination on arm" });
+			assert_or_err!(base != Register::Pc as u32, Error::IllegalShifter { reason: "register shifts cannot be used with pc as base on arm" });
+
+			let code = get_shift_code(shifter)?;
 
 			opcode |= 0b00000000_00000000_00000000_00010000;
 			opcode |= source as u32;
+			opcode |= code << 0x5;
+			opcode |= (shift as u32) << 0x8;
 		}
 
 		Immediate(source) => {
-			let (source, rotate) = if source <= 0xFF {
-				(source, 0x00)
-			} else {
-				todo!()
-			};
+			let (source, rotate) = Shifter::encode_arm_immediate(source)
+				.ok_or(Error::IllegalImmediate { reason: "cannot encode as rotated 8-bit value on arm" })?;
 
 			opcode |= 0b00000010_00000000_00000000_00000000;
 			opcode |= source;
@@ -92,7 +133,469 @@ fn add_shifter(mut opcode: u32, shifter: Shifter) -> Result<u32> {
 	Ok(opcode)
 }
 
+fn add_address(mut opcode: u32, address: Address) -> Result<u32> {
+	use Address::*;
+
+	let u = match address {
+		ImmediateOffset { base, source, .. } => {
+			let u = source >= 0x0;
+			let source = source.unsigned_abs();
+
+			assert_or_err!(source <= 0xFFF, Error::IllegalImmediate { reason: "cannot encode offset larger than (4095) on arm" });
+
+			opcode |= source;
+			opcode |= (base as u32) << 0x10;
+
+			u
+		}
+
+		RegisterOffset { base, source, sign, .. } => {
+			opcode |= 0b00000010_00000000_00000000_00000000;
+			opcode |= source as u32;
+			opcode |= (base as u32) << 0x10;
+
+			sign.is_positive()
+		}
+
+		ScaledRegisterOffset { base, source, sign, .. } => {
+			use Shifter::*;
+
+			assert_or_err!(
+				matches!(
+					source,
+					| ArithmeticShiftRightImmediate { .. }
+					| LogicalShiftLeftImmediate { .. }
+					| LogicalShiftRightImmediate { .. }
+					| RotateRightExtend { .. }
+					| RotateRightImmediate { .. }
+				),
+				Error::IllegalShifter { reason: "can only encode immediate shifts in addresses on arm" }
+			);
+
+			opcode |= 0b00000010_00000000_00000000_00000000;
+			opcode |= (base as u32) << 0x10;
+
+			opcode = add_shifter(opcode, source)?;
+
+			sign.is_positive()
+		}
+	};
+
+	add_indexing(opcode, address, u)
+}
+
+/// Adds the address of a miscellaneous transfer (i.e. of halfwords, signed bytes, or doublewords).
+///
+/// These only take `8`-bit immediates and unshifted registers as offsets.
+fn add_misc_address(mut opcode: u32, address: Address) -> Result<u32> {
+	use Address::*;
+
+	let u = match address {
+		ImmediateOffset { base, source, .. } => {
+			let u = source >= 0x0;
+			let source = source.unsigned_abs();
+
+			assert_or_err!(source <= 0xFF, Error::IllegalImmediate { reason: "cannot encode offset larger than (255) on arm" });
+
+			opcode |= 0b00000000_01000000_00000000_00000000;
+			opcode |= source & 0b00000000_00000000_00000000_00001111;
+			opcode |= (source & 0b00000000_00000000_00000000_11110000) << 0x4;
+			opcode |= (base as u32) << 0x10;
+
+			u
+		}
+
+		| RegisterOffset { base, source, sign, .. }
+		| ScaledRegisterOffset { base, source: Shifter::LogicalShiftLeftImmediate { source, shift: 0x0 }, sign, .. }
+		=> {
+			opcode |= source as u32;
+			opcode |= (base as u32) << 0x10;
+
+			sign.is_positive()
+		}
+
+		ScaledRegisterOffset { .. } => return Err(Error::IllegalShifter { reason: "cannot encode scaled register offsets for halfwords, signed bytes, or doublewords on arm" }),
+	};
+
+	let register = (opcode & 0b00000000_00000000_11110000_00000000) >> 0xC;
+	assert_or_err!(!address.has_writeback() || address.base() as u32 != register, Error::IllegalRegister { reason: "cannot write back to transferred register on arm" });
+
+	add_indexing(opcode, address, u)
+}
+
+/// Adds the indexing mode (P and W bits) and the offset sign (U bit) of an address.
+fn add_indexing(mut opcode: u32, address: Address, u: bool) -> Result<u32> {
+	let indexing = address.indexing();
+
+	assert_or_err!(!indexing.has_writeback() || address.base() != Register::Pc, Error::IllegalRegister { reason: "cannot write back to pc on arm" });
+
+	opcode |= u32::from(u) << 0x17;
+	opcode |= u32::from(indexing == Indexing::PreIndexed) << 0x15;
+	opcode |= u32::from(indexing.is_pre_indexed()) << 0x18;
+
+	Ok(opcode)
+}
+
+/// Adds the address of a single data transfer.
+///
+/// The transferred register is read from the already-encoded opcode.
+///
+/// Translated transfers are always post-indexed.
+/// For these, a lone base register is accepted as an alias for a post-indexed null offset.
+fn add_transfer_address(mut opcode: u32, mut address: Address, t: Tflag) -> Result<u32> {
+	let register = (opcode & 0b00000000_00000000_11110000_00000000) >> 0xC;
+
+	assert_or_err!(!address.has_writeback() || address.base() as u32 != register, Error::IllegalRegister { reason: "cannot write back to transferred register on arm" });
+
+	if t.is_on() {
+		if let Address::ImmediateOffset { base, source: 0x0, indexing: Indexing::Offset } = address {
+			address = Address::ImmediateOffset { base, source: 0x0, indexing: Indexing::PostIndexed };
+		}
+
+		assert_or_err!(address.indexing() == Indexing::PostIndexed, Error::IllegalInstruction { reason: "translated transfers must be post-indexed on arm" });
+
+		// The W bit denotes translation for post-indexed
+		// transfers.
+		opcode |= 0b00000000_00100000_00000000_00000000;
+	}
+
+	add_address(opcode, address)
+}
+
+/// Adds the operands of a block data transfer.
+///
+/// The L bit is read from the already-encoded opcode.
+fn add_block_transfer(mut opcode: u32, base: Register, registers: RegisterList, mode: BlockMode, w: Wflag, s: Sflag) -> Result<u32> {
+	let l = opcode & 0b00000000_00010000_00000000_00000000 != 0x0;
+
+	assert_or_err!(!registers.is_empty(), Error::IllegalInstruction { reason: "cannot transfer empty register list on arm" });
+	assert_or_err!(base != Register::Pc, Error::IllegalRegister { reason: "cannot use pc as base on arm" });
+
+	if w.is_on() && registers.contains(base) {
+		// Stores of the base register are only predict-
+		// able if it is the first register transferred.
+		assert_or_err!(!l && registers.lowest() == Some(base), Error::IllegalRegister { reason: "cannot write back to transferred register on arm" });
+	}
+
+	if s.is_on() && !(l && registers.contains(Register::Pc)) {
+		assert_or_err!(w.is_off(), Error::IllegalInstruction { reason: "cannot write back with user-mode registers on arm" });
+	}
+
+	opcode |= u32::from(registers.to_u16());
+	opcode |= (base as u32) << 0x10;
+	opcode |= u32::from(w) << 0x15;
+	opcode |= u32::from(s) << 0x16;
+	opcode |= u32::from(mode.is_increment()) << 0x17;
+	opcode |= u32::from(mode.is_before()) << 0x18;
+
+	Ok(opcode)
+}
+
+/// Adds the factors of a multiplication.
+///
+/// Cores before Armv6 cannot use `base` (`Rm`) as a destination.
+/// If `restrict` is set, an error is returned in this case.
+fn add_factors(mut opcode: u32, destinations: &[Register], base: Register, source: Register, restrict: bool) -> Result<u32> {
+	assert_or_err!(!restrict || !destinations.contains(&base), Error::IllegalRegister { reason: "destination cannot also be base (rm) before armv6" });
+	assert_or_err!(!destinations.contains(&Register::Pc), Error::IllegalRegister { reason: "cannot multiply into pc on arm" });
+	assert_or_err!(base != Register::Pc && source != Register::Pc, Error::IllegalRegister { reason: "cannot multiply pc on arm" });
+
+	opcode |= base as u32;
+	opcode |= (source as u32) << 0x8;
+
+	Ok(opcode)
+}
+
+/// Adds the destinations and factors of a long multiplication.
+fn add_long_factors(mut opcode: u32, destination_low: Register, destination_high: Register, base: Register, source: Register, restrict: bool) -> Result<u32> {
+	assert_or_err!(destination_low != destination_high, Error::IllegalRegister { reason: "destinations must differ on arm" });
+
+	opcode |= (destination_low as u32) << 0xC;
+	opcode |= (destination_high as u32) << 0x10;
+
+	add_factors(opcode, &[destination_low, destination_high], base, source, restrict)
+}
+
+/// Adds the address of a coprocessor data transfer.
+///
+/// Offsets are encoded as word counts.
+/// Unlike other transfers, post-indexing also sets the W bit.
+fn add_coprocessor_address(mut opcode: u32, address: Address) -> Result<u32> {
+	let Address::ImmediateOffset { base, source, indexing } = address else {
+		return Err(Error::IllegalInstruction { reason: "coprocessor transfers only take immediate offsets" });
+	};
+
+	assert_or_err!(source.unsigned_abs() <= 0x3FC, Error::IllegalImmediate { reason: "cannot encode offset larger than (1020)" });
+	assert_or_err!(source % 0x4 == 0x0, Error::IllegalImmediate { reason: "offset must be word-aligned" });
+	assert_or_err!(!indexing.has_writeback() || base != Register::Pc, Error::IllegalRegister { reason: "cannot write back to pc" });
+
+	opcode |= source.unsigned_abs() >> 0x2;
+	opcode |= (base as u32) << 0x10;
+	opcode |= u32::from(indexing.has_writeback()) << 0x15;
+	opcode |= u32::from(source >= 0x0) << 0x17;
+	opcode |= u32::from(indexing.is_pre_indexed()) << 0x18;
+
+	Ok(opcode)
+}
+
+/// Encodes a coprocessor instruction.
+///
+/// The coprocessor encodings are shared between Arm and Thumb, with Thumb using the "always" condition.
+/// The returned opcode therefore lacks its condition, which is instead returned alongside it.
+///
+/// If the instruction is not a coprocessor instruction, [`None`] is returned.
+pub(in super) fn encode_coprocessor(instruction: Instruction) -> Result<Option<(Predicate, u32)>> {
+	use Instruction::*;
+
+	let mut opcode = 0b00000000_00000000_00000000_00000000_u32;
+
+	let predicate = match instruction {
+		CoprocessorDataProcessing {
+			predicate,
+			coprocessor,
+			opcode1,
+			crd,
+			crn,
+			crm,
+			opcode2,
+		} => {
+			assert_or_err!(opcode1 <= 0xF, Error::IllegalImmediate { reason: "first coprocessor opcode cannot be greater than (15)" });
+			assert_or_err!(opcode2 <= 0x7, Error::IllegalImmediate { reason: "second coprocessor opcode cannot be greater than (7)" });
+
+			opcode |= 0b00001110_00000000_00000000_00000000;
+			opcode |= crm as u32;
+			opcode |= u32::from(opcode2) << 0x5;
+			opcode |= (coprocessor as u32) << 0x8;
+			opcode |= (crd as u32) << 0xC;
+			opcode |= (crn as u32) << 0x10;
+			opcode |= u32::from(opcode1) << 0x14;
+
+			predicate
+		}
+
+		LoadCoprocessor {
+			predicate,
+			coprocessor,
+			crd,
+			address,
+			l,
+		} => {
+			opcode |= 0b00001100_00010000_00000000_00000000;
+			opcode |= (coprocessor as u32) << 0x8;
+			opcode |= (crd as u32) << 0xC;
+			opcode |= u32::from(l) << 0x16;
+
+			opcode = add_coprocessor_address(opcode, address)?;
+
+			predicate
+		}
+
+		MoveFromCoprocessor {
+			predicate,
+			coprocessor,
+			opcode1,
+			register,
+			crn,
+			crm,
+			opcode2,
+		} => {
+			assert_or_err!(opcode1 <= 0x7, Error::IllegalImmediate { reason: "first coprocessor opcode cannot be greater than (7)" });
+			assert_or_err!(opcode2 <= 0x7, Error::IllegalImmediate { reason: "second coprocessor opcode cannot be greater than (7)" });
+
+			opcode |= 0b00001110_00010000_00000000_00010000;
+			opcode |= crm as u32;
+			opcode |= u32::from(opcode2) << 0x5;
+			opcode |= (coprocessor as u32) << 0x8;
+			opcode |= (register as u32) << 0xC;
+			opcode |= (crn as u32) << 0x10;
+			opcode |= u32::from(opcode1) << 0x15;
+
+			predicate
+		}
+
+		MoveFromCoprocessorDouble {
+			predicate,
+			coprocessor,
+			opcode1,
+			first,
+			second,
+			crm,
+		} => {
+			assert_or_err!(opcode1 <= 0xF, Error::IllegalImmediate { reason: "first coprocessor opcode cannot be greater than (15)" });
+			assert_or_err!(first != Register::Pc && second != Register::Pc, Error::IllegalRegister { reason: "cannot transfer pc to or from coprocessor" });
+			assert_or_err!(first != second, Error::IllegalRegister { reason: "registers must differ" });
+
+			opcode |= 0b00001100_01010000_00000000_00000000;
+			opcode |= crm as u32;
+			opcode |= u32::from(opcode1) << 0x4;
+			opcode |= (coprocessor as u32) << 0x8;
+			opcode |= (first as u32) << 0xC;
+			opcode |= (second as u32) << 0x10;
+
+			predicate
+		}
+
+		MoveToCoprocessor {
+			predicate,
+			coprocessor,
+			opcode1,
+			register,
+			crn,
+			crm,
+			opcode2,
+		} => {
+			assert_or_err!(opcode1 <= 0x7, Error::IllegalImmediate { reason: "first coprocessor opcode cannot be greater than (7)" });
+			assert_or_err!(opcode2 <= 0x7, Error::IllegalImmediate { reason: "second coprocessor opcode cannot be greater than (7)" });
+			assert_or_err!(register != Register::Pc, Error::IllegalRegister { reason: "cannot move pc to coprocessor" });
+
+			opcode |= 0b00001110_00000000_00000000_00010000;
+			opcode |= crm as u32;
+			opcode |= u32::from(opcode2) << 0x5;
+			opcode |= (coprocessor as u32) << 0x8;
+			opcode |= (register as u32) << 0xC;
+			opcode |= (crn as u32) << 0x10;
+			opcode |= u32::from(opcode1) << 0x15;
+
+			predicate
+		}
+
+		MoveToCoprocessorDouble {
+			predicate,
+			coprocessor,
+			opcode1,
+			first,
+			second,
+			crm,
+		} => {
+			assert_or_err!(opcode1 <= 0xF, Error::IllegalImmediate { reason: "first coprocessor opcode cannot be greater than (15)" });
+			assert_or_err!(first != Register::Pc && second != Register::Pc, Error::IllegalRegister { reason: "cannot transfer pc to or from coprocessor" });
+
+			opcode |= 0b00001100_01000000_00000000_00000000;
+			opcode |= crm as u32;
+			opcode |= u32::from(opcode1) << 0x4;
+			opcode |= (coprocessor as u32) << 0x8;
+			opcode |= (first as u32) << 0xC;
+			opcode |= (second as u32) << 0x10;
+
+			predicate
+		}
+
+		StoreCoprocessor {
+			predicate,
+			coprocessor,
+			crd,
+			address,
+			l,
+		} => {
+			opcode |= 0b00001100_00000000_00000000_00000000;
+			opcode |= (coprocessor as u32) << 0x8;
+			opcode |= (crd as u32) << 0xC;
+			opcode |= u32::from(l) << 0x16;
+
+			opcode = add_coprocessor_address(opcode, address)?;
+
+			predicate
+		}
+
+		_ => return Ok(None),
+	};
+
+	Ok(Some((predicate, opcode)))
+}
+
+/// Adds the address of an exclusive transfer.
+///
+/// Only the base register is accepted as the address.
+fn add_exclusive_address(mut opcode: u32, address: Address) -> Result<u32> {
+	let Address::ImmediateOffset { base, source: 0x0, indexing: Indexing::Offset } = address else {
+		return Err(Error::IllegalInstruction { reason: "can only access exclusively with base register as address on arm" });
+	};
+
+	assert_or_err!(base != Register::Pc, Error::IllegalRegister { reason: "cannot use pc as base on arm" });
+
+	opcode |= (base as u32) << 0x10;
+
+	Ok(opcode)
+}
+
+/// Adds the registers of an exclusive store.
+///
+/// The status register must differ from both the base and the transferred registers.
+fn add_exclusive_store(mut opcode: u32, status: Register, registers: &[Register], address: Address) -> Result<u32> {
+	assert_or_err!(status != Register::Pc, Error::IllegalRegister { reason: "cannot use pc as status on arm" });
+	assert_or_err!(!registers.contains(&status) && address.base() != status, Error::IllegalRegister { reason: "status must differ from other registers on arm" });
+
+	opcode |= (status as u32) << 0xC;
+
+	add_exclusive_address(opcode, address)
+}
+
+/// Checks that none of the given registers is `pc`.
+fn check_not_pc(registers: &[Register]) -> Result<()> {
+	assert_or_err!(!registers.contains(&Register::Pc), Error::IllegalRegister { reason: "cannot use pc on arm" });
+
+	Ok(())
+}
+
+/// Adds the operands of an extension.
+///
+/// Extensions without an addend are encoded with `pc` as their base.
+fn add_extend(mut opcode: u32, destination: Register, base: Option<Register>, source: Register, rotation: u32) -> Result<u32> {
+	assert_or_err!(matches!(rotation, 0x0 | 0x8 | 0x10 | 0x18), Error::IllegalImmediate { reason: "rotation must be any of (0), (8), (16), or (24) on arm" });
+
+	if let Some(base) = base { check_not_pc(&[base])? };
+	check_not_pc(&[destination, source])?;
+
+	opcode |= source as u32;
+	opcode |= (rotation >> 0x3) << 0xA;
+	opcode |= (destination as u32) << 0xC;
+	opcode |= (base.unwrap_or(Register::Pc) as u32) << 0x10;
+
+	Ok(opcode)
+}
+
+/// Adds the operands of a saturation.
+///
+/// The source may only be shifted by an immediate `LSL` or `ASR`, with `ASR #32` being encoded as a null shift.
+fn add_saturate(mut opcode: u32, destination: Register, immediate: u32, source: Shifter) -> Result<u32> {
+	let (source, shift, sh) = match source {
+		Shifter::LogicalShiftLeftImmediate { source, shift } => {
+			assert_or_err!(shift <= 0x1F, Error::IllegalImmediate { reason: "shift must be in range (0) to (31) on arm" });
+			(source, shift, false)
+		}
+
+		Shifter::ArithmeticShiftRightImmediate { source, shift } => {
+			assert_or_err!((0x1..=0x20).contains(&shift), Error::IllegalImmediate { reason: "shift must be in range (1) to (32) on arm" });
+			(source, shift & 0b11111, true)
+		}
+
+		_ => return Err(Error::IllegalShifter { reason: "saturations only take immediate lsl or asr shifts on arm" }),
+	};
+
+	check_not_pc(&[destination, source])?;
+
+	opcode |= source as u32;
+	opcode |= u32::from(sh) << 0x6;
+	opcode |= shift << 0x7;
+	opcode |= (destination as u32) << 0xC;
+	opcode |= immediate << 0x10;
+
+	Ok(opcode)
+}
+
 impl InstructionCodec {
+	/// Encodes the offset from `PC` to the given target as a `24`-bit word offset.
+	fn encode_branch_offset(&self, target: u32) -> Result<u32> {
+		let offset = (Wrapping(target) - self.address - Wrapping(0x8)).0 as i32;
+
+		assert_or_err!(offset >= -0x02000000, Error::IllegalImmediate { reason: "cannot encode offset larger than (-33554432) on arm" });
+		assert_or_err!(offset <= 0x01FFFFFC, Error::IllegalImmediate { reason: "cannot encode offset larger than (33554428) on arm" });
+		assert_or_err!(offset % 0x4 == 0x0, Error::IllegalImmediate { reason: "cannot encode unaligned offset on arm" });
+
+		let offset = (offset as u32) >> 0x2;
+		Ok(offset & 0b00000000_11111111_11111111_11111111)
+	}
+
 	/// Encodes the given Arm instruction.
 	///
 	/// # Errors
@@ -104,11 +607,112 @@ impl InstructionCodec {
 		let mut opcode = 0b00000000_00000000_00000000_00000000_u32;
 
 		match instruction {
+			Add {
+				predicate,
+				destination,
+				base,
+				source,
+				s,
+			} => {
+				opcode |= 0b00000000_10000000_00000000_00000000;
+				opcode |= (destination as u32) << 0xC;
+				opcode |= (base as u32) << 0x10;
+				opcode |= u32::from(s) << 0x14;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_shifter(opcode, source)?;
+			}
+
+			AddCarry {
+				predicate,
+				destination,
+				base,
+				source,
+				s,
+			} => {
+				opcode |= 0b00000000_10100000_00000000_00000000;
+				opcode |= (destination as u32) << 0xC;
+				opcode |= (base as u32) << 0x10;
+				opcode |= u32::from(s) << 0x14;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_shifter(opcode, source)?;
+			}
+
+			And {
+				predicate,
+				destination,
+				base,
+				source,
+				s,
+			} => {
+				opcode |= 0b00000000_00000000_00000000_00000000;
+				opcode |= (destination as u32) << 0xC;
+				opcode |= (base as u32) << 0x10;
+				opcode |= u32::from(s) << 0x14;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_shifter(opcode, source)?;
+			}
+
+			BitClear {
+				predicate,
+				destination,
+				base,
+				source,
+				s,
+			} => {
+				opcode |= 0b00000001_11000000_00000000_00000000;
+				opcode |= (destination as u32) << 0xC;
+				opcode |= (base as u32) << 0x10;
+				opcode |= u32::from(s) << 0x14;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_shifter(opcode, source)?;
+			}
+
+			BitFieldClear {
+				predicate,
+				destination,
+				lsb,
+				width,
+			} => {
+				check_bitfield(lsb, width)?;
+				check_not_pc(&[destination])?;
+
+				opcode |= 0b00000111_11000000_00000000_00011111;
+				opcode |= lsb << 0x7;
+				opcode |= (destination as u32) << 0xC;
+				opcode |= (lsb + width - 0x1) << 0x10;
+				opcode |= (predicate as u32) << 0x1C;
+			}
+
+			BitFieldInsert {
+				predicate,
+				destination,
+				source,
+				lsb,
+				width,
+			} => {
+				check_bitfield(lsb, width)?;
+				check_not_pc(&[destination, source])?;
+
+				opcode |= 0b00000111_11000000_00000000_00010000;
+				opcode |= source as u32;
+				opcode |= lsb << 0x7;
+				opcode |= (destination as u32) << 0xC;
+				opcode |= (lsb + width - 0x1) << 0x10;
+				opcode |= (predicate as u32) << 0x1C;
+			}
+
 			Branch {
 				predicate,
-				..
+				immediate,
 			} => {
-				opcode |= 0b00001011_00000000_00000000_00000000;
+				let offset = self.encode_branch_offset(immediate)?;
+
+				opcode |= 0b00001010_00000000_00000000_00000000;
+				opcode |= offset;
 				opcode |= (predicate as u32) << 0x1C;
 			}
 
@@ -123,12 +727,49 @@ impl InstructionCodec {
 
 			BranchLink {
 				predicate,
-				..
+				source,
 			} => {
-				opcode |= 0b00001010_00000000_00000000_00000000;
+				let offset = self.encode_branch_offset(source)?;
+
+				opcode |= 0b00001011_00000000_00000000_00000000;
+				opcode |= offset;
 				opcode |= (predicate as u32) << 0x1C;
 			}
 
+			BranchLinkExchange {
+				predicate,
+				source,
+			} => {
+				match source {
+					Shifter::Immediate(target) => {
+						assert_or_err!(predicate == Predicate::Always, Error::IllegalPredicate { reason: "immediate exchanges must be `AL` on arm" });
+
+						let offset = (Wrapping(target) - self.address - Wrapping(0x8)).0 as i32;
+
+						assert_or_err!(offset >= -0x02000000, Error::IllegalImmediate { reason: "cannot encode offset larger than (-33554432) on arm" });
+						assert_or_err!(offset <= 0x01FFFFFE, Error::IllegalImmediate { reason: "cannot encode offset larger than (33554430) on arm" });
+						assert_or_err!(offset % 0x2 == 0x0, Error::IllegalImmediate { reason: "cannot encode uneven offset on arm" });
+
+						let offset = offset as u32;
+
+						// The H bit holds the halfword of the target.
+						opcode |= 0b11111010_00000000_00000000_00000000;
+						opcode |= (offset >> 0x2) & 0b00000000_11111111_11111111_11111111;
+						opcode |= ((offset >> 0x1) & 0b1) << 0x18;
+					}
+
+					Shifter::LogicalShiftLeftImmediate { source, shift: 0x0 } => {
+						check_not_pc(&[source])?;
+
+						opcode |= 0b00000001_00101111_11111111_00110000;
+						opcode |= source as u32;
+						opcode |= (predicate as u32) << 0x1C;
+					}
+
+					_ => return Err(Error::IllegalShifter { reason: "can only exchange to immediates or registers on arm" }),
+				}
+			}
+
 			Breakpoint {
 				immediate,
 			} => {
@@ -137,20 +778,812 @@ impl InstructionCodec {
 				opcode |= (immediate & 0b00000000_00000000_11111111_11110000) << 0x4;
 			}
 
-			Move {
+			ChangeProcessorMode { mode } => {
+				opcode |= 0b11110001_00000010_00000000_00000000;
+				opcode |= mode as u32;
+			}
+
+			ClearExclusive => opcode |= 0b11110101_01111111_11110000_00011111,
+
+			CountLeadingZeroes {
 				predicate,
 				destination,
 				source,
-				s,
 			} => {
-				opcode |= 0b00000001_10100000_00000000_00000000;
+				check_not_pc(&[destination, source])?;
+
+				opcode |= 0b00000001_01101111_00001111_00010000;
+				opcode |= source as u32;
 				opcode |= (destination as u32) << 0xC;
-				opcode |= u32::from(s) << 0x14;
+				opcode |= (predicate as u32) << 0x1C;
+			}
+
+			Compare {
+				predicate,
+				lhs,
+				rhs,
+			} => {
+				opcode |= 0b00000001_01010000_00000000_00000000;
+				opcode |= (lhs as u32) << 0x10;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_shifter(opcode, rhs)?;
+			}
+
+			CompareNegated {
+				predicate,
+				lhs,
+				rhs,
+			} => {
+				opcode |= 0b00000001_01110000_00000000_00000000;
+				opcode |= (lhs as u32) << 0x10;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_shifter(opcode, rhs)?;
+			}
+
+			DataMemoryBarrier { option } => {
+				opcode |= 0b11110101_01111111_11110000_01010000;
+				opcode |= option as u32;
+			}
+
+			DataSynchronisationBarrier { option } => {
+				opcode |= 0b11110101_01111111_11110000_01000000;
+				opcode |= option as u32;
+			}
+
+			DisableInterrupts {
+				mask,
+				mode,
+			} => {
+				assert_or_err!(!mask.is_empty(), Error::IllegalInstruction { reason: "interrupt mask cannot be empty" });
+
+				opcode |= 0b11110001_00001100_00000000_00000000;
+				opcode |= u32::from(mask.to_u8()) << 0x6;
+
+				if let Some(mode) = mode {
+					opcode |= 0b00000000_00000010_00000000_00000000;
+					opcode |= mode as u32;
+				}
+			}
+
+			EnableInterrupts {
+				mask,
+				mode,
+			} => {
+				assert_or_err!(!mask.is_empty(), Error::IllegalInstruction { reason: "interrupt mask cannot be empty" });
+
+				opcode |= 0b11110001_00001000_00000000_00000000;
+				opcode |= u32::from(mask.to_u8()) << 0x6;
+
+				if let Some(mode) = mode {
+					opcode |= 0b00000000_00000010_00000000_00000000;
+					opcode |= mode as u32;
+				}
+			}
+
+			ExclusiveOr {
+				predicate,
+				destination,
+				base,
+				source,
+				s,
+			} => {
+				opcode |= 0b00000000_00100000_00000000_00000000;
+				opcode |= (destination as u32) << 0xC;
+				opcode |= (base as u32) << 0x10;
+				opcode |= u32::from(s) << 0x14;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_shifter(opcode, source)?;
+			}
+
+			InclusiveOr {
+				predicate,
+				destination,
+				base,
+				source,
+				s,
+			} => {
+				opcode |= 0b00000001_10000000_00000000_00000000;
+				opcode |= (destination as u32) << 0xC;
+				opcode |= (base as u32) << 0x10;
+				opcode |= u32::from(s) << 0x14;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_shifter(opcode, source)?;
+			}
+
+			InstructionSynchronisationBarrier { option } => {
+				opcode |= 0b11110101_01111111_11110000_01100000;
+				opcode |= option as u32;
+			}
+
+			Load {
+				predicate,
+				register,
+				address,
+				b,
+				t,
+			} => {
+				opcode |= 0b00000100_00010000_00000000_00000000;
+				opcode |= (register as u32) << 0xC;
+				opcode |= u32::from(b) << 0x16;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_transfer_address(opcode, address, t)?;
+			}
+
+			LoadDoubleword {
+				predicate,
+				first,
+				second,
+				address,
+			} => {
+				assert_or_err!(first as u8 & 0b1 == 0b0, Error::IllegalRegister { reason: "first register must be even on arm" });
+				assert_or_err!(first != Register::Lr, Error::IllegalRegister { reason: "first register cannot be lr on arm" });
+				assert_or_err!(second as u8 == first as u8 + 0x1, Error::IllegalRegister { reason: "second register must follow first on arm" });
+				assert_or_err!(!address.has_writeback() || address.base() != second, Error::IllegalRegister { reason: "cannot write back to transferred register on arm" });
+
+				opcode |= 0b00000000_00000000_00000000_11010000;
+				opcode |= (first as u32) << 0xC;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_misc_address(opcode, address)?;
+			}
+
+			LoadExclusive {
+				predicate,
+				register,
+				address,
+			} => {
+				assert_or_err!(register != Register::Pc, Error::IllegalRegister { reason: "cannot load exclusively into pc on arm" });
+
+				opcode |= 0b00000001_10010000_00001111_10011111;
+				opcode |= (register as u32) << 0xC;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_exclusive_address(opcode, address)?;
+			}
+
+			LoadExclusiveByte {
+				predicate,
+				register,
+				address,
+			} => {
+				assert_or_err!(register != Register::Pc, Error::IllegalRegister { reason: "cannot load exclusively into pc on arm" });
+
+				opcode |= 0b00000001_11010000_00001111_10011111;
+				opcode |= (register as u32) << 0xC;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_exclusive_address(opcode, address)?;
+			}
+
+			LoadExclusiveDoubleword {
+				predicate,
+				first,
+				second,
+				address,
+			} => {
+				assert_or_err!(first as u8 & 0b1 == 0b0, Error::IllegalRegister { reason: "first register must be even on arm" });
+				assert_or_err!(first != Register::Lr, Error::IllegalRegister { reason: "first register cannot be lr on arm" });
+				assert_or_err!(second as u8 == first as u8 + 0x1, Error::IllegalRegister { reason: "second register must follow first on arm" });
+
+				opcode |= 0b00000001_10110000_00001111_10011111;
+				opcode |= (first as u32) << 0xC;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_exclusive_address(opcode, address)?;
+			}
+
+			LoadExclusiveHalfword {
+				predicate,
+				register,
+				address,
+			} => {
+				assert_or_err!(register != Register::Pc, Error::IllegalRegister { reason: "cannot load exclusively into pc on arm" });
+
+				opcode |= 0b00000001_11110000_00001111_10011111;
+				opcode |= (register as u32) << 0xC;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_exclusive_address(opcode, address)?;
+			}
+
+			LoadHalfword {
+				predicate,
+				register,
+				address,
+			} => {
+				opcode |= 0b00000000_00010000_00000000_10110000;
+				opcode |= (register as u32) << 0xC;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_misc_address(opcode, address)?;
+			}
+
+			LoadMultiple {
+				predicate,
+				base,
+				registers,
+				mode,
+				w,
+				s,
+			} => {
+				opcode |= 0b00001000_00010000_00000000_00000000;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_block_transfer(opcode, base, registers, mode, w, s)?;
+			}
+
+			LoadSignedByte {
+				predicate,
+				register,
+				address,
+			} => {
+				opcode |= 0b00000000_00010000_00000000_11010000;
+				opcode |= (register as u32) << 0xC;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_misc_address(opcode, address)?;
+			}
+
+			LoadSignedHalfword {
+				predicate,
+				register,
+				address,
+			} => {
+				opcode |= 0b00000000_00010000_00000000_11110000;
+				opcode |= (register as u32) << 0xC;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_misc_address(opcode, address)?;
+			}
+
+			Move {
+				predicate,
+				destination,
+				source,
+				s,
+			} => {
+				opcode |= 0b00000001_10100000_00000000_00000000;
+				opcode |= (destination as u32) << 0xC;
+				opcode |= u32::from(s) << 0x14;
 				opcode |= (predicate as u32) << 0x1C;
 
 				opcode = add_shifter(opcode, source)?;
 			}
 
+			MoveFromStatusRegister {
+				predicate,
+				destination,
+				source,
+			} => {
+				assert_or_err!(destination != Register::Pc, Error::IllegalRegister { reason: "cannot move status register into pc on arm" });
+
+				opcode |= 0b00000001_00001111_00000000_00000000;
+				opcode |= (destination as u32) << 0xC;
+				opcode |= u32::from(source.is_saved()) << 0x16;
+				opcode |= (predicate as u32) << 0x1C;
+			}
+
+			MoveNot {
+				predicate,
+				destination,
+				source,
+				s,
+			} => {
+				opcode |= 0b00000001_11100000_00000000_00000000;
+				opcode |= (destination as u32) << 0xC;
+				opcode |= u32::from(s) << 0x14;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_shifter(opcode, source)?;
+			}
+
+			MoveToStatusRegister {
+				predicate,
+				destination,
+				fields,
+				source,
+			} => {
+				assert_or_err!(!fields.is_empty(), Error::IllegalInstruction { reason: "cannot write status register without fields on arm" });
+
+				opcode |= 0b00000001_00100000_11110000_00000000;
+				opcode |= u32::from(fields.to_u8()) << 0x10;
+				opcode |= u32::from(destination.is_saved()) << 0x16;
+				opcode |= (predicate as u32) << 0x1C;
+
+				match source {
+					Shifter::Immediate(..) => opcode = add_shifter(opcode, source)?,
+
+					Shifter::LogicalShiftLeftImmediate { source, shift: 0x0 } => {
+						assert_or_err!(source != Register::Pc, Error::IllegalRegister { reason: "cannot move pc into status register on arm" });
+
+						opcode |= source as u32;
+					}
+
+					_ => return Err(Error::IllegalShifter { reason: "status register source must be immediate or register on arm" }),
+				}
+			}
+
+			MoveTop {
+				predicate,
+				destination,
+				immediate,
+			} => {
+				assert_or_err!(immediate <= 0xFFFF, Error::IllegalImmediate { reason: "cannot encode larger than (65535) on arm" });
+				check_not_pc(&[destination])?;
+
+				opcode |= 0b00000011_01000000_00000000_00000000;
+				opcode |= immediate & 0b00000000_00000000_00001111_11111111;
+				opcode |= (destination as u32) << 0xC;
+				opcode |= (immediate & 0b00000000_00000000_11110000_00000000) << 0x4;
+				opcode |= (predicate as u32) << 0x1C;
+			}
+
+			MoveWide {
+				predicate,
+				destination,
+				immediate,
+			} => {
+				assert_or_err!(immediate <= 0xFFFF, Error::IllegalImmediate { reason: "cannot encode larger than (65535) on arm" });
+				check_not_pc(&[destination])?;
+
+				opcode |= 0b00000011_00000000_00000000_00000000;
+				opcode |= immediate & 0b00000000_00000000_00001111_11111111;
+				opcode |= (destination as u32) << 0xC;
+				opcode |= (immediate & 0b00000000_00000000_11110000_00000000) << 0x4;
+				opcode |= (predicate as u32) << 0x1C;
+			}
+
+			Multiply {
+				predicate,
+				destination,
+				base,
+				source,
+				s,
+			} => {
+				opcode |= 0b00000000_00000000_00000000_10010000;
+				opcode |= (destination as u32) << 0x10;
+				opcode |= u32::from(s) << 0x14;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_factors(opcode, &[destination], base, source, self.pre_armv6)?;
+			}
+
+			MultiplyAccumulate {
+				predicate,
+				destination,
+				base,
+				source,
+				accumulator,
+				s,
+			} => {
+				assert_or_err!(accumulator != Register::Pc, Error::IllegalRegister { reason: "cannot accumulate pc on arm" });
+
+				opcode |= 0b00000000_00100000_00000000_10010000;
+				opcode |= (accumulator as u32) << 0xC;
+				opcode |= (destination as u32) << 0x10;
+				opcode |= u32::from(s) << 0x14;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_factors(opcode, &[destination], base, source, self.pre_armv6)?;
+			}
+
+			MultiplySubtract {
+				predicate,
+				destination,
+				base,
+				source,
+				accumulator,
+			} => {
+				assert_or_err!(accumulator != Register::Pc, Error::IllegalRegister { reason: "cannot accumulate pc on arm" });
+
+				opcode |= 0b00000000_01100000_00000000_10010000;
+				opcode |= (accumulator as u32) << 0xC;
+				opcode |= (destination as u32) << 0x10;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_factors(opcode, &[destination], base, source, false)?;
+			}
+
+			NoOperation { predicate } => {
+				opcode |= 0b00000011_00100000_11110000_00000000;
+				opcode |= (predicate as u32) << 0x1C;
+			}
+
+			PackHalfword {
+				predicate,
+				destination,
+				base,
+				source,
+			} => {
+				check_not_pc(&[destination, base])?;
+
+				let (source, tb, shift) = match source {
+					Shifter::LogicalShiftLeftImmediate { source, shift: shift @ 0x0..=0x1F } => (source, false, shift),
+
+					Shifter::ArithmeticShiftRightImmediate { source, shift: shift @ 0x1..=0x20 } => (source, true, shift & 0b11111),
+
+					_ => return Err(Error::IllegalShifter { reason: "can only pack with left shifts up to (31) or arithmetic right shifts up to (32) on arm" }),
+				};
+
+				check_not_pc(&[source])?;
+
+				opcode |= 0b00000110_10000000_00000000_00010000;
+				opcode |= source as u32;
+				opcode |= u32::from(tb) << 0x6;
+				opcode |= shift << 0x7;
+				opcode |= (destination as u32) << 0xC;
+				opcode |= (base as u32) << 0x10;
+				opcode |= (predicate as u32) << 0x1C;
+			}
+
+			ParallelArithmetic {
+				predicate,
+				prefix,
+				operation,
+				destination,
+				base,
+				source,
+			} => {
+				check_not_pc(&[destination, base, source])?;
+
+				opcode |= 0b00000110_00000000_00001111_00010000;
+				opcode |= source as u32;
+				opcode |= (operation as u32) << 0x5;
+				opcode |= (destination as u32) << 0xC;
+				opcode |= (base as u32) << 0x10;
+				opcode |= (prefix as u32) << 0x14;
+				opcode |= (predicate as u32) << 0x1C;
+			}
+
+			PreloadData { address } => {
+				assert_or_err!(address.indexing() == Indexing::Offset, Error::IllegalInstruction { reason: "cannot preload with writeback on arm" });
+
+				opcode |= 0b11110101_01010000_11110000_00000000;
+
+				opcode = add_address(opcode, address)?;
+			}
+
+			Reverse {
+				predicate,
+				destination,
+				source,
+			} => {
+				check_not_pc(&[destination, source])?;
+
+				opcode |= 0b00000110_10111111_00001111_00110000;
+				opcode |= source as u32;
+				opcode |= (destination as u32) << 0xC;
+				opcode |= (predicate as u32) << 0x1C;
+			}
+
+			ReverseBits {
+				predicate,
+				destination,
+				source,
+			} => {
+				check_not_pc(&[destination, source])?;
+
+				opcode |= 0b00000110_11111111_00001111_00110000;
+				opcode |= source as u32;
+				opcode |= (destination as u32) << 0xC;
+				opcode |= (predicate as u32) << 0x1C;
+			}
+
+			ReversePackedHalfword {
+				predicate,
+				destination,
+				source,
+			} => {
+				check_not_pc(&[destination, source])?;
+
+				opcode |= 0b00000110_10111111_00001111_10110000;
+				opcode |= source as u32;
+				opcode |= (destination as u32) << 0xC;
+				opcode |= (predicate as u32) << 0x1C;
+			}
+
+			ReverseSignedHalfword {
+				predicate,
+				destination,
+				source,
+			} => {
+				check_not_pc(&[destination, source])?;
+
+				opcode |= 0b00000110_11111111_00001111_10110000;
+				opcode |= source as u32;
+				opcode |= (destination as u32) << 0xC;
+				opcode |= (predicate as u32) << 0x1C;
+			}
+
+			ReverseSubtract {
+				predicate,
+				destination,
+				base,
+				source,
+				s,
+			} => {
+				opcode |= 0b00000000_01100000_00000000_00000000;
+				opcode |= (destination as u32) << 0xC;
+				opcode |= (base as u32) << 0x10;
+				opcode |= u32::from(s) << 0x14;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_shifter(opcode, source)?;
+			}
+
+			ReverseSubtractCarry {
+				predicate,
+				destination,
+				base,
+				source,
+				s,
+			} => {
+				opcode |= 0b00000000_11100000_00000000_00000000;
+				opcode |= (destination as u32) << 0xC;
+				opcode |= (base as u32) << 0x10;
+				opcode |= u32::from(s) << 0x14;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_shifter(opcode, source)?;
+			}
+
+			SaturatingAdd {
+				predicate,
+				destination,
+				base,
+				source,
+			} => {
+				check_not_pc(&[destination, base, source])?;
+
+				opcode |= 0b00000001_00000000_00000000_01010000;
+				opcode |= base as u32;
+				opcode |= (destination as u32) << 0xC;
+				opcode |= (source as u32) << 0x10;
+				opcode |= (predicate as u32) << 0x1C;
+			}
+
+			SaturatingDoubleAdd {
+				predicate,
+				destination,
+				base,
+				source,
+			} => {
+				check_not_pc(&[destination, base, source])?;
+
+				opcode |= 0b00000001_01000000_00000000_01010000;
+				opcode |= base as u32;
+				opcode |= (destination as u32) << 0xC;
+				opcode |= (source as u32) << 0x10;
+				opcode |= (predicate as u32) << 0x1C;
+			}
+
+			SaturatingDoubleSubtract {
+				predicate,
+				destination,
+				base,
+				source,
+			} => {
+				check_not_pc(&[destination, base, source])?;
+
+				opcode |= 0b00000001_01100000_00000000_01010000;
+				opcode |= base as u32;
+				opcode |= (destination as u32) << 0xC;
+				opcode |= (source as u32) << 0x10;
+				opcode |= (predicate as u32) << 0x1C;
+			}
+
+			SaturatingSubtract {
+				predicate,
+				destination,
+				base,
+				source,
+			} => {
+				check_not_pc(&[destination, base, source])?;
+
+				opcode |= 0b00000001_00100000_00000000_01010000;
+				opcode |= base as u32;
+				opcode |= (destination as u32) << 0xC;
+				opcode |= (source as u32) << 0x10;
+				opcode |= (predicate as u32) << 0x1C;
+			}
+
+			Select {
+				predicate,
+				destination,
+				base,
+				source,
+			} => {
+				check_not_pc(&[destination, base, source])?;
+
+				opcode |= 0b00000110_10000000_00001111_10110000;
+				opcode |= source as u32;
+				opcode |= (destination as u32) << 0xC;
+				opcode |= (base as u32) << 0x10;
+				opcode |= (predicate as u32) << 0x1C;
+			}
+
+			SendEvent { predicate } => {
+				opcode |= 0b00000011_00100000_11110000_00000100;
+				opcode |= (predicate as u32) << 0x1C;
+			}
+
+			SetEndianness { endianness } => {
+				opcode |= 0b11110001_00000001_00000000_00000000;
+				opcode |= (endianness as u32) << 0x9;
+			}
+
+			SignExtendAddByte {
+				predicate,
+				destination,
+				base,
+				source,
+				rotation,
+			} => {
+				opcode |= 0b00000110_10100000_00000000_01110000;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_extend(opcode, destination, Some(base), source, rotation)?;
+			}
+
+			SignExtendAddByte16 {
+				predicate,
+				destination,
+				base,
+				source,
+				rotation,
+			} => {
+				opcode |= 0b00000110_10000000_00000000_01110000;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_extend(opcode, destination, Some(base), source, rotation)?;
+			}
+
+			SignExtendAddHalfword {
+				predicate,
+				destination,
+				base,
+				source,
+				rotation,
+			} => {
+				opcode |= 0b00000110_10110000_00000000_01110000;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_extend(opcode, destination, Some(base), source, rotation)?;
+			}
+
+			SignExtendByte {
+				predicate,
+				destination,
+				source,
+				rotation,
+			} => {
+				opcode |= 0b00000110_10100000_00000000_01110000;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_extend(opcode, destination, None, source, rotation)?;
+			}
+
+			SignExtendByte16 {
+				predicate,
+				destination,
+				source,
+				rotation,
+			} => {
+				opcode |= 0b00000110_10000000_00000000_01110000;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_extend(opcode, destination, None, source, rotation)?;
+			}
+
+			SignExtendHalfword {
+				predicate,
+				destination,
+				source,
+				rotation,
+			} => {
+				opcode |= 0b00000110_10110000_00000000_01110000;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_extend(opcode, destination, None, source, rotation)?;
+			}
+
+			SignedBitFieldExtract {
+				predicate,
+				destination,
+				source,
+				lsb,
+				width,
+			} => {
+				check_bitfield(lsb, width)?;
+				check_not_pc(&[destination, source])?;
+
+				opcode |= 0b00000111_10100000_00000000_01010000;
+				opcode |= source as u32;
+				opcode |= lsb << 0x7;
+				opcode |= (destination as u32) << 0xC;
+				opcode |= (width - 0x1) << 0x10;
+				opcode |= (predicate as u32) << 0x1C;
+			}
+
+			SignedDivide {
+				predicate,
+				destination,
+				base,
+				source,
+			} => {
+				check_not_pc(&[destination, base, source])?;
+
+				opcode |= 0b00000111_00010000_11110000_00010000;
+				opcode |= base as u32;
+				opcode |= (source as u32) << 0x8;
+				opcode |= (destination as u32) << 0x10;
+				opcode |= (predicate as u32) << 0x1C;
+			}
+
+			SignedMultiplyAccumulateLong {
+				predicate,
+				destination_low,
+				destination_high,
+				base,
+				source,
+				s,
+			} => {
+				opcode |= 0b00000000_11100000_00000000_10010000;
+				opcode |= u32::from(s) << 0x14;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_long_factors(opcode, destination_low, destination_high, base, source, self.pre_armv6)?;
+			}
+
+			SignedMultiplyLong {
+				predicate,
+				destination_low,
+				destination_high,
+				base,
+				source,
+				s,
+			} => {
+				opcode |= 0b00000000_11000000_00000000_10010000;
+				opcode |= u32::from(s) << 0x14;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_long_factors(opcode, destination_low, destination_high, base, source, self.pre_armv6)?;
+			}
+
+			SignedSaturate {
+				predicate,
+				destination,
+				immediate,
+				source,
+			} => {
+				assert_or_err!((0x1..=0x20).contains(&immediate), Error::IllegalImmediate { reason: "saturation must be in range (1) to (32) on arm" });
+
+				opcode |= 0b00000110_10100000_00000000_00010000;
+				opcode = add_saturate(opcode, destination, immediate - 0x1, source)?;
+				opcode |= (predicate as u32) << 0x1C;
+			}
+
+			SignedSaturate16 {
+				predicate,
+				destination,
+				immediate,
+				source,
+			} => {
+				assert_or_err!((0x1..=0x10).contains(&immediate), Error::IllegalImmediate { reason: "saturation must be in range (1) to (16) on arm" });
+				check_not_pc(&[destination, source])?;
+
+				opcode |= 0b00000110_10100000_00001111_00110000;
+				opcode |= source as u32;
+				opcode |= (destination as u32) << 0xC;
+				opcode |= (immediate - 0x1) << 0x10;
+				opcode |= (predicate as u32) << 0x1C;
+			}
+
 			SoftwareInterrupt {
 				predicate,
 				immediate,
@@ -160,7 +1593,443 @@ impl InstructionCodec {
 				opcode |= (predicate as u32) << 0x1C;
 			}
 
-			_ => todo!(),
+			Store {
+				predicate,
+				register,
+				address,
+				b,
+				t,
+			} => {
+				opcode |= 0b00000100_00000000_00000000_00000000;
+				opcode |= (register as u32) << 0xC;
+				opcode |= u32::from(b) << 0x16;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_transfer_address(opcode, address, t)?;
+			}
+
+			StoreDoubleword {
+				predicate,
+				first,
+				second,
+				address,
+			} => {
+				assert_or_err!(first as u8 & 0b1 == 0b0, Error::IllegalRegister { reason: "first register must be even on arm" });
+				assert_or_err!(first != Register::Lr, Error::IllegalRegister { reason: "first register cannot be lr on arm" });
+				assert_or_err!(second as u8 == first as u8 + 0x1, Error::IllegalRegister { reason: "second register must follow first on arm" });
+				assert_or_err!(!address.has_writeback() || address.base() != second, Error::IllegalRegister { reason: "cannot write back to transferred register on arm" });
+
+				opcode |= 0b00000000_00000000_00000000_11110000;
+				opcode |= (first as u32) << 0xC;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_misc_address(opcode, address)?;
+			}
+
+			StoreExclusive {
+				predicate,
+				status,
+				register,
+				address,
+			} => {
+				assert_or_err!(register != Register::Pc, Error::IllegalRegister { reason: "cannot store pc exclusively on arm" });
+
+				opcode |= 0b00000001_10000000_00001111_10010000;
+				opcode |= register as u32;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_exclusive_store(opcode, status, &[register], address)?;
+			}
+
+			StoreExclusiveByte {
+				predicate,
+				status,
+				register,
+				address,
+			} => {
+				assert_or_err!(register != Register::Pc, Error::IllegalRegister { reason: "cannot store pc exclusively on arm" });
+
+				opcode |= 0b00000001_11000000_00001111_10010000;
+				opcode |= register as u32;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_exclusive_store(opcode, status, &[register], address)?;
+			}
+
+			StoreExclusiveDoubleword {
+				predicate,
+				status,
+				first,
+				second,
+				address,
+			} => {
+				assert_or_err!(first as u8 & 0b1 == 0b0, Error::IllegalRegister { reason: "first register must be even on arm" });
+				assert_or_err!(first != Register::Lr, Error::IllegalRegister { reason: "first register cannot be lr on arm" });
+				assert_or_err!(second as u8 == first as u8 + 0x1, Error::IllegalRegister { reason: "second register must follow first on arm" });
+
+				opcode |= 0b00000001_10100000_00001111_10010000;
+				opcode |= first as u32;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_exclusive_store(opcode, status, &[first, second], address)?;
+			}
+
+			StoreExclusiveHalfword {
+				predicate,
+				status,
+				register,
+				address,
+			} => {
+				assert_or_err!(register != Register::Pc, Error::IllegalRegister { reason: "cannot store pc exclusively on arm" });
+
+				opcode |= 0b00000001_11100000_00001111_10010000;
+				opcode |= register as u32;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_exclusive_store(opcode, status, &[register], address)?;
+			}
+
+			StoreHalfword {
+				predicate,
+				register,
+				address,
+			} => {
+				opcode |= 0b00000000_00000000_00000000_10110000;
+				opcode |= (register as u32) << 0xC;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_misc_address(opcode, address)?;
+			}
+
+			StoreMultiple {
+				predicate,
+				base,
+				registers,
+				mode,
+				w,
+				s,
+			} => {
+				opcode |= 0b00001000_00000000_00000000_00000000;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_block_transfer(opcode, base, registers, mode, w, s)?;
+			}
+
+			Subtract {
+				predicate,
+				destination,
+				base,
+				source,
+				s,
+			} => {
+				opcode |= 0b00000000_01000000_00000000_00000000;
+				opcode |= (destination as u32) << 0xC;
+				opcode |= (base as u32) << 0x10;
+				opcode |= u32::from(s) << 0x14;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_shifter(opcode, source)?;
+			}
+
+			SubtractCarry {
+				predicate,
+				destination,
+				base,
+				source,
+				s,
+			} => {
+				opcode |= 0b00000000_11000000_00000000_00000000;
+				opcode |= (destination as u32) << 0xC;
+				opcode |= (base as u32) << 0x10;
+				opcode |= u32::from(s) << 0x14;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_shifter(opcode, source)?;
+			}
+
+			Swap {
+				predicate,
+				register,
+				source,
+				address,
+				b,
+			} => {
+				let Address::ImmediateOffset { base, source: 0x0, indexing: Indexing::Offset } = address else {
+					return Err(Error::IllegalInstruction { reason: "can only swap with base register as address on arm" });
+				};
+
+				opcode |= 0b00000001_00000000_00000000_10010000;
+				opcode |= source as u32;
+				opcode |= (register as u32) << 0xC;
+				opcode |= (base as u32) << 0x10;
+				opcode |= u32::from(b) << 0x16;
+				opcode |= (predicate as u32) << 0x1C;
+			}
+
+			UnsignedBitFieldExtract {
+				predicate,
+				destination,
+				source,
+				lsb,
+				width,
+			} => {
+				check_bitfield(lsb, width)?;
+				check_not_pc(&[destination, source])?;
+
+				opcode |= 0b00000111_11100000_00000000_01010000;
+				opcode |= source as u32;
+				opcode |= lsb << 0x7;
+				opcode |= (destination as u32) << 0xC;
+				opcode |= (width - 0x1) << 0x10;
+				opcode |= (predicate as u32) << 0x1C;
+			}
+
+			UnsignedDivide {
+				predicate,
+				destination,
+				base,
+				source,
+			} => {
+				check_not_pc(&[destination, base, source])?;
+
+				opcode |= 0b00000111_00110000_11110000_00010000;
+				opcode |= base as u32;
+				opcode |= (source as u32) << 0x8;
+				opcode |= (destination as u32) << 0x10;
+				opcode |= (predicate as u32) << 0x1C;
+			}
+
+			UnsignedMultiplyAccumulateAccumulateLong {
+				predicate,
+				destination_low,
+				destination_high,
+				base,
+				source,
+			} => {
+				opcode |= 0b00000000_01000000_00000000_10010000;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_long_factors(opcode, destination_low, destination_high, base, source, false)?;
+			}
+
+			UnsignedMultiplyAccumulateLong {
+				predicate,
+				destination_low,
+				destination_high,
+				base,
+				source,
+				s,
+			} => {
+				opcode |= 0b00000000_10100000_00000000_10010000;
+				opcode |= u32::from(s) << 0x14;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_long_factors(opcode, destination_low, destination_high, base, source, self.pre_armv6)?;
+			}
+
+			UnsignedMultiplyLong {
+				predicate,
+				destination_low,
+				destination_high,
+				base,
+				source,
+				s,
+			} => {
+				opcode |= 0b00000000_10000000_00000000_10010000;
+				opcode |= u32::from(s) << 0x14;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_long_factors(opcode, destination_low, destination_high, base, source, self.pre_armv6)?;
+			}
+
+			UnsignedSaturate {
+				predicate,
+				destination,
+				immediate,
+				source,
+			} => {
+				assert_or_err!(immediate <= 0x1F, Error::IllegalImmediate { reason: "saturation must be in range (0) to (31) on arm" });
+
+				opcode |= 0b00000110_11100000_00000000_00010000;
+				opcode = add_saturate(opcode, destination, immediate, source)?;
+				opcode |= (predicate as u32) << 0x1C;
+			}
+
+			UnsignedSaturate16 {
+				predicate,
+				destination,
+				immediate,
+				source,
+			} => {
+				assert_or_err!(immediate <= 0xF, Error::IllegalImmediate { reason: "saturation must be in range (0) to (15) on arm" });
+				check_not_pc(&[destination, source])?;
+
+				opcode |= 0b00000110_11100000_00001111_00110000;
+				opcode |= source as u32;
+				opcode |= (destination as u32) << 0xC;
+				opcode |= immediate << 0x10;
+				opcode |= (predicate as u32) << 0x1C;
+			}
+
+			UnsignedSumAbsoluteDifferences {
+				predicate,
+				destination,
+				base,
+				source,
+			} => {
+				check_not_pc(&[destination, base, source])?;
+
+				opcode |= 0b00000111_10000000_11110000_00010000;
+				opcode |= base as u32;
+				opcode |= (source as u32) << 0x8;
+				opcode |= (destination as u32) << 0x10;
+				opcode |= (predicate as u32) << 0x1C;
+			}
+
+			UnsignedSumAbsoluteDifferencesAccumulate {
+				predicate,
+				destination,
+				base,
+				source,
+				accumulator,
+			} => {
+				check_not_pc(&[destination, base, source, accumulator])?;
+
+				opcode |= 0b00000111_10000000_00000000_00010000;
+				opcode |= base as u32;
+				opcode |= (source as u32) << 0x8;
+				opcode |= (accumulator as u32) << 0xC;
+				opcode |= (destination as u32) << 0x10;
+				opcode |= (predicate as u32) << 0x1C;
+			}
+
+			Test {
+				predicate,
+				lhs,
+				rhs,
+			} => {
+				opcode |= 0b00000001_00010000_00000000_00000000;
+				opcode |= (lhs as u32) << 0x10;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_shifter(opcode, rhs)?;
+			}
+
+			TestEquivalence {
+				predicate,
+				lhs,
+				rhs,
+			} => {
+				opcode |= 0b00000001_00110000_00000000_00000000;
+				opcode |= (lhs as u32) << 0x10;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_shifter(opcode, rhs)?;
+			}
+
+			WaitForEvent { predicate } => {
+				opcode |= 0b00000011_00100000_11110000_00000010;
+				opcode |= (predicate as u32) << 0x1C;
+			}
+
+			WaitForInterrupt { predicate } => {
+				opcode |= 0b00000011_00100000_11110000_00000011;
+				opcode |= (predicate as u32) << 0x1C;
+			}
+
+			Yield { predicate } => {
+				opcode |= 0b00000011_00100000_11110000_00000001;
+				opcode |= (predicate as u32) << 0x1C;
+			}
+
+			ZeroExtendAddByte {
+				predicate,
+				destination,
+				base,
+				source,
+				rotation,
+			} => {
+				opcode |= 0b00000110_11100000_00000000_01110000;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_extend(opcode, destination, Some(base), source, rotation)?;
+			}
+
+			ZeroExtendAddByte16 {
+				predicate,
+				destination,
+				base,
+				source,
+				rotation,
+			} => {
+				opcode |= 0b00000110_11000000_00000000_01110000;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_extend(opcode, destination, Some(base), source, rotation)?;
+			}
+
+			ZeroExtendAddHalfword {
+				predicate,
+				destination,
+				base,
+				source,
+				rotation,
+			} => {
+				opcode |= 0b00000110_11110000_00000000_01110000;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_extend(opcode, destination, Some(base), source, rotation)?;
+			}
+
+			ZeroExtendByte {
+				predicate,
+				destination,
+				source,
+				rotation,
+			} => {
+				opcode |= 0b00000110_11100000_00000000_01110000;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_extend(opcode, destination, None, source, rotation)?;
+			}
+
+			ZeroExtendByte16 {
+				predicate,
+				destination,
+				source,
+				rotation,
+			} => {
+				opcode |= 0b00000110_11000000_00000000_01110000;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_extend(opcode, destination, None, source, rotation)?;
+			}
+
+			ZeroExtendHalfword {
+				predicate,
+				destination,
+				source,
+				rotation,
+			} => {
+				opcode |= 0b00000110_11110000_00000000_01110000;
+				opcode |= (predicate as u32) << 0x1C;
+
+				opcode = add_extend(opcode, destination, None, source, rotation)?;
+			}
+
+			instruction => {
+				if let Some((predicate, vfp_opcode)) = encode_vfp(instruction)? {
+					opcode |= vfp_opcode;
+					opcode |= (predicate as u32) << 0x1C;
+				} else if let Some((predicate, coprocessor_opcode)) = encode_coprocessor(instruction)? {
+					opcode |= coprocessor_opcode;
+					opcode |= (predicate as u32) << 0x1C;
+				} else if let Some(neon_opcode) = encode_neon(instruction)? {
+					opcode = neon_opcode;
+				} else {
+					return Err(Error::IllegalInstruction { reason: "not supported on arm" });
+				}
+			}
 		}
 
 		self.skip_words(0x1);
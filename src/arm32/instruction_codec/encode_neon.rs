@@ -0,0 +1,547 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Pollex.
+//
+// Pollex is free software: you can redistribute it
+// and/or modify it under the terms of the GNU Af-
+// fero General Public License as published by the
+// Free Software Foundation, either version 3 of
+// the License, or (at your option) any later ver-
+// sion.
+//
+// Pollex is distributed in the hope that it will
+// be useful, but WITHOUT ANY WARRANTY; without
+// even the implied warranty of MERCHANTABILITY or
+// FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// Affero General Public License for more details.
+//
+// You should have received a copy of the GNU Af-
+// fero General Public License along with Pollex.
+// If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{assert_or_err, Error, Result};
+use crate::arm32::{
+	DoubleRegister,
+	ElementType,
+	Instruction,
+	Register,
+	Scalar,
+	StructureAddress,
+	VectorRegister,
+};
+
+/// Adds an Advanced SIMD register in the `Vd` field.
+#[inline]
+#[must_use]
+const fn add_vd(mut opcode: u32, register: VectorRegister) -> u32 {
+	let value = register.to_double_index() as u32;
+
+	opcode |= (value & 0b1111) << 0xC;
+	opcode |= (value >> 0x4) << 0x16;
+
+	opcode
+}
+
+/// Adds an Advanced SIMD register in the `Vn` field.
+#[inline]
+#[must_use]
+const fn add_vn(mut opcode: u32, register: VectorRegister) -> u32 {
+	let value = register.to_double_index() as u32;
+
+	opcode |= (value & 0b1111) << 0x10;
+	opcode |= (value >> 0x4) << 0x7;
+
+	opcode
+}
+
+/// Adds an Advanced SIMD register in the `Vm` field.
+#[inline]
+#[must_use]
+const fn add_vm(mut opcode: u32, register: VectorRegister) -> u32 {
+	let value = register.to_double_index() as u32;
+
+	opcode |= value & 0b1111;
+	opcode |= (value >> 0x4) << 0x5;
+
+	opcode
+}
+
+/// Checks that all of the given registers are of the same width.
+///
+/// The Q bit of the common width is returned.
+fn check_width(registers: &[VectorRegister]) -> Result<u32> {
+	let quad = registers.first().is_some_and(|register| register.is_quad());
+
+	assert_or_err!(registers.iter().all(|register| register.is_quad() == quad), Error::IllegalRegister { reason: "registers must be of the same width for neon" });
+
+	Ok(u32::from(quad))
+}
+
+/// Gets the size code of an element type.
+///
+/// The element must be at most `max` bits wide.
+const fn size_code(element: ElementType, max: u32) -> Result<u32> {
+	assert_or_err!(element.size() <= max, Error::IllegalInstruction { reason: "element type too wide for neon instruction" });
+
+	Ok(element.size().trailing_zeros() - 0x3)
+}
+
+/// Gets the size code of an integer element type.
+const fn integer_size(element: ElementType, max: u32) -> Result<u32> {
+	assert_or_err!(element.is_integer(), Error::IllegalInstruction { reason: "element type must be integer for neon instruction" });
+
+	size_code(element, max)
+}
+
+/// Gets the size code and U bit of a signed or unsigned element type.
+fn signed_size(element: ElementType, max: u32) -> Result<(u32, u32)> {
+	assert_or_err!(element.is_signed() || element.is_unsigned(), Error::IllegalInstruction { reason: "element type must be signed or unsigned for neon instruction" });
+
+	Ok((size_code(element, max)?, u32::from(element.is_unsigned())))
+}
+
+/// Adds a scalar in the `Vm` field.
+///
+/// Scalars of halfword elements may only use `d0` through `d7`.
+fn add_scalar(mut opcode: u32, scalar: Scalar, size: u32) -> Result<u32> {
+	let register = scalar.register as u32;
+	let index    = u32::from(scalar.index);
+
+	if size == 0x10 {
+		assert_or_err!(register <= 0x7, Error::IllegalRegister { reason: "halfword scalar must be in d0 through d7 for neon" });
+		assert_or_err!(index <= 0x3, Error::IllegalImmediate { reason: "scalar index out of range for neon" });
+
+		opcode |= register;
+		opcode |= (index & 0b1) << 0x3;
+		opcode |= (index >> 0x1) << 0x5;
+	} else {
+		assert_or_err!(index <= 0x1, Error::IllegalImmediate { reason: "scalar index out of range for neon" });
+
+		opcode |= register;
+		opcode |= index << 0x5;
+	}
+
+	Ok(opcode)
+}
+
+/// Encodes the `opc1` and `opc2` fields of scalar transfers.
+fn encode_scalar_index(mut opcode: u32, scalar: Scalar, size: u32) -> Result<u32> {
+	let index = u32::from(scalar.index);
+
+	assert_or_err!(index < 0x40 / size, Error::IllegalImmediate { reason: "scalar index out of range for neon" });
+
+	let field = match size {
+		0x8  => 0b1000 | index,
+		0x10 => 0b0001 | index << 0x1,
+		_    => index << 0x2,
+	};
+
+	opcode |= (field & 0b11) << 0x5;
+	opcode |= (field >> 0x2) << 0x15;
+
+	Ok(add_vn(opcode, VectorRegister::Double(scalar.register)))
+}
+
+/// Gets the `type` field of a structure transfer.
+///
+/// On success, the field is returned together with the largest permitted alignment.
+const fn structure_type(interleave: u8, count: u8, spacing: u8) -> Result<(u32, u32)> {
+	let value = match (interleave, count, spacing) {
+		(0x1, 0x1, 0x1) => (0b0111, 0x40),
+		(0x1, 0x2, 0x1) => (0b1010, 0x80),
+		(0x1, 0x3, 0x1) => (0b0110, 0x40),
+		(0x1, 0x4, 0x1) => (0b0010, 0x100),
+		(0x2, 0x2, 0x1) => (0b1000, 0x80),
+		(0x2, 0x2, 0x2) => (0b1001, 0x80),
+		(0x2, 0x4, 0x1) => (0b0011, 0x100),
+		(0x3, 0x3, 0x1) => (0b0100, 0x40),
+		(0x3, 0x3, 0x2) => (0b0101, 0x40),
+		(0x4, 0x4, 0x1) => (0b0000, 0x100),
+		(0x4, 0x4, 0x2) => (0b0001, 0x100),
+
+		_ => return Err(Error::IllegalInstruction { reason: "invalid structure register list for neon" }),
+	};
+
+	Ok(value)
+}
+
+/// Adds the operands of a structure transfer.
+fn add_structure_transfer(mut opcode: u32, interleave: u8, element: ElementType, first: DoubleRegister, count: u8, spacing: u8, address: StructureAddress) -> Result<u32> {
+	let (kind, max_alignment) = structure_type(interleave, count, spacing)?;
+
+	let last = u32::from(first as u8) + (u32::from(count) - 0x1) * u32::from(spacing);
+	assert_or_err!(last <= 0x1F, Error::IllegalRegister { reason: "register list out of range for neon" });
+
+	let size = size_code(element, if interleave == 0x1 { 0x40 } else { 0x20 })?;
+
+	let alignment = match address.alignment() {
+		0x0   => 0b00,
+		0x40  => 0b01,
+		0x80  => 0b10,
+		0x100 => 0b11,
+
+		_ => return Err(Error::IllegalImmediate { reason: "alignment must be any of (64), (128), or (256) for neon" }),
+	};
+
+	assert_or_err!(address.alignment() <= max_alignment, Error::IllegalImmediate { reason: "alignment too large for structure transfer for neon" });
+
+	let base = address.base();
+	assert_or_err!(base != Register::Pc, Error::IllegalRegister { reason: "cannot use pc as base for neon" });
+
+	let increment = match address {
+		StructureAddress::Offset { .. } => 0b1111,
+
+		StructureAddress::PostIndexed { .. } => 0b1101,
+
+		StructureAddress::RegisterPostIndexed { source, .. } => {
+			assert_or_err!(!matches!(source, Register::Sp | Register::Pc), Error::IllegalRegister { reason: "cannot increment by sp or pc for neon" });
+
+			source as u32
+		}
+	};
+
+	opcode |= increment;
+	opcode |= alignment << 0x4;
+	opcode |= size << 0x6;
+	opcode |= kind << 0x8;
+	opcode |= (base as u32) << 0x10;
+
+	Ok(add_vd(opcode, VectorRegister::Double(first)))
+}
+
+/// Encodes an Advanced SIMD instruction.
+///
+/// The Arm encoding is returned, including its condition (which is `0b1111` for unconditional instructions).
+/// The Thumb encodings are derived from these.
+///
+/// If the instruction is not an Advanced SIMD instruction, [`None`] is returned.
+pub(in super) fn encode_neon(instruction: Instruction) -> Result<Option<u32>> {
+	use Instruction::*;
+
+	let mut opcode = 0b11110000_00000000_00000000_00000000_u32;
+
+	match instruction {
+		| VectorAbsoluteDifference { element, destination, base, source }
+		| VectorAdd { element, destination, base, source }
+		| VectorCompareEqual { element, destination, base, source }
+		| VectorCompareGreaterThan { element, destination, base, source }
+		| VectorCompareGreaterThanOrEqual { element, destination, base, source }
+		| VectorMaximum { element, destination, base, source }
+		| VectorMinimum { element, destination, base, source }
+		| VectorMultiply { element, destination, base, source }
+		| VectorMultiplyAccumulate { element, destination, base, source }
+		| VectorMultiplySubtract { element, destination, base, source }
+		| VectorSaturatingAdd { element, destination, base, source }
+		| VectorSaturatingSubtract { element, destination, base, source }
+		| VectorSubtract { element, destination, base, source }
+		=> {
+			if element.is_float() {
+				opcode |= match instruction {
+					VectorAbsoluteDifference { .. }        => 0b00000011_00100000_00001101_00000000,
+					VectorAdd { .. }                       => 0b00000010_00000000_00001101_00000000,
+					VectorCompareEqual { .. }              => 0b00000010_00000000_00001110_00000000,
+					VectorCompareGreaterThan { .. }        => 0b00000011_00100000_00001110_00000000,
+					VectorCompareGreaterThanOrEqual { .. } => 0b00000011_00000000_00001110_00000000,
+					VectorMaximum { .. }                   => 0b00000010_00000000_00001111_00000000,
+					VectorMinimum { .. }                   => 0b00000010_00100000_00001111_00000000,
+					VectorMultiply { .. }                  => 0b00000011_00000000_00001101_00010000,
+					VectorMultiplyAccumulate { .. }        => 0b00000010_00000000_00001101_00010000,
+					VectorMultiplySubtract { .. }          => 0b00000010_00100000_00001101_00010000,
+					VectorSubtract { .. }                  => 0b00000010_00100000_00001101_00000000,
+
+					_ => return Err(Error::IllegalInstruction { reason: "element type cannot be float for neon instruction" }),
+				};
+			} else if element == ElementType::P8 {
+				assert_or_err!(matches!(instruction, VectorMultiply { .. }), Error::IllegalInstruction { reason: "element type cannot be polynomial for neon instruction" });
+
+				opcode |= 0b00000011_00000000_00001001_00010000;
+			} else {
+				let (code, size) = match instruction {
+					VectorAdd { .. }                => (0b00000010_00000000_00001000_00000000, integer_size(element, 0x40)?),
+					VectorCompareEqual { .. }       => (0b00000011_00000000_00001000_00010000, integer_size(element, 0x20)?),
+					VectorMultiply { .. }           => (0b00000010_00000000_00001001_00010000, integer_size(element, 0x20)?),
+					VectorMultiplyAccumulate { .. } => (0b00000010_00000000_00001001_00000000, integer_size(element, 0x20)?),
+					VectorMultiplySubtract { .. }   => (0b00000011_00000000_00001001_00000000, integer_size(element, 0x20)?),
+					VectorSubtract { .. }           => (0b00000011_00000000_00001000_00000000, integer_size(element, 0x40)?),
+
+					_ => {
+						let (code, max) = match instruction {
+							VectorAbsoluteDifference { .. }        => (0b00000010_00000000_00000111_00000000, 0x20),
+							VectorCompareGreaterThan { .. }        => (0b00000010_00000000_00000011_00000000, 0x20),
+							VectorCompareGreaterThanOrEqual { .. } => (0b00000010_00000000_00000011_00010000, 0x20),
+							VectorMaximum { .. }                   => (0b00000010_00000000_00000110_00000000, 0x20),
+							VectorMinimum { .. }                   => (0b00000010_00000000_00000110_00010000, 0x20),
+							VectorSaturatingAdd { .. }             => (0b00000010_00000000_00000000_00010000, 0x40),
+							_                                      => (0b00000010_00000000_00000010_00010000, 0x40),
+						};
+
+						let (size, u) = signed_size(element, max)?;
+						(code | u << 0x18, size)
+					}
+				};
+
+				opcode |= code;
+				opcode |= size << 0x14;
+			}
+
+			opcode |= check_width(&[destination, base, source])? << 0x6;
+
+			opcode = add_vd(opcode, destination);
+			opcode = add_vn(opcode, base);
+			opcode = add_vm(opcode, source);
+		}
+
+		| VectorAnd { destination, base, source }
+		| VectorBitClear { destination, base, source }
+		| VectorExclusiveOr { destination, base, source }
+		| VectorInclusiveOr { destination, base, source }
+		=> {
+			opcode |= match instruction {
+				VectorAnd { .. }         => 0b00000010_00000000_00000001_00010000,
+				VectorBitClear { .. }    => 0b00000010_00010000_00000001_00010000,
+				VectorExclusiveOr { .. } => 0b00000011_00000000_00000001_00010000,
+				_                        => 0b00000010_00100000_00000001_00010000,
+			};
+
+			opcode |= check_width(&[destination, base, source])? << 0x6;
+
+			opcode = add_vd(opcode, destination);
+			opcode = add_vn(opcode, base);
+			opcode = add_vm(opcode, source);
+		}
+
+		| VectorAbsolute { element, destination, source }
+		| VectorNegate { element, destination, source }
+		=> {
+			let negate = u32::from(matches!(instruction, VectorNegate { .. }));
+
+			if element.is_float() {
+				opcode |= 0b00000011_10111001_00000111_00000000;
+			} else {
+				assert_or_err!(element.is_signed(), Error::IllegalInstruction { reason: "element type must be signed for neon instruction" });
+
+				opcode |= 0b00000011_10110001_00000011_00000000;
+				opcode |= size_code(element, 0x20)? << 0x12;
+			}
+
+			opcode |= negate << 0x7;
+			opcode |= check_width(&[destination, source])? << 0x6;
+
+			opcode = add_vd(opcode, destination);
+			opcode = add_vm(opcode, source);
+		}
+
+		| VectorNot { destination, source }
+		| VectorSwap { destination, source }
+		=> {
+			opcode |= if matches!(instruction, VectorNot { .. }) {
+				0b00000011_10110000_00000101_10000000
+			} else {
+				0b00000011_10110010_00000000_00000000
+			};
+
+			opcode |= check_width(&[destination, source])? << 0x6;
+
+			opcode = add_vd(opcode, destination);
+			opcode = add_vm(opcode, source);
+		}
+
+		| VectorReverse16 { element, destination, source }
+		| VectorReverse32 { element, destination, source }
+		| VectorReverse64 { element, destination, source }
+		| VectorTranspose { element, destination, source }
+		| VectorUnzip { element, destination, source }
+		| VectorZip { element, destination, source }
+		=> {
+			let (code, max) = match instruction {
+				VectorReverse16 { .. } => (0b00000011_10110000_00000001_00000000, 0x8),
+				VectorReverse32 { .. } => (0b00000011_10110000_00000000_10000000, 0x10),
+				VectorReverse64 { .. } => (0b00000011_10110000_00000000_00000000, 0x20),
+				VectorTranspose { .. } => (0b00000011_10110010_00000000_10000000, 0x20),
+				VectorUnzip { .. }     => (0b00000011_10110010_00000001_00000000, 0x20),
+				_                      => (0b00000011_10110010_00000001_10000000, 0x20),
+			};
+
+			let quad = check_width(&[destination, source])?;
+
+			// Zipping words of double registers is identical
+			// to transposing them.
+			let max = if matches!(instruction, VectorUnzip { .. } | VectorZip { .. }) && quad == 0b0 { 0x10 } else { max };
+
+			opcode |= code;
+			opcode |= size_code(element, max)? << 0x12;
+			opcode |= quad << 0x6;
+
+			opcode = add_vd(opcode, destination);
+			opcode = add_vm(opcode, source);
+		}
+
+		| VectorShiftLeft { element, destination, source, shift }
+		| VectorShiftRight { element, destination, source, shift }
+		=> {
+			let size = element.size();
+
+			let immediate = if matches!(instruction, VectorShiftLeft { .. }) {
+				assert_or_err!(element.is_integer(), Error::IllegalInstruction { reason: "element type must be integer for neon instruction" });
+				assert_or_err!(shift < size, Error::IllegalImmediate { reason: "shift must be less than element size for neon" });
+
+				opcode |= 0b00000010_10000000_00000101_00010000;
+
+				size + shift
+			} else {
+				let (_, u) = signed_size(element, 0x40)?;
+				assert_or_err!(shift != 0x0 && shift <= size, Error::IllegalImmediate { reason: "shift must be between (1) and element size for neon" });
+
+				opcode |= 0b00000010_10000000_00000000_00010000;
+				opcode |= u << 0x18;
+
+				size * 0x2 - shift
+			};
+
+			opcode |= (immediate & 0b111111) << 0x10;
+			opcode |= u32::from(size == 0x40) << 0x7;
+			opcode |= check_width(&[destination, source])? << 0x6;
+
+			opcode = add_vd(opcode, destination);
+			opcode = add_vm(opcode, source);
+		}
+
+		VectorExtract {
+			destination,
+			base,
+			source,
+			index,
+		} => {
+			let quad = check_width(&[destination, base, source])?;
+			assert_or_err!(u32::from(index) < 0x8 << quad, Error::IllegalImmediate { reason: "extraction index out of range for neon" });
+
+			opcode |= 0b00000010_10110000_00000000_00000000;
+			opcode |= u32::from(index) << 0x8;
+			opcode |= quad << 0x6;
+
+			opcode = add_vd(opcode, destination);
+			opcode = add_vn(opcode, base);
+			opcode = add_vm(opcode, source);
+		}
+
+		VectorMultiplyScalar {
+			element,
+			destination,
+			base,
+			source,
+		} => {
+			if element.is_float() {
+				opcode |= 0b00000010_10100000_00001001_01000000;
+			} else {
+				let size = integer_size(element, 0x20)?;
+				assert_or_err!(size != 0b00, Error::IllegalInstruction { reason: "element type too narrow for neon instruction" });
+
+				opcode |= 0b00000010_10000000_00001000_01000000;
+				opcode |= size << 0x14;
+			}
+
+			opcode |= check_width(&[destination, base])? << 0x18;
+
+			opcode = add_vd(opcode, destination);
+			opcode = add_vn(opcode, base);
+			opcode = add_scalar(opcode, source, element.size())?;
+		}
+
+		VectorDuplicateScalar {
+			element,
+			destination,
+			source,
+		} => {
+			let size  = element.size();
+			let index = u32::from(source.index);
+
+			assert_or_err!(size <= 0x20, Error::IllegalInstruction { reason: "element type too wide for neon instruction" });
+			assert_or_err!(index < 0x40 / size, Error::IllegalImmediate { reason: "scalar index out of range for neon" });
+
+			let field = size >> 0x3 | index << (size.trailing_zeros() - 0x2);
+
+			opcode |= 0b00000011_10110000_00001100_00000000;
+			opcode |= field << 0x10;
+			opcode |= u32::from(destination.is_quad()) << 0x6;
+
+			opcode = add_vd(opcode, destination);
+			opcode = add_vm(opcode, VectorRegister::Double(source.register));
+		}
+
+		VectorDuplicate {
+			predicate,
+			element,
+			destination,
+			source,
+		} => {
+			assert_or_err!(source != Register::Pc, Error::IllegalRegister { reason: "cannot use pc for neon" });
+
+			let (b, e) = match element.size() {
+				0x8  => (0b1, 0b0),
+				0x10 => (0b0, 0b1),
+				0x20 => (0b0, 0b0),
+
+				_ => return Err(Error::IllegalInstruction { reason: "element type too wide for neon instruction" }),
+			};
+
+			opcode = 0b00001110_10000000_00001011_00010000;
+			opcode |= e << 0x5;
+			opcode |= (source as u32) << 0xC;
+			opcode |= u32::from(destination.is_quad()) << 0x15;
+			opcode |= b << 0x16;
+			opcode |= (predicate as u32) << 0x1C;
+
+			// The destination is kept in the `Vn` field.
+			opcode = add_vn(opcode, destination);
+		}
+
+		MoveFromScalar {
+			predicate,
+			element,
+			destination,
+			source,
+		} => {
+			assert_or_err!(destination != Register::Pc, Error::IllegalRegister { reason: "cannot use pc for neon" });
+
+			let size = element.size();
+			assert_or_err!(size <= 0x20, Error::IllegalInstruction { reason: "element type too wide for neon instruction" });
+			assert_or_err!(size == 0x20 || element.is_signed() || element.is_unsigned(), Error::IllegalInstruction { reason: "element type must be signed or unsigned for neon instruction" });
+
+			opcode = 0b00001110_00010000_00001011_00010000;
+			opcode |= (destination as u32) << 0xC;
+			opcode |= u32::from(element.is_unsigned() && size != 0x20) << 0x17;
+			opcode |= (predicate as u32) << 0x1C;
+
+			opcode = encode_scalar_index(opcode, source, size)?;
+		}
+
+		MoveToScalar {
+			predicate,
+			element,
+			destination,
+			source,
+		} => {
+			assert_or_err!(source != Register::Pc, Error::IllegalRegister { reason: "cannot use pc for neon" });
+			assert_or_err!(element.size() <= 0x20, Error::IllegalInstruction { reason: "element type too wide for neon instruction" });
+
+			opcode = 0b00001110_00000000_00001011_00010000;
+			opcode |= (source as u32) << 0xC;
+			opcode |= (predicate as u32) << 0x1C;
+
+			opcode = encode_scalar_index(opcode, destination, element.size())?;
+		}
+
+		| VectorLoadStructure { interleave, element, first, count, spacing, address }
+		| VectorStoreStructure { interleave, element, first, count, spacing, address }
+		=> {
+			opcode |= 0b00000100_00000000_00000000_00000000;
+			opcode |= u32::from(matches!(instruction, VectorLoadStructure { .. })) << 0x15;
+
+			opcode = add_structure_transfer(opcode, interleave, element, first, count, spacing, address)?;
+		}
+
+		_ => return Ok(None),
+	}
+
+	Ok(Some(opcode))
+}
@@ -21,23 +21,42 @@
 
 use crate::{assert_or_err, Error, Result};
 use crate::arm32::{
+	Address,
+	Bflag,
+	BlockMode,
+	Indexing,
 	Instruction,
 	InstructionCodec,
+	ParallelOperation,
 	Predicate,
+	Register,
+	RegisterList,
 	Sflag,
 	Shifter,
 	ThumbOpcode,
+	Wflag,
 };
 
+use alloc::vec::Vec;
 use core::num::Wrapping;
 
+use super::{check_bitfield, encode_if_then_state, is_branch, narrow_s_flag};
+use super::encode_arm::encode_coprocessor;
+use super::encode_neon::encode_neon;
+use super::encode_thumb_wide::encode_thumb_wide;
+use super::encode_vfp::encode_vfp;
+
 macro_rules! quick_assert {
 	(base_equals_destination: $base:expr, $destination:expr) => {{
 		assert_or_err!($base == $destination, Error::IllegalRegister { reason: "base must also be destination on thumb" });
 	}};
 
 	(low_register: $register:expr) => {{
-		assert_or_err!($register.is_low(), Error::IllegalRegister { reason: "cannot encode low register on thumb" });
+		assert_or_err!($register.is_low(), Error::IllegalRegister { reason: "cannot encode high register on thumb" });
+	}};
+
+	(not_sp_or_pc: $register:expr) => {{
+		assert_or_err!(!matches!($register, Register::Sp | Register::Pc), Error::IllegalRegister { reason: "cannot encode sp or pc on thumb" });
 	}};
 
 	(predicate_always: $predicate:expr) => {{
@@ -53,6 +72,40 @@ macro_rules! quick_assert {
 	}};
 }
 
+/// Groups the leading instructions of the given sequence into an if-then block.
+///
+/// The block starts with the first instruction, and then takes any instruction predicated on the same condition or its inverse, up to four.
+/// Branches end the block, and conditional branches do not start one, as these can be encoded as such.
+/// If the first instruction is unconditional, [`None`] is returned.
+fn group_if_then(instructions: &[Instruction]) -> Option<Instruction> {
+	let (&first, remaining) = instructions.split_first()?;
+
+	let predicate = first.predicate();
+	let inverse   = predicate.inverse()?;
+
+	if matches!(first, Instruction::Branch { .. }) { return None };
+
+	// Then-instructions are denoted by set bits, followed by the terminating bit.
+	let mut mask  = 0b0000_u8;
+	let mut count = 0x0;
+	let mut last  = first;
+
+	for &instruction in remaining.iter().take(0x3) {
+		if is_branch(last) { break };
+
+		let condition = instruction.predicate();
+		if condition != predicate && condition != inverse { break };
+
+		if condition == predicate { mask |= 0b1000 >> count };
+
+		count += 0x1;
+		last = instruction;
+	}
+
+	mask |= 0b1000 >> count;
+	Some(Instruction::IfThen { predicate, mask })
+}
+
 #[inline]
 const fn encode_shift_immediate(shift: u32) -> Result<u32> {
 	match shift {
@@ -66,28 +119,479 @@ const fn encode_shift_immediate(shift: u32) -> Result<u32> {
 	}
 }
 
+/// Encodes a word or byte transfer (i.e. `LDR`, `STR`, `LDRB`, or `STRB`).
+fn encode_transfer(l: bool, register: Register, address: Address, b: Bflag) -> Result<u16> {
+	use Address::*;
+
+	assert_or_err!(address.indexing() == Indexing::Offset, Error::IllegalInstruction { reason: "cannot encode writeback on thumb" });
+
+	quick_assert!(low_register: register);
+
+	let mut opcode = 0b00000000_00000000_u16;
+
+	match address {
+		ImmediateOffset { base: Register::Pc, source, .. } => {
+			assert_or_err!(l, Error::IllegalRegister { reason: "cannot store relative to pc on thumb" });
+			assert_or_err!(b.is_off(), Error::IllegalFlag { reason: "b flag must be off for pc-relative loads on thumb" });
+
+			let source = encode_word_offset(source, 0x3FC)?;
+
+			opcode |= 0b01001000_00000000;
+			opcode |= source;
+			opcode |= (register as u16).wrapping_shl(0x8);
+		}
+
+		ImmediateOffset { base: Register::Sp, source, .. } => {
+			assert_or_err!(b.is_off(), Error::IllegalFlag { reason: "b flag must be off for sp-relative transfers on thumb" });
+
+			let source = encode_word_offset(source, 0x3FC)?;
+
+			opcode |= 0b10010000_00000000;
+			opcode |= source;
+			opcode |= (register as u16).wrapping_shl(0x8);
+			opcode |= u16::from(l).wrapping_shl(0xB);
+		}
+
+		ImmediateOffset { base, source, .. } => {
+			quick_assert!(low_register: base);
+
+			let source = if b.is_on() {
+				assert_or_err!((0x0..=0x1F).contains(&source), Error::IllegalImmediate { reason: "byte offset must be in range (0) to (31) on thumb" });
+				source as u16
+			} else {
+				encode_word_offset(source, 0x7C)?
+			};
+
+			opcode |= 0b01100000_00000000;
+			opcode |= register as u16;
+			opcode |= (base as u16).wrapping_shl(0x3);
+			opcode |= source.wrapping_shl(0x6);
+			opcode |= u16::from(l).wrapping_shl(0xB);
+			opcode |= u16::from(b).wrapping_shl(0xC);
+		}
+
+		| RegisterOffset { base, source, sign, .. }
+		| ScaledRegisterOffset { base, source: Shifter::LogicalShiftLeftImmediate { source, shift: 0x0 }, sign, .. }
+		=> {
+			assert_or_err!(sign.is_positive(), Error::IllegalRegister { reason: "cannot subtract register offsets on thumb" });
+
+			quick_assert!(low_register: base);
+			quick_assert!(low_register: source);
+
+			opcode |= 0b01010000_00000000;
+			opcode |= register as u16;
+			opcode |= (base as u16).wrapping_shl(0x3);
+			opcode |= (source as u16).wrapping_shl(0x6);
+			opcode |= u16::from(b).wrapping_shl(0xA);
+			opcode |= u16::from(l).wrapping_shl(0xB);
+		}
+
+		ScaledRegisterOffset { .. } => return Err(Error::IllegalShifter { reason: "cannot encode scaled register offsets on thumb" }),
+	}
+
+	Ok(opcode)
+}
+
+/// Encodes a halfword or signed byte transfer.
+///
+/// The provided opcodes are used for the register offset and the (halfword-scaled) immediate offset forms, respectively.
+/// Signed transfers do not have the latter.
+fn encode_misc_transfer(register: Register, address: Address, register_opcode: u16, immediate_opcode: Option<u16>) -> Result<u16> {
+	use Address::*;
+
+	assert_or_err!(address.indexing() == Indexing::Offset, Error::IllegalInstruction { reason: "cannot encode writeback on thumb" });
+
+	quick_assert!(low_register: register);
+
+	let mut opcode = 0b00000000_00000000_u16;
+
+	match address {
+		ImmediateOffset { base, source, .. } => {
+			let Some(immediate_opcode) = immediate_opcode else {
+				return Err(Error::IllegalInstruction { reason: "cannot encode signed transfers with immediate offsets on thumb" });
+			};
+
+			quick_assert!(low_register: base);
+
+			assert_or_err!((0x0..=0x3E).contains(&source), Error::IllegalImmediate { reason: "halfword offset must be in range (0) to (62) on thumb" });
+			assert_or_err!(source % 0x2 == 0x0, Error::IllegalImmediate { reason: "halfword offset must be even on thumb" });
+
+			opcode |= immediate_opcode;
+			opcode |= register as u16;
+			opcode |= (base as u16).wrapping_shl(0x3);
+			opcode |= ((source / 0x2) as u16).wrapping_shl(0x6);
+		}
+
+		| RegisterOffset { base, source, sign, .. }
+		| ScaledRegisterOffset { base, source: Shifter::LogicalShiftLeftImmediate { source, shift: 0x0 }, sign, .. }
+		=> {
+			assert_or_err!(sign.is_positive(), Error::IllegalRegister { reason: "cannot subtract register offsets on thumb" });
+
+			quick_assert!(low_register: base);
+			quick_assert!(low_register: source);
+
+			opcode |= register_opcode;
+			opcode |= register as u16;
+			opcode |= (base as u16).wrapping_shl(0x3);
+			opcode |= (source as u16).wrapping_shl(0x6);
+		}
+
+		ScaledRegisterOffset { .. } => return Err(Error::IllegalShifter { reason: "cannot encode scaled register offsets on thumb" }),
+	}
+
+	Ok(opcode)
+}
+
+/// Encodes a block data transfer (i.e. `LDMIA`, `STMIA`, `PUSH`, or `POP`).
+///
+/// `PUSH` and `POP` are used for `sp` with writeback and may additionally transfer `lr` or `pc`, respectively.
+fn encode_block_transfer(l: bool, base: Register, registers: RegisterList, mode: BlockMode, w: Wflag, s: Sflag) -> Result<u16> {
+	assert_or_err!(s.is_off(), Error::IllegalFlag { reason: "s flag must be off on thumb" });
+	assert_or_err!(!registers.is_empty(), Error::IllegalInstruction { reason: "cannot transfer empty register list on thumb" });
+
+	let mut opcode = 0b00000000_00000000_u16;
+
+	if base == Register::Sp && w.is_on() {
+		let (extra, expected_mode) = if l {
+			(Register::Pc, BlockMode::IncrementAfter)
+		} else {
+			(Register::Lr, BlockMode::DecrementBefore)
+		};
+
+		assert_or_err!(mode == expected_mode, Error::IllegalInstruction { reason: "can only push with decrement before and pop with increment after on thumb" });
+		assert_or_err!(registers.without(extra).is_low(), Error::IllegalRegister { reason: "cannot encode high register on thumb" });
+
+		opcode |= 0b10110100_00000000;
+		opcode |= registers.to_u16() & 0b00000000_11111111;
+		opcode |= u16::from(registers.contains(extra)).wrapping_shl(0x8);
+	} else {
+		assert_or_err!(mode == BlockMode::IncrementAfter, Error::IllegalInstruction { reason: "can only increment after on thumb" });
+
+		quick_assert!(low_register: base);
+		assert_or_err!(registers.is_low(), Error::IllegalRegister { reason: "cannot encode high register on thumb" });
+
+		// Loads write back exactly when the base register
+		// is not overwritten, and stores always do.
+		let has_writeback = !l || !registers.contains(base);
+		assert_or_err!(w.is_on() == has_writeback, Error::IllegalFlag { reason: "w flag must be on unless base is loaded on thumb" });
+
+		assert_or_err!(l || !registers.contains(base) || registers.lowest() == Some(base), Error::IllegalRegister { reason: "cannot write back to transferred register on thumb" });
+
+		opcode |= 0b11000000_00000000;
+		opcode |= registers.to_u16();
+		opcode |= (base as u16).wrapping_shl(0x8);
+	}
+
+	opcode |= u16::from(l).wrapping_shl(0xB);
+
+	Ok(opcode)
+}
+
+/// Encodes the address of an exclusive transfer.
+///
+/// Only word transfers take immediate offsets, for which `max` is the largest allowed offset.
+/// For all other transfers, it is null.
+fn encode_exclusive_address(address: Address, max: i32) -> Result<u32> {
+	let Address::ImmediateOffset { base, source, indexing: Indexing::Offset } = address else {
+		return Err(Error::IllegalInstruction { reason: "can only access exclusively with immediate offsets on thumb" });
+	};
+
+	assert_or_err!(base != Register::Pc, Error::IllegalRegister { reason: "cannot use pc as base on thumb" });
+
+	let source = if max == 0x0 {
+		assert_or_err!(source == 0x0, Error::IllegalImmediate { reason: "cannot encode offset for exclusive transfer on thumb" });
+		0x0
+	} else {
+		encode_word_offset(source, max)?
+	};
+
+	let mut opcode = 0b00000000_00000000_00000000_00000000_u32;
+	opcode |= u32::from(source);
+	opcode |= (base as u32).wrapping_shl(0x10);
+
+	Ok(opcode)
+}
+
+/// Encodes the status register of an exclusive store.
+///
+/// The status register must differ from both the base and the transferred registers.
+fn encode_exclusive_status(status: Register, registers: &[Register], address: Address) -> Result<u32> {
+	quick_assert!(not_sp_or_pc: status);
+
+	assert_or_err!(!registers.contains(&status) && address.base() != status, Error::IllegalRegister { reason: "status must differ from other registers on thumb" });
+
+	Ok(status as u32)
+}
+
+/// Encodes the least significant bit of a wide bitfield instruction.
+///
+/// This is split into `imm3` and `imm2`.
+#[inline(always)]
+#[must_use]
+const fn encode_bitfield_lsb(lsb: u32) -> u32 { (lsb & 0b11100) << 0xA | (lsb & 0b00011) << 0x6 }
+
+/// Adds the operands of a wide saturation.
+///
+/// The source may only be shifted by an immediate `LSL` or `ASR`, with the shift amount split like the least significant bit of a bitfield.
+fn add_saturate(mut opcode: u32, destination: Register, immediate: u32, source: Shifter) -> Result<u32> {
+	let (source, shift, sh) = match source {
+		Shifter::LogicalShiftLeftImmediate { source, shift } => {
+			assert_or_err!(shift <= 0x1F, Error::IllegalImmediate { reason: "shift must be in range (0) to (31) on thumb" });
+			(source, shift, false)
+		}
+
+		Shifter::ArithmeticShiftRightImmediate { source, shift } => {
+			assert_or_err!((0x1..=0x1F).contains(&shift), Error::IllegalImmediate { reason: "shift must be in range (1) to (31) on thumb" });
+			(source, shift, true)
+		}
+
+		_ => return Err(Error::IllegalShifter { reason: "saturations only take immediate lsl or asr shifts on thumb" }),
+	};
+
+	quick_assert!(not_sp_or_pc: destination);
+	quick_assert!(not_sp_or_pc: source);
+
+	opcode |= immediate;
+	opcode |= encode_bitfield_lsb(shift);
+	opcode |= (destination as u32).wrapping_shl(0x8);
+	opcode |= (source as u32).wrapping_shl(0x10);
+	opcode |= u32::from(sh).wrapping_shl(0x15);
+
+	Ok(opcode)
+}
+
+/// Splits a wide (Thumb-2) opcode into its two halfwords.
+#[inline(always)]
+#[must_use]
+const fn split_wide(opcode: u32) -> (u16, u16) { ((opcode >> 0x10) as u16, opcode as u16) }
+
+/// Converts the Arm encoding of an Advanced SIMD instruction to Thumb.
+///
+/// The unconditional data-processing instructions move their U bit from bit `24` to bit `28`, and the structure transfers take a new prefix.
+/// The remaining (conditional) instructions are encoded identically.
+fn convert_neon(opcode: u32) -> Result<u32> {
+	let opcode = match opcode >> 0x18 {
+		0b11110010 | 0b11110011 => {
+			let u = (opcode >> 0x18) & 0b1;
+
+			0b11101111_00000000_00000000_00000000 | u << 0x1C | opcode & 0b00000000_11111111_11111111_11111111
+		}
+
+		0b11110100 => 0b11111001_00000000_00000000_00000000 | opcode & 0b00000000_11111111_11111111_11111111,
+
+		_ => {
+			let predicate = Predicate::from_u8((opcode >> 0x1C) as u8).unwrap();
+			quick_assert!(predicate_always: predicate);
+
+			opcode
+		}
+	};
+
+	Ok(opcode)
+}
+
+/// Encodes an addition or subtraction.
+///
+/// Flag-setting instructions take low registers and immediates of up to (7), or up to (255) if the base is also the destination.
+/// Without the s flag, registers may be added if either operand is also the destination, and word-aligned immediates may be added to `sp` or `pc` or subtracted from `sp`.
+fn encode_add(subtract: bool, destination: Register, base: Register, source: Shifter, s: Sflag) -> Result<u16> {
+	let mut opcode = 0b00000000_00000000_u16;
+
+	match (source, s) {
+		(Shifter::Immediate(source), Sflag::On) => {
+			quick_assert!(low_register: destination);
+			quick_assert!(low_register: base);
+
+			if destination == base && source <= 0xFF {
+				opcode |= 0b00110000_00000000;
+				opcode |= source as u16;
+				opcode |= (destination as u16).wrapping_shl(0x8);
+				opcode |= u16::from(subtract).wrapping_shl(0xB);
+			} else {
+				assert_or_err!(source <= 0x7, Error::IllegalImmediate { reason: "cannot encode larger than (7) on thumb" });
+
+				opcode |= 0b00011100_00000000;
+				opcode |= destination as u16;
+				opcode |= (base as u16).wrapping_shl(0x3);
+				opcode |= (source as u16).wrapping_shl(0x6);
+				opcode |= u16::from(subtract).wrapping_shl(0x9);
+			}
+		}
+
+		(Shifter::Immediate(source), Sflag::Off) => {
+			if destination == Register::Sp && base == Register::Sp {
+				opcode |= 0b10110000_00000000;
+				opcode |= encode_word_offset(source as i32, 0x1FC)?;
+				opcode |= u16::from(subtract).wrapping_shl(0x7);
+			} else {
+				assert_or_err!(!subtract, Error::IllegalRegister { reason: "can only subtract immediates from sp without s flag on thumb" });
+				assert_or_err!(matches!(base, Register::Sp | Register::Pc), Error::IllegalRegister { reason: "can only add immediates to sp or pc without s flag on thumb" });
+
+				quick_assert!(low_register: destination);
+
+				opcode |= 0b10100000_00000000;
+				opcode |= encode_word_offset(source as i32, 0x3FC)?;
+				opcode |= (destination as u16).wrapping_shl(0x8);
+				opcode |= u16::from(base == Register::Sp).wrapping_shl(0xB);
+			}
+		}
+
+		(source, Sflag::On) => {
+			let source = source.as_register()?;
+
+			quick_assert!(low_register: destination);
+			quick_assert!(low_register: base);
+			quick_assert!(low_register: source);
+
+			opcode |= 0b00011000_00000000;
+			opcode |= destination as u16;
+			opcode |= (base as u16).wrapping_shl(0x3);
+			opcode |= (source as u16).wrapping_shl(0x6);
+			opcode |= u16::from(subtract).wrapping_shl(0x9);
+		}
+
+		(source, Sflag::Off) => {
+			let source = source.as_register()?;
+
+			assert_or_err!(!subtract, Error::IllegalRegister { reason: "can only subtract low registers with s flag on thumb" });
+
+			// The terms commute, so either one may double as
+			// the destination.
+			let (base, source) = if source == destination { (source, base) } else { (base, source) };
+			quick_assert!(base_equals_destination: base, destination);
+
+			opcode |= 0b01000100_00000000;
+			opcode |= destination as u16 & 0b00000000_00000111;
+			opcode |= (source as u16).wrapping_shl(0x3);
+			opcode |= (destination as u16 & 0b00000000_00001000).wrapping_shl(0x4);
+		}
+	}
+
+	Ok(opcode)
+}
+
+/// Encodes a word-aligned offset as its word count.
+#[inline]
+const fn encode_word_offset(offset: i32, max: i32) -> Result<u16> {
+	assert_or_err!(offset >= 0x0, Error::IllegalImmediate { reason: "cannot encode negative offsets on thumb" });
+	assert_or_err!(offset <= max, Error::IllegalImmediate { reason: "offset too large for thumb" });
+	assert_or_err!(offset % 0x4 == 0x0, Error::IllegalImmediate { reason: "offset must be word-aligned on thumb" });
+
+	Ok((offset / 0x4) as u16)
+}
+
 impl InstructionCodec {
 	/// Encodes the given Thumb instruction.
 	///
+	/// Narrow (16-bit) encodings are preferred.
+	/// If Thumb-2 is enabled (see [`set_thumb2`](InstructionCodec::set_thumb2)), instructions that have no narrow encoding for their operands fall back to a wide (32-bit) encoding.
+	/// Wide encodings are returned as two halfwords, the first of which is to be placed first.
+	///
+	/// Within if-then blocks, the predicate of the instruction must match the block, and it is then encoded as if unconditional.
+	/// As narrow encodings do not set flags there, instructions with the S flag on can only use wide encodings.
+	///
 	/// # Errors
 	///
 	/// If the given instruction cannot be encoded for Thumb, an error is returned.
-	pub fn encode_thumb(&mut self, instruction: Instruction) -> Result<(ThumbOpcode, Option<ThumbOpcode>)> {
+	/// This includes instructions that violate the current if-then block.
+	pub fn encode_thumb(&mut self, mut instruction: Instruction) -> Result<(ThumbOpcode, Option<ThumbOpcode>)> {
+		self.check_if_then(instruction)?;
+
+		let in_block = self.if_then_predicate().is_some();
+		if in_block { instruction.set_predicate(Predicate::Always) };
+
+		let narrow = if in_block {
+			self.encode_thumb_narrow_in_block(instruction)
+		} else {
+			self.encode_thumb_narrow(instruction)
+		};
+
+		let (opcode0, opcode1) = match narrow {
+			Err(error) if self.thumb2 => {
+				let Some(wide) = encode_thumb_wide(instruction, self.address.0)? else { return Err(error) };
+
+				let (opcode0, opcode1) = split_wide(wide);
+				(opcode0, Some(opcode1))
+			}
+
+			result => result?,
+		};
+
+		self.advance_if_then(instruction)?;
+
+		let opcode_count = 0x1 + u32::from(opcode1.is_some());
+		self.skip_halfwords(opcode_count);
+
+		Ok((opcode0.into(), opcode1.map(Into::into)))
+	}
+
+	/// Encodes the given sequence of Thumb instructions, inserting if-then blocks as needed.
+	///
+	/// Consecutive conditional instructions are grouped into blocks of up to four instructions, each predicated on the condition of the first or its inverse.
+	/// Branches are only placed last in blocks, and conditional branches that would start a block are instead encoded with their own condition.
+	/// Instructions within blocks given by the sequence itself (i.e. following an [`IfThen`](Instruction::IfThen) instruction) are left as they are.
+	///
+	/// The encoded halfwords are returned in order.
+	/// See [`encode_thumb`](InstructionCodec::encode_thumb) for how the instructions themselves are encoded.
+	///
+	/// # Errors
+	///
+	/// If any of the instructions cannot be encoded for Thumb, an error is returned.
+	pub fn encode_thumb_sequence(&mut self, instructions: &[Instruction]) -> Result<Vec<ThumbOpcode>> {
+		let mut opcodes = Vec::with_capacity(instructions.len());
+
+		for (index, &instruction) in instructions.iter().enumerate() {
+			let block = if self.if_then_predicate().is_none() {
+				group_if_then(&instructions[index..])
+			} else {
+				None
+			};
+
+			for instruction in block.into_iter().chain([instruction]) {
+				let (opcode0, opcode1) = self.encode_thumb(instruction)?;
+
+				opcodes.push(opcode0);
+				opcodes.extend(opcode1);
+			}
+		}
+
+		Ok(opcodes)
+	}
+
+	/// Encodes the given (unpredicated) Thumb instruction within an if-then block without falling back to Thumb-2.
+	///
+	/// Narrow encodings that set flags outside of blocks are used for their non-flag-setting counterparts.
+	fn encode_thumb_narrow_in_block(&self, instruction: Instruction) -> Result<(u16, Option<u16>)> {
+		let mut flag_setting = instruction;
+		let Some(s) = narrow_s_flag(&mut flag_setting) else { return self.encode_thumb_narrow(instruction) };
+
+		assert_or_err!(s.is_off(), Error::IllegalFlag { reason: "s flag must be off within if-then blocks on thumb" });
+		*s = Sflag::On;
+
+		self.encode_thumb_narrow(instruction).or_else(|_| self.encode_thumb_narrow(flag_setting))
+	}
+
+	/// Encodes the given Thumb instruction without falling back to Thumb-2.
+	///
+	/// Instructions that only exist as wide encodings are still encoded as such.
+	fn encode_thumb_narrow(&self, instruction: Instruction) -> Result<(u16, Option<u16>)> {
 		use Instruction::*;
 
 		let mut opcode = (0b00000000_00000000_u16, 0b00000000_00000000_u16);
 		let mut has_opcode1 = false;
 
 		match instruction {
-//			Add {
-//				predicate,
-//				destination,
-//				base,
-//				source,
-//				s,
-//			} => {
-//
-//			}
+			Add {
+				predicate,
+				destination,
+				base,
+				source,
+				s,
+			} => {
+				quick_assert!(predicate_always: predicate);
+
+				opcode.0 = encode_add(false, destination, base, source, s)?;
+			}
 
 			AddCarry {
 				predicate,
@@ -149,6 +653,49 @@ impl InstructionCodec {
 				opcode.0 |= (source as u16).wrapping_shl(0x3);
 			}
 
+			BitFieldClear {
+				predicate,
+				destination,
+				lsb,
+				width,
+			} => {
+				quick_assert!(predicate_always: predicate);
+				quick_assert!(not_sp_or_pc: destination);
+
+				check_bitfield(lsb, width)?;
+
+				let mut wide = 0b11110011_01101111_00000000_00000000_u32;
+				wide |= lsb + width - 0x1;
+				wide |= encode_bitfield_lsb(lsb);
+				wide |= (destination as u32).wrapping_shl(0x8);
+
+				opcode = split_wide(wide);
+				has_opcode1 = true;
+			}
+
+			BitFieldInsert {
+				predicate,
+				destination,
+				source,
+				lsb,
+				width,
+			} => {
+				quick_assert!(predicate_always: predicate);
+				quick_assert!(not_sp_or_pc: destination);
+				quick_assert!(not_sp_or_pc: source);
+
+				check_bitfield(lsb, width)?;
+
+				let mut wide = 0b11110011_01100000_00000000_00000000_u32;
+				wide |= lsb + width - 0x1;
+				wide |= encode_bitfield_lsb(lsb);
+				wide |= (destination as u32).wrapping_shl(0x8);
+				wide |= (source as u32).wrapping_shl(0x10);
+
+				opcode = split_wide(wide);
+				has_opcode1 = true;
+			}
+
 			Branch {
 				predicate,
 				immediate,
@@ -164,6 +711,9 @@ impl InstructionCodec {
 					opcode.0 |= 0b11100000_00000000;
 					opcode.0 |= ((offset & 0b00001111_11111111).wrapping_shr(0x1)) as u16;
 				} else {
+					assert_or_err!(offset >= -0x100, Error::IllegalImmediate { reason: "cannot encode offset larger than (-256) on thumb" });
+					assert_or_err!(offset <= 0xFE, Error::IllegalImmediate { reason: "cannot encode offset larger than (254) on thumb" });
+
 					opcode.0 |= 0b11010000_00000000;
 					opcode.0 |= (predicate as u16).wrapping_shl(0x8);
 					opcode.0 |= ((offset & 0b00000001_11111111).wrapping_shr(0x1)) as u16;
@@ -195,7 +745,7 @@ impl InstructionCodec {
 				let offset = offset as u32;
 
 				opcode.0 |= 0b11110000_00000000;
-				opcode.0 |= ((offset & 0b00000000_00111111_11110000_00000000).wrapping_shr(0xC)) as u16;
+				opcode.0 |= ((offset & 0b00000000_01111111_11110000_00000000).wrapping_shr(0xC)) as u16;
 
 				opcode.1 |= 0b11111000_00000000;
 				opcode.1 |= ((offset & 0b00000000_00000000_00001111_11111110).wrapping_shr(0x1)) as u16;
@@ -225,6 +775,131 @@ impl InstructionCodec {
 				opcode.0 |= immediate as u16;
 			}
 
+			ChangeProcessorMode { mode } => {
+				opcode = split_wide(0b11110011_10101111_10000001_00000000 | mode as u32);
+				has_opcode1 = true;
+			}
+
+			ClearExclusive => {
+				opcode = split_wide(0b11110011_10111111_10001111_00101111);
+				has_opcode1 = true;
+			}
+
+			Compare {
+				predicate,
+				lhs,
+				rhs,
+			} => {
+				quick_assert!(predicate_always: predicate);
+
+				if let Shifter::Immediate(rhs) = rhs {
+					quick_assert!(low_register: lhs);
+
+					assert_or_err!(rhs <= 0xFF, Error::IllegalImmediate { reason: "cannot encode larger than (255) on thumb" });
+
+					opcode.0 |= 0b00101000_00000000;
+					opcode.0 |= rhs as u16;
+					opcode.0 |= (lhs as u16).wrapping_shl(0x8);
+				} else {
+					let rhs = rhs.as_register()?;
+
+					if lhs.is_low() && rhs.is_low() {
+						opcode.0 |= 0b01000010_10000000;
+						opcode.0 |= lhs as u16;
+						opcode.0 |= (rhs as u16).wrapping_shl(0x3);
+					} else {
+						opcode.0 |= 0b01000101_00000000;
+						opcode.0 |= lhs as u16 & 0b00000000_00000111;
+						opcode.0 |= (rhs as u16).wrapping_shl(0x3);
+						opcode.0 |= (lhs as u16 & 0b00000000_00001000).wrapping_shl(0x4);
+					}
+				}
+			}
+
+			| CompareBranchNonZero { source, immediate }
+			| CompareBranchZero { source, immediate }
+			=> {
+				quick_assert!(low_register: source);
+
+				// These can only branch forwards.
+				let offset = (Wrapping(immediate) - self.address - Wrapping(0x4)).0 as i32;
+
+				assert_or_err!((0x0..=0x7E).contains(&offset), Error::IllegalImmediate { reason: "cannot encode offset outside (0) to (126) on thumb" });
+				assert_or_err!(offset % 0x2 == 0x0, Error::IllegalImmediate { reason: "cannot encode uneven offset on thumb" });
+
+				let offset = offset as u16;
+
+				opcode.0 |= 0b10110001_00000000;
+				opcode.0 |= source as u16;
+				opcode.0 |= (offset & 0b00111110).wrapping_shl(0x2);
+				opcode.0 |= (offset & 0b01000000).wrapping_shl(0x3);
+				opcode.0 |= u16::from(matches!(instruction, CompareBranchNonZero { .. })).wrapping_shl(0xB);
+			}
+
+			CompareNegated {
+				predicate,
+				lhs,
+				rhs,
+			} => {
+				let rhs = rhs.as_register()?;
+
+				quick_assert!(predicate_always: predicate);
+				quick_assert!(low_register: lhs);
+				quick_assert!(low_register: rhs);
+
+				opcode.0 |= 0b01000010_11000000;
+				opcode.0 |= lhs as u16;
+				opcode.0 |= (rhs as u16).wrapping_shl(0x3);
+			}
+
+			DisableInterrupts {
+				mask,
+				mode,
+			} => {
+				assert_or_err!(!mask.is_empty(), Error::IllegalInstruction { reason: "interrupt mask cannot be empty" });
+
+				if let Some(mode) = mode {
+					let mut wide = 0b11110011_10101111_10000111_00000000_u32;
+					wide |= u32::from(mask.to_u8()) << 0x5;
+					wide |= mode as u32;
+
+					opcode = split_wide(wide);
+					has_opcode1 = true;
+				} else {
+					opcode.0 |= 0b10110110_01110000;
+					opcode.0 |= u16::from(mask.to_u8());
+				}
+			}
+
+			EnableInterrupts {
+				mask,
+				mode,
+			} => {
+				assert_or_err!(!mask.is_empty(), Error::IllegalInstruction { reason: "interrupt mask cannot be empty" });
+
+				if let Some(mode) = mode {
+					let mut wide = 0b11110011_10101111_10000101_00000000_u32;
+					wide |= u32::from(mask.to_u8()) << 0x5;
+					wide |= mode as u32;
+
+					opcode = split_wide(wide);
+					has_opcode1 = true;
+				} else {
+					opcode.0 |= 0b10110110_01100000;
+					opcode.0 |= u16::from(mask.to_u8());
+				}
+			}
+
+			DataMemoryBarrier { option } => {
+				opcode = split_wide(0b11110011_10111111_10001111_01010000 | option as u32);
+				has_opcode1 = true;
+			}
+
+			DataSynchronisationBarrier { option } => {
+				opcode = split_wide(0b11110011_10111111_10001111_01000000 | option as u32);
+				has_opcode1 = true;
+			}
+
 			ExclusiveOr {
 				predicate,
 				destination,
@@ -245,6 +920,14 @@ impl InstructionCodec {
 				opcode.0 |= (source as u16).wrapping_shl(0x3);
 			}
 
+			IfThen {
+				predicate,
+				mask,
+			} => {
+				opcode.0 |= 0b10111111_00000000;
+				opcode.0 |= u16::from(encode_if_then_state(predicate, mask)?);
+			}
+
 			InclusiveOr {
 				predicate,
 				destination,
@@ -265,36 +948,199 @@ impl InstructionCodec {
 				opcode.0 |= (source as u16).wrapping_shl(0x3);
 			}
 
-			Move {
-				predicate,
-				destination,
-				source:      Shifter::ArithmeticShiftRightImmediate { source, shift },
-				s,
-			} => {
-					quick_assert!(predicate_always: predicate);
-					quick_assert!(source_equals_destination: source, destination);
-					quick_assert!(low_register: destination);
-					quick_assert!(low_register: source);
-					quick_assert!(s_flag_on: s);
+			InstructionSynchronisationBarrier { option } => {
+				opcode = split_wide(0b11110011_10111111_10001111_01100000 | option as u32);
+				has_opcode1 = true;
+			}
 
-					let shift = encode_shift_immediate(shift)?;
+			| InclusiveOrNot { .. }
+			| PreloadData { .. }
+			=> {
+				// These only have wide encodings.
+				let wide = encode_thumb_wide(instruction, self.address.0)?.ok_or(Error::IllegalInstruction { reason: "not supported on thumb" })?;
 
-					opcode.0 |= 0b00010000_00000000;
-					opcode.0 |= destination as u16;
-					opcode.0 |= (source as u16).wrapping_shl(0x3);
-					opcode.0 |= (shift as u16).wrapping_shl(0x6);
+				opcode = split_wide(wide);
+				has_opcode1 = true;
 			}
 
-			Move {
+			Load {
 				predicate,
-				destination,
-				source:      Shifter::ArithmeticShiftRightRegister { source, shift },
-				s,
+				register,
+				address,
+				b,
+				t,
 			} => {
-					quick_assert!(predicate_always: predicate);
+				quick_assert!(predicate_always: predicate);
+				assert_or_err!(t.is_off(), Error::IllegalFlag { reason: "t flag must be off on thumb" });
+
+				opcode.0 = encode_transfer(true, register, address, b)?;
+			}
+
+			LoadExclusive {
+				predicate,
+				register,
+				address,
+			} => {
+				quick_assert!(predicate_always: predicate);
+				quick_assert!(not_sp_or_pc: register);
+
+				let mut wide = 0b11101000_01010000_00001111_00000000_u32;
+				wide |= (register as u32).wrapping_shl(0xC);
+				wide |= encode_exclusive_address(address, 0x3FC)?;
+
+				opcode = split_wide(wide);
+				has_opcode1 = true;
+			}
+
+			LoadExclusiveByte {
+				predicate,
+				register,
+				address,
+			} => {
+				quick_assert!(predicate_always: predicate);
+				quick_assert!(not_sp_or_pc: register);
+
+				let mut wide = 0b11101000_11010000_00001111_01001111_u32;
+				wide |= (register as u32).wrapping_shl(0xC);
+				wide |= encode_exclusive_address(address, 0x0)?;
+
+				opcode = split_wide(wide);
+				has_opcode1 = true;
+			}
+
+			LoadExclusiveDoubleword {
+				predicate,
+				first,
+				second,
+				address,
+			} => {
+				quick_assert!(predicate_always: predicate);
+				quick_assert!(not_sp_or_pc: first);
+				quick_assert!(not_sp_or_pc: second);
+
+				assert_or_err!(first != second, Error::IllegalRegister { reason: "registers must differ on thumb" });
+
+				let mut wide = 0b11101000_11010000_00000000_01111111_u32;
+				wide |= (second as u32).wrapping_shl(0x8);
+				wide |= (first as u32).wrapping_shl(0xC);
+				wide |= encode_exclusive_address(address, 0x0)?;
+
+				opcode = split_wide(wide);
+				has_opcode1 = true;
+			}
+
+			LoadExclusiveHalfword {
+				predicate,
+				register,
+				address,
+			} => {
+				quick_assert!(predicate_always: predicate);
+				quick_assert!(not_sp_or_pc: register);
+
+				let mut wide = 0b11101000_11010000_00001111_01011111_u32;
+				wide |= (register as u32).wrapping_shl(0xC);
+				wide |= encode_exclusive_address(address, 0x0)?;
+
+				opcode = split_wide(wide);
+				has_opcode1 = true;
+			}
+
+			LoadHalfword {
+				predicate,
+				register,
+				address,
+			} => {
+				quick_assert!(predicate_always: predicate);
+
+				opcode.0 = encode_misc_transfer(register, address, 0b01011010_00000000, Some(0b10001000_00000000))?;
+			}
+
+			LoadMultiple {
+				predicate,
+				base,
+				registers,
+				mode,
+				w,
+				s,
+			} => {
+				quick_assert!(predicate_always: predicate);
+
+				opcode.0 = encode_block_transfer(true, base, registers, mode, w, s)?;
+			}
+
+			LoadSignedByte {
+				predicate,
+				register,
+				address,
+			} => {
+				quick_assert!(predicate_always: predicate);
+
+				opcode.0 = encode_misc_transfer(register, address, 0b01010110_00000000, None)?;
+			}
+
+			LoadSignedHalfword {
+				predicate,
+				register,
+				address,
+			} => {
+				quick_assert!(predicate_always: predicate);
+
+				opcode.0 = encode_misc_transfer(register, address, 0b01011110_00000000, None)?;
+			}
+
+			Move {
+				predicate,
+				destination,
+				source,
+				s:           Sflag::Off,
+			} => {
+				quick_assert!(predicate_always: predicate);
+
+				if let Ok(source) = source.as_register() {
+					opcode.0 |= 0b01000110_00000000;
+
+					let h0 = destination.is_high();
+					let h1 = source.is_high();
+
+					opcode.0 |= destination as u16 & 0b00000000_00000111;
+					opcode.0 |= (source as u16 & 0b00000000_00000111).wrapping_shl(0x3);
+					opcode.0 |= u16::from(h0).wrapping_shl(0x7);
+					opcode.0 |= u16::from(h1).wrapping_shl(0x6);
+				} else {
+					return Err(Error::IllegalShifter { reason: "can only encode registers with s flag off" });
+				}
+			}
+
+			Move {
+				predicate,
+				destination,
+				source:      Shifter::ArithmeticShiftRightImmediate { source, shift },
+				s,
+			} => {
+					quick_assert!(predicate_always: predicate);
+					quick_assert!(low_register: destination);
+					quick_assert!(low_register: source);
+					quick_assert!(s_flag_on: s);
+
+					let shift = encode_shift_immediate(shift)?;
+
+					opcode.0 |= 0b00010000_00000000;
+					opcode.0 |= destination as u16;
+					opcode.0 |= (source as u16).wrapping_shl(0x3);
+					opcode.0 |= (shift as u16).wrapping_shl(0x6);
+			}
+
+			Move {
+				predicate,
+				destination,
+				source:      Shifter::ArithmeticShiftRightRegister { source, shift },
+				s,
+			} => {
+					quick_assert!(predicate_always: predicate);
 					quick_assert!(source_equals_destination: source, destination);
 					quick_assert!(low_register: destination);
 					quick_assert!(low_register: source);
+					quick_assert!(low_register: shift);
 					quick_assert!(s_flag_on: s);
 
 					opcode.0 |= 0b01000001_00000000;
@@ -309,7 +1155,6 @@ impl InstructionCodec {
 				s,
 			} => {
 					quick_assert!(predicate_always: predicate);
-					quick_assert!(source_equals_destination: source, destination);
 					quick_assert!(low_register: destination);
 					quick_assert!(low_register: source);
 					quick_assert!(s_flag_on: s);
@@ -332,6 +1177,7 @@ impl InstructionCodec {
 					quick_assert!(source_equals_destination: source, destination);
 					quick_assert!(low_register: destination);
 					quick_assert!(low_register: source);
+					quick_assert!(low_register: shift);
 					quick_assert!(s_flag_on: s);
 
 					opcode.0 |= 0b01000000_10000000;
@@ -346,7 +1192,6 @@ impl InstructionCodec {
 				s,
 			} => {
 					quick_assert!(predicate_always: predicate);
-					quick_assert!(source_equals_destination: source, destination);
 					quick_assert!(low_register: destination);
 					quick_assert!(low_register: source);
 					quick_assert!(s_flag_on: s);
@@ -369,6 +1214,7 @@ impl InstructionCodec {
 					quick_assert!(source_equals_destination: source, destination);
 					quick_assert!(low_register: destination);
 					quick_assert!(low_register: source);
+					quick_assert!(low_register: shift);
 					quick_assert!(s_flag_on: s);
 
 					opcode.0 |= 0b01000000_11000000;
@@ -386,6 +1232,7 @@ impl InstructionCodec {
 				quick_assert!(source_equals_destination: source, destination);
 				quick_assert!(low_register: destination);
 				quick_assert!(low_register: source);
+				quick_assert!(low_register: shift);
 				quick_assert!(s_flag_on: s);
 
 				opcode.0 |= 0b01000001_11000000;
@@ -393,29 +1240,6 @@ impl InstructionCodec {
 				opcode.0 |= (shift as u16).wrapping_shl(0x3);
 			}
 
-			Move {
-				predicate,
-				destination,
-				source,
-				s:           Sflag::Off,
-			} => {
-				quick_assert!(predicate_always: predicate);
-
-				if let Ok(source) = source.as_register() {
-					opcode.0 |= 0b01000110_00000000;
-
-					let h0 = destination.is_high();
-					let h1 = source.is_high();
-
-					opcode.0 |= destination as u16 & 0b00000000_00000111;
-					opcode.0 |= (source as u16 & 0b00000000_00000111).wrapping_shl(0x3);
-					opcode.0 |= u16::from(h0).wrapping_shl(0x6);
-					opcode.0 |= u16::from(h1).wrapping_shl(0x7);
-				} else {
-					return Err(Error::IllegalShifter { reason: "can only encode registers with s flag off" });
-				}
-			}
-
 			Move {
 				predicate,
 				destination,
@@ -462,13 +1286,99 @@ impl InstructionCodec {
 				opcode.0 |= (source as u16).wrapping_shl(0x3);
 			}
 
-			Multiply {
+			MoveFromStatusRegister {
 				predicate,
 				destination,
-				base,
 				source,
+			} => {
+				quick_assert!(predicate_always: predicate);
+				quick_assert!(not_sp_or_pc: destination);
+
+				let mut wide = 0b11110011_11101111_10000000_00000000_u32;
+				wide |= (destination as u32).wrapping_shl(0x8);
+				wide |= u32::from(source.is_saved()).wrapping_shl(0x14);
+
+				opcode = split_wide(wide);
+				has_opcode1 = true;
+			}
+
+			MoveToStatusRegister {
+				predicate,
+				destination,
+				fields,
+				source,
+			} => {
+				quick_assert!(predicate_always: predicate);
+
+				assert_or_err!(!fields.is_empty(), Error::IllegalInstruction { reason: "cannot write status register without fields on thumb" });
+
+				let source = source.as_register()
+					.map_err(|_| Error::IllegalShifter { reason: "status register source must be register on thumb" })?;
+
+				quick_assert!(not_sp_or_pc: source);
+
+				let mut wide = 0b11110011_10000000_10000000_00000000_u32;
+				wide |= u32::from(fields.to_u8()).wrapping_shl(0x8);
+				wide |= (source as u32).wrapping_shl(0x10);
+				wide |= u32::from(destination.is_saved()).wrapping_shl(0x14);
+
+				opcode = split_wide(wide);
+				has_opcode1 = true;
+			}
+
+			MoveTop {
+				predicate,
+				destination,
+				immediate,
+			} => {
+				quick_assert!(predicate_always: predicate);
+				quick_assert!(not_sp_or_pc: destination);
+
+				assert_or_err!(immediate <= 0xFFFF, Error::IllegalImmediate { reason: "cannot encode larger than (65535) on thumb" });
+
+				let mut wide = 0b11110010_11000000_00000000_00000000_u32;
+				wide |= immediate & 0b00000000_00000000_00000000_11111111;
+				wide |= (destination as u32).wrapping_shl(0x8);
+				wide |= (immediate & 0b00000000_00000000_00000111_00000000).wrapping_shl(0x4);
+				wide |= (immediate & 0b00000000_00000000_11110000_00000000).wrapping_shl(0x4);
+				wide |= (immediate & 0b00000000_00000000_00001000_00000000).wrapping_shl(0xF);
+
+				opcode = split_wide(wide);
+				has_opcode1 = true;
+			}
+
+			MoveWide {
+				predicate,
+				destination,
+				immediate,
+			} => {
+				quick_assert!(predicate_always: predicate);
+				quick_assert!(not_sp_or_pc: destination);
+
+				assert_or_err!(immediate <= 0xFFFF, Error::IllegalImmediate { reason: "cannot encode larger than (65535) on thumb" });
+
+				let mut wide = 0b11110010_01000000_00000000_00000000_u32;
+				wide |= immediate & 0b00000000_00000000_00000000_11111111;
+				wide |= (destination as u32).wrapping_shl(0x8);
+				wide |= (immediate & 0b00000000_00000000_00000111_00000000).wrapping_shl(0x4);
+				wide |= (immediate & 0b00000000_00000000_11110000_00000000).wrapping_shl(0x4);
+				wide |= (immediate & 0b00000000_00000000_00001000_00000000).wrapping_shl(0xF);
+
+				opcode = split_wide(wide);
+				has_opcode1 = true;
+			}
+
+			Multiply {
+				predicate,
+				destination,
+				mut base,
+				mut source,
 				s,
 			} => {
+				// The factors commute, so either one may double
+				// as the destination.
+				if source == destination { (base, source) = (source, base) };
+
 				quick_assert!(predicate_always: predicate);
 				quick_assert!(base_equals_destination: base, destination);
 				quick_assert!(low_register: destination);
@@ -480,44 +1390,724 @@ impl InstructionCodec {
 				opcode.0 |= (source as u16).wrapping_shl(0x3);
 			}
 
-			SoftwareInterrupt {
+			NoOperation { predicate } => {
+				quick_assert!(predicate_always: predicate);
+
+				opcode.0 |= 0b10111111_00000000;
+			}
+
+			PackHalfword {
 				predicate,
-				immediate,
+				destination,
+				base,
+				source,
 			} => {
 				quick_assert!(predicate_always: predicate);
+				quick_assert!(not_sp_or_pc: destination);
+				quick_assert!(not_sp_or_pc: base);
 
-				assert_or_err!(immediate <= 0xFF, Error::IllegalImmediate { reason: "cannot encode larger than (255) on thumb" });
+				let (source, tb, shift) = match source {
+					Shifter::LogicalShiftLeftImmediate { source, shift: shift @ 0x0..=0x1F } => (source, false, shift),
 
-				opcode.0 |= 0b11011111_00000000;
-				opcode.0 |= immediate as u16;
+					Shifter::ArithmeticShiftRightImmediate { source, shift: shift @ 0x1..=0x20 } => (source, true, shift & 0b11111),
+
+					_ => return Err(Error::IllegalShifter { reason: "can only pack with left shifts up to (31) or arithmetic right shifts up to (32) on thumb" }),
+				};
+
+				quick_assert!(not_sp_or_pc: source);
+
+				let mut wide = 0b11101010_11000000_00000000_00000000_u32;
+				wide |= source as u32;
+				wide |= u32::from(tb).wrapping_shl(0x5);
+				wide |= encode_bitfield_lsb(shift);
+				wide |= (destination as u32).wrapping_shl(0x8);
+				wide |= (base as u32).wrapping_shl(0x10);
+
+				opcode = split_wide(wide);
+				has_opcode1 = true;
 			}
 
-			SubtractCarry {
+			ParallelArithmetic {
 				predicate,
+				prefix,
+				operation,
 				destination,
 				base,
 				source,
-				s,
 			} => {
-				let source = source.as_register()?;
+				quick_assert!(predicate_always: predicate);
+				quick_assert!(not_sp_or_pc: destination);
+				quick_assert!(not_sp_or_pc: base);
+				quick_assert!(not_sp_or_pc: source);
+
+				// The operations are numbered differently from
+				// Arm, where the prefix is also placed elsewhere.
+				let operation = match operation {
+					ParallelOperation::Add8                => 0b000_u32,
+					ParallelOperation::Add16               => 0b001,
+					ParallelOperation::AddSubtractExchange => 0b010,
+					ParallelOperation::Subtract8           => 0b100,
+					ParallelOperation::Subtract16          => 0b101,
+					ParallelOperation::SubtractAddExchange => 0b110,
+				};
+
+				// The lower bits of the prefix are decremented.
+				let prefix = prefix as u32;
+				let prefix = prefix & 0b100 | ((prefix & 0b011) - 0x1);
+
+				let mut wide = 0b11111010_10000000_11110000_00000000_u32;
+				wide |= source as u32;
+				wide |= prefix.wrapping_shl(0x4);
+				wide |= (destination as u32).wrapping_shl(0x8);
+				wide |= (base as u32).wrapping_shl(0x10);
+				wide |= operation.wrapping_shl(0x14);
+
+				opcode = split_wide(wide);
+				has_opcode1 = true;
+			}
 
+			Reverse {
+				predicate,
+				destination,
+				source,
+			} => {
 				quick_assert!(predicate_always: predicate);
-				quick_assert!(base_equals_destination: base, destination);
 				quick_assert!(low_register: destination);
 				quick_assert!(low_register: source);
-				quick_assert!(s_flag_on: s);
 
-				opcode.0 |= 0b01000000_11000000;
+				opcode.0 |= 0b10111010_00000000;
 				opcode.0 |= destination as u16;
 				opcode.0 |= (source as u16).wrapping_shl(0x3);
 			}
 
-			_ => return Err(Error::IllegalInstruction { reason: "not supported on thumb" } ),
-		}
+			ReverseBits {
+				predicate,
+				destination,
+				source,
+			} => {
+				quick_assert!(predicate_always: predicate);
+				quick_assert!(not_sp_or_pc: destination);
+				quick_assert!(not_sp_or_pc: source);
 
-		let opcode_count = 0x1 + u32::from(has_opcode1);
-		self.skip_halfwords(opcode_count);
+				// The source register is encoded twice.
+				let mut wide = 0b11111010_10010000_11110000_10100000_u32;
+				wide |= source as u32;
+				wide |= (destination as u32).wrapping_shl(0x8);
+				wide |= (source as u32).wrapping_shl(0x10);
+
+				opcode = split_wide(wide);
+				has_opcode1 = true;
+			}
+
+			ReversePackedHalfword {
+				predicate,
+				destination,
+				source,
+			} => {
+				quick_assert!(predicate_always: predicate);
+				quick_assert!(low_register: destination);
+				quick_assert!(low_register: source);
+
+				opcode.0 |= 0b10111010_01000000;
+				opcode.0 |= destination as u16;
+				opcode.0 |= (source as u16).wrapping_shl(0x3);
+			}
+
+			ReverseSignedHalfword {
+				predicate,
+				destination,
+				source,
+			} => {
+				quick_assert!(predicate_always: predicate);
+				quick_assert!(low_register: destination);
+				quick_assert!(low_register: source);
+
+				opcode.0 |= 0b10111010_11000000;
+				opcode.0 |= destination as u16;
+				opcode.0 |= (source as u16).wrapping_shl(0x3);
+			}
+
+			ReverseSubtract {
+				predicate,
+				destination,
+				base,
+				source,
+				s,
+			} => {
+				quick_assert!(predicate_always: predicate);
+				quick_assert!(low_register: destination);
+				quick_assert!(low_register: base);
+				quick_assert!(s_flag_on: s);
+
+				assert_or_err!(source == Shifter::Immediate(0x0), Error::IllegalShifter { reason: "can only subtract from null on thumb" });
+
+				opcode.0 |= 0b01000010_01000000;
+				opcode.0 |= destination as u16;
+				opcode.0 |= (base as u16).wrapping_shl(0x3);
+			}
+
+			SaturatingAdd {
+				predicate,
+				destination,
+				base,
+				source,
+			} => {
+				quick_assert!(predicate_always: predicate);
+				quick_assert!(not_sp_or_pc: destination);
+				quick_assert!(not_sp_or_pc: base);
+				quick_assert!(not_sp_or_pc: source);
+
+				let mut wide = 0b11111010_10000000_11110000_10000000_u32;
+				wide |= base as u32;
+				wide |= (destination as u32).wrapping_shl(0x8);
+				wide |= (source as u32).wrapping_shl(0x10);
+
+				opcode = split_wide(wide);
+				has_opcode1 = true;
+			}
+
+			SaturatingDoubleAdd {
+				predicate,
+				destination,
+				base,
+				source,
+			} => {
+				quick_assert!(predicate_always: predicate);
+				quick_assert!(not_sp_or_pc: destination);
+				quick_assert!(not_sp_or_pc: base);
+				quick_assert!(not_sp_or_pc: source);
+
+				let mut wide = 0b11111010_10000000_11110000_10010000_u32;
+				wide |= base as u32;
+				wide |= (destination as u32).wrapping_shl(0x8);
+				wide |= (source as u32).wrapping_shl(0x10);
+
+				opcode = split_wide(wide);
+				has_opcode1 = true;
+			}
+
+			SaturatingDoubleSubtract {
+				predicate,
+				destination,
+				base,
+				source,
+			} => {
+				quick_assert!(predicate_always: predicate);
+				quick_assert!(not_sp_or_pc: destination);
+				quick_assert!(not_sp_or_pc: base);
+				quick_assert!(not_sp_or_pc: source);
+
+				let mut wide = 0b11111010_10000000_11110000_10110000_u32;
+				wide |= base as u32;
+				wide |= (destination as u32).wrapping_shl(0x8);
+				wide |= (source as u32).wrapping_shl(0x10);
+
+				opcode = split_wide(wide);
+				has_opcode1 = true;
+			}
+
+			SaturatingSubtract {
+				predicate,
+				destination,
+				base,
+				source,
+			} => {
+				quick_assert!(predicate_always: predicate);
+				quick_assert!(not_sp_or_pc: destination);
+				quick_assert!(not_sp_or_pc: base);
+				quick_assert!(not_sp_or_pc: source);
+
+				let mut wide = 0b11111010_10000000_11110000_10100000_u32;
+				wide |= base as u32;
+				wide |= (destination as u32).wrapping_shl(0x8);
+				wide |= (source as u32).wrapping_shl(0x10);
+
+				opcode = split_wide(wide);
+				has_opcode1 = true;
+			}
+
+			Select {
+				predicate,
+				destination,
+				base,
+				source,
+			} => {
+				quick_assert!(predicate_always: predicate);
+				quick_assert!(not_sp_or_pc: destination);
+				quick_assert!(not_sp_or_pc: base);
+				quick_assert!(not_sp_or_pc: source);
+
+				let mut wide = 0b11111010_10100000_11110000_10000000_u32;
+				wide |= source as u32;
+				wide |= (destination as u32).wrapping_shl(0x8);
+				wide |= (base as u32).wrapping_shl(0x10);
+
+				opcode = split_wide(wide);
+				has_opcode1 = true;
+			}
+
+			SendEvent { predicate } => {
+				quick_assert!(predicate_always: predicate);
+
+				opcode.0 |= 0b10111111_01000000;
+			}
+
+			SetEndianness { endianness } => {
+				opcode.0 |= 0b10110110_01010000;
+				opcode.0 |= (endianness as u16) << 0x3;
+			}
+
+			| SignExtendByte { predicate, destination, source, rotation }
+			| SignExtendHalfword { predicate, destination, source, rotation }
+			| ZeroExtendByte { predicate, destination, source, rotation }
+			| ZeroExtendHalfword { predicate, destination, source, rotation }
+			=> {
+				quick_assert!(predicate_always: predicate);
+				quick_assert!(low_register: destination);
+				quick_assert!(low_register: source);
+
+				assert_or_err!(rotation == 0x0, Error::IllegalImmediate { reason: "cannot encode rotation on thumb" });
+
+				opcode.0 |= match instruction {
+					SignExtendHalfword { .. } => 0b10110010_00000000,
+					SignExtendByte { .. }     => 0b10110010_01000000,
+					ZeroExtendHalfword { .. } => 0b10110010_10000000,
+					_                         => 0b10110010_11000000,
+				};
+
+				opcode.0 |= destination as u16;
+				opcode.0 |= (source as u16).wrapping_shl(0x3);
+			}
+
+			SignedBitFieldExtract {
+				predicate,
+				destination,
+				source,
+				lsb,
+				width,
+			} => {
+				quick_assert!(predicate_always: predicate);
+				quick_assert!(not_sp_or_pc: destination);
+				quick_assert!(not_sp_or_pc: source);
+
+				check_bitfield(lsb, width)?;
+
+				let mut wide = 0b11110011_01000000_00000000_00000000_u32;
+				wide |= width - 0x1;
+				wide |= encode_bitfield_lsb(lsb);
+				wide |= (destination as u32).wrapping_shl(0x8);
+				wide |= (source as u32).wrapping_shl(0x10);
+
+				opcode = split_wide(wide);
+				has_opcode1 = true;
+			}
+
+			SignedDivide {
+				predicate,
+				destination,
+				base,
+				source,
+			} => {
+				quick_assert!(predicate_always: predicate);
+				quick_assert!(not_sp_or_pc: destination);
+				quick_assert!(not_sp_or_pc: base);
+				quick_assert!(not_sp_or_pc: source);
+
+				let mut wide = 0b11111011_10010000_11110000_11110000_u32;
+				wide |= source as u32;
+				wide |= (destination as u32).wrapping_shl(0x8);
+				wide |= (base as u32).wrapping_shl(0x10);
+
+				opcode = split_wide(wide);
+				has_opcode1 = true;
+			}
+
+			SignedSaturate {
+				predicate,
+				destination,
+				immediate,
+				source,
+			} => {
+				quick_assert!(predicate_always: predicate);
+
+				assert_or_err!((0x1..=0x20).contains(&immediate), Error::IllegalImmediate { reason: "saturation must be in range (1) to (32) on thumb" });
+
+				let wide = add_saturate(0b11110011_00000000_00000000_00000000, destination, immediate - 0x1, source)?;
+
+				opcode = split_wide(wide);
+				has_opcode1 = true;
+			}
+
+			SignedSaturate16 {
+				predicate,
+				destination,
+				immediate,
+				source,
+			} => {
+				quick_assert!(predicate_always: predicate);
+				quick_assert!(not_sp_or_pc: destination);
+				quick_assert!(not_sp_or_pc: source);
+
+				assert_or_err!((0x1..=0x10).contains(&immediate), Error::IllegalImmediate { reason: "saturation must be in range (1) to (16) on thumb" });
+
+				let mut wide = 0b11110011_00100000_00000000_00000000_u32;
+				wide |= immediate - 0x1;
+				wide |= (destination as u32).wrapping_shl(0x8);
+				wide |= (source as u32).wrapping_shl(0x10);
+
+				opcode = split_wide(wide);
+				has_opcode1 = true;
+			}
+
+			SoftwareInterrupt {
+				predicate,
+				immediate,
+			} => {
+				quick_assert!(predicate_always: predicate);
+
+				assert_or_err!(immediate <= 0xFF, Error::IllegalImmediate { reason: "cannot encode larger than (255) on thumb" });
+
+				opcode.0 |= 0b11011111_00000000;
+				opcode.0 |= immediate as u16;
+			}
+
+			Store {
+				predicate,
+				register,
+				address,
+				b,
+				t,
+			} => {
+				quick_assert!(predicate_always: predicate);
+				assert_or_err!(t.is_off(), Error::IllegalFlag { reason: "t flag must be off on thumb" });
+
+				opcode.0 = encode_transfer(false, register, address, b)?;
+			}
+
+			StoreExclusive {
+				predicate,
+				status,
+				register,
+				address,
+			} => {
+				quick_assert!(predicate_always: predicate);
+				quick_assert!(not_sp_or_pc: register);
+
+				let mut wide = 0b11101000_01000000_00000000_00000000_u32;
+				wide |= encode_exclusive_status(status, &[register], address)?.wrapping_shl(0x8);
+				wide |= (register as u32).wrapping_shl(0xC);
+				wide |= encode_exclusive_address(address, 0x3FC)?;
+
+				opcode = split_wide(wide);
+				has_opcode1 = true;
+			}
+
+			StoreExclusiveByte {
+				predicate,
+				status,
+				register,
+				address,
+			} => {
+				quick_assert!(predicate_always: predicate);
+				quick_assert!(not_sp_or_pc: register);
+
+				let mut wide = 0b11101000_11000000_00001111_01000000_u32;
+				wide |= encode_exclusive_status(status, &[register], address)?;
+				wide |= (register as u32).wrapping_shl(0xC);
+				wide |= encode_exclusive_address(address, 0x0)?;
+
+				opcode = split_wide(wide);
+				has_opcode1 = true;
+			}
+
+			StoreExclusiveDoubleword {
+				predicate,
+				status,
+				first,
+				second,
+				address,
+			} => {
+				quick_assert!(predicate_always: predicate);
+				quick_assert!(not_sp_or_pc: first);
+				quick_assert!(not_sp_or_pc: second);
+
+				let mut wide = 0b11101000_11000000_00000000_01110000_u32;
+				wide |= encode_exclusive_status(status, &[first, second], address)?;
+				wide |= (second as u32).wrapping_shl(0x8);
+				wide |= (first as u32).wrapping_shl(0xC);
+				wide |= encode_exclusive_address(address, 0x0)?;
+
+				opcode = split_wide(wide);
+				has_opcode1 = true;
+			}
+
+			StoreExclusiveHalfword {
+				predicate,
+				status,
+				register,
+				address,
+			} => {
+				quick_assert!(predicate_always: predicate);
+				quick_assert!(not_sp_or_pc: register);
+
+				let mut wide = 0b11101000_11000000_00001111_01010000_u32;
+				wide |= encode_exclusive_status(status, &[register], address)?;
+				wide |= (register as u32).wrapping_shl(0xC);
+				wide |= encode_exclusive_address(address, 0x0)?;
+
+				opcode = split_wide(wide);
+				has_opcode1 = true;
+			}
+
+			StoreHalfword {
+				predicate,
+				register,
+				address,
+			} => {
+				quick_assert!(predicate_always: predicate);
+
+				opcode.0 = encode_misc_transfer(register, address, 0b01010010_00000000, Some(0b10000000_00000000))?;
+			}
+
+			StoreMultiple {
+				predicate,
+				base,
+				registers,
+				mode,
+				w,
+				s,
+			} => {
+				quick_assert!(predicate_always: predicate);
+
+				opcode.0 = encode_block_transfer(false, base, registers, mode, w, s)?;
+			}
+
+			Subtract {
+				predicate,
+				destination,
+				base,
+				source,
+				s,
+			} => {
+				quick_assert!(predicate_always: predicate);
+
+				opcode.0 = encode_add(true, destination, base, source, s)?;
+			}
+
+			SubtractCarry {
+				predicate,
+				destination,
+				base,
+				source,
+				s,
+			} => {
+				let source = source.as_register()?;
+
+				quick_assert!(predicate_always: predicate);
+				quick_assert!(base_equals_destination: base, destination);
+				quick_assert!(low_register: destination);
+				quick_assert!(low_register: source);
+				quick_assert!(s_flag_on: s);
+
+				opcode.0 |= 0b01000001_10000000;
+				opcode.0 |= destination as u16;
+				opcode.0 |= (source as u16).wrapping_shl(0x3);
+			}
+
+			| TableBranchByte { predicate, base, index }
+			| TableBranchHalfword { predicate, base, index }
+			=> {
+				quick_assert!(predicate_always: predicate);
+				quick_assert!(not_sp_or_pc: index);
+
+				assert_or_err!(base != Register::Sp, Error::IllegalRegister { reason: "cannot use sp as table on thumb" });
+
+				let mut wide = 0b11101000_11010000_11110000_00000000_u32;
+				wide |= index as u32;
+				wide |= u32::from(matches!(instruction, TableBranchHalfword { .. })).wrapping_shl(0x4);
+				wide |= (base as u32).wrapping_shl(0x10);
+
+				opcode = split_wide(wide);
+				has_opcode1 = true;
+			}
+
+			Test {
+				predicate,
+				lhs,
+				rhs,
+			} => {
+				let rhs = rhs.as_register()?;
+
+				quick_assert!(predicate_always: predicate);
+				quick_assert!(low_register: lhs);
+				quick_assert!(low_register: rhs);
+
+				opcode.0 |= 0b01000010_00000000;
+				opcode.0 |= lhs as u16;
+				opcode.0 |= (rhs as u16).wrapping_shl(0x3);
+			}
+
+			UnsignedBitFieldExtract {
+				predicate,
+				destination,
+				source,
+				lsb,
+				width,
+			} => {
+				quick_assert!(predicate_always: predicate);
+				quick_assert!(not_sp_or_pc: destination);
+				quick_assert!(not_sp_or_pc: source);
+
+				check_bitfield(lsb, width)?;
+
+				let mut wide = 0b11110011_11000000_00000000_00000000_u32;
+				wide |= width - 0x1;
+				wide |= encode_bitfield_lsb(lsb);
+				wide |= (destination as u32).wrapping_shl(0x8);
+				wide |= (source as u32).wrapping_shl(0x10);
+
+				opcode = split_wide(wide);
+				has_opcode1 = true;
+			}
+
+			UnsignedDivide {
+				predicate,
+				destination,
+				base,
+				source,
+			} => {
+				quick_assert!(predicate_always: predicate);
+				quick_assert!(not_sp_or_pc: destination);
+				quick_assert!(not_sp_or_pc: base);
+				quick_assert!(not_sp_or_pc: source);
+
+				let mut wide = 0b11111011_10110000_11110000_11110000_u32;
+				wide |= source as u32;
+				wide |= (destination as u32).wrapping_shl(0x8);
+				wide |= (base as u32).wrapping_shl(0x10);
+
+				opcode = split_wide(wide);
+				has_opcode1 = true;
+			}
+
+			UnsignedSaturate {
+				predicate,
+				destination,
+				immediate,
+				source,
+			} => {
+				quick_assert!(predicate_always: predicate);
+
+				assert_or_err!(immediate <= 0x1F, Error::IllegalImmediate { reason: "saturation must be in range (0) to (31) on thumb" });
+
+				let wide = add_saturate(0b11110011_10000000_00000000_00000000, destination, immediate, source)?;
+
+				opcode = split_wide(wide);
+				has_opcode1 = true;
+			}
+
+			UnsignedSaturate16 {
+				predicate,
+				destination,
+				immediate,
+				source,
+			} => {
+				quick_assert!(predicate_always: predicate);
+				quick_assert!(not_sp_or_pc: destination);
+				quick_assert!(not_sp_or_pc: source);
+
+				assert_or_err!(immediate <= 0xF, Error::IllegalImmediate { reason: "saturation must be in range (0) to (15) on thumb" });
+
+				let mut wide = 0b11110011_10100000_00000000_00000000_u32;
+				wide |= immediate;
+				wide |= (destination as u32).wrapping_shl(0x8);
+				wide |= (source as u32).wrapping_shl(0x10);
+
+				opcode = split_wide(wide);
+				has_opcode1 = true;
+			}
+
+			UnsignedSumAbsoluteDifferences {
+				predicate,
+				destination,
+				base,
+				source,
+			} => {
+				quick_assert!(predicate_always: predicate);
+				quick_assert!(not_sp_or_pc: destination);
+				quick_assert!(not_sp_or_pc: base);
+				quick_assert!(not_sp_or_pc: source);
+
+				let mut wide = 0b11111011_01110000_11110000_00000000_u32;
+				wide |= source as u32;
+				wide |= (destination as u32).wrapping_shl(0x8);
+				wide |= (base as u32).wrapping_shl(0x10);
+
+				opcode = split_wide(wide);
+				has_opcode1 = true;
+			}
+
+			UnsignedSumAbsoluteDifferencesAccumulate {
+				predicate,
+				destination,
+				base,
+				source,
+				accumulator,
+			} => {
+				quick_assert!(predicate_always: predicate);
+				quick_assert!(not_sp_or_pc: destination);
+				quick_assert!(not_sp_or_pc: base);
+				quick_assert!(not_sp_or_pc: source);
+				quick_assert!(not_sp_or_pc: accumulator);
+
+				let mut wide = 0b11111011_01110000_00000000_00000000_u32;
+				wide |= source as u32;
+				wide |= (destination as u32).wrapping_shl(0x8);
+				wide |= (accumulator as u32).wrapping_shl(0xC);
+				wide |= (base as u32).wrapping_shl(0x10);
+
+				opcode = split_wide(wide);
+				has_opcode1 = true;
+			}
+
+			WaitForEvent { predicate } => {
+				quick_assert!(predicate_always: predicate);
+
+				opcode.0 |= 0b10111111_00100000;
+			}
+
+			WaitForInterrupt { predicate } => {
+				quick_assert!(predicate_always: predicate);
+
+				opcode.0 |= 0b10111111_00110000;
+			}
+
+			Yield { predicate } => {
+				quick_assert!(predicate_always: predicate);
+
+				opcode.0 |= 0b10111111_00010000;
+			}
+
+			instruction => {
+				let wide = if let Some((predicate, vfp_opcode)) = encode_vfp(instruction)? {
+					quick_assert!(predicate_always: predicate);
+
+					vfp_opcode | 0b11100000_00000000_00000000_00000000
+				} else if let Some((predicate, coprocessor_opcode)) = encode_coprocessor(instruction)? {
+					quick_assert!(predicate_always: predicate);
+
+					coprocessor_opcode | 0b11100000_00000000_00000000_00000000
+				} else if let Some(neon_opcode) = encode_neon(instruction)? {
+					convert_neon(neon_opcode)?
+				} else {
+					return Err(Error::IllegalInstruction { reason: "not supported on thumb" } );
+				};
+
+				opcode = split_wide(wide);
+				has_opcode1 = true;
+			}
+		}
 
-		Ok((opcode.0.into(), has_opcode1.then_some(opcode.1.into())))
+		Ok((opcode.0, has_opcode1.then_some(opcode.1)))
 	}
 }
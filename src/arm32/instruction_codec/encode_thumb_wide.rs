@@ -0,0 +1,756 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Pollex.
+//
+// Pollex is free software: you can redistribute it
+// and/or modify it under the terms of the GNU Af-
+// fero General Public License as published by the
+// Free Software Foundation, either version 3 of
+// the License, or (at your option) any later ver-
+// sion.
+//
+// Pollex is distributed in the hope that it will
+// be useful, but WITHOUT ANY WARRANTY; without
+// even the implied warranty of MERCHANTABILITY or
+// FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// Affero General Public License for more details.
+//
+// You should have received a copy of the GNU Af-
+// fero General Public License along with Pollex.
+// If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{assert_or_err, Error, Result};
+use crate::arm32::{
+	Address,
+	BlockMode,
+	Indexing,
+	Instruction,
+	Predicate,
+	Register,
+	RegisterList,
+	Sflag,
+	Shifter,
+	Sign,
+	Tflag,
+	Wflag,
+};
+
+/// Checks that none of the given registers is `sp` or `pc`.
+fn check_not_sp_or_pc(registers: &[Register]) -> Result<()> {
+	assert_or_err!(!registers.iter().any(|register| matches!(register, Register::Sp | Register::Pc)), Error::IllegalRegister { reason: "cannot encode sp or pc on thumb" });
+
+	Ok(())
+}
+
+/// Checks that the predicate is `AL`.
+///
+/// Only branches are conditional outside of `IT` blocks.
+fn check_predicate(predicate: Predicate) -> Result<()> {
+	assert_or_err!(predicate == Predicate::Always, Error::IllegalPredicate { reason: "must be `AL` on thumb" });
+
+	Ok(())
+}
+
+/// Adds the source operand of a data-processing instruction.
+///
+/// Immediates use the modified-immediate encoding, and registers use the shifted-register encoding.
+/// Register-shifted registers are only encodable by moves.
+fn add_operand(mut opcode: u32, source: Shifter) -> Result<u32> {
+	use Shifter::*;
+
+	let (source, code, shift) = match source {
+		Immediate(source) => {
+			let source = Shifter::encode_thumb_immediate(source)
+				.ok_or(Error::IllegalImmediate { reason: "cannot encode as modified immediate on thumb" })?;
+
+			opcode |= 0b11110000_00000000_00000000_00000000;
+			opcode |= source & 0b00000000_11111111;
+			opcode |= (source & 0b00000111_00000000) << 0x4;
+			opcode |= (source & 0b00001000_00000000) << 0xF;
+
+			return Ok(opcode);
+		}
+
+		LogicalShiftLeftImmediate { source, shift } => {
+			assert_or_err!(shift <= 0x1F, Error::IllegalImmediate { reason: "shift must be in range (0) to (31) on thumb" });
+			(source, 0b00, shift)
+		}
+
+		LogicalShiftRightImmediate { source, shift } => {
+			assert_or_err!((0x1..=0x20).contains(&shift), Error::IllegalImmediate { reason: "shift must be in range (1) to (32) on thumb" });
+			(source, 0b01, shift)
+		}
+
+		ArithmeticShiftRightImmediate { source, shift } => {
+			assert_or_err!((0x1..=0x20).contains(&shift), Error::IllegalImmediate { reason: "shift must be in range (1) to (32) on thumb" });
+			(source, 0b10, shift)
+		}
+
+		RotateRightImmediate { source, shift } => {
+			assert_or_err!((0x1..=0x1F).contains(&shift), Error::IllegalImmediate { reason: "shift must be in range (1) to (31) on thumb" });
+			(source, 0b11, shift)
+		}
+
+		// This is encoded as `ROR #0`.
+		RotateRightExtend { source } => (source, 0b11, 0x0),
+
+		_ => return Err(Error::IllegalShifter { reason: "register shifts are only encodable by moves on thumb" }),
+	};
+
+	check_not_sp_or_pc(&[source])?;
+
+	// Shifts of (32) are encoded as null for `LSR` and
+	// `ASR`.
+	let shift = shift & 0b11111;
+
+	opcode |= 0b11101010_00000000_00000000_00000000;
+	opcode |= source as u32;
+	opcode |= code << 0x4;
+	opcode |= (shift & 0b00011) << 0x6;
+	opcode |= (shift & 0b11100) << 0xA;
+
+	Ok(opcode)
+}
+
+/// Encodes a data-processing instruction.
+///
+/// Compares and tests are encoded with `pc` as their destination, and moves are encoded with `pc` as their base.
+fn encode_data_processing(op: u32, destination: Register, base: Register, source: Shifter, s: Sflag) -> Result<u32> {
+	let mut opcode = 0b00000000_00000000_00000000_00000000_u32;
+	opcode |= (destination as u32) << 0x8;
+	opcode |= (base as u32) << 0x10;
+	opcode |= u32::from(s) << 0x14;
+	opcode |= op << 0x15;
+
+	add_operand(opcode, source)
+}
+
+/// Encodes an addition or subtraction.
+///
+/// These may use `sp` as their base and destination, though `sp` may only be the destination if it is also the base.
+/// Immediates that cannot be encoded as modified immediates use the plain `12`-bit encoding (i.e. `ADDW` or `SUBW`), which also allows `pc` as the base.
+fn encode_add(op: u32, destination: Register, base: Register, source: Shifter, s: Sflag) -> Result<u32> {
+	assert_or_err!(destination != Register::Pc, Error::IllegalRegister { reason: "cannot encode pc as destination on thumb" });
+	assert_or_err!(destination != Register::Sp || base == Register::Sp, Error::IllegalRegister { reason: "can only use sp as destination with sp as base on thumb" });
+
+	if let Shifter::Immediate(source) = source {
+		if s.is_off() && (base == Register::Pc || Shifter::encode_thumb_immediate(source).is_none()) {
+			assert_or_err!(source <= 0xFFF, Error::IllegalImmediate { reason: "cannot encode larger than (4095) on thumb" });
+
+			// `ADDW` and `SUBW` use the opcodes of `AND`
+			// and `ORR`, respectively.
+			let op = if op == 0b1000 { 0b0000 } else { 0b0101 };
+
+			let mut opcode = 0b11110010_00000000_00000000_00000000_u32;
+			opcode |= source & 0b00000000_11111111;
+			opcode |= (source & 0b00000111_00000000) << 0x4;
+			opcode |= (source & 0b00001000_00000000) << 0xF;
+			opcode |= (destination as u32) << 0x8;
+			opcode |= (base as u32) << 0x10;
+			opcode |= op << 0x15;
+
+			return Ok(opcode);
+		}
+	}
+
+	assert_or_err!(base != Register::Pc, Error::IllegalRegister { reason: "can only use pc as base with plain immediates on thumb" });
+
+	encode_data_processing(op, destination, base, source, s)
+}
+
+/// Adds the address of a word, byte, or halfword transfer.
+///
+/// Loads relative to `pc` use the literal encoding, and unindexed non-negative offsets of up to (4095) use the `12`-bit encoding.
+/// Other immediate offsets use the `8`-bit encoding, which also supports indexing.
+/// Register offsets may be shifted left by up to (3).
+fn add_transfer_address(mut opcode: u32, l: bool, address: Address, t: Tflag) -> Result<u32> {
+	use Address::*;
+
+	let base = address.base();
+	opcode |= (base as u32) << 0x10;
+
+	match address {
+		ImmediateOffset { base: Register::Pc, source, indexing } => {
+			assert_or_err!(l, Error::IllegalRegister { reason: "cannot store relative to pc on thumb" });
+			assert_or_err!(t.is_off(), Error::IllegalFlag { reason: "t flag must be off for pc-relative loads on thumb" });
+			assert_or_err!(indexing == Indexing::Offset, Error::IllegalInstruction { reason: "cannot encode writeback to pc on thumb" });
+			assert_or_err!(source.unsigned_abs() <= 0xFFF, Error::IllegalImmediate { reason: "cannot encode offset larger than (4095) on thumb" });
+
+			opcode |= source.unsigned_abs();
+			opcode |= u32::from(source >= 0x0) << 0x17;
+		}
+
+		ImmediateOffset { source, indexing, .. } => {
+			if t.is_on() {
+				assert_or_err!(indexing == Indexing::Offset, Error::IllegalFlag { reason: "cannot encode writeback with t flag on thumb" });
+				assert_or_err!((0x0..=0xFF).contains(&source), Error::IllegalImmediate { reason: "offset must be in range (0) to (255) with t flag on thumb" });
+
+				opcode |= 0b00000000_00000000_00001110_00000000;
+				opcode |= source as u32;
+			} else if indexing == Indexing::Offset && (0x0..=0xFFF).contains(&source) {
+				opcode |= 0b00000000_10000000_00000000_00000000;
+				opcode |= source as u32;
+			} else {
+				assert_or_err!(source.unsigned_abs() <= 0xFF, Error::IllegalImmediate { reason: "cannot encode offset larger than (255) on thumb" });
+
+				opcode |= 0b00000000_00000000_00001000_00000000;
+				opcode |= source.unsigned_abs();
+				opcode |= u32::from(indexing.has_writeback()) << 0x8;
+				opcode |= u32::from(source >= 0x0) << 0x9;
+				opcode |= u32::from(indexing != Indexing::PostIndexed) << 0xA;
+			}
+		}
+
+		| RegisterOffset { source, sign, indexing, .. }
+		| ScaledRegisterOffset { source: Shifter::LogicalShiftLeftImmediate { source, shift: 0x0 }, sign, indexing, .. }
+		=> {
+			opcode = add_register_offset(opcode, source, 0x0, sign, indexing, t)?;
+		}
+
+		ScaledRegisterOffset { source: Shifter::LogicalShiftLeftImmediate { source, shift }, sign, indexing, .. } => {
+			assert_or_err!(shift <= 0x3, Error::IllegalShifter { reason: "register offsets can only be shifted left by up to (3) on thumb" });
+
+			opcode = add_register_offset(opcode, source, shift, sign, indexing, t)?;
+		}
+
+		ScaledRegisterOffset { .. } => return Err(Error::IllegalShifter { reason: "register offsets can only be shifted left on thumb" }),
+	}
+
+	Ok(opcode)
+}
+
+/// Adds a register offset of a transfer.
+fn add_register_offset(mut opcode: u32, source: Register, shift: u32, sign: Sign, indexing: Indexing, t: Tflag) -> Result<u32> {
+	assert_or_err!(sign.is_positive(), Error::IllegalRegister { reason: "cannot subtract register offsets on thumb" });
+	assert_or_err!(indexing == Indexing::Offset, Error::IllegalInstruction { reason: "cannot encode writeback with register offsets on thumb" });
+	assert_or_err!(t.is_off(), Error::IllegalFlag { reason: "t flag must be off with register offsets on thumb" });
+
+	check_not_sp_or_pc(&[source])?;
+
+	opcode |= source as u32;
+	opcode |= shift << 0x4;
+
+	Ok(opcode)
+}
+
+/// Encodes a word, byte, or halfword transfer.
+///
+/// The transferred register may only be `pc` for word loads, and may never be `sp`.
+fn encode_transfer(opcode: u32, register: Register, address: Address, t: Tflag) -> Result<u32> {
+	let l    = opcode & 0b00000000_00010000_00000000_00000000 != 0x0;
+	let word = opcode & 0b00000000_01100000_00000000_00000000 == 0b00000000_01000000_00000000_00000000;
+
+	assert_or_err!(register != Register::Sp, Error::IllegalRegister { reason: "cannot transfer sp on thumb" });
+	assert_or_err!(register != Register::Pc || (l && word), Error::IllegalRegister { reason: "can only load pc with word transfers on thumb" });
+	assert_or_err!(!address.has_writeback() || address.base() != register, Error::IllegalRegister { reason: "cannot write back to transferred register on thumb" });
+
+	let opcode = add_transfer_address(opcode, l, address, t)?;
+
+	Ok(opcode | (register as u32) << 0xC)
+}
+
+/// Encodes a doubleword transfer.
+///
+/// Only immediate offsets are supported, and these must be word-aligned.
+fn encode_doubleword_transfer(mut opcode: u32, first: Register, second: Register, address: Address) -> Result<u32> {
+	let Address::ImmediateOffset { base, source, indexing } = address else {
+		return Err(Error::IllegalInstruction { reason: "can only transfer doublewords with immediate offsets on thumb" });
+	};
+
+	check_not_sp_or_pc(&[first, second])?;
+
+	assert_or_err!(first != second, Error::IllegalRegister { reason: "cannot transfer the same register twice on thumb" });
+	assert_or_err!(source.unsigned_abs() <= 0x3FC, Error::IllegalImmediate { reason: "cannot encode offset larger than (1020) on thumb" });
+	assert_or_err!(source % 0x4 == 0x0, Error::IllegalImmediate { reason: "offset must be word-aligned on thumb" });
+	assert_or_err!(!indexing.has_writeback() || (base != first && base != second), Error::IllegalRegister { reason: "cannot write back to transferred register on thumb" });
+
+	opcode |= source.unsigned_abs() >> 0x2;
+	opcode |= (second as u32) << 0x8;
+	opcode |= (first as u32) << 0xC;
+	opcode |= (base as u32) << 0x10;
+	opcode |= u32::from(indexing.has_writeback()) << 0x15;
+	opcode |= u32::from(source >= 0x0) << 0x17;
+	opcode |= u32::from(indexing != Indexing::PostIndexed) << 0x18;
+
+	Ok(opcode)
+}
+
+/// Encodes a block transfer.
+///
+/// Only the increment-after and decrement-before modes exist, and at least two registers must be transferred.
+/// `sp` may never be transferred, and neither may `pc` by stores, and loads cannot transfer both `lr` and `pc`.
+fn encode_block_transfer(l: bool, base: Register, registers: RegisterList, mode: BlockMode, w: Wflag, s: Sflag) -> Result<u32> {
+	assert_or_err!(s.is_off(), Error::IllegalFlag { reason: "s flag must be off on thumb" });
+	assert_or_err!(registers.len() >= 0x2, Error::IllegalInstruction { reason: "must transfer at least two registers on thumb" });
+	assert_or_err!(base != Register::Pc, Error::IllegalRegister { reason: "cannot use pc as base on thumb" });
+	assert_or_err!(!registers.contains(Register::Sp), Error::IllegalRegister { reason: "cannot transfer sp on thumb" });
+	assert_or_err!(w.is_off() || !registers.contains(base), Error::IllegalRegister { reason: "cannot write back to transferred register on thumb" });
+
+	if l {
+		assert_or_err!(!registers.contains(Register::Lr) || !registers.contains(Register::Pc), Error::IllegalRegister { reason: "cannot load both lr and pc on thumb" });
+	} else {
+		assert_or_err!(!registers.contains(Register::Pc), Error::IllegalRegister { reason: "cannot store pc on thumb" });
+	}
+
+	let mut opcode = match mode {
+		BlockMode::IncrementAfter => 0b11101000_10000000_00000000_00000000_u32,
+
+		BlockMode::DecrementBefore => 0b11101001_00000000_00000000_00000000_u32,
+
+		_ => return Err(Error::IllegalInstruction { reason: "can only increment after or decrement before on thumb" }),
+	};
+
+	opcode |= u32::from(registers.to_u16());
+	opcode |= (base as u32) << 0x10;
+	opcode |= u32::from(l) << 0x14;
+	opcode |= u32::from(w) << 0x15;
+
+	Ok(opcode)
+}
+
+/// Adds a branch offset split into `S`, `imm10`, `J1`, `J2`, and `imm11`.
+///
+/// `J1` and `J2` are the two bits following the sign, inverted unless the offset is negative.
+#[must_use]
+const fn add_branch_offset(mut opcode: u32, offset: i32) -> u32 {
+	let offset = offset as u32;
+
+	let s  = offset >> 0x1F;
+	let j1 = !((offset >> 0x17) ^ s) & 0b1;
+	let j2 = !((offset >> 0x16) ^ s) & 0b1;
+
+	opcode |= (offset >> 0x1) & 0b00000111_11111111;
+	opcode |= j2 << 0xB;
+	opcode |= j1 << 0xD;
+	opcode |= ((offset >> 0xC) & 0b00000011_11111111) << 0x10;
+	opcode |= s << 0x1A;
+
+	opcode
+}
+
+/// Adds the operands of an extension.
+///
+/// Extensions without an addend are encoded with `pc` as their base.
+fn add_extend(mut opcode: u32, destination: Register, base: Option<Register>, source: Register, rotation: u32) -> Result<u32> {
+	assert_or_err!(matches!(rotation, 0x0 | 0x8 | 0x10 | 0x18), Error::IllegalImmediate { reason: "rotation must be any of (0), (8), (16), or (24) on thumb" });
+
+	if let Some(base) = base { check_not_sp_or_pc(&[base])? };
+	check_not_sp_or_pc(&[destination, source])?;
+
+	opcode |= source as u32;
+	opcode |= (rotation >> 0x3) << 0x4;
+	opcode |= (destination as u32) << 0x8;
+	opcode |= (base.unwrap_or(Register::Pc) as u32) << 0x10;
+
+	Ok(opcode)
+}
+
+/// Encodes the wide (Thumb-2) form of an instruction.
+///
+/// This is used when no narrow encoding exists for the provided operands.
+/// The provided address is that of the instruction, used for branches.
+///
+/// If the instruction has no wide encoding, [`None`] is returned.
+pub(in super) fn encode_thumb_wide(instruction: Instruction, address: u32) -> Result<Option<u32>> {
+	use Instruction::*;
+
+	let opcode = match instruction {
+		| Add { predicate, destination, base, source, s, .. }
+		| Subtract { predicate, destination, base, source, s, .. }
+		=> {
+			check_predicate(predicate)?;
+
+			let op = if matches!(instruction, Add { .. }) { 0b1000 } else { 0b1101 };
+
+			encode_add(op, destination, base, source, s)?
+		}
+
+		| AddCarry { predicate, destination, base, source, s, .. }
+		| And { predicate, destination, base, source, s, .. }
+		| BitClear { predicate, destination, base, source, s, .. }
+		| ExclusiveOr { predicate, destination, base, source, s, .. }
+		| InclusiveOr { predicate, destination, base, source, s, .. }
+		| InclusiveOrNot { predicate, destination, base, source, s, .. }
+		| ReverseSubtract { predicate, destination, base, source, s, .. }
+		| SubtractCarry { predicate, destination, base, source, s, .. }
+		=> {
+			check_predicate(predicate)?;
+
+			let op = match instruction {
+				And { .. }             => 0b0000,
+				BitClear { .. }        => 0b0001,
+				InclusiveOr { .. }     => 0b0010,
+				InclusiveOrNot { .. }  => 0b0011,
+				ExclusiveOr { .. }     => 0b0100,
+				AddCarry { .. }        => 0b1010,
+				SubtractCarry { .. }   => 0b1011,
+				_                      => 0b1110,
+			};
+
+			check_not_sp_or_pc(&[destination, base])?;
+
+			encode_data_processing(op, destination, base, source, s)?
+		}
+
+		| Compare { predicate, lhs, rhs, .. }
+		| CompareNegated { predicate, lhs, rhs, .. }
+		| Test { predicate, lhs, rhs, .. }
+		| TestEquivalence { predicate, lhs, rhs, .. }
+		=> {
+			check_predicate(predicate)?;
+
+			let op = match instruction {
+				Test { .. }            => 0b0000,
+				TestEquivalence { .. } => 0b0100,
+				CompareNegated { .. }  => 0b1000,
+				_                      => 0b1101,
+			};
+
+			assert_or_err!(lhs != Register::Pc, Error::IllegalRegister { reason: "cannot compare pc on thumb" });
+
+			encode_data_processing(op, Register::Pc, lhs, rhs, Sflag::On)?
+		}
+
+		Move {
+			predicate,
+			destination,
+			source:      Shifter::Immediate(source),
+			s,
+			..
+		} => {
+			check_predicate(predicate)?;
+
+			check_not_sp_or_pc(&[destination])?;
+
+			if s.is_off() && Shifter::encode_thumb_immediate(source).is_none() {
+				assert_or_err!(source <= 0xFFFF, Error::IllegalImmediate { reason: "cannot encode larger than (65535) on thumb" });
+
+				let mut opcode = 0b11110010_01000000_00000000_00000000_u32;
+				opcode |= source & 0b00000000_00000000_00000000_11111111;
+				opcode |= (destination as u32) << 0x8;
+				opcode |= (source & 0b00000000_00000000_00000111_00000000) << 0x4;
+				opcode |= (source & 0b00000000_00000000_11110000_00000000) << 0x4;
+				opcode |= (source & 0b00000000_00000000_00001000_00000000) << 0xF;
+
+				opcode
+			} else {
+				encode_data_processing(0b0010, destination, Register::Pc, Shifter::Immediate(source), s)?
+			}
+		}
+
+		Move {
+			predicate,
+			destination,
+			source:      shifter @ (
+				| Shifter::ArithmeticShiftRightRegister { source, shift }
+				| Shifter::LogicalShiftLeftRegister { source, shift }
+				| Shifter::LogicalShiftRightRegister { source, shift }
+				| Shifter::RotateRightRegister { source, shift }
+			),
+			s,
+		} => {
+			check_predicate(predicate)?;
+
+			let code = match shifter {
+				Shifter::LogicalShiftLeftRegister { .. }     => 0b00,
+				Shifter::LogicalShiftRightRegister { .. }    => 0b01,
+				Shifter::ArithmeticShiftRightRegister { .. } => 0b10,
+				_                                            => 0b11,
+			};
+
+			check_not_sp_or_pc(&[destination, source, shift])?;
+
+			let mut opcode = 0b11111010_00000000_11110000_00000000_u32;
+			opcode |= shift as u32;
+			opcode |= (destination as u32) << 0x8;
+			opcode |= (source as u32) << 0x10;
+			opcode |= u32::from(s) << 0x14;
+			opcode |= code << 0x15;
+
+			opcode
+		}
+
+		| Move { predicate, destination, source, s, .. }
+		| MoveNot { predicate, destination, source, s, .. }
+		=> {
+			check_predicate(predicate)?;
+
+			let op = if matches!(instruction, Move { .. }) { 0b0010 } else { 0b0011 };
+
+			check_not_sp_or_pc(&[destination])?;
+
+			encode_data_processing(op, destination, Register::Pc, source, s)?
+		}
+
+		| Multiply { predicate, destination, base, source, s, .. }
+		| MultiplyAccumulate { predicate, destination, base, source, s, .. }
+		=> {
+			check_predicate(predicate)?;
+
+			assert_or_err!(s.is_off(), Error::IllegalFlag { reason: "s flag must be off for wide multiplications on thumb" });
+
+			// Plain multiplications are encoded with `pc` as
+			// their accumulator.
+			let accumulator = if let MultiplyAccumulate { accumulator, .. } = instruction { accumulator } else { Register::Pc };
+
+			check_not_sp_or_pc(&[destination, base, source])?;
+			assert_or_err!(accumulator != Register::Sp, Error::IllegalRegister { reason: "cannot encode sp or pc on thumb" });
+
+			let mut opcode = 0b11111011_00000000_00000000_00000000_u32;
+			opcode |= source as u32;
+			opcode |= (destination as u32) << 0x8;
+			opcode |= (accumulator as u32) << 0xC;
+			opcode |= (base as u32) << 0x10;
+
+			opcode
+		}
+
+		MultiplySubtract { predicate, destination, base, source, accumulator, .. } => {
+			check_predicate(predicate)?;
+
+			check_not_sp_or_pc(&[destination, base, source, accumulator])?;
+
+			let mut opcode = 0b11111011_00000000_00000000_00010000_u32;
+			opcode |= source as u32;
+			opcode |= (destination as u32) << 0x8;
+			opcode |= (accumulator as u32) << 0xC;
+			opcode |= (base as u32) << 0x10;
+
+			opcode
+		}
+
+		| SignedMultiplyAccumulateLong { predicate, destination_low, destination_high, base, source, .. }
+		| SignedMultiplyLong { predicate, destination_low, destination_high, base, source, .. }
+		| UnsignedMultiplyAccumulateLong { predicate, destination_low, destination_high, base, source, .. }
+		| UnsignedMultiplyLong { predicate, destination_low, destination_high, base, source, .. }
+		| UnsignedMultiplyAccumulateAccumulateLong { predicate, destination_low, destination_high, base, source }
+		=> {
+			check_predicate(predicate)?;
+
+			if let
+				| SignedMultiplyAccumulateLong { s, .. }
+				| SignedMultiplyLong { s, .. }
+				| UnsignedMultiplyAccumulateLong { s, .. }
+				| UnsignedMultiplyLong { s, .. }
+				= instruction
+			{
+				assert_or_err!(s.is_off(), Error::IllegalFlag { reason: "s flag must be off for wide multiplications on thumb" });
+			}
+
+			check_not_sp_or_pc(&[destination_low, destination_high, base, source])?;
+			assert_or_err!(destination_low != destination_high, Error::IllegalRegister { reason: "destinations must differ on thumb" });
+
+			let mut opcode = match instruction {
+				SignedMultiplyLong { .. }                       => 0b11111011_10000000_00000000_00000000_u32,
+				UnsignedMultiplyLong { .. }                     => 0b11111011_10100000_00000000_00000000,
+				SignedMultiplyAccumulateLong { .. }             => 0b11111011_11000000_00000000_00000000,
+				UnsignedMultiplyAccumulateLong { .. }           => 0b11111011_11100000_00000000_00000000,
+				_                                               => 0b11111011_11100000_00000000_01100000,
+			};
+
+			opcode |= source as u32;
+			opcode |= (destination_high as u32) << 0x8;
+			opcode |= (destination_low as u32) << 0xC;
+			opcode |= (base as u32) << 0x10;
+
+			opcode
+		}
+
+		| Load { predicate, register, address, b, t, .. }
+		| Store { predicate, register, address, b, t, .. }
+		=> {
+			check_predicate(predicate)?;
+
+			let mut opcode = 0b11111000_00000000_00000000_00000000_u32;
+			opcode |= u32::from(matches!(instruction, Load { .. })) << 0x14;
+			opcode |= u32::from(b.is_off()) << 0x16;
+
+			encode_transfer(opcode, register, address, t)?
+		}
+
+		| LoadHalfword { predicate, register, address, .. }
+		| LoadSignedByte { predicate, register, address, .. }
+		| LoadSignedHalfword { predicate, register, address, .. }
+		| StoreHalfword { predicate, register, address, .. }
+		=> {
+			check_predicate(predicate)?;
+
+			let opcode = match instruction {
+				LoadHalfword { .. }       => 0b11111000_00110000_00000000_00000000,
+				LoadSignedByte { .. }     => 0b11111001_00010000_00000000_00000000,
+				LoadSignedHalfword { .. } => 0b11111001_00110000_00000000_00000000,
+				_                         => 0b11111000_00100000_00000000_00000000,
+			};
+
+			encode_transfer(opcode, register, address, Tflag::Off)?
+		}
+
+		PreloadData { address } => {
+			assert_or_err!(address.indexing() == Indexing::Offset, Error::IllegalInstruction { reason: "cannot preload with writeback on thumb" });
+
+			// This is encoded as a byte load to `pc`.
+			let opcode = 0b11111000_00010000_11110000_00000000_u32;
+
+			add_transfer_address(opcode, true, address, Tflag::Off)?
+		}
+
+		| LoadDoubleword { predicate, first, second, address, .. }
+		| StoreDoubleword { predicate, first, second, address, .. }
+		=> {
+			check_predicate(predicate)?;
+
+			let mut opcode = 0b11101000_01000000_00000000_00000000_u32;
+			opcode |= u32::from(matches!(instruction, LoadDoubleword { .. })) << 0x14;
+
+			encode_doubleword_transfer(opcode, first, second, address)?
+		}
+
+		| LoadMultiple { predicate, base, registers, mode, w, s, .. }
+		| StoreMultiple { predicate, base, registers, mode, w, s, .. }
+		=> {
+			check_predicate(predicate)?;
+
+			let l = matches!(instruction, LoadMultiple { .. });
+
+			encode_block_transfer(l, base, registers, mode, w, s)?
+		}
+
+		Branch {
+			predicate,
+			immediate,
+		} => {
+			let offset = immediate.wrapping_sub(address).wrapping_sub(0x4) as i32;
+
+			assert_or_err!(offset % 0x2 == 0x0, Error::IllegalImmediate { reason: "cannot encode uneven offset on thumb" });
+
+			if predicate == Predicate::Always {
+				assert_or_err!((-0x01000000..=0x00FFFFFE).contains(&offset), Error::IllegalImmediate { reason: "cannot encode offset outside (-16777216) to (16777214) on thumb" });
+
+				add_branch_offset(0b11110000_00000000_10010000_00000000, offset)
+			} else {
+				assert_or_err!((-0x00100000..=0x000FFFFE).contains(&offset), Error::IllegalImmediate { reason: "cannot encode offset outside (-1048576) to (1048574) on thumb" });
+
+				// The conditional encoding has no `imm10`, but
+				// takes the condition and `imm6` in its place.
+				// Its `J1` and `J2` are likewise not inverted.
+
+				let offset = offset as u32;
+
+				let mut opcode = 0b11110000_00000000_10000000_00000000_u32;
+				opcode |= (offset >> 0x1) & 0b00000111_11111111;
+				opcode |= ((offset >> 0x13) & 0b1) << 0xB;
+				opcode |= ((offset >> 0x12) & 0b1) << 0xD;
+				opcode |= ((offset >> 0xC) & 0b00111111) << 0x10;
+				opcode |= (predicate as u32) << 0x16;
+				opcode |= (offset >> 0x1F) << 0x1A;
+
+				opcode
+			}
+		}
+
+		BranchLink { predicate, source, .. } => {
+			check_predicate(predicate)?;
+
+			let offset = source.wrapping_sub(address).wrapping_sub(0x4) as i32;
+
+			assert_or_err!((-0x01000000..=0x00FFFFFE).contains(&offset), Error::IllegalImmediate { reason: "cannot encode offset outside (-16777216) to (16777214) on thumb" });
+			assert_or_err!(offset % 0x2 == 0x0, Error::IllegalImmediate { reason: "cannot encode uneven offset on thumb" });
+
+			add_branch_offset(0b11110000_00000000_11010000_00000000, offset)
+		}
+
+		BranchLinkExchange { predicate, source: Shifter::Immediate(target), .. } => {
+			check_predicate(predicate)?;
+
+			// The offset is relative to the word-aligned `PC`.
+			let offset = target.wrapping_sub(address.wrapping_add(0x4) & !0b11) as i32;
+
+			assert_or_err!((-0x01000000..=0x00FFFFFC).contains(&offset), Error::IllegalImmediate { reason: "cannot encode offset outside (-16777216) to (16777212) on thumb" });
+			assert_or_err!(offset % 0x4 == 0x0, Error::IllegalImmediate { reason: "offset must be word-aligned on thumb" });
+
+			add_branch_offset(0b11110000_00000000_11000000_00000000, offset)
+		}
+
+		CountLeadingZeroes { predicate, destination, source, .. } => {
+			check_predicate(predicate)?;
+
+			check_not_sp_or_pc(&[destination, source])?;
+
+			// The source register is encoded twice.
+			let mut opcode = 0b11111010_10110000_11110000_10000000_u32;
+			opcode |= source as u32;
+			opcode |= (destination as u32) << 0x8;
+			opcode |= (source as u32) << 0x10;
+
+			opcode
+		}
+
+		| Reverse { predicate, destination, source, .. }
+		| ReversePackedHalfword { predicate, destination, source, .. }
+		| ReverseSignedHalfword { predicate, destination, source, .. }
+		=> {
+			check_predicate(predicate)?;
+
+			check_not_sp_or_pc(&[destination, source])?;
+
+			let mut opcode = match instruction {
+				Reverse { .. }               => 0b11111010_10010000_11110000_10000000_u32,
+				ReversePackedHalfword { .. } => 0b11111010_10010000_11110000_10010000,
+				_                            => 0b11111010_10010000_11110000_10110000,
+			};
+
+			// The source register is encoded twice.
+			opcode |= source as u32;
+			opcode |= (destination as u32) << 0x8;
+			opcode |= (source as u32) << 0x10;
+
+			opcode
+		}
+
+		| SignExtendAddByte { predicate, destination, base, source, rotation, .. }
+		| SignExtendAddByte16 { predicate, destination, base, source, rotation, .. }
+		| SignExtendAddHalfword { predicate, destination, base, source, rotation, .. }
+		| ZeroExtendAddByte { predicate, destination, base, source, rotation, .. }
+		| ZeroExtendAddByte16 { predicate, destination, base, source, rotation, .. }
+		| ZeroExtendAddHalfword { predicate, destination, base, source, rotation, .. }
+		=> {
+			check_predicate(predicate)?;
+
+			let opcode = match instruction {
+				SignExtendAddHalfword { .. } => 0b11111010_00000000_11110000_10000000,
+				ZeroExtendAddHalfword { .. } => 0b11111010_00010000_11110000_10000000,
+				SignExtendAddByte16 { .. }   => 0b11111010_00100000_11110000_10000000,
+				ZeroExtendAddByte16 { .. }   => 0b11111010_00110000_11110000_10000000,
+				SignExtendAddByte { .. }     => 0b11111010_01000000_11110000_10000000,
+				_                            => 0b11111010_01010000_11110000_10000000,
+			};
+
+			add_extend(opcode, destination, Some(base), source, rotation)?
+		}
+
+		| SignExtendByte { predicate, destination, source, rotation, .. }
+		| SignExtendByte16 { predicate, destination, source, rotation, .. }
+		| SignExtendHalfword { predicate, destination, source, rotation, .. }
+		| ZeroExtendByte { predicate, destination, source, rotation, .. }
+		| ZeroExtendByte16 { predicate, destination, source, rotation, .. }
+		| ZeroExtendHalfword { predicate, destination, source, rotation, .. }
+		=> {
+			check_predicate(predicate)?;
+
+			let opcode = match instruction {
+				SignExtendHalfword { .. } => 0b11111010_00000000_11110000_10000000,
+				ZeroExtendHalfword { .. } => 0b11111010_00010000_11110000_10000000,
+				SignExtendByte16 { .. }   => 0b11111010_00100000_11110000_10000000,
+				ZeroExtendByte16 { .. }   => 0b11111010_00110000_11110000_10000000,
+				SignExtendByte { .. }     => 0b11111010_01000000_11110000_10000000,
+				_                         => 0b11111010_01010000_11110000_10000000,
+			};
+
+			add_extend(opcode, destination, None, source, rotation)?
+		}
+
+		_ => return Ok(None),
+	};
+
+	Ok(Some(opcode))
+}
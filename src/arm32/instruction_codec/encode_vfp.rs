@@ -0,0 +1,358 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Pollex.
+//
+// Pollex is free software: you can redistribute it
+// and/or modify it under the terms of the GNU Af-
+// fero General Public License as published by the
+// Free Software Foundation, either version 3 of
+// the License, or (at your option) any later ver-
+// sion.
+//
+// Pollex is distributed in the hope that it will
+// be useful, but WITHOUT ANY WARRANTY; without
+// even the implied warranty of MERCHANTABILITY or
+// FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// Affero General Public License for more details.
+//
+// You should have received a copy of the GNU Af-
+// fero General Public License along with Pollex.
+// If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{assert_or_err, Error, Result};
+use crate::arm32::{
+	Address,
+	BlockMode,
+	Indexing,
+	Instruction,
+	Predicate,
+	Register,
+	VfpRegister,
+	Wflag,
+};
+
+/// Splits a floating-point register into its four-bit and one-bit fields.
+///
+/// Single registers keep their lowest bit in the latter, whilst double registers keep their highest bit there.
+#[inline]
+#[must_use]
+const fn split_register(register: VfpRegister) -> (u32, u32) {
+	let value = register.to_u8() as u32;
+
+	if register.is_double() {
+		(value & 0b1111, value >> 0x4)
+	} else {
+		(value >> 0x1, value & 0b1)
+	}
+}
+
+/// Adds a floating-point register in the `Vd` field.
+#[inline]
+#[must_use]
+const fn add_vd(mut opcode: u32, register: VfpRegister) -> u32 {
+	let (vd, d) = split_register(register);
+
+	opcode |= vd << 0xC;
+	opcode |= d << 0x16;
+
+	opcode
+}
+
+/// Adds a floating-point register in the `Vn` field.
+#[inline]
+#[must_use]
+const fn add_vn(mut opcode: u32, register: VfpRegister) -> u32 {
+	let (vn, n) = split_register(register);
+
+	opcode |= vn << 0x10;
+	opcode |= n << 0x7;
+
+	opcode
+}
+
+/// Adds a floating-point register in the `Vm` field.
+#[inline]
+#[must_use]
+const fn add_vm(mut opcode: u32, register: VfpRegister) -> u32 {
+	let (vm, m) = split_register(register);
+
+	opcode |= vm;
+	opcode |= m << 0x5;
+
+	opcode
+}
+
+/// Checks that all of the given registers are of the same precision.
+///
+/// The `sz` bit of the common precision is returned.
+fn check_precision(registers: &[VfpRegister]) -> Result<u32> {
+	let double = registers.first().is_some_and(|register| register.is_double());
+
+	assert_or_err!(registers.iter().all(|register| register.is_double() == double), Error::IllegalRegister { reason: "registers must be of the same precision for vfp" });
+
+	Ok(u32::from(double))
+}
+
+/// Adds the address of a floating-point load or store.
+///
+/// Only word-aligned immediate offsets of up to (1020) are accepted, without indexing.
+fn add_vfp_address(mut opcode: u32, address: Address) -> Result<u32> {
+	let Address::ImmediateOffset { base, source, indexing: Indexing::Offset } = address else {
+		return Err(Error::IllegalInstruction { reason: "floating-point transfers only take unindexed immediate offsets" });
+	};
+
+	assert_or_err!(source.unsigned_abs() <= 0x3FC, Error::IllegalImmediate { reason: "cannot encode offset larger than (1020) for vfp" });
+	assert_or_err!(source % 0x4 == 0x0, Error::IllegalImmediate { reason: "offset must be word-aligned for vfp" });
+
+	opcode |= source.unsigned_abs() >> 0x2;
+	opcode |= (base as u32) << 0x10;
+	opcode |= u32::from(source >= 0x0) << 0x17;
+
+	Ok(opcode)
+}
+
+/// Adds the operands of a floating-point block transfer.
+fn add_vfp_block_transfer(mut opcode: u32, base: Register, first: VfpRegister, count: u8, mode: BlockMode, w: Wflag) -> Result<u32> {
+	assert_or_err!(count != 0x0, Error::IllegalInstruction { reason: "cannot transfer empty register list for vfp" });
+	assert_or_err!(!first.is_double() || count <= 0x10, Error::IllegalInstruction { reason: "cannot transfer more than (16) double registers for vfp" });
+	assert_or_err!(u32::from(first.to_u8()) + u32::from(count) <= 0x20, Error::IllegalRegister { reason: "register list out of range for vfp" });
+	assert_or_err!(!w.is_on() || base != Register::Pc, Error::IllegalRegister { reason: "cannot write back to pc for vfp" });
+
+	match mode {
+		BlockMode::IncrementAfter => opcode |= 0b00000000_10000000_00000000_00000000,
+
+		BlockMode::DecrementBefore => {
+			assert_or_err!(w.is_on(), Error::IllegalFlag { reason: "decrementing transfers must write back for vfp" });
+
+			opcode |= 0b00000001_00000000_00000000_00000000;
+		}
+
+		_ => return Err(Error::IllegalInstruction { reason: "only increment after and decrement before are supported for vfp" }),
+	}
+
+	opcode |= u32::from(count) << u32::from(first.is_double());
+	opcode |= u32::from(first.is_double()) << 0x8;
+	opcode |= u32::from(w) << 0x15;
+	opcode |= (base as u32) << 0x10;
+
+	Ok(add_vd(opcode, first))
+}
+
+/// Encodes a floating-point instruction.
+///
+/// The VFP encodings are shared between Arm and Thumb, with Thumb using the "always" condition.
+/// The returned opcode therefore lacks its condition, which is instead returned alongside it.
+///
+/// If the instruction is not a floating-point instruction, [`None`] is returned.
+pub(in super) fn encode_vfp(instruction: Instruction) -> Result<Option<(Predicate, u32)>> {
+	use Instruction::*;
+
+	let mut opcode = 0b00000000_00000000_00000000_00000000_u32;
+
+	let predicate = match instruction {
+		| FloatAdd { predicate, destination, base, source }
+		| FloatDivide { predicate, destination, base, source }
+		| FloatMultiply { predicate, destination, base, source }
+		| FloatSubtract { predicate, destination, base, source }
+		=> {
+			opcode |= match instruction {
+				FloatAdd { .. }      => 0b00001110_00110000_00001010_00000000,
+				FloatDivide { .. }   => 0b00001110_10000000_00001010_00000000,
+				FloatMultiply { .. } => 0b00001110_00100000_00001010_00000000,
+				_                    => 0b00001110_00110000_00001010_01000000,
+			};
+
+			opcode |= check_precision(&[destination, base, source])? << 0x8;
+
+			opcode = add_vd(opcode, destination);
+			opcode = add_vn(opcode, base);
+			opcode = add_vm(opcode, source);
+
+			predicate
+		}
+
+		| FloatAbsolute { predicate, destination, source }
+		| FloatMove { predicate, destination, source }
+		| FloatNegate { predicate, destination, source }
+		| FloatSquareRoot { predicate, destination, source }
+		=> {
+			opcode |= match instruction {
+				FloatAbsolute { .. } => 0b00001110_10110000_00001010_11000000,
+				FloatMove { .. }     => 0b00001110_10110000_00001010_01000000,
+				FloatNegate { .. }   => 0b00001110_10110001_00001010_01000000,
+				_                    => 0b00001110_10110001_00001010_11000000,
+			};
+
+			opcode |= check_precision(&[destination, source])? << 0x8;
+
+			opcode = add_vd(opcode, destination);
+			opcode = add_vm(opcode, source);
+
+			predicate
+		}
+
+		FloatCompare {
+			predicate,
+			lhs,
+			rhs,
+			e,
+		} => {
+			opcode |= 0b00001110_10110100_00001010_01000000;
+			opcode |= u32::from(e) << 0x7;
+
+			opcode = add_vd(opcode, lhs);
+
+			if let Some(rhs) = rhs {
+				opcode |= check_precision(&[lhs, rhs])? << 0x8;
+				opcode = add_vm(opcode, rhs);
+			} else {
+				opcode |= u32::from(lhs.is_double()) << 0x8;
+				opcode |= 0b00000000_00000001_00000000_00000000;
+			}
+
+			predicate
+		}
+
+		FloatConvert {
+			predicate,
+			destination,
+			source,
+		} => {
+			assert_or_err!(destination.is_double() != source.is_double(), Error::IllegalRegister { reason: "conversion must change precision for vfp" });
+
+			opcode |= 0b00001110_10110111_00001010_11000000;
+			opcode |= u32::from(source.is_double()) << 0x8;
+
+			opcode = add_vd(opcode, destination);
+			opcode = add_vm(opcode, source);
+
+			predicate
+		}
+
+		| FloatLoad { predicate, register, address }
+		| FloatStore { predicate, register, address }
+		=> {
+			opcode |= 0b00001101_00000000_00001010_00000000;
+			opcode |= u32::from(matches!(instruction, FloatLoad { .. })) << 0x14;
+			opcode |= u32::from(register.is_double()) << 0x8;
+
+			opcode = add_vd(opcode, register);
+			opcode = add_vfp_address(opcode, address)?;
+
+			predicate
+		}
+
+		| FloatLoadMultiple { predicate, base, first, count, mode, w }
+		| FloatStoreMultiple { predicate, base, first, count, mode, w }
+		=> {
+			opcode |= 0b00001100_00000000_00001010_00000000;
+			opcode |= u32::from(matches!(instruction, FloatLoadMultiple { .. })) << 0x14;
+
+			opcode = add_vfp_block_transfer(opcode, base, first, count, mode, w)?;
+
+			predicate
+		}
+
+		FloatMoveImmediate {
+			predicate,
+			destination,
+			immediate,
+		} => {
+			let immediate = u32::from(immediate.to_u8());
+
+			opcode |= 0b00001110_10110000_00001010_00000000;
+			opcode |= immediate & 0b1111;
+			opcode |= u32::from(destination.is_double()) << 0x8;
+			opcode |= immediate >> 0x4 << 0x10;
+
+			opcode = add_vd(opcode, destination);
+
+			predicate
+		}
+
+		| FloatToSigned { predicate, destination, source, r }
+		| FloatToUnsigned { predicate, destination, source, r }
+		=> {
+			opcode |= 0b00001110_10111100_00001010_01000000;
+			opcode |= u32::from(r.is_off()) << 0x7;
+			opcode |= u32::from(source.is_double()) << 0x8;
+			opcode |= u32::from(matches!(instruction, FloatToSigned { .. })) << 0x10;
+
+			opcode = add_vd(opcode, destination.into());
+			opcode = add_vm(opcode, source);
+
+			predicate
+		}
+
+		| SignedToFloat { predicate, destination, source }
+		| UnsignedToFloat { predicate, destination, source }
+		=> {
+			opcode |= 0b00001110_10111000_00001010_01000000;
+			opcode |= u32::from(matches!(instruction, SignedToFloat { .. })) << 0x7;
+			opcode |= u32::from(destination.is_double()) << 0x8;
+
+			opcode = add_vd(opcode, destination);
+			opcode = add_vm(opcode, source.into());
+
+			predicate
+		}
+
+		| MoveFromFloat { predicate, destination: register, source: single }
+		| MoveToFloat { predicate, destination: single, source: register }
+		=> {
+			assert_or_err!(register != Register::Pc, Error::IllegalRegister { reason: "cannot use pc for vfp" });
+
+			opcode |= 0b00001110_00000000_00001010_00010000;
+			opcode |= (register as u32) << 0xC;
+			opcode |= u32::from(matches!(instruction, MoveFromFloat { .. })) << 0x14;
+
+			opcode = add_vn(opcode, single.into());
+
+			predicate
+		}
+
+		| MoveFromFloatDouble { predicate, low, high, source: double }
+		| MoveToFloatDouble { predicate, destination: double, low, high }
+		=> {
+			let load = matches!(instruction, MoveFromFloatDouble { .. });
+
+			assert_or_err!(low != Register::Pc && high != Register::Pc, Error::IllegalRegister { reason: "cannot use pc for vfp" });
+			assert_or_err!(!load || low != high, Error::IllegalRegister { reason: "destinations must differ for vfp" });
+
+			opcode |= 0b00001100_01000000_00001011_00010000;
+			opcode |= (low as u32) << 0xC;
+			opcode |= (high as u32) << 0x10;
+			opcode |= u32::from(load) << 0x14;
+
+			opcode = add_vm(opcode, double.into());
+
+			predicate
+		}
+
+		MoveFromFloatStatus {
+			predicate,
+			destination,
+		} => {
+			opcode |= 0b00001110_11110001_00001010_00010000;
+			opcode |= (destination as u32) << 0xC;
+
+			predicate
+		}
+
+		MoveToFloatStatus {
+			predicate,
+			source,
+		} => {
+			assert_or_err!(source != Register::Pc, Error::IllegalRegister { reason: "cannot use pc for vfp" });
+
+			opcode |= 0b00001110_11100001_00001010_00010000;
+			opcode |= (source as u32) << 0xC;
+
+			predicate
+		}
+
+		_ => return Ok(None),
+	};
+
+	Ok(Some((predicate, opcode)))
+}
@@ -19,17 +19,216 @@
 // fero General Public License along with Pollex.
 // If not, see <https://www.gnu.org/licenses/>.
 
+mod decode_arm;
+mod decode_neon;
 mod decode_thumb;
+mod decode_thumb_wide;
+mod decode_vfp;
 mod encode_arm;
+mod encode_neon;
 mod encode_thumb;
+mod encode_thumb_wide;
+mod encode_vfp;
+
+use crate::{assert_or_err, Error, Result};
+use crate::arm32::{
+	Instruction,
+	Predicate,
+	Register,
+	Sflag,
+};
 
 use core::num::Wrapping;
 
+/// Checks the least significant bit and the width of a bitfield.
+const fn check_bitfield(lsb: u32, width: u32) -> Result<()> {
+	assert_or_err!(lsb <= 0x1F, Error::IllegalImmediate { reason: "least significant bit cannot be greater than (31)" });
+	assert_or_err!(width >= 0x1 && width <= 0x20 - lsb, Error::IllegalImmediate { reason: "bitfield width must be in range (1) to (32 - lsb)" });
+
+	Ok(())
+}
+
+/// Extracts the register at the given bit position.
+#[inline(always)]
+#[must_use]
+const fn register_at(opcode: u32, shift: u32) -> Register {
+	Register::from_u8(((opcode >> shift) & 0b1111) as u8).unwrap()
+}
+
+/// Selects the bits of the given if-then mask that precede its terminating bit.
+#[inline(always)]
+#[must_use]
+const fn if_then_block(mask: u8) -> u8 { !((mask & mask.wrapping_neg()) << 0x1).wrapping_sub(0x1) & 0xF }
+
+/// Converts the given if-then instruction into its state (`ITSTATE`).
+///
+/// The state is laid out as the low byte of the `IT` opcode, i.e. with the mask inverted for predicates with even codes.
+const fn encode_if_then_state(predicate: Predicate, mask: u8) -> Result<u8> {
+	assert_or_err!(mask != 0x0, Error::IllegalImmediate { reason: "if-then mask cannot be null" });
+	assert_or_err!(mask <= 0xF, Error::IllegalImmediate { reason: "if-then mask must be at most (15)" });
+
+	let block = if_then_block(mask);
+
+	let predicate = predicate as u8;
+	assert_or_err!(predicate != Predicate::Always as u8 || mask & block == block, Error::IllegalPredicate { reason: "cannot use else with `AL`" });
+
+	let mask = if predicate & 0b1 == 0x0 { mask ^ block } else { mask };
+	Ok(predicate << 0x4 | mask)
+}
+
+/// Converts the given if-then state (`ITSTATE`) into an `IT` instruction.
+///
+/// The state must denote a block (i.e. have a non-null mask).
+const fn decode_if_then_state(state: u8) -> Result<Instruction> {
+	let Some(predicate) = Predicate::from_u8(state >> 0x4) else { return Err(Error::InvalidOpcode) };
+
+	let mask  = state & 0xF;
+	let block = if_then_block(mask);
+
+	let mask = if state & 0b00010000 == 0x0 { mask ^ block } else { mask };
+	if state >> 0x4 == Predicate::Always as u8 && mask & block != block { return Err(Error::InvalidOpcode) };
+
+	Ok(Instruction::IfThen { predicate, mask })
+}
+
+/// Checks if the given instruction branches, i.e. writes to `pc`.
+///
+/// Writes that are unpredictable (e.g. multiplications into `pc`) are treated as branches as well.
+const fn is_branch(instruction: Instruction) -> bool {
+	use Instruction::*;
+
+	match instruction {
+		Branch { .. }
+		| BranchExchange { .. }
+		| BranchLink { .. }
+		| BranchLinkExchange { .. }
+		| CompareBranchNonZero { .. }
+		| CompareBranchZero { .. }
+		| TableBranchByte { .. }
+		| TableBranchHalfword { .. }
+		| Add { destination: Register::Pc, .. }
+		| AddCarry { destination: Register::Pc, .. }
+		| And { destination: Register::Pc, .. }
+		| BitClear { destination: Register::Pc, .. }
+		| BitFieldClear { destination: Register::Pc, .. }
+		| BitFieldInsert { destination: Register::Pc, .. }
+		| CountLeadingZeroes { destination: Register::Pc, .. }
+		| ExclusiveOr { destination: Register::Pc, .. }
+		| InclusiveOr { destination: Register::Pc, .. }
+		| InclusiveOrNot { destination: Register::Pc, .. }
+		| Move { destination: Register::Pc, .. }
+		| MoveFromStatusRegister { destination: Register::Pc, .. }
+		| MoveNot { destination: Register::Pc, .. }
+		| MoveTop { destination: Register::Pc, .. }
+		| MoveWide { destination: Register::Pc, .. }
+		| Multiply { destination: Register::Pc, .. }
+		| MultiplyAccumulate { destination: Register::Pc, .. }
+		| MultiplySubtract { destination: Register::Pc, .. }
+		| PackHalfword { destination: Register::Pc, .. }
+		| ParallelArithmetic { destination: Register::Pc, .. }
+		| Reverse { destination: Register::Pc, .. }
+		| ReverseBits { destination: Register::Pc, .. }
+		| ReversePackedHalfword { destination: Register::Pc, .. }
+		| ReverseSignedHalfword { destination: Register::Pc, .. }
+		| ReverseSubtract { destination: Register::Pc, .. }
+		| ReverseSubtractCarry { destination: Register::Pc, .. }
+		| SaturatingAdd { destination: Register::Pc, .. }
+		| SaturatingDoubleAdd { destination: Register::Pc, .. }
+		| SaturatingDoubleSubtract { destination: Register::Pc, .. }
+		| SaturatingSubtract { destination: Register::Pc, .. }
+		| Select { destination: Register::Pc, .. }
+		| SignExtendAddByte { destination: Register::Pc, .. }
+		| SignExtendAddByte16 { destination: Register::Pc, .. }
+		| SignExtendAddHalfword { destination: Register::Pc, .. }
+		| SignExtendByte { destination: Register::Pc, .. }
+		| SignExtendByte16 { destination: Register::Pc, .. }
+		| SignExtendHalfword { destination: Register::Pc, .. }
+		| SignedBitFieldExtract { destination: Register::Pc, .. }
+		| SignedDivide { destination: Register::Pc, .. }
+		| SignedSaturate { destination: Register::Pc, .. }
+		| SignedSaturate16 { destination: Register::Pc, .. }
+		| Subtract { destination: Register::Pc, .. }
+		| SubtractCarry { destination: Register::Pc, .. }
+		| UnsignedBitFieldExtract { destination: Register::Pc, .. }
+		| UnsignedDivide { destination: Register::Pc, .. }
+		| UnsignedSaturate { destination: Register::Pc, .. }
+		| UnsignedSaturate16 { destination: Register::Pc, .. }
+		| UnsignedSumAbsoluteDifferences { destination: Register::Pc, .. }
+		| UnsignedSumAbsoluteDifferencesAccumulate { destination: Register::Pc, .. }
+		| ZeroExtendAddByte { destination: Register::Pc, .. }
+		| ZeroExtendAddByte16 { destination: Register::Pc, .. }
+		| ZeroExtendAddHalfword { destination: Register::Pc, .. }
+		| ZeroExtendByte { destination: Register::Pc, .. }
+		| ZeroExtendByte16 { destination: Register::Pc, .. }
+		| ZeroExtendHalfword { destination: Register::Pc, .. }
+		| SignedMultiplyAccumulateLong { destination_low: Register::Pc, .. }
+		| SignedMultiplyAccumulateLong { destination_high: Register::Pc, .. }
+		| SignedMultiplyLong { destination_low: Register::Pc, .. }
+		| SignedMultiplyLong { destination_high: Register::Pc, .. }
+		| UnsignedMultiplyAccumulateAccumulateLong { destination_low: Register::Pc, .. }
+		| UnsignedMultiplyAccumulateAccumulateLong { destination_high: Register::Pc, .. }
+		| UnsignedMultiplyAccumulateLong { destination_low: Register::Pc, .. }
+		| UnsignedMultiplyAccumulateLong { destination_high: Register::Pc, .. }
+		| UnsignedMultiplyLong { destination_low: Register::Pc, .. }
+		| UnsignedMultiplyLong { destination_high: Register::Pc, .. }
+		| Load { register: Register::Pc, .. }
+		| LoadExclusive { register: Register::Pc, .. }
+		| LoadExclusiveByte { register: Register::Pc, .. }
+		| LoadExclusiveHalfword { register: Register::Pc, .. }
+		| LoadHalfword { register: Register::Pc, .. }
+		| LoadSignedByte { register: Register::Pc, .. }
+		| LoadSignedHalfword { register: Register::Pc, .. }
+		| Swap { register: Register::Pc, .. }
+		| LoadDoubleword { first: Register::Pc, .. }
+		| LoadDoubleword { second: Register::Pc, .. }
+		| LoadExclusiveDoubleword { first: Register::Pc, .. }
+		| LoadExclusiveDoubleword { second: Register::Pc, .. } => true,
+
+		LoadMultiple { registers, .. } => registers.contains(Register::Pc),
+
+		_ => false,
+	}
+}
+
+/// Retrieves the S flag of the given instruction, if it has a narrow Thumb encoding with the flag.
+///
+/// These narrow encodings only set flags outside of if-then blocks.
+const fn narrow_s_flag(instruction: &mut Instruction) -> Option<&mut Sflag> {
+	use Instruction::*;
+
+	match *instruction {
+		Add { ref mut s, .. }
+		| AddCarry { ref mut s, .. }
+		| And { ref mut s, .. }
+		| BitClear { ref mut s, .. }
+		| ExclusiveOr { ref mut s, .. }
+		| InclusiveOr { ref mut s, .. }
+		| Move { ref mut s, .. }
+		| MoveNot { ref mut s, .. }
+		| Multiply { ref mut s, .. }
+		| ReverseSubtract { ref mut s, .. }
+		| Subtract { ref mut s, .. }
+		| SubtractCarry { ref mut s, .. } => Some(s),
+
+		_ => None,
+	}
+}
+
 /// Codec for encoding and decoding instruction.
 ///
-/// Arm instructions can be encoded/decoded using the [`encode_arm`](InstructionCodec::encode_arm) and (soon) `decode_arm`.
+/// Arm instructions can be encoded/decoded using the [`encode_arm`](InstructionCodec::encode_arm) and [`decode_arm`](InstructionCodec::decode_arm).
 /// Thumb instruction will similarly be manipulated using [`encode_thumb`](InstructionCodec::encode_thumb) and [`decode_thumb`](InstructionCodec::decode_thumb).
 ///
+/// By default, only Thumb-1 encodings (and the instructions exclusive to Thumb-2) are produced.
+/// Wide Thumb-2 encodings may additionally be enabled using [`set_thumb2`](InstructionCodec::set_thumb2).
+///
+/// Encodings are checked against Armv6 and later by default.
+/// The restrictions of older cores may be enabled using [`set_pre_armv6`](InstructionCodec::set_pre_armv6).
+///
+/// The codec also keeps track of Thumb if-then blocks (see [`IfThen`](Instruction::IfThen)).
+/// Instructions within a block are encoded and decoded with the predicates given by the block, and violating the block (e.g. with a mismatched predicate, a branch that is not the last instruction, or another `IT` instruction) yields an error.
+/// Do note that the narrow encodings of `ADDS`, `MOVS`, etc. do not set flags within blocks, and only represent their non-flag-setting counterparts there.
+///
 /// This structure keeps track of the adress at which instructions are to be placed (see *Rationale*).
 /// If encoding causes this internal address to go past `0xFFFFFFFF`, the value is safely wrapped to the origin (i.e. `0x00000000`).
 ///
@@ -42,6 +241,9 @@ use core::num::Wrapping;
 #[derive(Clone, Debug)]
 pub struct InstructionCodec {
 	address: Wrapping<u32>,
+	thumb2:    bool,
+	pre_armv6: bool,
+	if_then:   u8,
 }
 
 impl InstructionCodec {
@@ -54,12 +256,54 @@ impl InstructionCodec {
 	#[inline(always)]
 	#[must_use]
 	pub const fn new_at(address: u32) -> Self {
-		Self { address: Wrapping(address) }
+		Self { address: Wrapping(address), thumb2: false, pre_armv6: false, if_then: 0x0 }
+	}
+
+	/// Enables or disables Thumb-2 encodings.
+	///
+	/// This is disabled by default.
+	/// See [`encode_thumb`](InstructionCodec::encode_thumb) for more information.
+	#[inline(always)]
+	pub const fn set_thumb2(&mut self, thumb2: bool) { self.thumb2 = thumb2 }
+
+	/// Checks if Thumb-2 encodings are enabled.
+	#[inline(always)]
+	#[must_use]
+	pub const fn is_thumb2(&self) -> bool { self.thumb2 }
+
+	/// Enables or disables the restrictions of cores before Armv6.
+	///
+	/// This is disabled by default.
+	/// When enabled, multiplications cannot use `base` as a destination on Arm.
+	#[inline(always)]
+	pub const fn set_pre_armv6(&mut self, pre_armv6: bool) { self.pre_armv6 = pre_armv6 }
+
+	/// Checks if the restrictions of cores before Armv6 are enabled.
+	#[inline(always)]
+	#[must_use]
+	pub const fn is_pre_armv6(&self) -> bool { self.pre_armv6 }
+
+	/// Retrieves the predicate of the next instruction in the current if-then block.
+	///
+	/// Outside of blocks, [`None`] is returned.
+	#[inline]
+	#[must_use]
+	pub const fn if_then_predicate(&self) -> Option<Predicate> {
+		if self.if_then.trailing_zeros() >= 0x4 {
+			None
+		} else {
+			Predicate::from_u8(self.if_then >> 0x4)
+		}
 	}
 
 	/// Sets the internal address to the provided one.
+	///
+	/// This also leaves any current if-then block.
 	#[inline(always)]
-	pub fn seek_to(&mut self, address: u32) { self.address = Wrapping(address) }
+	pub const fn seek_to(&mut self, address: u32) {
+		self.address = Wrapping(address);
+		self.if_then = 0x0;
+	}
 
 	/// Skips the given ammount of bytes.
 	#[inline(always)]
@@ -72,6 +316,64 @@ impl InstructionCodec {
 	/// Skips the given ammount of words.
 	#[inline(always)]
 	pub fn skip_words(&mut self, count: u32) { self.address += Wrapping(count) * Wrapping(0x4) }
+
+	/// Checks that the given decoded instruction can be encoded again.
+	///
+	/// Decoding is strict, so opcodes with unpredictable operands (e.g. writeback into the transferred register) are rejected.
+	/// The encoders already reject these, so the check is made by encoding the instruction using a copy of the codec.
+	fn check_encodable<T, F: FnOnce(&mut Self, Instruction) -> Result<T>>(&self, instruction: Instruction, encode: F) -> Result<()> {
+		let mut codec = self.clone();
+		codec.thumb2 = true;
+
+		encode(&mut codec, instruction).map_err(|_| Error::InvalidOpcode)?;
+		Ok(())
+	}
+
+	/// Checks the given instruction against the current if-then block.
+	fn check_if_then(&self, instruction: Instruction) -> Result<()> {
+		let Some(predicate) = self.if_then_predicate() else { return Ok(()) };
+
+		assert_or_err!(!matches!(instruction, Instruction::IfThen { .. }), Error::IllegalInstruction { reason: "cannot nest if-then blocks" });
+		assert_or_err!(!matches!(instruction, Instruction::CompareBranchNonZero { .. } | Instruction::CompareBranchZero { .. }), Error::IllegalInstruction { reason: "cannot compare and branch within if-then blocks" });
+		assert_or_err!(instruction.predicate() == predicate, Error::IllegalPredicate { reason: "must match if-then block" });
+
+		// The last instruction is reached once only the terminating bit remains.
+		assert_or_err!(!is_branch(instruction) || self.if_then.trailing_zeros() >= 0x3, Error::IllegalInstruction { reason: "branches must be last in if-then block" });
+
+		Ok(())
+	}
+
+	/// Applies the current if-then block to the given decoded instruction.
+	///
+	/// The instruction must have been decoded without its predicate.
+	fn apply_if_then(&self, mut instruction: Instruction, narrow: bool) -> Result<Instruction> {
+		let Some(predicate) = self.if_then_predicate() else { return Ok(instruction) };
+
+		// Conditional branches cannot be encoded within blocks.
+		assert_or_err!(instruction.predicate() == Predicate::Always, Error::InvalidOpcode);
+
+		instruction.set_predicate(predicate);
+
+		if narrow {
+			if let Some(s) = narrow_s_flag(&mut instruction) { *s = Sflag::Off };
+		}
+
+		self.check_if_then(instruction).map_err(|_| Error::InvalidOpcode)?;
+		Ok(instruction)
+	}
+
+	/// Enters or advances the current if-then block past the given instruction.
+	fn advance_if_then(&mut self, instruction: Instruction) -> Result<()> {
+		self.if_then = match instruction {
+			Instruction::IfThen { predicate, mask } => encode_if_then_state(predicate, mask)?,
+
+			_ if self.if_then.trailing_zeros() >= 0x3 => 0x0,
+
+			_ => self.if_then & 0b11100000 | (self.if_then << 0x1) & 0b00011111,
+		};
+
+		Ok(())
+	}
 }
 
 impl Default for InstructionCodec {
@@ -0,0 +1,120 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Pollex.
+//
+// Pollex is free software: you can redistribute it
+// and/or modify it under the terms of the GNU Af-
+// fero General Public License as published by the
+// Free Software Foundation, either version 3 of
+// the License, or (at your option) any later ver-
+// sion.
+//
+// Pollex is distributed in the hope that it will
+// be useful, but WITHOUT ANY WARRANTY; without
+// even the implied warranty of MERCHANTABILITY or
+// FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// Affero General Public License for more details.
+//
+// You should have received a copy of the GNU Af-
+// fero General Public License along with Pollex.
+// If not, see <https://www.gnu.org/licenses/>.
+
+use core::fmt::{Display, Formatter};
+use core::ops::{BitOr, BitOrAssign};
+
+/// A mask of interrupt kinds.
+///
+/// `CPSIE` and `CPSID` only enable or disable the selected kinds of interrupts:
+///
+/// | Kind | Contents                 |
+/// | :--- | :----------------------- |
+/// | `a`  | Asynchronous aborts.     |
+/// | `i`  | Interrupt requests.      |
+/// | `f`  | Fast interrupt requests. |
+///
+/// Kinds are displayed in the order `aif`, e.g.:
+///
+/// ```
+/// use pollex::arm32::InterruptMask;
+///
+/// let mask = InterruptMask::FIQ | InterruptMask::IRQ;
+///
+/// assert_eq!(mask.to_string(), "if");
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[repr(transparent)]
+pub struct InterruptMask(u8);
+
+impl InterruptMask {
+	/// Fast interrupt requests (`f`).
+	pub const FIQ: Self = Self(0b001);
+
+	/// Interrupt requests (`i`).
+	pub const IRQ: Self = Self(0b010);
+
+	/// Asynchronous aborts (`a`).
+	pub const ABORT: Self = Self(0b100);
+
+	/// All kinds.
+	pub const ALL: Self = Self(0b111);
+
+	/// Constructs a new, empty mask.
+	#[inline(always)]
+	#[must_use]
+	pub const fn new() -> Self { Self(0b000) }
+
+	/// Constructs a mask from its encoding.
+	///
+	/// Bits above the lowest three are discarded.
+	#[inline(always)]
+	#[must_use]
+	pub const fn from_u8(value: u8) -> Self { Self(value & 0b111) }
+
+	/// Extracts the mask as its encoding.
+	#[inline(always)]
+	#[must_use]
+	pub const fn to_u8(self) -> u8 { self.0 }
+
+	/// Returns the mask with the kinds of `other` added.
+	#[inline(always)]
+	#[must_use]
+	pub const fn with(self, other: Self) -> Self { Self(self.0 | other.0) }
+
+	/// Checks if all kinds of `other` are in the mask.
+	#[inline(always)]
+	#[must_use]
+	pub const fn contains(self, other: Self) -> bool { self.0 & other.0 == other.0 }
+
+	/// Checks if the mask is empty.
+	#[inline(always)]
+	#[must_use]
+	pub const fn is_empty(self) -> bool { self.0 == 0b000 }
+}
+
+impl BitOr for InterruptMask {
+	type Output = Self;
+
+	#[inline(always)]
+	fn bitor(self, rhs: Self) -> Self::Output { self.with(rhs) }
+}
+
+impl BitOrAssign for InterruptMask {
+	#[inline(always)]
+	fn bitor_assign(&mut self, rhs: Self) { *self = *self | rhs }
+}
+
+impl Display for InterruptMask {
+	fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+		const KINDS: [(InterruptMask, char); 0x3] = [
+			(InterruptMask::ABORT, 'a'),
+			(InterruptMask::IRQ,   'i'),
+			(InterruptMask::FIQ,   'f'),
+		];
+
+		for (kind, name) in KINDS {
+			if self.contains(kind) { write!(f, "{name}")? };
+		}
+
+		Ok(())
+	}
+}
@@ -26,10 +26,33 @@
 use crate::use_mod;
 use_mod!(pub address);
 use_mod!(pub arm_opcode);
+use_mod!(pub barrier_option);
+use_mod!(pub block_mode);
+use_mod!(pub coprocessor);
+use_mod!(pub coprocessor_register);
+use_mod!(pub double_register);
+use_mod!(pub element_type);
+use_mod!(pub endianness);
 use_mod!(pub flag);
+use_mod!(pub float_immediate);
+use_mod!(pub indexing);
 use_mod!(pub instruction);
 use_mod!(pub instruction_codec);
+use_mod!(pub interrupt_mask);
+use_mod!(pub parallel_operation);
+use_mod!(pub parallel_prefix);
 use_mod!(pub predicate);
+use_mod!(pub processor_mode);
+use_mod!(pub quad_register);
 use_mod!(pub register);
+use_mod!(pub register_list);
+use_mod!(pub scalar);
 use_mod!(pub shifter);
+use_mod!(pub sign);
+use_mod!(pub single_register);
+use_mod!(pub status_fields);
+use_mod!(pub status_register);
+use_mod!(pub structure_address);
 use_mod!(pub thumb_opcode);
+use_mod!(pub vector_register);
+use_mod!(pub vfp_register);
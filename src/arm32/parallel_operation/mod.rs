@@ -0,0 +1,72 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Pollex.
+//
+// Pollex is free software: you can redistribute it
+// and/or modify it under the terms of the GNU Af-
+// fero General Public License as published by the
+// Free Software Foundation, either version 3 of
+// the License, or (at your option) any later ver-
+// sion.
+//
+// Pollex is distributed in the hope that it will
+// be useful, but WITHOUT ANY WARRANTY; without
+// even the implied warranty of MERCHANTABILITY or
+// FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// Affero General Public License for more details.
+//
+// You should have received a copy of the GNU Af-
+// fero General Public License along with Pollex.
+// If not, see <https://www.gnu.org/licenses/>.
+
+use core::fmt::{Display, Formatter};
+use core::mem::transmute;
+
+/// An operation of a parallel arithmetic instruction.
+///
+/// Operations either work on two halfword lanes or four byte lanes.
+/// The exchanging operations (`ADDSUBX` and `SUBADDX`) swap the halfwords of the second operand, and then respectively add to the top and subtract from the bottom halfword (or vice versa).
+///
+/// See also [`ParallelPrefix`](crate::arm32::ParallelPrefix).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u8)]
+pub enum ParallelOperation {
+	Add16               = 0b000,
+	AddSubtractExchange = 0b001,
+	SubtractAddExchange = 0b010,
+	Subtract16          = 0b011,
+	Add8                = 0b100,
+	Subtract8           = 0b111,
+}
+
+impl ParallelOperation {
+	/// Converts the provided byte into an operation.
+	/// If the byte's value is not a valid operation, [`None`] is returned.
+	#[inline]
+	#[must_use]
+	pub const fn from_u8(value: u8) -> Option<Self> {
+		match value {
+			0b000..=0b100 | 0b111 => Some(unsafe { transmute::<u8, Self>(value) }),
+
+			_ => None,
+		}
+	}
+}
+
+impl Display for ParallelOperation {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+		use ParallelOperation::*;
+
+		let operation = match *self {
+			Add16               => "ADD16",
+			AddSubtractExchange => "ADDSUBX",
+			SubtractAddExchange => "SUBADDX",
+			Subtract16          => "SUB16",
+			Add8                => "ADD8",
+			Subtract8           => "SUB8",
+		};
+
+		write!(f, "{operation}")
+	}
+}
@@ -0,0 +1,80 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Pollex.
+//
+// Pollex is free software: you can redistribute it
+// and/or modify it under the terms of the GNU Af-
+// fero General Public License as published by the
+// Free Software Foundation, either version 3 of
+// the License, or (at your option) any later ver-
+// sion.
+//
+// Pollex is distributed in the hope that it will
+// be useful, but WITHOUT ANY WARRANTY; without
+// even the implied warranty of MERCHANTABILITY or
+// FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// Affero General Public License for more details.
+//
+// You should have received a copy of the GNU Af-
+// fero General Public License along with Pollex.
+// If not, see <https://www.gnu.org/licenses/>.
+
+use core::fmt::{Display, Formatter};
+use core::mem::transmute;
+
+/// A prefix of a parallel arithmetic instruction.
+///
+/// This denotes how the individual lanes are treated:
+///
+/// | Prefix | Lanes    | Results                            |
+/// | :----- | :------- | :--------------------------------- |
+/// | `S`    | Signed   | Wrapping (sets `GE` flags).        |
+/// | `Q`    | Signed   | Saturated.                         |
+/// | `SH`   | Signed   | Halved.                            |
+/// | `U`    | Unsigned | Wrapping (sets `GE` flags).        |
+/// | `UQ`   | Unsigned | Saturated.                         |
+/// | `UH`   | Unsigned | Halved.                            |
+///
+/// See also [`ParallelOperation`](crate::arm32::ParallelOperation).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u8)]
+pub enum ParallelPrefix {
+	Signed             = 0b001,
+	Saturating         = 0b010,
+	SignedHalving      = 0b011,
+	Unsigned           = 0b101,
+	UnsignedSaturating = 0b110,
+	UnsignedHalving    = 0b111,
+}
+
+impl ParallelPrefix {
+	/// Converts the provided byte into a prefix.
+	/// If the byte's value is not a valid prefix, [`None`] is returned.
+	#[inline]
+	#[must_use]
+	pub const fn from_u8(value: u8) -> Option<Self> {
+		match value {
+			0b001..=0b011 | 0b101..=0b111 => Some(unsafe { transmute::<u8, Self>(value) }),
+
+			_ => None,
+		}
+	}
+}
+
+impl Display for ParallelPrefix {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+		use ParallelPrefix::*;
+
+		let prefix = match *self {
+			Signed             => "S",
+			Saturating         => "Q",
+			SignedHalving      => "SH",
+			Unsigned           => "U",
+			UnsignedSaturating => "UQ",
+			UnsignedHalving    => "UH",
+		};
+
+		write!(f, "{prefix}")
+	}
+}
@@ -63,6 +63,19 @@ impl Predicate {
 			None
 		}
 	}
+
+	/// Inverts the predicate, e.g. yielding [`NotEqual`](Predicate::NotEqual) for [`Equal`](Predicate::Equal).
+	///
+	/// As [`Always`](Predicate::Always) has no inverse, [`None`] is returned for it.
+	#[inline]
+	#[must_use]
+	pub const fn inverse(self) -> Option<Self> {
+		match self {
+			Self::Always => None,
+
+			_ => Self::from_u8(self as u8 ^ 0b1),
+		}
+	}
 }
 
 impl Display for Predicate {
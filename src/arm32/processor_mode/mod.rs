@@ -0,0 +1,96 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Pollex.
+//
+// Pollex is free software: you can redistribute it
+// and/or modify it under the terms of the GNU Af-
+// fero General Public License as published by the
+// Free Software Foundation, either version 3 of
+// the License, or (at your option) any later ver-
+// sion.
+//
+// Pollex is distributed in the hope that it will
+// be useful, but WITHOUT ANY WARRANTY; without
+// even the implied warranty of MERCHANTABILITY or
+// FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// Affero General Public License for more details.
+//
+// You should have received a copy of the GNU Af-
+// fero General Public License along with Pollex.
+// If not, see <https://www.gnu.org/licenses/>.
+
+use core::fmt::{Display, Formatter};
+use core::mem::transmute;
+
+/// A processor mode.
+///
+/// The mode is stored in the lowest five bits of `CPSR` and can be changed using `CPS`:
+///
+/// ```as
+/// CPS #19
+/// CPSID if, #31
+/// ```
+///
+/// Modes are displayed using their conventional abbreviations (e.g. `svc`), but are written as their encodings in assembly.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[repr(u8)]
+pub enum ProcessorMode {
+	User       = 0b10000,
+	Fiq        = 0b10001,
+	Irq        = 0b10010,
+	Supervisor = 0b10011,
+	Monitor    = 0b10110,
+	Abort      = 0b10111,
+	Hypervisor = 0b11010,
+	Undefined  = 0b11011,
+	System     = 0b11111,
+}
+
+impl ProcessorMode {
+	/// Converts the provided byte into a processor mode.
+	/// If the byte's value is not a valid mode, [`None`] is returned.
+	#[inline]
+	#[must_use]
+	pub const fn from_u8(value: u8) -> Option<Self> {
+		match value {
+			| 0b10000
+			| 0b10001
+			| 0b10010
+			| 0b10011
+			| 0b10110
+			| 0b10111
+			| 0b11010
+			| 0b11011
+			| 0b11111
+			=> Some(unsafe { transmute::<u8, Self>(value) }),
+
+			_ => None,
+		}
+	}
+
+	/// Checks if the mode is privileged, i.e. any mode but [`User`](ProcessorMode::User).
+	#[inline(always)]
+	#[must_use]
+	pub const fn is_privileged(self) -> bool { !matches!(self, Self::User) }
+}
+
+impl Display for ProcessorMode {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+		use ProcessorMode::*;
+
+		let name = match *self {
+			User       => "usr",
+			Fiq        => "fiq",
+			Irq        => "irq",
+			Supervisor => "svc",
+			Monitor    => "mon",
+			Abort      => "abt",
+			Hypervisor => "hyp",
+			Undefined  => "und",
+			System     => "sys",
+		};
+
+		write!(f, "{name}")
+	}
+}
@@ -0,0 +1,123 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Pollex.
+//
+// Pollex is free software: you can redistribute it
+// and/or modify it under the terms of the GNU Af-
+// fero General Public License as published by the
+// Free Software Foundation, either version 3 of
+// the License, or (at your option) any later ver-
+// sion.
+//
+// Pollex is distributed in the hope that it will
+// be useful, but WITHOUT ANY WARRANTY; without
+// even the implied warranty of MERCHANTABILITY or
+// FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// Affero General Public License for more details.
+//
+// You should have received a copy of the GNU Af-
+// fero General Public License along with Pollex.
+// If not, see <https://www.gnu.org/licenses/>.
+
+use alloc::borrow::ToOwned;
+
+use crate::Error;
+
+use core::fmt::Display;
+use core::mem::transmute;
+use core::str::FromStr;
+
+/// A quadword Advanced SIMD register.
+///
+/// These are the 128-bit registers of the Advanced SIMD (NEON) extension, from `q0` to `q15`.
+/// Each quad register overlaps two double registers, e.g. `q1` is made up of `d2` and `d3`.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[repr(u8)]
+pub enum QuadRegister {
+	Q0  = 0b0000,
+	Q1  = 0b0001,
+	Q2  = 0b0010,
+	Q3  = 0b0011,
+	Q4  = 0b0100,
+	Q5  = 0b0101,
+	Q6  = 0b0110,
+	Q7  = 0b0111,
+	Q8  = 0b1000,
+	Q9  = 0b1001,
+	Q10 = 0b1010,
+	Q11 = 0b1011,
+	Q12 = 0b1100,
+	Q13 = 0b1101,
+	Q14 = 0b1110,
+	Q15 = 0b1111,
+}
+
+impl QuadRegister {
+	/// Converts the provided byte into a register identifier.
+	/// If the byte's value is not a valid identifier, [`None`] is returned.
+	///
+	/// This conversion is valid for all `4`-bit values.
+	#[inline]
+	#[must_use]
+	pub const fn from_u8(value: u8) -> Option<Self> {
+		if value <= 0b1111 {
+			Some(unsafe { transmute::<u8, Self>(value) })
+		} else {
+			None
+		}
+	}
+}
+
+impl Display for QuadRegister {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		use QuadRegister::*;
+
+		match *self {
+			Q0     => write!(f, "q0"),
+			Q1     => write!(f, "q1"),
+			Q2     => write!(f, "q2"),
+			Q3     => write!(f, "q3"),
+			Q4     => write!(f, "q4"),
+			Q5     => write!(f, "q5"),
+			Q6     => write!(f, "q6"),
+			Q7     => write!(f, "q7"),
+			Q8     => write!(f, "q8"),
+			Q9     => write!(f, "q9"),
+			Q10    => write!(f, "q10"),
+			Q11    => write!(f, "q11"),
+			Q12    => write!(f, "q12"),
+			Q13    => write!(f, "q13"),
+			Q14    => write!(f, "q14"),
+			Q15    => write!(f, "q15"),
+		}
+	}
+}
+
+impl FromStr for QuadRegister {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		use QuadRegister::*;
+
+		match s.to_lowercase().as_str() {
+			"q0"  => Ok(Q0),
+			"q1"  => Ok(Q1),
+			"q2"  => Ok(Q2),
+			"q3"  => Ok(Q3),
+			"q4"  => Ok(Q4),
+			"q5"  => Ok(Q5),
+			"q6"  => Ok(Q6),
+			"q7"  => Ok(Q7),
+			"q8"  => Ok(Q8),
+			"q9"  => Ok(Q9),
+			"q10" => Ok(Q10),
+			"q11" => Ok(Q11),
+			"q12" => Ok(Q12),
+			"q13" => Ok(Q13),
+			"q14" => Ok(Q14),
+			"q15" => Ok(Q15),
+
+			_ => Err(Error::UnknownRegister(s.to_owned()))
+		}
+	}
+}
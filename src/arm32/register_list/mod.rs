@@ -0,0 +1,327 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Pollex.
+//
+// Pollex is free software: you can redistribute it
+// and/or modify it under the terms of the GNU Af-
+// fero General Public License as published by the
+// Free Software Foundation, either version 3 of
+// the License, or (at your option) any later ver-
+// sion.
+//
+// Pollex is distributed in the hope that it will
+// be useful, but WITHOUT ANY WARRANTY; without
+// even the implied warranty of MERCHANTABILITY or
+// FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// Affero General Public License for more details.
+//
+// You should have received a copy of the GNU Af-
+// fero General Public License along with Pollex.
+// If not, see <https://www.gnu.org/licenses/>.
+
+use crate::arm32::Register;
+
+use core::fmt::{Display, Formatter};
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Not, Sub, SubAssign};
+
+/// A set of registers.
+///
+/// These are used by load/store multiple instructions, e.g.:
+///
+/// ```as
+/// PUSH {r4-r7, lr}
+/// LDMIA r0!, {r1, r2}
+/// ```
+///
+/// The list is stored as a bitset with bit `n` denoting `rn`, which is also how it is encoded in most opcodes.
+/// Registers are thus always ordered by their identifiers, regardless of the order in which they were added.
+///
+/// # Examples
+///
+/// ```
+/// use pollex::arm32::{Register, RegisterList};
+///
+/// let list = RegisterList::new()
+///     .with(Register::R4)
+///     .with(Register::R5)
+///     .with(Register::R6)
+///     .with(Register::R7)
+///     .with(Register::Lr);
+///
+/// assert_eq!(list.to_string(), "{r4-r7, lr}");
+/// assert_eq!(list.len(), 0x5);
+/// assert!(list.contains(Register::R6));
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[repr(transparent)]
+pub struct RegisterList(u16);
+
+impl RegisterList {
+	/// Constructs a new, empty list.
+	#[inline(always)]
+	#[must_use]
+	pub const fn new() -> Self { Self(0b00000000_00000000) }
+
+	/// Constructs a list from a bitset.
+	///
+	/// Bit `n` denotes register `rn`.
+	#[inline(always)]
+	#[must_use]
+	pub const fn from_u16(value: u16) -> Self { Self(value) }
+
+	/// Extracts the list as a bitset.
+	#[inline(always)]
+	#[must_use]
+	pub const fn to_u16(self) -> u16 { self.0 }
+
+	/// Returns the list with the given register added.
+	#[inline(always)]
+	#[must_use]
+	pub const fn with(self, register: Register) -> Self { Self(self.0 | Self::bit(register)) }
+
+	/// Returns the list with the given register removed.
+	#[inline(always)]
+	#[must_use]
+	pub const fn without(self, register: Register) -> Self { Self(self.0 & !Self::bit(register)) }
+
+	/// Adds the given register to the list.
+	///
+	/// If the register was already present, `false` is returned.
+	#[inline]
+	pub const fn insert(&mut self, register: Register) -> bool {
+		let present = self.contains(register);
+		self.0 |= Self::bit(register);
+
+		!present
+	}
+
+	/// Removes the given register from the list.
+	///
+	/// If the register was not present, `false` is returned.
+	#[inline]
+	pub const fn remove(&mut self, register: Register) -> bool {
+		let present = self.contains(register);
+		self.0 &= !Self::bit(register);
+
+		present
+	}
+
+	/// Checks if the given register is in the list.
+	#[inline(always)]
+	#[must_use]
+	pub const fn contains(self, register: Register) -> bool { self.0 & Self::bit(register) != 0x0 }
+
+	/// Counts the registers in the list.
+	#[inline(always)]
+	#[must_use]
+	pub const fn len(self) -> u32 { self.0.count_ones() }
+
+	/// Checks if the list is empty.
+	#[inline(always)]
+	#[must_use]
+	pub const fn is_empty(self) -> bool { self.0 == 0x0 }
+
+	/// Retrieves the lowest-numbered register in the list.
+	///
+	/// If the list is empty, [`None`] is returned.
+	#[inline]
+	#[must_use]
+	pub const fn lowest(self) -> Option<Register> {
+		if self.is_empty() { return None };
+
+		Register::from_u8(self.0.trailing_zeros() as u8)
+	}
+
+	/// Retrieves the highest-numbered register in the list.
+	///
+	/// If the list is empty, [`None`] is returned.
+	#[inline]
+	#[must_use]
+	pub const fn highest(self) -> Option<Register> {
+		if self.is_empty() { return None };
+
+		Register::from_u8((0xF - self.0.leading_zeros()) as u8)
+	}
+
+	/// Checks if the list only contains low registers.
+	///
+	/// See also [`Register::is_low`].
+	#[inline(always)]
+	#[must_use]
+	pub const fn is_low(self) -> bool { self.0 & 0b11111111_00000000 == 0x0 }
+
+	/// Computes the union of two lists.
+	#[inline(always)]
+	#[must_use]
+	pub const fn union(self, other: Self) -> Self { Self(self.0 | other.0) }
+
+	/// Computes the intersection of two lists.
+	#[inline(always)]
+	#[must_use]
+	pub const fn intersection(self, other: Self) -> Self { Self(self.0 & other.0) }
+
+	/// Computes the registers of `self` that are not in `other`.
+	#[inline(always)]
+	#[must_use]
+	pub const fn difference(self, other: Self) -> Self { Self(self.0 & !other.0) }
+
+	/// Checks if all registers of `self` are also in `other`.
+	#[inline(always)]
+	#[must_use]
+	pub const fn is_subset(self, other: Self) -> bool { self.0 & !other.0 == 0x0 }
+
+	/// Iterates over the registers in the list, in ascending order.
+	#[inline(always)]
+	pub const fn iter(&self) -> IntoIter { IntoIter(self.0) }
+
+	#[inline(always)]
+	#[must_use]
+	const fn bit(register: Register) -> u16 { 0b1 << register as u16 }
+}
+
+impl BitAnd for RegisterList {
+	type Output = Self;
+
+	#[inline(always)]
+	fn bitand(self, rhs: Self) -> Self::Output { self.intersection(rhs) }
+}
+
+impl BitAndAssign for RegisterList {
+	#[inline(always)]
+	fn bitand_assign(&mut self, rhs: Self) { *self = *self & rhs }
+}
+
+impl BitOr for RegisterList {
+	type Output = Self;
+
+	#[inline(always)]
+	fn bitor(self, rhs: Self) -> Self::Output { self.union(rhs) }
+}
+
+impl BitOrAssign for RegisterList {
+	#[inline(always)]
+	fn bitor_assign(&mut self, rhs: Self) { *self = *self | rhs }
+}
+
+impl Display for RegisterList {
+	fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+		write!(f, "{{")?;
+
+		let mut registers = self.iter().peekable();
+		let mut first     = true;
+
+		while let Some(start) = registers.next() {
+			if !first { write!(f, ", ")? };
+			first = false;
+
+			// Only ranges of at least three general-purpose
+			// (numbered) registers are collapsed.
+			let mut end = start;
+			while let Some(&next) = registers.peek() {
+				if next > Register::R12 || next as u8 != end as u8 + 0x1 { break };
+
+				end = next;
+				registers.next();
+			}
+
+			match end as u8 - start as u8 {
+				0x0 => write!(f, "{start}")?,
+
+				0x1 => write!(f, "{start}, {end}")?,
+
+				_ => write!(f, "{start}-{end}")?,
+			}
+		}
+
+		write!(f, "}}")
+	}
+}
+
+impl From<Register> for RegisterList {
+	#[inline(always)]
+	fn from(value: Register) -> Self { Self::new().with(value) }
+}
+
+impl From<u16> for RegisterList {
+	#[inline(always)]
+	fn from(value: u16) -> Self { Self::from_u16(value) }
+}
+
+impl From<RegisterList> for u16 {
+	#[inline(always)]
+	fn from(value: RegisterList) -> Self { value.to_u16() }
+}
+
+impl FromIterator<Register> for RegisterList {
+	#[inline]
+	fn from_iter<I: IntoIterator<Item = Register>>(iter: I) -> Self {
+		let mut list = Self::new();
+		for register in iter { list.insert(register); }
+
+		list
+	}
+}
+
+impl IntoIterator for RegisterList {
+	type Item = Register;
+
+	type IntoIter = IntoIter;
+
+	#[inline(always)]
+	fn into_iter(self) -> Self::IntoIter { IntoIter(self.0) }
+}
+
+impl IntoIterator for &RegisterList {
+	type Item = Register;
+
+	type IntoIter = IntoIter;
+
+	#[inline(always)]
+	fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl Not for RegisterList {
+	type Output = Self;
+
+	#[inline(always)]
+	fn not(self) -> Self::Output { Self(!self.0) }
+}
+
+impl Sub for RegisterList {
+	type Output = Self;
+
+	#[inline(always)]
+	fn sub(self, rhs: Self) -> Self::Output { self.difference(rhs) }
+}
+
+impl SubAssign for RegisterList {
+	#[inline(always)]
+	fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs }
+}
+
+/// Iterator over the registers of a [`RegisterList`].
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct IntoIter(u16);
+
+impl Iterator for IntoIter {
+	type Item = Register;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.0 == 0x0 { return None };
+
+		let register = Register::from_u8(self.0.trailing_zeros() as u8);
+		self.0 &= self.0 - 0x1;
+
+		register
+	}
+
+	#[inline(always)]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.0.count_ones() as usize;
+		(len, Some(len))
+	}
+}
+
+impl ExactSizeIterator for IntoIter { }
@@ -0,0 +1,48 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Pollex.
+//
+// Pollex is free software: you can redistribute it
+// and/or modify it under the terms of the GNU Af-
+// fero General Public License as published by the
+// Free Software Foundation, either version 3 of
+// the License, or (at your option) any later ver-
+// sion.
+//
+// Pollex is distributed in the hope that it will
+// be useful, but WITHOUT ANY WARRANTY; without
+// even the implied warranty of MERCHANTABILITY or
+// FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// Affero General Public License for more details.
+//
+// You should have received a copy of the GNU Af-
+// fero General Public License along with Pollex.
+// If not, see <https://www.gnu.org/licenses/>.
+
+use crate::arm32::DoubleRegister;
+
+use core::fmt::{Display, Formatter};
+
+/// A scalar of a double register.
+///
+/// This denotes a single element (or lane) of a double register, e.g. `d1[3]`.
+/// Which element is denoted by `index` depends on the element size of the instruction in question.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Scalar {
+	pub register: DoubleRegister,
+	pub index:    u8,
+}
+
+impl Scalar {
+	/// Constructs a new scalar.
+	#[inline(always)]
+	#[must_use]
+	pub const fn new(register: DoubleRegister, index: u8) -> Self { Self { register, index } }
+}
+
+impl Display for Scalar {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+		write!(f, "{}[{}]", self.register, self.index)
+	}
+}
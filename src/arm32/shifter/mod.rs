@@ -53,6 +53,10 @@ use core::fmt::{Display, Formatter};
 /// In theory, these values are limited to *at most* `24` bits on Arm, and to `11` bits on Thumb.
 /// In practice, however, these are even further limited -- in some cases down to `3` bits.
 ///
+/// Data-processing instructions on Arm encode their immediates as an `8`-bit value rotated right by an even ammount of figures.
+/// Thus, `0x000000FF`, `0x00000FF0`, and `0xF000000F` can all be encoded, whilst `0x00000101` cannot.
+/// See [`is_arm_encodable`](Shifter::is_arm_encodable) for querying this ahead of encoding.
+///
 /// # Shifts
 ///
 /// As mentioned, shifters can also incorporate the functionality of other instructions.
@@ -138,6 +142,98 @@ impl Shifter {
 			Err(Error::IllegalShifter { reason: "cannot collapse to register" })
 		}
 	}
+
+	/// Checks if the shifter can be encoded on Arm.
+	///
+	/// For immediates, this tests whether the value can be represented as an `8`-bit value rotated right by an even ammount.
+	/// For immediate shifts, this tests the shift ammount against the ranges described in *Shifts*.
+	/// Register shifts cannot use `pc` as either the source or the shift register.
+	#[must_use]
+	pub const fn is_arm_encodable(self) -> bool {
+		use Shifter::*;
+
+		match self {
+			Immediate(source) => Self::encode_arm_immediate(source).is_some(),
+
+			LogicalShiftLeftImmediate { shift, .. } => shift <= 0x1F,
+
+			| ArithmeticShiftRightImmediate { shift, .. }
+			| LogicalShiftRightImmediate { shift, .. }
+			=> shift >= 0x1 && shift <= 0x20,
+
+			RotateRightImmediate { shift, .. } => shift >= 0x1 && shift <= 0x1F,
+
+			| ArithmeticShiftRightRegister { source, shift }
+			| LogicalShiftLeftRegister { source, shift }
+			| LogicalShiftRightRegister { source, shift }
+			| RotateRightRegister { source, shift }
+			=> source as u8 != Register::Pc as u8 && shift as u8 != Register::Pc as u8,
+
+			RotateRightExtend { .. } => true,
+		}
+	}
+
+	/// Encodes the given value as an Arm modified immediate.
+	///
+	/// On success, the `8`-bit value and the `4`-bit rotation field (i.e. half the rotation) are returned.
+	/// If multiple rotations can encode the value, the smallest one is chosen as is canonical.
+	#[must_use]
+	pub(in crate) const fn encode_arm_immediate(value: u32) -> Option<(u32, u32)> {
+		let mut rotate = 0x0;
+		while rotate <= 0xF {
+			let source = value.rotate_left(rotate * 0x2);
+			if source <= 0xFF { return Some((source, rotate)) };
+
+			rotate += 0x1;
+		}
+
+		None
+	}
+
+	/// Encodes the given value as a Thumb modified immediate.
+	///
+	/// On success, the `12`-bit `i:imm3:imm8` field is returned.
+	/// This is either an `8`-bit value replicated across the word in one of three patterns, or an `8`-bit value with its highest bit set rotated right by (8) to (31).
+	#[must_use]
+	pub(in crate) const fn encode_thumb_immediate(value: u32) -> Option<u32> {
+		let byte = value & 0xFF;
+
+		if value <= 0xFF { return Some(value) };
+
+		if value == byte << 0x10 | byte { return Some(0b0001_00000000 | byte) };
+
+		let byte = (value >> 0x8) & 0xFF;
+		if value == byte << 0x18 | byte << 0x8 { return Some(0b0010_00000000 | byte) };
+
+		if value == byte.wrapping_mul(0x01010101) { return Some(0b0011_00000000 | byte) };
+
+		let rotate = value.leading_zeros() + 0x8;
+		let source = value.rotate_left(rotate);
+
+		if source > 0xFF { return None };
+
+		Some(rotate << 0x7 | source & 0b01111111)
+	}
+
+	/// Decodes the given Thumb modified immediate.
+	///
+	/// This is the inverse of [`encode_thumb_immediate`](Shifter::encode_thumb_immediate) and takes the same `12`-bit field.
+	#[must_use]
+	pub(in crate) const fn decode_thumb_immediate(field: u32) -> u32 {
+		let byte = field & 0xFF;
+
+		match (field >> 0x8) & 0b1111 {
+			0b0000 => byte,
+
+			0b0001 => byte << 0x10 | byte,
+
+			0b0010 => byte << 0x18 | byte << 0x8,
+
+			0b0011 => byte.wrapping_mul(0x01010101),
+
+			_ => (0b10000000 | field & 0b01111111).rotate_right((field >> 0x7) & 0b11111),
+		}
+	}
 }
 
 impl Display for Shifter {
@@ -0,0 +1,71 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Pollex.
+//
+// Pollex is free software: you can redistribute it
+// and/or modify it under the terms of the GNU Af-
+// fero General Public License as published by the
+// Free Software Foundation, either version 3 of
+// the License, or (at your option) any later ver-
+// sion.
+//
+// Pollex is distributed in the hope that it will
+// be useful, but WITHOUT ANY WARRANTY; without
+// even the implied warranty of MERCHANTABILITY or
+// FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// Affero General Public License for more details.
+//
+// You should have received a copy of the GNU Af-
+// fero General Public License along with Pollex.
+// If not, see <https://www.gnu.org/licenses/>.
+
+use core::fmt::{Display, Formatter};
+
+/// The sign of an offset.
+///
+/// Register offsets can be either added to or subtracted from their base:
+///
+/// ```as
+/// LDR r0, [r1, r2]
+/// LDR r0, [r1, -r2]
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Sign {
+	Positive,
+
+	Negative,
+}
+
+impl Sign {
+	/// Checks if the sign is positive.
+	#[inline(always)]
+	#[must_use]
+	pub const fn is_positive(self) -> bool { matches!(self, Self::Positive) }
+
+	/// Checks if the sign is negative.
+	#[inline(always)]
+	#[must_use]
+	pub const fn is_negative(self) -> bool { matches!(self, Self::Negative) }
+}
+
+impl Display for Sign {
+	fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+		if self.is_negative() { write!(f, "-")? };
+
+		Ok(())
+	}
+}
+
+impl From<bool> for Sign {
+	/// Converts a U bit into a sign.
+	///
+	/// That is, `true` (add) maps to positive and `false` (subtract) maps to negative.
+	#[inline(always)]
+	fn from(value: bool) -> Self {
+		if value {
+			Self::Positive
+		} else {
+			Self::Negative
+		}
+	}
+}
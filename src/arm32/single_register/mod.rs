@@ -0,0 +1,171 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Pollex.
+//
+// Pollex is free software: you can redistribute it
+// and/or modify it under the terms of the GNU Af-
+// fero General Public License as published by the
+// Free Software Foundation, either version 3 of
+// the License, or (at your option) any later ver-
+// sion.
+//
+// Pollex is distributed in the hope that it will
+// be useful, but WITHOUT ANY WARRANTY; without
+// even the implied warranty of MERCHANTABILITY or
+// FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// Affero General Public License for more details.
+//
+// You should have received a copy of the GNU Af-
+// fero General Public License along with Pollex.
+// If not, see <https://www.gnu.org/licenses/>.
+
+use alloc::borrow::ToOwned;
+
+use crate::Error;
+
+use core::fmt::Display;
+use core::mem::transmute;
+use core::str::FromStr;
+
+/// A single-precision floating-point register.
+///
+/// These are the 32-bit registers of the VFP extension, from `s0` to `s31`.
+/// Each pair of single registers overlaps a double register, e.g. `s2` and `s3` make up `d1`.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[repr(u8)]
+pub enum SingleRegister {
+	S0  = 0b00000,
+	S1  = 0b00001,
+	S2  = 0b00010,
+	S3  = 0b00011,
+	S4  = 0b00100,
+	S5  = 0b00101,
+	S6  = 0b00110,
+	S7  = 0b00111,
+	S8  = 0b01000,
+	S9  = 0b01001,
+	S10 = 0b01010,
+	S11 = 0b01011,
+	S12 = 0b01100,
+	S13 = 0b01101,
+	S14 = 0b01110,
+	S15 = 0b01111,
+	S16 = 0b10000,
+	S17 = 0b10001,
+	S18 = 0b10010,
+	S19 = 0b10011,
+	S20 = 0b10100,
+	S21 = 0b10101,
+	S22 = 0b10110,
+	S23 = 0b10111,
+	S24 = 0b11000,
+	S25 = 0b11001,
+	S26 = 0b11010,
+	S27 = 0b11011,
+	S28 = 0b11100,
+	S29 = 0b11101,
+	S30 = 0b11110,
+	S31 = 0b11111,
+}
+
+impl SingleRegister {
+	/// Converts the provided byte into a register identifier.
+	/// If the byte's value is not a valid identifier, [`None`] is returned.
+	///
+	/// This conversion is valid for all `5`-bit values.
+	#[inline]
+	#[must_use]
+	pub const fn from_u8(value: u8) -> Option<Self> {
+		if value <= 0b11111 {
+			Some(unsafe { transmute::<u8, Self>(value) })
+		} else {
+			None
+		}
+	}
+}
+
+impl Display for SingleRegister {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		use SingleRegister::*;
+
+		match *self {
+			S0     => write!(f, "s0"),
+			S1     => write!(f, "s1"),
+			S2     => write!(f, "s2"),
+			S3     => write!(f, "s3"),
+			S4     => write!(f, "s4"),
+			S5     => write!(f, "s5"),
+			S6     => write!(f, "s6"),
+			S7     => write!(f, "s7"),
+			S8     => write!(f, "s8"),
+			S9     => write!(f, "s9"),
+			S10    => write!(f, "s10"),
+			S11    => write!(f, "s11"),
+			S12    => write!(f, "s12"),
+			S13    => write!(f, "s13"),
+			S14    => write!(f, "s14"),
+			S15    => write!(f, "s15"),
+			S16    => write!(f, "s16"),
+			S17    => write!(f, "s17"),
+			S18    => write!(f, "s18"),
+			S19    => write!(f, "s19"),
+			S20    => write!(f, "s20"),
+			S21    => write!(f, "s21"),
+			S22    => write!(f, "s22"),
+			S23    => write!(f, "s23"),
+			S24    => write!(f, "s24"),
+			S25    => write!(f, "s25"),
+			S26    => write!(f, "s26"),
+			S27    => write!(f, "s27"),
+			S28    => write!(f, "s28"),
+			S29    => write!(f, "s29"),
+			S30    => write!(f, "s30"),
+			S31    => write!(f, "s31"),
+		}
+	}
+}
+
+impl FromStr for SingleRegister {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		use SingleRegister::*;
+
+		match s.to_lowercase().as_str() {
+			"s0"  => Ok(S0),
+			"s1"  => Ok(S1),
+			"s2"  => Ok(S2),
+			"s3"  => Ok(S3),
+			"s4"  => Ok(S4),
+			"s5"  => Ok(S5),
+			"s6"  => Ok(S6),
+			"s7"  => Ok(S7),
+			"s8"  => Ok(S8),
+			"s9"  => Ok(S9),
+			"s10" => Ok(S10),
+			"s11" => Ok(S11),
+			"s12" => Ok(S12),
+			"s13" => Ok(S13),
+			"s14" => Ok(S14),
+			"s15" => Ok(S15),
+			"s16" => Ok(S16),
+			"s17" => Ok(S17),
+			"s18" => Ok(S18),
+			"s19" => Ok(S19),
+			"s20" => Ok(S20),
+			"s21" => Ok(S21),
+			"s22" => Ok(S22),
+			"s23" => Ok(S23),
+			"s24" => Ok(S24),
+			"s25" => Ok(S25),
+			"s26" => Ok(S26),
+			"s27" => Ok(S27),
+			"s28" => Ok(S28),
+			"s29" => Ok(S29),
+			"s30" => Ok(S30),
+			"s31" => Ok(S31),
+
+			_ => Err(Error::UnknownRegister(s.to_owned()))
+		}
+	}
+}
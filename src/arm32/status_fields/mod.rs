@@ -0,0 +1,125 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Pollex.
+//
+// Pollex is free software: you can redistribute it
+// and/or modify it under the terms of the GNU Af-
+// fero General Public License as published by the
+// Free Software Foundation, either version 3 of
+// the License, or (at your option) any later ver-
+// sion.
+//
+// Pollex is distributed in the hope that it will
+// be useful, but WITHOUT ANY WARRANTY; without
+// even the implied warranty of MERCHANTABILITY or
+// FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// Affero General Public License for more details.
+//
+// You should have received a copy of the GNU Af-
+// fero General Public License along with Pollex.
+// If not, see <https://www.gnu.org/licenses/>.
+
+use core::fmt::{Display, Formatter};
+use core::ops::{BitOr, BitOrAssign};
+
+/// A mask of status register fields.
+///
+/// `MSR` only writes the selected bytes of its status register:
+///
+/// | Field | Bits   | Contents                          |
+/// | :---- | :----- | :-------------------------------- |
+/// | `c`   | 0..8   | Mode, interrupt masks, and state. |
+/// | `x`   | 8..16  | Extension.                        |
+/// | `s`   | 16..24 | Status.                           |
+/// | `f`   | 24..32 | Condition flags.                  |
+///
+/// Fields are displayed in the order `fsxc`, e.g.:
+///
+/// ```
+/// use pollex::arm32::StatusFields;
+///
+/// let fields = StatusFields::CONTROL | StatusFields::FLAGS;
+///
+/// assert_eq!(fields.to_string(), "fc");
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[repr(transparent)]
+pub struct StatusFields(u8);
+
+impl StatusFields {
+	/// The control field (`c`).
+	pub const CONTROL: Self = Self(0b0001);
+
+	/// The extension field (`x`).
+	pub const EXTENSION: Self = Self(0b0010);
+
+	/// The status field (`s`).
+	pub const STATUS: Self = Self(0b0100);
+
+	/// The flags field (`f`).
+	pub const FLAGS: Self = Self(0b1000);
+
+	/// All fields.
+	pub const ALL: Self = Self(0b1111);
+
+	/// Constructs a new, empty mask.
+	#[inline(always)]
+	#[must_use]
+	pub const fn new() -> Self { Self(0b0000) }
+
+	/// Constructs a mask from its encoding.
+	///
+	/// Bits above the lowest four are discarded.
+	#[inline(always)]
+	#[must_use]
+	pub const fn from_u8(value: u8) -> Self { Self(value & 0b1111) }
+
+	/// Extracts the mask as its encoding.
+	#[inline(always)]
+	#[must_use]
+	pub const fn to_u8(self) -> u8 { self.0 }
+
+	/// Returns the mask with the fields of `other` added.
+	#[inline(always)]
+	#[must_use]
+	pub const fn with(self, other: Self) -> Self { Self(self.0 | other.0) }
+
+	/// Checks if all fields of `other` are in the mask.
+	#[inline(always)]
+	#[must_use]
+	pub const fn contains(self, other: Self) -> bool { self.0 & other.0 == other.0 }
+
+	/// Checks if the mask is empty.
+	#[inline(always)]
+	#[must_use]
+	pub const fn is_empty(self) -> bool { self.0 == 0b0000 }
+}
+
+impl BitOr for StatusFields {
+	type Output = Self;
+
+	#[inline(always)]
+	fn bitor(self, rhs: Self) -> Self::Output { self.with(rhs) }
+}
+
+impl BitOrAssign for StatusFields {
+	#[inline(always)]
+	fn bitor_assign(&mut self, rhs: Self) { *self = *self | rhs }
+}
+
+impl Display for StatusFields {
+	fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+		const FIELDS: [(StatusFields, char); 0x4] = [
+			(StatusFields::FLAGS,     'f'),
+			(StatusFields::STATUS,    's'),
+			(StatusFields::EXTENSION, 'x'),
+			(StatusFields::CONTROL,   'c'),
+		];
+
+		for (field, name) in FIELDS {
+			if self.contains(field) { write!(f, "{name}")? };
+		}
+
+		Ok(())
+	}
+}
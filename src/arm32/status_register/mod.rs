@@ -0,0 +1,68 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Pollex.
+//
+// Pollex is free software: you can redistribute it
+// and/or modify it under the terms of the GNU Af-
+// fero General Public License as published by the
+// Free Software Foundation, either version 3 of
+// the License, or (at your option) any later ver-
+// sion.
+//
+// Pollex is distributed in the hope that it will
+// be useful, but WITHOUT ANY WARRANTY; without
+// even the implied warranty of MERCHANTABILITY or
+// FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// Affero General Public License for more details.
+//
+// You should have received a copy of the GNU Af-
+// fero General Public License along with Pollex.
+// If not, see <https://www.gnu.org/licenses/>.
+
+use core::fmt::{Display, Formatter};
+
+/// A program status register.
+///
+/// This is either the current (`CPSR`) or the saved (`SPSR`) program status register.
+/// The latter is banked per exception mode and is not accessible from user or system mode.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StatusRegister {
+	Cpsr,
+
+	Spsr,
+}
+
+impl StatusRegister {
+	/// Checks if the register is the saved status register.
+	///
+	/// This corresponds to the R bit of `MRS` and `MSR`.
+	#[inline(always)]
+	#[must_use]
+	pub const fn is_saved(self) -> bool { matches!(self, Self::Spsr) }
+}
+
+impl Display for StatusRegister {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+		use StatusRegister::*;
+
+		let name = match *self {
+			Cpsr => "CPSR",
+			Spsr => "SPSR",
+		};
+
+		write!(f, "{name}")
+	}
+}
+
+impl From<bool> for StatusRegister {
+	/// Converts an R bit into a status register.
+	#[inline(always)]
+	fn from(value: bool) -> Self {
+		if value {
+			Self::Spsr
+		} else {
+			Self::Cpsr
+		}
+	}
+}
@@ -0,0 +1,88 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Pollex.
+//
+// Pollex is free software: you can redistribute it
+// and/or modify it under the terms of the GNU Af-
+// fero General Public License as published by the
+// Free Software Foundation, either version 3 of
+// the License, or (at your option) any later ver-
+// sion.
+//
+// Pollex is distributed in the hope that it will
+// be useful, but WITHOUT ANY WARRANTY; without
+// even the implied warranty of MERCHANTABILITY or
+// FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// Affero General Public License for more details.
+//
+// You should have received a copy of the GNU Af-
+// fero General Public License along with Pollex.
+// If not, see <https://www.gnu.org/licenses/>.
+
+use crate::arm32::Register;
+
+use core::fmt::{Display, Formatter};
+
+/// An address of an Advanced SIMD structure transfer.
+///
+/// In contrast to [`Address`](crate::arm32::Address), these never take offsets.
+/// They instead optionally increment the base register after the transfer, either by the size of the transfer or by a register.
+///
+/// The `alignment` of the address is given in bits, with `0` denoting the standard alignment.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StructureAddress {
+	Offset { base: Register, alignment: u32 },
+
+	PostIndexed { base: Register, alignment: u32 },
+
+	RegisterPostIndexed { base: Register, alignment: u32, source: Register },
+}
+
+impl StructureAddress {
+	/// Creates a new address from a lone base register.
+	#[inline(always)]
+	#[must_use]
+	pub const fn from_register(base: Register) -> Self {
+		Self::Offset { base, alignment: 0x0 }
+	}
+
+	/// Retrieves the base register.
+	#[inline]
+	#[must_use]
+	pub const fn base(self) -> Register {
+		match self {
+			| Self::Offset { base, .. }
+			| Self::PostIndexed { base, .. }
+			| Self::RegisterPostIndexed { base, .. }
+			=> base,
+		}
+	}
+
+	/// Retrieves the alignment in bits.
+	#[inline]
+	#[must_use]
+	pub const fn alignment(self) -> u32 {
+		match self {
+			| Self::Offset { alignment, .. }
+			| Self::PostIndexed { alignment, .. }
+			| Self::RegisterPostIndexed { alignment, .. }
+			=> alignment,
+		}
+	}
+}
+
+impl Display for StructureAddress {
+	fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+		write!(f, "[{}", self.base())?;
+		if self.alignment() != 0x0 { write!(f, ":{}", self.alignment())? };
+		write!(f, "]")?;
+
+		match *self {
+			Self::Offset { .. } => Ok(()),
+
+			Self::PostIndexed { .. } => write!(f, "!"),
+
+			Self::RegisterPostIndexed { source, .. } => write!(f, ", {source}"),
+		}
+	}
+}
@@ -41,6 +41,14 @@ impl ThumbOpcode {
 	#[inline(always)]
 	#[must_use]
 	pub const fn to_u16(self) -> u16 { self.0.to_le() }
+
+	/// Checks if the opcode is the first halfword of a wide (32-bit) opcode.
+	///
+	/// Such opcodes are prefixed by either `0b11101`, `0b11110`, or `0b11111`, and must be decoded together with the following halfword.
+	/// See [`decode_thumb_wide`](crate::arm32::InstructionCodec::decode_thumb_wide).
+	#[inline(always)]
+	#[must_use]
+	pub const fn is_wide_prefix(self) -> bool { self.to_u16() >> 0xB >= 0b11101 }
 }
 
 impl Debug for ThumbOpcode {
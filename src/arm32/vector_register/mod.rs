@@ -0,0 +1,109 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Pollex.
+//
+// Pollex is free software: you can redistribute it
+// and/or modify it under the terms of the GNU Af-
+// fero General Public License as published by the
+// Free Software Foundation, either version 3 of
+// the License, or (at your option) any later ver-
+// sion.
+//
+// Pollex is distributed in the hope that it will
+// be useful, but WITHOUT ANY WARRANTY; without
+// even the implied warranty of MERCHANTABILITY or
+// FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// Affero General Public License for more details.
+//
+// You should have received a copy of the GNU Af-
+// fero General Public License along with Pollex.
+// If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Error;
+use crate::arm32::{DoubleRegister, QuadRegister};
+
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// An Advanced SIMD register of either width.
+///
+/// Most Advanced SIMD instructions operate on either double (`64`-bit) or quad (`128`-bit) registers.
+/// The width of such instructions is given by the kind of their register operands.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum VectorRegister {
+	Double(DoubleRegister),
+
+	Quad(QuadRegister),
+}
+
+impl VectorRegister {
+	/// Checks if the register is a quad register.
+	///
+	/// This corresponds to the Q bit of Advanced SIMD instructions.
+	#[inline(always)]
+	#[must_use]
+	pub const fn is_quad(self) -> bool { matches!(self, Self::Quad(..)) }
+
+	/// Retrieves the number of the (first) double register of the register.
+	///
+	/// For quad registers, this is twice the register's own number.
+	#[inline]
+	#[must_use]
+	pub const fn to_double_index(self) -> u8 {
+		match self {
+			Self::Double(register) => register as u8,
+			Self::Quad(register)   => register as u8 * 0x2,
+		}
+	}
+
+	/// Constructs a register from the number of its (first) double register.
+	///
+	/// If the number is out of range, or if it is odd for quad registers, [`None`] is returned.
+	#[inline]
+	#[must_use]
+	pub const fn from_double_index(value: u8, quad: bool) -> Option<Self> {
+		if quad {
+			if value & 0b1 != 0b0 { return None };
+
+			match QuadRegister::from_u8(value / 0x2) {
+				Some(register) => Some(Self::Quad(register)),
+				None           => None,
+			}
+		} else {
+			match DoubleRegister::from_u8(value) {
+				Some(register) => Some(Self::Double(register)),
+				None           => None,
+			}
+		}
+	}
+}
+
+impl Display for VectorRegister {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+		match *self {
+			Self::Double(ref register) => write!(f, "{register}"),
+			Self::Quad(ref register)   => write!(f, "{register}"),
+		}
+	}
+}
+
+impl FromStr for VectorRegister {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		s.parse::<DoubleRegister>()
+			.map(Self::Double)
+			.or_else(|_| s.parse::<QuadRegister>().map(Self::Quad))
+	}
+}
+
+impl From<DoubleRegister> for VectorRegister {
+	#[inline(always)]
+	fn from(value: DoubleRegister) -> Self { Self::Double(value) }
+}
+
+impl From<QuadRegister> for VectorRegister {
+	#[inline(always)]
+	fn from(value: QuadRegister) -> Self { Self::Quad(value) }
+}
@@ -0,0 +1,104 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Pollex.
+//
+// Pollex is free software: you can redistribute it
+// and/or modify it under the terms of the GNU Af-
+// fero General Public License as published by the
+// Free Software Foundation, either version 3 of
+// the License, or (at your option) any later ver-
+// sion.
+//
+// Pollex is distributed in the hope that it will
+// be useful, but WITHOUT ANY WARRANTY; without
+// even the implied warranty of MERCHANTABILITY or
+// FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// Affero General Public License for more details.
+//
+// You should have received a copy of the GNU Af-
+// fero General Public License along with Pollex.
+// If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Error;
+use crate::arm32::{DoubleRegister, SingleRegister};
+
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// A floating-point register of either precision.
+///
+/// Most VFP instructions exist in both a single- and a double-precision form.
+/// The precision of such instructions is given by the kind of their register operands, which must therefore all agree.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum VfpRegister {
+	Single(SingleRegister),
+
+	Double(DoubleRegister),
+}
+
+impl VfpRegister {
+	/// Checks if the register is double-precision.
+	///
+	/// This corresponds to the `sz` bit of VFP instructions.
+	#[inline(always)]
+	#[must_use]
+	pub const fn is_double(self) -> bool { matches!(self, Self::Double(..)) }
+
+	/// Retrieves the number of the register.
+	#[inline]
+	#[must_use]
+	pub const fn to_u8(self) -> u8 {
+		match self {
+			Self::Single(register) => register as u8,
+			Self::Double(register) => register as u8,
+		}
+	}
+
+	/// Constructs a register of the given precision from its number.
+	/// If the number is out of range, [`None`] is returned.
+	#[inline]
+	#[must_use]
+	pub const fn from_u8(value: u8, double: bool) -> Option<Self> {
+		if double {
+			match DoubleRegister::from_u8(value) {
+				Some(register) => Some(Self::Double(register)),
+				None           => None,
+			}
+		} else {
+			match SingleRegister::from_u8(value) {
+				Some(register) => Some(Self::Single(register)),
+				None           => None,
+			}
+		}
+	}
+}
+
+impl Display for VfpRegister {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+		match *self {
+			Self::Single(ref register) => write!(f, "{register}"),
+			Self::Double(ref register) => write!(f, "{register}"),
+		}
+	}
+}
+
+impl FromStr for VfpRegister {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		s.parse::<SingleRegister>()
+			.map(Self::Single)
+			.or_else(|_| s.parse::<DoubleRegister>().map(Self::Double))
+	}
+}
+
+impl From<DoubleRegister> for VfpRegister {
+	#[inline(always)]
+	fn from(value: DoubleRegister) -> Self { Self::Double(value) }
+}
+
+impl From<SingleRegister> for VfpRegister {
+	#[inline(always)]
+	fn from(value: SingleRegister) -> Self { Self::Single(value) }
+}
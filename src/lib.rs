@@ -73,8 +73,6 @@
 //! # Ok::<(), Box<dyn std::error::Error>>(())
 //! ```
 //!
-//! But do note that the latter is currently **not** implemented.
-//!
 //! Instructions can be encoded to both Arm and Thumb using the [`InstructionCodec`](arm32::InstructionCodec) type:
 //!
 //! ```
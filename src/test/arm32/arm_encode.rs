@@ -19,13 +19,44 @@
 // fero General Public License along with Pollex.
 // If not, see <https://www.gnu.org/licenses/>.
 
+use crate::Error;
 use crate::arm32::{
+	Address,
+	BarrierOption,
+	Bflag,
+	BlockMode,
+	Coprocessor,
+	CoprocessorRegister,
+	DoubleRegister,
+	Eflag,
+	ElementType,
+	Endianness,
+	FloatImmediate,
+	Indexing,
+	InterruptMask,
+	Lflag,
+	ParallelOperation,
+	ParallelPrefix,
+	ProcessorMode,
+	QuadRegister,
+	Rflag,
+	Scalar,
 	Sflag,
 	Instruction,
 	InstructionCodec,
 	Predicate,
 	Register,
+	RegisterList,
 	Shifter,
+	Sign,
+	SingleRegister,
+	StatusFields,
+	StatusRegister,
+	StructureAddress,
+	Tflag,
+	VectorRegister,
+	VfpRegister,
+	Wflag,
 };
 
 use alloc::vec::Vec;
@@ -35,7 +66,7 @@ fn test_arm_encode() {
 	let tree = [
 		Instruction::BranchLink {
 			predicate: Predicate::HigherOrSame,
-			source:    0x1F,
+			source:    0x08000028,
 		},
 
 		Instruction::Breakpoint {
@@ -53,6 +84,1447 @@ fn test_arm_encode() {
 			source:      Shifter::ArithmeticShiftRightImmediate { source: Register::R3, shift: 0x20 },
 			s:           Sflag::On,
 		},
+
+		Instruction::Add {
+			predicate:   Predicate::Equal,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Shifter::Immediate(0x45),
+			s:           Sflag::On,
+		},
+
+		Instruction::And {
+			predicate:   Predicate::NotEqual,
+			destination: Register::R2,
+			base:        Register::R3,
+			source:      Shifter::from_register(Register::R4),
+			s:           Sflag::Off,
+		},
+
+		Instruction::BitClear {
+			predicate:   Predicate::GreaterThan,
+			destination: Register::R5,
+			base:        Register::R6,
+			source:      Shifter::LogicalShiftLeftImmediate { source: Register::R7, shift: 0x3 },
+			s:           Sflag::On,
+		},
+
+		Instruction::Compare {
+			predicate: Predicate::Always,
+			lhs:       Register::R8,
+			rhs:       Shifter::Immediate(0xFF),
+		},
+
+		Instruction::MoveNot {
+			predicate:   Predicate::LessThan,
+			destination: Register::R4,
+			source:      Shifter::from_register(Register::R5),
+			s:           Sflag::Off,
+		},
+
+		Instruction::ReverseSubtractCarry {
+			predicate:   Predicate::Higher,
+			destination: Register::R1,
+			base:        Register::R2,
+			source:      Shifter::from_register(Register::R3),
+			s:           Sflag::Off,
+		},
+
+		Instruction::Subtract {
+			predicate:   Predicate::Always,
+			destination: Register::Sp,
+			base:        Register::Sp,
+			source:      Shifter::Immediate(0x8),
+			s:           Sflag::Off,
+		},
+
+		Instruction::TestEquivalence {
+			predicate: Predicate::Always,
+			lhs:       Register::R5,
+			rhs:       Shifter::from_register(Register::R6),
+		},
+
+		Instruction::AddCarry {
+			predicate:   Predicate::Minus,
+			destination: Register::R7,
+			base:        Register::R8,
+			source:      Shifter::from_register(Register::R9),
+			s:           Sflag::Off,
+		},
+
+		Instruction::Move {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      Shifter::Immediate(0x000003FC),
+			s:           Sflag::Off,
+		},
+
+		Instruction::Add {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Shifter::Immediate(0xFF000000),
+			s:           Sflag::On,
+		},
+
+		Instruction::Move {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      Shifter::Immediate(0xF000000F),
+			s:           Sflag::Off,
+		},
+
+		Instruction::Add {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Shifter::ArithmeticShiftRightRegister { source: Register::R2, shift: Register::R3 },
+			s:           Sflag::Off,
+		},
+
+		Instruction::Compare {
+			predicate: Predicate::Always,
+			lhs:       Register::R0,
+			rhs:       Shifter::RotateRightRegister { source: Register::R1, shift: Register::R4 },
+		},
+
+		Instruction::Move {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      Shifter::RotateRightExtend { source: Register::R1 },
+			s:           Sflag::Off,
+		},
+
+		Instruction::Add {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Shifter::LogicalShiftRightImmediate { source: Register::R2, shift: 0x20 },
+			s:           Sflag::On,
+		},
+
+		Instruction::Branch {
+			predicate: Predicate::Always,
+			immediate: 0x08000050,
+		},
+
+		Instruction::Branch {
+			predicate: Predicate::Equal,
+			immediate: 0x07FFFFFC,
+		},
+
+		Instruction::Load {
+			predicate: Predicate::Always,
+			register:  Register::R0,
+			address:   Address::ImmediateOffset { base: Register::R1, source: -0x4, indexing: Indexing::Offset },
+			b:         Bflag::Off,
+			t:         Tflag::Off,
+		},
+
+		Instruction::Store {
+			predicate: Predicate::Always,
+			register:  Register::R2,
+			address:   Address::RegisterOffset { base: Register::R3, source: Register::R4, sign: Sign::Positive, indexing: Indexing::Offset },
+			b:         Bflag::On,
+			t:         Tflag::Off,
+		},
+
+		Instruction::Load {
+			predicate: Predicate::Always,
+			register:  Register::R5,
+			address:   Address::ScaledRegisterOffset { base: Register::R6, source: Shifter::LogicalShiftLeftImmediate { source: Register::R7, shift: 0x2 }, sign: Sign::Positive, indexing: Indexing::Offset },
+			b:         Bflag::Off,
+			t:         Tflag::Off,
+		},
+
+		Instruction::Load {
+			predicate: Predicate::NotEqual,
+			register:  Register::Pc,
+			address:   Address::ImmediateOffset { base: Register::Sp, source: 0xFFF, indexing: Indexing::Offset },
+			b:         Bflag::Off,
+			t:         Tflag::Off,
+		},
+
+		Instruction::Store {
+			predicate: Predicate::Always,
+			register:  Register::R0,
+			address:   Address::ImmediateOffset { base: Register::R1, source: 0x0, indexing: Indexing::Offset },
+			b:         Bflag::Off,
+			t:         Tflag::On,
+		},
+
+		Instruction::Swap {
+			predicate: Predicate::Always,
+			register:  Register::R0,
+			source:    Register::R1,
+			address:   Address::ImmediateOffset { base: Register::R2, source: 0x0, indexing: Indexing::Offset },
+			b:         Bflag::On,
+		},
+
+		Instruction::Load {
+			predicate: Predicate::Always,
+			register:  Register::R0,
+			address:   Address::ImmediateOffset { base: Register::R1, source: 0x4, indexing: Indexing::PreIndexed },
+			b:         Bflag::Off,
+			t:         Tflag::Off,
+		},
+
+		Instruction::Load {
+			predicate: Predicate::Always,
+			register:  Register::R0,
+			address:   Address::ImmediateOffset { base: Register::R1, source: -0x4, indexing: Indexing::PostIndexed },
+			b:         Bflag::Off,
+			t:         Tflag::Off,
+		},
+
+		Instruction::Store {
+			predicate: Predicate::Always,
+			register:  Register::R0,
+			address:   Address::RegisterOffset { base: Register::R1, source: Register::R2, sign: Sign::Negative, indexing: Indexing::PreIndexed },
+			b:         Bflag::Off,
+			t:         Tflag::Off,
+		},
+
+		Instruction::Load {
+			predicate: Predicate::Always,
+			register:  Register::R0,
+			address:   Address::ScaledRegisterOffset { base: Register::R1, source: Shifter::LogicalShiftLeftImmediate { source: Register::R2, shift: 0x2 }, sign: Sign::Negative, indexing: Indexing::PostIndexed },
+			b:         Bflag::Off,
+			t:         Tflag::Off,
+		},
+
+		Instruction::Load {
+			predicate: Predicate::Always,
+			register:  Register::R0,
+			address:   Address::RegisterOffset { base: Register::R1, source: Register::R2, sign: Sign::Negative, indexing: Indexing::PostIndexed },
+			b:         Bflag::On,
+			t:         Tflag::On,
+		},
+
+		Instruction::LoadHalfword {
+			predicate: Predicate::Always,
+			register:  Register::R0,
+			address:   Address::ImmediateOffset { base: Register::R1, source: -0xFF, indexing: Indexing::Offset },
+		},
+
+		Instruction::StoreHalfword {
+			predicate: Predicate::Always,
+			register:  Register::R0,
+			address:   Address::RegisterOffset { base: Register::R1, source: Register::R2, sign: Sign::Positive, indexing: Indexing::PreIndexed },
+		},
+
+		Instruction::LoadSignedByte {
+			predicate: Predicate::Always,
+			register:  Register::R0,
+			address:   Address::ImmediateOffset { base: Register::R1, source: 0x4, indexing: Indexing::PostIndexed },
+		},
+
+		Instruction::LoadSignedHalfword {
+			predicate: Predicate::Always,
+			register:  Register::R0,
+			address:   Address::RegisterOffset { base: Register::R1, source: Register::R2, sign: Sign::Negative, indexing: Indexing::Offset },
+		},
+
+		Instruction::LoadDoubleword {
+			predicate: Predicate::Always,
+			first:     Register::R2,
+			second:    Register::R3,
+			address:   Address::ImmediateOffset { base: Register::R4, source: 0x8, indexing: Indexing::PreIndexed },
+		},
+
+		Instruction::StoreDoubleword {
+			predicate: Predicate::Always,
+			first:     Register::R4,
+			second:    Register::R5,
+			address:   Address::RegisterOffset { base: Register::R6, source: Register::R7, sign: Sign::Negative, indexing: Indexing::PostIndexed },
+		},
+
+		Instruction::LoadMultiple {
+			predicate: Predicate::Always,
+			base:      Register::R0,
+			registers: RegisterList::from_u16(0b00000000_00001110),
+			mode:      BlockMode::IncrementAfter,
+			w:         Wflag::On,
+			s:         Sflag::Off,
+		},
+
+		Instruction::StoreMultiple {
+			predicate: Predicate::Always,
+			base:      Register::Sp,
+			registers: RegisterList::from_u16(0b01000000_11110000),
+			mode:      BlockMode::DecrementBefore,
+			w:         Wflag::On,
+			s:         Sflag::Off,
+		},
+
+		Instruction::LoadMultiple {
+			predicate: Predicate::Always,
+			base:      Register::R0,
+			registers: RegisterList::from(Register::R1),
+			mode:      BlockMode::IncrementBefore,
+			w:         Wflag::Off,
+			s:         Sflag::Off,
+		},
+
+		Instruction::StoreMultiple {
+			predicate: Predicate::Always,
+			base:      Register::R0,
+			registers: RegisterList::new().with(Register::R1).with(Register::R2),
+			mode:      BlockMode::DecrementAfter,
+			w:         Wflag::Off,
+			s:         Sflag::Off,
+		},
+
+		Instruction::LoadMultiple {
+			predicate: Predicate::Always,
+			base:      Register::R0,
+			registers: RegisterList::from_u16(0b11111111_11111111),
+			mode:      BlockMode::IncrementAfter,
+			w:         Wflag::Off,
+			s:         Sflag::On,
+		},
+
+		Instruction::Multiply {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Register::R2,
+			s:           Sflag::Off,
+		},
+
+		Instruction::Multiply {
+			predicate:   Predicate::Always,
+			destination: Register::R1,
+			base:        Register::R2,
+			source:      Register::R1,
+			s:           Sflag::Off,
+		},
+
+		Instruction::MultiplyAccumulate {
+			predicate:   Predicate::Always,
+			destination: Register::R3,
+			base:        Register::R4,
+			source:      Register::R5,
+			accumulator: Register::R6,
+			s:           Sflag::On,
+		},
+
+		Instruction::MultiplySubtract {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Register::R2,
+			accumulator: Register::R3,
+		},
+
+		Instruction::UnsignedMultiplyLong {
+			predicate:        Predicate::Always,
+			destination_low:  Register::R0,
+			destination_high: Register::R1,
+			base:             Register::R2,
+			source:           Register::R3,
+			s:                Sflag::Off,
+		},
+
+		Instruction::SignedMultiplyAccumulateLong {
+			predicate:        Predicate::NotEqual,
+			destination_low:  Register::R4,
+			destination_high: Register::R5,
+			base:             Register::R6,
+			source:           Register::R7,
+			s:                Sflag::Off,
+		},
+
+		Instruction::UnsignedMultiplyAccumulateLong {
+			predicate:        Predicate::Always,
+			destination_low:  Register::R0,
+			destination_high: Register::R1,
+			base:             Register::R2,
+			source:           Register::R3,
+			s:                Sflag::On,
+		},
+
+		Instruction::SignedMultiplyLong {
+			predicate:        Predicate::Always,
+			destination_low:  Register::R8,
+			destination_high: Register::R9,
+			base:             Register::R10,
+			source:           Register::R11,
+			s:                Sflag::Off,
+		},
+
+		Instruction::UnsignedMultiplyAccumulateAccumulateLong {
+			predicate:        Predicate::Always,
+			destination_low:  Register::R0,
+			destination_high: Register::R1,
+			base:             Register::R2,
+			source:           Register::R3,
+		},
+
+		Instruction::MoveFromStatusRegister {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      StatusRegister::Cpsr,
+		},
+
+		Instruction::MoveFromStatusRegister {
+			predicate:   Predicate::NotEqual,
+			destination: Register::R1,
+			source:      StatusRegister::Spsr,
+		},
+
+		Instruction::MoveToStatusRegister {
+			predicate:   Predicate::Always,
+			destination: StatusRegister::Cpsr,
+			fields:      StatusFields::from_u8(0b1001),
+			source:      Shifter::LogicalShiftLeftImmediate { source: Register::R0, shift: 0x0 },
+		},
+
+		Instruction::MoveToStatusRegister {
+			predicate:   Predicate::Always,
+			destination: StatusRegister::Spsr,
+			fields:      StatusFields::ALL,
+			source:      Shifter::LogicalShiftLeftImmediate { source: Register::R2, shift: 0x0 },
+		},
+
+		Instruction::MoveToStatusRegister {
+			predicate:   Predicate::Always,
+			destination: StatusRegister::Cpsr,
+			fields:      StatusFields::FLAGS,
+			source:      Shifter::Immediate(0xF0000000),
+		},
+
+		Instruction::MoveToStatusRegister {
+			predicate:   Predicate::Always,
+			destination: StatusRegister::Cpsr,
+			fields:      StatusFields::CONTROL,
+			source:      Shifter::Immediate(0x1F),
+		},
+
+		Instruction::MoveFromCoprocessor {
+			predicate:   Predicate::Always,
+			coprocessor: Coprocessor::P15,
+			opcode1:     0x0,
+			register:    Register::R0,
+			crn:         CoprocessorRegister::C1,
+			crm:         CoprocessorRegister::C0,
+			opcode2:     0x0,
+		},
+
+		Instruction::MoveToCoprocessor {
+			predicate:   Predicate::NotEqual,
+			coprocessor: Coprocessor::P14,
+			opcode1:     0x7,
+			register:    Register::R2,
+			crn:         CoprocessorRegister::C3,
+			crm:         CoprocessorRegister::C4,
+			opcode2:     0x5,
+		},
+
+		Instruction::MoveToCoprocessorDouble {
+			predicate:   Predicate::Always,
+			coprocessor: Coprocessor::P15,
+			opcode1:     0x0,
+			first:       Register::R0,
+			second:      Register::R1,
+			crm:         CoprocessorRegister::C2,
+		},
+
+		Instruction::MoveFromCoprocessorDouble {
+			predicate:   Predicate::Always,
+			coprocessor: Coprocessor::P15,
+			opcode1:     0x1,
+			first:       Register::R2,
+			second:      Register::R3,
+			crm:         CoprocessorRegister::C14,
+		},
+
+		Instruction::CoprocessorDataProcessing {
+			predicate:   Predicate::Always,
+			coprocessor: Coprocessor::P5,
+			opcode1:     0xF,
+			crd:         CoprocessorRegister::C1,
+			crn:         CoprocessorRegister::C2,
+			crm:         CoprocessorRegister::C3,
+			opcode2:     0x7,
+		},
+
+		Instruction::LoadCoprocessor {
+			predicate:   Predicate::Always,
+			coprocessor: Coprocessor::P14,
+			crd:         CoprocessorRegister::C5,
+			address:     Address::ImmediateOffset { base: Register::R1, source: 0x4, indexing: Indexing::Offset },
+			l:           Lflag::Off,
+		},
+
+		Instruction::StoreCoprocessor {
+			predicate:   Predicate::Always,
+			coprocessor: Coprocessor::P6,
+			crd:         CoprocessorRegister::C2,
+			address:     Address::ImmediateOffset { base: Register::R3, source: -0x8, indexing: Indexing::PreIndexed },
+			l:           Lflag::On,
+		},
+
+		Instruction::LoadCoprocessor {
+			predicate:   Predicate::Always,
+			coprocessor: Coprocessor::P7,
+			crd:         CoprocessorRegister::C3,
+			address:     Address::ImmediateOffset { base: Register::R2, source: 0x3FC, indexing: Indexing::PostIndexed },
+			l:           Lflag::Off,
+		},
+
+		Instruction::LoadExclusive {
+			predicate: Predicate::Always,
+			register:  Register::R0,
+			address:   Address::ImmediateOffset { base: Register::R1, source: 0x0, indexing: Indexing::Offset },
+		},
+
+		Instruction::StoreExclusive {
+			predicate: Predicate::Always,
+			status:    Register::R2,
+			register:  Register::R3,
+			address:   Address::ImmediateOffset { base: Register::R4, source: 0x0, indexing: Indexing::Offset },
+		},
+
+		Instruction::LoadExclusiveByte {
+			predicate: Predicate::Always,
+			register:  Register::R0,
+			address:   Address::ImmediateOffset { base: Register::R1, source: 0x0, indexing: Indexing::Offset },
+		},
+
+		Instruction::StoreExclusiveHalfword {
+			predicate: Predicate::Always,
+			status:    Register::R0,
+			register:  Register::R1,
+			address:   Address::ImmediateOffset { base: Register::R2, source: 0x0, indexing: Indexing::Offset },
+		},
+
+		Instruction::LoadExclusiveDoubleword {
+			predicate: Predicate::Always,
+			first:     Register::R2,
+			second:    Register::R3,
+			address:   Address::ImmediateOffset { base: Register::R4, source: 0x0, indexing: Indexing::Offset },
+		},
+
+		Instruction::StoreExclusiveDoubleword {
+			predicate: Predicate::Always,
+			status:    Register::R1,
+			first:     Register::R2,
+			second:    Register::R3,
+			address:   Address::ImmediateOffset { base: Register::R4, source: 0x0, indexing: Indexing::Offset },
+		},
+
+		Instruction::ClearExclusive,
+
+		Instruction::DataMemoryBarrier {
+			option: BarrierOption::Sy,
+		},
+
+		Instruction::DataSynchronisationBarrier {
+			option: BarrierOption::IshSt,
+		},
+
+		Instruction::InstructionSynchronisationBarrier {
+			option: BarrierOption::Sy,
+		},
+
+		Instruction::DataMemoryBarrier {
+			option: BarrierOption::Osh,
+		},
+
+		Instruction::SignExtendByte {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      Register::R1,
+			rotation:    0x0,
+		},
+
+		Instruction::SignExtendByte {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      Register::R1,
+			rotation:    0x8,
+		},
+
+		Instruction::SignExtendAddByte {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Register::R2,
+			rotation:    0x10,
+		},
+
+		Instruction::ZeroExtendHalfword {
+			predicate:   Predicate::Always,
+			destination: Register::R3,
+			source:      Register::R4,
+			rotation:    0x0,
+		},
+
+		Instruction::ZeroExtendAddHalfword {
+			predicate:   Predicate::Always,
+			destination: Register::R3,
+			base:        Register::R4,
+			source:      Register::R5,
+			rotation:    0x0,
+		},
+
+		Instruction::SignExtendByte16 {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      Register::R1,
+			rotation:    0x0,
+		},
+
+		Instruction::ZeroExtendAddByte16 {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Register::R2,
+			rotation:    0x0,
+		},
+
+		Instruction::ZeroExtendByte16 {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      Register::R1,
+			rotation:    0x18,
+		},
+
+		Instruction::PackHalfword {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Shifter::LogicalShiftLeftImmediate { source: Register::R2, shift: 0x8 },
+		},
+
+		Instruction::PackHalfword {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Shifter::ArithmeticShiftRightImmediate { source: Register::R2, shift: 0x8 },
+		},
+
+		Instruction::PackHalfword {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Shifter::ArithmeticShiftRightImmediate { source: Register::R2, shift: 0x20 },
+		},
+
+		Instruction::Select {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Register::R2,
+		},
+
+		Instruction::UnsignedSumAbsoluteDifferences {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Register::R2,
+		},
+
+		Instruction::UnsignedSumAbsoluteDifferencesAccumulate {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Register::R2,
+			accumulator: Register::R3,
+		},
+
+		Instruction::ParallelArithmetic {
+			predicate:   Predicate::Always,
+			prefix:      ParallelPrefix::Signed,
+			operation:   ParallelOperation::Add16,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Register::R2,
+		},
+
+		Instruction::ParallelArithmetic {
+			predicate:   Predicate::Always,
+			prefix:      ParallelPrefix::Unsigned,
+			operation:   ParallelOperation::Add8,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Register::R2,
+		},
+
+		Instruction::ParallelArithmetic {
+			predicate:   Predicate::Always,
+			prefix:      ParallelPrefix::Saturating,
+			operation:   ParallelOperation::Add8,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Register::R2,
+		},
+
+		Instruction::ParallelArithmetic {
+			predicate:   Predicate::Always,
+			prefix:      ParallelPrefix::SignedHalving,
+			operation:   ParallelOperation::Add16,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Register::R2,
+		},
+
+		Instruction::ParallelArithmetic {
+			predicate:   Predicate::Always,
+			prefix:      ParallelPrefix::UnsignedHalving,
+			operation:   ParallelOperation::Subtract8,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Register::R2,
+		},
+
+		Instruction::ParallelArithmetic {
+			predicate:   Predicate::Always,
+			prefix:      ParallelPrefix::UnsignedSaturating,
+			operation:   ParallelOperation::AddSubtractExchange,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Register::R2,
+		},
+
+		Instruction::ParallelArithmetic {
+			predicate:   Predicate::Always,
+			prefix:      ParallelPrefix::Signed,
+			operation:   ParallelOperation::SubtractAddExchange,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Register::R2,
+		},
+
+		Instruction::MoveWide {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			immediate:   0x1234,
+		},
+
+		Instruction::MoveTop {
+			predicate:   Predicate::Always,
+			destination: Register::R1,
+			immediate:   0xFFFF,
+		},
+
+		Instruction::BitFieldInsert {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      Register::R1,
+			lsb:         0x4,
+			width:       0x8,
+		},
+
+		Instruction::BitFieldClear {
+			predicate:   Predicate::Always,
+			destination: Register::R2,
+			lsb:         0x0,
+			width:       0x20,
+		},
+
+		Instruction::UnsignedBitFieldExtract {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      Register::R1,
+			lsb:         0x3,
+			width:       0x5,
+		},
+
+		Instruction::SignedBitFieldExtract {
+			predicate:   Predicate::Always,
+			destination: Register::R2,
+			source:      Register::R3,
+			lsb:         0x1F,
+			width:       0x1,
+		},
+
+		Instruction::ReverseBits {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      Register::R1,
+		},
+
+		Instruction::Reverse {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      Register::R1,
+		},
+
+		Instruction::ReversePackedHalfword {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      Register::R1,
+		},
+
+		Instruction::ReverseSignedHalfword {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      Register::R1,
+		},
+
+		Instruction::SignedDivide {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Register::R2,
+		},
+
+		Instruction::UnsignedDivide {
+			predicate:   Predicate::Always,
+			destination: Register::R3,
+			base:        Register::R4,
+			source:      Register::R5,
+		},
+
+		Instruction::FloatAdd {
+			predicate:   Predicate::NotEqual,
+			destination: VfpRegister::Single(SingleRegister::S0),
+			base:        VfpRegister::Single(SingleRegister::S1),
+			source:      VfpRegister::Single(SingleRegister::S2),
+		},
+
+		Instruction::FloatSubtract {
+			predicate:   Predicate::Always,
+			destination: VfpRegister::Double(DoubleRegister::D16),
+			base:        VfpRegister::Double(DoubleRegister::D17),
+			source:      VfpRegister::Double(DoubleRegister::D31),
+		},
+
+		Instruction::FloatMultiply {
+			predicate:   Predicate::Always,
+			destination: VfpRegister::Single(SingleRegister::S3),
+			base:        VfpRegister::Single(SingleRegister::S5),
+			source:      VfpRegister::Single(SingleRegister::S31),
+		},
+
+		Instruction::FloatDivide {
+			predicate:   Predicate::Always,
+			destination: VfpRegister::Double(DoubleRegister::D5),
+			base:        VfpRegister::Double(DoubleRegister::D6),
+			source:      VfpRegister::Double(DoubleRegister::D7),
+		},
+
+		Instruction::FloatSquareRoot {
+			predicate:   Predicate::Always,
+			destination: VfpRegister::Single(SingleRegister::S0),
+			source:      VfpRegister::Single(SingleRegister::S1),
+		},
+
+		Instruction::FloatAbsolute {
+			predicate:   Predicate::Always,
+			destination: VfpRegister::Double(DoubleRegister::D0),
+			source:      VfpRegister::Double(DoubleRegister::D1),
+		},
+
+		Instruction::FloatNegate {
+			predicate:   Predicate::Always,
+			destination: VfpRegister::Single(SingleRegister::S2),
+			source:      VfpRegister::Single(SingleRegister::S3),
+		},
+
+		Instruction::FloatMove {
+			predicate:   Predicate::Always,
+			destination: VfpRegister::Double(DoubleRegister::D0),
+			source:      VfpRegister::Double(DoubleRegister::D17),
+		},
+
+		Instruction::FloatCompare {
+			predicate: Predicate::Always,
+			lhs:       VfpRegister::Double(DoubleRegister::D0),
+			rhs:       Some(VfpRegister::Double(DoubleRegister::D1)),
+			e:         Eflag::On,
+		},
+
+		Instruction::FloatCompare {
+			predicate: Predicate::Always,
+			lhs:       VfpRegister::Single(SingleRegister::S0),
+			rhs:       None,
+			e:         Eflag::Off,
+		},
+
+		Instruction::FloatConvert {
+			predicate:   Predicate::Always,
+			destination: VfpRegister::Double(DoubleRegister::D0),
+			source:      VfpRegister::Single(SingleRegister::S1),
+		},
+
+		Instruction::FloatConvert {
+			predicate:   Predicate::Always,
+			destination: VfpRegister::Single(SingleRegister::S1),
+			source:      VfpRegister::Double(DoubleRegister::D0),
+		},
+
+		Instruction::FloatToSigned {
+			predicate:   Predicate::Always,
+			destination: SingleRegister::S0,
+			source:      VfpRegister::Single(SingleRegister::S1),
+			r:           Rflag::Off,
+		},
+
+		Instruction::FloatToUnsigned {
+			predicate:   Predicate::Always,
+			destination: SingleRegister::S0,
+			source:      VfpRegister::Double(DoubleRegister::D1),
+			r:           Rflag::On,
+		},
+
+		Instruction::SignedToFloat {
+			predicate:   Predicate::Always,
+			destination: VfpRegister::Single(SingleRegister::S0),
+			source:      SingleRegister::S1,
+		},
+
+		Instruction::UnsignedToFloat {
+			predicate:   Predicate::Always,
+			destination: VfpRegister::Double(DoubleRegister::D0),
+			source:      SingleRegister::S1,
+		},
+
+		Instruction::FloatMoveImmediate {
+			predicate:   Predicate::Always,
+			destination: VfpRegister::Single(SingleRegister::S0),
+			immediate:   FloatImmediate::new(0b01111000),
+		},
+
+		Instruction::FloatMoveImmediate {
+			predicate:   Predicate::Always,
+			destination: VfpRegister::Double(DoubleRegister::D0),
+			immediate:   FloatImmediate::new(0b10000000),
+		},
+
+		Instruction::MoveToFloat {
+			predicate:   Predicate::Always,
+			destination: SingleRegister::S1,
+			source:      Register::R0,
+		},
+
+		Instruction::MoveFromFloat {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      SingleRegister::S1,
+		},
+
+		Instruction::MoveToFloatDouble {
+			predicate:   Predicate::Always,
+			destination: DoubleRegister::D0,
+			low:         Register::R0,
+			high:        Register::R1,
+		},
+
+		Instruction::MoveFromFloatDouble {
+			predicate: Predicate::Always,
+			low:       Register::R2,
+			high:      Register::R3,
+			source:    DoubleRegister::D17,
+		},
+
+		Instruction::MoveFromFloatStatus {
+			predicate:   Predicate::Always,
+			destination: Register::Pc,
+		},
+
+		Instruction::MoveToFloatStatus {
+			predicate: Predicate::Always,
+			source:    Register::R1,
+		},
+
+		Instruction::FloatLoad {
+			predicate: Predicate::Always,
+			register:  VfpRegister::Double(DoubleRegister::D0),
+			address:   Address::ImmediateOffset { base: Register::R0, source: 0x8, indexing: Indexing::Offset },
+		},
+
+		Instruction::FloatStore {
+			predicate: Predicate::Always,
+			register:  VfpRegister::Single(SingleRegister::S1),
+			address:   Address::ImmediateOffset { base: Register::Sp, source: -0x4, indexing: Indexing::Offset },
+		},
+
+		Instruction::FloatLoadMultiple {
+			predicate: Predicate::Always,
+			base:      Register::R0,
+			first:     VfpRegister::Double(DoubleRegister::D0),
+			count:     0x4,
+			mode:      BlockMode::IncrementAfter,
+			w:         Wflag::On,
+		},
+
+		Instruction::FloatStoreMultiple {
+			predicate: Predicate::Always,
+			base:      Register::Sp,
+			first:     VfpRegister::Double(DoubleRegister::D8),
+			count:     0x8,
+			mode:      BlockMode::DecrementBefore,
+			w:         Wflag::On,
+		},
+
+		Instruction::FloatLoadMultiple {
+			predicate: Predicate::Always,
+			base:      Register::Sp,
+			first:     VfpRegister::Single(SingleRegister::S16),
+			count:     0x10,
+			mode:      BlockMode::IncrementAfter,
+			w:         Wflag::On,
+		},
+
+		Instruction::VectorAdd {
+			element:     ElementType::I8,
+			destination: VectorRegister::Double(DoubleRegister::D0),
+			base:        VectorRegister::Double(DoubleRegister::D1),
+			source:      VectorRegister::Double(DoubleRegister::D2),
+		},
+
+		Instruction::VectorAdd {
+			element:     ElementType::I64,
+			destination: VectorRegister::Quad(QuadRegister::Q0),
+			base:        VectorRegister::Quad(QuadRegister::Q1),
+			source:      VectorRegister::Quad(QuadRegister::Q2),
+		},
+
+		Instruction::VectorSubtract {
+			element:     ElementType::I32,
+			destination: VectorRegister::Quad(QuadRegister::Q8),
+			base:        VectorRegister::Quad(QuadRegister::Q9),
+			source:      VectorRegister::Quad(QuadRegister::Q15),
+		},
+
+		Instruction::VectorMultiply {
+			element:     ElementType::P8,
+			destination: VectorRegister::Double(DoubleRegister::D0),
+			base:        VectorRegister::Double(DoubleRegister::D1),
+			source:      VectorRegister::Double(DoubleRegister::D2),
+		},
+
+		Instruction::VectorMultiplyAccumulate {
+			element:     ElementType::I32,
+			destination: VectorRegister::Quad(QuadRegister::Q0),
+			base:        VectorRegister::Quad(QuadRegister::Q1),
+			source:      VectorRegister::Quad(QuadRegister::Q2),
+		},
+
+		Instruction::VectorSaturatingAdd {
+			element:     ElementType::U64,
+			destination: VectorRegister::Quad(QuadRegister::Q0),
+			base:        VectorRegister::Quad(QuadRegister::Q1),
+			source:      VectorRegister::Quad(QuadRegister::Q2),
+		},
+
+		Instruction::VectorSaturatingSubtract {
+			element:     ElementType::S16,
+			destination: VectorRegister::Double(DoubleRegister::D0),
+			base:        VectorRegister::Double(DoubleRegister::D1),
+			source:      VectorRegister::Double(DoubleRegister::D2),
+		},
+
+		Instruction::VectorMinimum {
+			element:     ElementType::U8,
+			destination: VectorRegister::Double(DoubleRegister::D0),
+			base:        VectorRegister::Double(DoubleRegister::D1),
+			source:      VectorRegister::Double(DoubleRegister::D2),
+		},
+
+		Instruction::VectorCompareEqual {
+			element:     ElementType::I32,
+			destination: VectorRegister::Double(DoubleRegister::D0),
+			base:        VectorRegister::Double(DoubleRegister::D1),
+			source:      VectorRegister::Double(DoubleRegister::D2),
+		},
+
+		Instruction::VectorCompareGreaterThanOrEqual {
+			element:     ElementType::S8,
+			destination: VectorRegister::Double(DoubleRegister::D0),
+			base:        VectorRegister::Double(DoubleRegister::D1),
+			source:      VectorRegister::Double(DoubleRegister::D2),
+		},
+
+		Instruction::VectorBitClear {
+			destination: VectorRegister::Quad(QuadRegister::Q0),
+			base:        VectorRegister::Quad(QuadRegister::Q1),
+			source:      VectorRegister::Quad(QuadRegister::Q2),
+		},
+
+		Instruction::VectorInclusiveOr {
+			destination: VectorRegister::Quad(QuadRegister::Q0),
+			base:        VectorRegister::Quad(QuadRegister::Q1),
+			source:      VectorRegister::Quad(QuadRegister::Q1),
+		},
+
+		Instruction::VectorMultiply {
+			element:     ElementType::F32,
+			destination: VectorRegister::Double(DoubleRegister::D0),
+			base:        VectorRegister::Double(DoubleRegister::D1),
+			source:      VectorRegister::Double(DoubleRegister::D2),
+		},
+
+		Instruction::VectorCompareGreaterThan {
+			element:     ElementType::F32,
+			destination: VectorRegister::Double(DoubleRegister::D0),
+			base:        VectorRegister::Double(DoubleRegister::D1),
+			source:      VectorRegister::Double(DoubleRegister::D2),
+		},
+
+		Instruction::VectorMinimum {
+			element:     ElementType::F32,
+			destination: VectorRegister::Quad(QuadRegister::Q0),
+			base:        VectorRegister::Quad(QuadRegister::Q1),
+			source:      VectorRegister::Quad(QuadRegister::Q2),
+		},
+
+		Instruction::VectorNegate {
+			element:     ElementType::S16,
+			destination: VectorRegister::Quad(QuadRegister::Q0),
+			source:      VectorRegister::Quad(QuadRegister::Q1),
+		},
+
+		Instruction::VectorAbsolute {
+			element:     ElementType::F32,
+			destination: VectorRegister::Double(DoubleRegister::D0),
+			source:      VectorRegister::Double(DoubleRegister::D1),
+		},
+
+		Instruction::VectorNot {
+			destination: VectorRegister::Quad(QuadRegister::Q0),
+			source:      VectorRegister::Quad(QuadRegister::Q1),
+		},
+
+		Instruction::VectorShiftLeft {
+			element:     ElementType::I64,
+			destination: VectorRegister::Quad(QuadRegister::Q0),
+			source:      VectorRegister::Quad(QuadRegister::Q1),
+			shift:       0x3F,
+		},
+
+		Instruction::VectorShiftRight {
+			element:     ElementType::S16,
+			destination: VectorRegister::Double(DoubleRegister::D0),
+			source:      VectorRegister::Double(DoubleRegister::D1),
+			shift:       0x10,
+		},
+
+		Instruction::VectorShiftRight {
+			element:     ElementType::U32,
+			destination: VectorRegister::Quad(QuadRegister::Q0),
+			source:      VectorRegister::Quad(QuadRegister::Q1),
+			shift:       0x1,
+		},
+
+		Instruction::VectorMultiplyScalar {
+			element:     ElementType::I16,
+			destination: VectorRegister::Double(DoubleRegister::D0),
+			base:        VectorRegister::Double(DoubleRegister::D1),
+			source:      Scalar::new(DoubleRegister::D2, 0x3),
+		},
+
+		Instruction::VectorMultiplyScalar {
+			element:     ElementType::F32,
+			destination: VectorRegister::Quad(QuadRegister::Q0),
+			base:        VectorRegister::Quad(QuadRegister::Q1),
+			source:      Scalar::new(DoubleRegister::D3, 0x0),
+		},
+
+		Instruction::VectorDuplicate {
+			predicate:   Predicate::Always,
+			element:     ElementType::Untyped32,
+			destination: VectorRegister::Quad(QuadRegister::Q0),
+			source:      Register::R0,
+		},
+
+		Instruction::VectorDuplicateScalar {
+			element:     ElementType::Untyped8,
+			destination: VectorRegister::Quad(QuadRegister::Q0),
+			source:      Scalar::new(DoubleRegister::D1, 0x7),
+		},
+
+		Instruction::MoveToScalar {
+			predicate:   Predicate::Always,
+			element:     ElementType::Untyped16,
+			destination: Scalar::new(DoubleRegister::D17, 0x3),
+			source:      Register::R3,
+		},
+
+		Instruction::MoveToScalar {
+			predicate:   Predicate::Equal,
+			element:     ElementType::Untyped32,
+			destination: Scalar::new(DoubleRegister::D0, 0x0),
+			source:      Register::R0,
+		},
+
+		Instruction::MoveFromScalar {
+			predicate:   Predicate::Always,
+			element:     ElementType::S8,
+			destination: Register::R0,
+			source:      Scalar::new(DoubleRegister::D1, 0x7),
+		},
+
+		Instruction::VectorExtract {
+			destination: VectorRegister::Quad(QuadRegister::Q0),
+			base:        VectorRegister::Quad(QuadRegister::Q1),
+			source:      VectorRegister::Quad(QuadRegister::Q2),
+			index:       0xF,
+		},
+
+		Instruction::VectorReverse32 {
+			element:     ElementType::Untyped16,
+			destination: VectorRegister::Quad(QuadRegister::Q0),
+			source:      VectorRegister::Quad(QuadRegister::Q1),
+		},
+
+		Instruction::VectorSwap {
+			destination: VectorRegister::Double(DoubleRegister::D0),
+			source:      VectorRegister::Double(DoubleRegister::D1),
+		},
+
+		Instruction::VectorTranspose {
+			element:     ElementType::Untyped32,
+			destination: VectorRegister::Quad(QuadRegister::Q0),
+			source:      VectorRegister::Quad(QuadRegister::Q1),
+		},
+
+		Instruction::VectorUnzip {
+			element:     ElementType::Untyped16,
+			destination: VectorRegister::Double(DoubleRegister::D0),
+			source:      VectorRegister::Double(DoubleRegister::D1),
+		},
+
+		Instruction::VectorZip {
+			element:     ElementType::Untyped8,
+			destination: VectorRegister::Quad(QuadRegister::Q0),
+			source:      VectorRegister::Quad(QuadRegister::Q1),
+		},
+
+		Instruction::VectorLoadStructure {
+			interleave: 0x1,
+			element:    ElementType::Untyped16,
+			first:      DoubleRegister::D0,
+			count:      0x2,
+			spacing:    0x1,
+			address:    StructureAddress::PostIndexed { base: Register::R1, alignment: 0x80 },
+		},
+
+		Instruction::VectorLoadStructure {
+			interleave: 0x1,
+			element:    ElementType::Untyped32,
+			first:      DoubleRegister::D0,
+			count:      0x3,
+			spacing:    0x1,
+			address:    StructureAddress::RegisterPostIndexed { base: Register::R2, alignment: 0x0, source: Register::R3 },
+		},
+
+		Instruction::VectorLoadStructure {
+			interleave: 0x3,
+			element:    ElementType::Untyped16,
+			first:      DoubleRegister::D0,
+			count:      0x3,
+			spacing:    0x2,
+			address:    StructureAddress::Offset { base: Register::R0, alignment: 0x40 },
+		},
+
+		Instruction::VectorStoreStructure {
+			interleave: 0x1,
+			element:    ElementType::Untyped8,
+			first:      DoubleRegister::D16,
+			count:      0x1,
+			spacing:    0x1,
+			address:    StructureAddress::from_register(Register::R0),
+		},
+
+		Instruction::VectorStoreStructure {
+			interleave: 0x4,
+			element:    ElementType::Untyped8,
+			first:      DoubleRegister::D0,
+			count:      0x4,
+			spacing:    0x1,
+			address:    StructureAddress::PostIndexed { base: Register::R0, alignment: 0x100 },
+		},
+
+		Instruction::NoOperation {
+			predicate: Predicate::Always,
+		},
+
+		Instruction::NoOperation {
+			predicate: Predicate::Equal,
+		},
+
+		Instruction::Yield {
+			predicate: Predicate::Always,
+		},
+
+		Instruction::WaitForEvent {
+			predicate: Predicate::Always,
+		},
+
+		Instruction::WaitForInterrupt {
+			predicate: Predicate::Always,
+		},
+
+		Instruction::SendEvent {
+			predicate: Predicate::Always,
+		},
+
+		Instruction::EnableInterrupts {
+			mask: InterruptMask::ALL,
+			mode: None,
+		},
+
+		Instruction::DisableInterrupts {
+			mask: InterruptMask::IRQ,
+			mode: None,
+		},
+
+		Instruction::EnableInterrupts {
+			mask: InterruptMask::FIQ,
+			mode: Some(ProcessorMode::Supervisor),
+		},
+
+		Instruction::ChangeProcessorMode {
+			mode: ProcessorMode::System,
+		},
+
+		Instruction::SetEndianness {
+			endianness: Endianness::Big,
+		},
+
+		Instruction::SetEndianness {
+			endianness: Endianness::Little,
+		},
+
+		Instruction::UnsignedSaturate {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			immediate:   0x0,
+			source:      Shifter::from_register(Register::R1),
+		},
+
+		Instruction::UnsignedSaturate {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			immediate:   0x1F,
+			source:      Shifter::LogicalShiftLeftImmediate { source: Register::R1, shift: 0x3 },
+		},
+
+		Instruction::UnsignedSaturate {
+			predicate:   Predicate::Always,
+			destination: Register::R2,
+			immediate:   0x7,
+			source:      Shifter::ArithmeticShiftRightImmediate { source: Register::R3, shift: 0x20 },
+		},
+
+		Instruction::SignedSaturate {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			immediate:   0x1,
+			source:      Shifter::from_register(Register::R1),
+		},
+
+		Instruction::SignedSaturate {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			immediate:   0x20,
+			source:      Shifter::ArithmeticShiftRightImmediate { source: Register::R1, shift: 0x1 },
+		},
+
+		Instruction::SignedSaturate16 {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			immediate:   0x1,
+			source:      Register::R1,
+		},
+
+		Instruction::SignedSaturate16 {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			immediate:   0x10,
+			source:      Register::R1,
+		},
+
+		Instruction::UnsignedSaturate16 {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			immediate:   0x0,
+			source:      Register::R1,
+		},
+
+		Instruction::UnsignedSaturate16 {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			immediate:   0xF,
+			source:      Register::R1,
+		},
+
+		Instruction::SaturatingAdd {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Register::R2,
+		},
+
+		Instruction::SaturatingSubtract {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Register::R2,
+		},
+
+		Instruction::SaturatingDoubleAdd {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Register::R2,
+		},
+
+		Instruction::SaturatingDoubleSubtract {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Register::R2,
+		},
+
+		Instruction::BranchLinkExchange {
+			predicate: Predicate::Always,
+			source:    Shifter::from_register(Register::R3),
+		},
+
+		Instruction::BranchLinkExchange {
+			predicate: Predicate::NotEqual,
+			source:    Shifter::from_register(Register::Lr),
+		},
+
+		Instruction::CountLeadingZeroes {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      Register::R1,
+		},
+
+		Instruction::CountLeadingZeroes {
+			predicate:   Predicate::Equal,
+			destination: Register::R12,
+			source:      Register::R2,
+		},
+
+		Instruction::BranchLinkExchange {
+			predicate: Predicate::Always,
+			source:    Shifter::Immediate(0x08001348),
+		},
+
+		Instruction::BranchLinkExchange {
+			predicate: Predicate::Always,
+			source:    Shifter::Immediate(0x0800134E),
+		},
+
+		Instruction::PreloadData {
+			address: Address::ImmediateOffset { base: Register::R0, source: 0x4, indexing: Indexing::Offset },
+		},
+
+		Instruction::PreloadData {
+			address: Address::ScaledRegisterOffset {
+				base:     Register::R1,
+				source:   Shifter::LogicalShiftLeftImmediate { source: Register::R2, shift: 0x2 },
+				sign:     Sign::Negative,
+				indexing: Indexing::Offset,
+			},
+		},
 	];
 
 	let mut codec = InstructionCodec::new_at(0x08000000);
@@ -63,10 +1535,370 @@ fn test_arm_encode() {
 	assert_eq!(
 		opcodes,
 		[
-			0b00101010_00000000_00000000_00000000,
+			0b00101011_00000000_00000000_00001000,
 			0b11100001_00100000_00000100_01110101,
 			0b11101111_00000000_00000000_01010100,
 			0b01010001_10110000_11110000_01000011,
+			0b00000010_10010001_00000000_01000101,
+			0b00010000_00000011_00100000_00000100,
+			0b11000001_11010110_01010001_10000111,
+			0b11100011_01011000_00000000_11111111,
+			0b10110001_11100000_01000000_00000101,
+			0b10000000_11100010_00010000_00000011,
+			0b11100010_01001101_11010000_00001000,
+			0b11100001_00110101_00000000_00000110,
+			0b01000000_10101000_01110000_00001001,
+			0b11100011_10100000_00001111_11111111,
+			0b11100010_10010001_00000100_11111111,
+			0b11100011_10100000_00000010_11111111,
+			0b11100000_10000001_00000011_01010010,
+			0b11100001_01010000_00000100_01110001,
+			0b11100001_10100000_00000000_01100001,
+			0b11100000_10010001_00000000_00100010,
+			0b11101010_11111111_11111111_11111110,
+			0b00001010_11111111_11111111_11101000,
+			0b11100101_00010001_00000000_00000100,
+			0b11100111_11000011_00100000_00000100,
+			0b11100111_10010110_01010001_00000111,
+			0b00010101_10011101_11111111_11111111,
+			0b11100100_10100001_00000000_00000000,
+			0b11100001_01000010_00000000_10010001,
+			0b11100101_10110001_00000000_00000100,
+			0b11100100_00010001_00000000_00000100,
+			0b11100111_00100001_00000000_00000010,
+			0b11100110_00010001_00000001_00000010,
+			0b11100110_01110001_00000000_00000010,
+			0b11100001_01010001_00001111_10111111,
+			0b11100001_10100001_00000000_10110010,
+			0b11100000_11010001_00000000_11010100,
+			0b11100001_00010001_00000000_11110010,
+			0b11100001_11100100_00100000_11011000,
+			0b11100000_00000110_01000000_11110111,
+			0b11101000_10110000_00000000_00001110,
+			0b11101001_00101101_01000000_11110000,
+			0b11101001_10010000_00000000_00000010,
+			0b11101000_00000000_00000000_00000110,
+			0b11101000_11010000_11111111_11111111,
+			0b11100000_00000000_00000010_10010001,
+			0b11100000_00000001_00000001_10010010,
+			0b11100000_00110011_01100101_10010100,
+			0b11100000_01100000_00110010_10010001,
+			0b11100000_10000001_00000011_10010010,
+			0b00010000_11100101_01000111_10010110,
+			0b11100000_10110001_00000011_10010010,
+			0b11100000_11001001_10001011_10011010,
+			0b11100000_01000001_00000011_10010010,
+			0b11100001_00001111_00000000_00000000,
+			0b00010001_01001111_00010000_00000000,
+			0b11100001_00101001_11110000_00000000,
+			0b11100001_01101111_11110000_00000010,
+			0b11100011_00101000_11110010_00001111,
+			0b11100011_00100001_11110000_00011111,
+			0b11101110_00010001_00001111_00010000,
+			0b00011110_11100011_00101110_10110100,
+			0b11101100_01000001_00001111_00000010,
+			0b11101100_01010011_00101111_00011110,
+			0b11101110_11110010_00010101_11100011,
+			0b11101101_10010001_01011110_00000001,
+			0b11101101_01100011_00100110_00000010,
+			0b11101100_10110010_00110111_11111111,
+			0b11100001_10010001_00001111_10011111,
+			0b11100001_10000100_00101111_10010011,
+			0b11100001_11010001_00001111_10011111,
+			0b11100001_11100010_00001111_10010001,
+			0b11100001_10110100_00101111_10011111,
+			0b11100001_10100100_00011111_10010010,
+			0b11110101_01111111_11110000_00011111,
+			0b11110101_01111111_11110000_01011111,
+			0b11110101_01111111_11110000_01001010,
+			0b11110101_01111111_11110000_01101111,
+			0b11110101_01111111_11110000_01010011,
+			0b11100110_10101111_00000000_01110001,
+			0b11100110_10101111_00000100_01110001,
+			0b11100110_10100001_00001000_01110010,
+			0b11100110_11111111_00110000_01110100,
+			0b11100110_11110100_00110000_01110101,
+			0b11100110_10001111_00000000_01110001,
+			0b11100110_11000001_00000000_01110010,
+			0b11100110_11001111_00001100_01110001,
+			0b11100110_10000001_00000100_00010010,
+			0b11100110_10000001_00000100_01010010,
+			0b11100110_10000001_00000000_01010010,
+			0b11100110_10000001_00001111_10110010,
+			0b11100111_10000000_11110010_00010001,
+			0b11100111_10000000_00110010_00010001,
+			0b11100110_00010001_00001111_00010010,
+			0b11100110_01010001_00001111_10010010,
+			0b11100110_00100001_00001111_10010010,
+			0b11100110_00110001_00001111_00010010,
+			0b11100110_01110001_00001111_11110010,
+			0b11100110_01100001_00001111_00110010,
+			0b11100110_00010001_00001111_01010010,
+			0b11100011_00000001_00000010_00110100,
+			0b11100011_01001111_00011111_11111111,
+			0b11100111_11001011_00000010_00010001,
+			0b11100111_11011111_00100000_00011111,
+			0b11100111_11100100_00000001_11010001,
+			0b11100111_10100000_00101111_11010011,
+			0b11100110_11111111_00001111_00110001,
+			0b11100110_10111111_00001111_00110001,
+			0b11100110_10111111_00001111_10110001,
+			0b11100110_11111111_00001111_10110001,
+			0b11100111_00010000_11110010_00010001,
+			0b11100111_00110011_11110101_00010100,
+			0b00011110_00110000_00001010_10000001,
+			0b11101110_01110001_00001011_11101111,
+			0b11101110_01100010_00011010_10101111,
+			0b11101110_10000110_01011011_00000111,
+			0b11101110_10110001_00001010_11100000,
+			0b11101110_10110000_00001011_11000001,
+			0b11101110_10110001_00011010_01100001,
+			0b11101110_10110000_00001011_01100001,
+			0b11101110_10110100_00001011_11000001,
+			0b11101110_10110101_00001010_01000000,
+			0b11101110_10110111_00001010_11100000,
+			0b11101110_11110111_00001011_11000000,
+			0b11101110_10111101_00001010_11100000,
+			0b11101110_10111100_00001011_01000001,
+			0b11101110_10111000_00001010_11100000,
+			0b11101110_10111000_00001011_01100000,
+			0b11101110_10110111_00001010_00001000,
+			0b11101110_10111000_00001011_00000000,
+			0b11101110_00000000_00001010_10010000,
+			0b11101110_00010000_00001010_10010000,
+			0b11101100_01000001_00001011_00010000,
+			0b11101100_01010011_00101011_00110001,
+			0b11101110_11110001_11111010_00010000,
+			0b11101110_11100001_00011010_00010000,
+			0b11101101_10010000_00001011_00000010,
+			0b11101101_01001101_00001010_00000001,
+			0b11101100_10110000_00001011_00001000,
+			0b11101101_00101101_10001011_00010000,
+			0b11101100_10111101_10001010_00010000,
+			0b11110010_00000001_00001000_00000010,
+			0b11110010_00110010_00001000_01000100,
+			0b11110011_01100010_00001000_11101110,
+			0b11110011_00000001_00001001_00010010,
+			0b11110010_00100010_00001001_01000100,
+			0b11110011_00110010_00000000_01010100,
+			0b11110010_00010001_00000010_00010010,
+			0b11110011_00000001_00000110_00010010,
+			0b11110011_00100001_00001000_00010010,
+			0b11110010_00000001_00000011_00010010,
+			0b11110010_00010010_00000001_01010100,
+			0b11110010_00100010_00000001_01010010,
+			0b11110011_00000001_00001101_00010010,
+			0b11110011_00100001_00001110_00000010,
+			0b11110010_00100010_00001111_01000100,
+			0b11110011_10110101_00000011_11000010,
+			0b11110011_10111001_00000111_00000001,
+			0b11110011_10110000_00000101_11000010,
+			0b11110010_10111111_00000101_11010010,
+			0b11110010_10010000_00000000_00010001,
+			0b11110011_10111111_00000000_01010010,
+			0b11110010_10010001_00001000_01101010,
+			0b11110011_10100010_00001001_01000011,
+			0b11101110_10100000_00001011_00010000,
+			0b11110011_10111111_00001100_01000001,
+			0b11101110_00100001_00111011_11110000,
+			0b00001110_00000000_00001011_00010000,
+			0b11101110_01110001_00001011_01110000,
+			0b11110010_10110010_00001111_01000100,
+			0b11110011_10110100_00000000_11000010,
+			0b11110011_10110010_00000000_00000001,
+			0b11110011_10111010_00000000_11000010,
+			0b11110011_10110110_00000001_00000001,
+			0b11110011_10110010_00000001_11000010,
+			0b11110100_00100001_00001010_01101101,
+			0b11110100_00100010_00000110_10000011,
+			0b11110100_00100000_00000101_01011111,
+			0b11110100_01000000_00000111_00001111,
+			0b11110100_00000000_00000000_00111101,
+			0b11100011_00100000_11110000_00000000,
+			0b00000011_00100000_11110000_00000000,
+			0b11100011_00100000_11110000_00000001,
+			0b11100011_00100000_11110000_00000010,
+			0b11100011_00100000_11110000_00000011,
+			0b11100011_00100000_11110000_00000100,
+			0b11110001_00001000_00000001_11000000,
+			0b11110001_00001100_00000000_10000000,
+			0b11110001_00001010_00000000_01010011,
+			0b11110001_00000010_00000000_00011111,
+			0b11110001_00000001_00000010_00000000,
+			0b11110001_00000001_00000000_00000000,
+			0b11100110_11100000_00000000_00010001,
+			0b11100110_11111111_00000001_10010001,
+			0b11100110_11100111_00100000_01010011,
+			0b11100110_10100000_00000000_00010001,
+			0b11100110_10111111_00000000_11010001,
+			0b11100110_10100000_00001111_00110001,
+			0b11100110_10101111_00001111_00110001,
+			0b11100110_11100000_00001111_00110001,
+			0b11100110_11101111_00001111_00110001,
+			0b11100001_00000010_00000000_01010001,
+			0b11100001_00100010_00000000_01010001,
+			0b11100001_01000010_00000000_01010001,
+			0b11100001_01100010_00000000_01010001,
+			0b11100001_00101111_11111111_00110011,
+			0b00010001_00101111_11111111_00111110,
+			0b11100001_01101111_00001111_00010001,
+			0b00000001_01101111_11001111_00010010,
+			0b11111010_00000000_00000100_00000000,
+			0b11111011_00000000_00000100_00000000,
+			0b11110101_11010000_11110000_00000100,
+			0b11110111_01010001_11110001_00000010,
 		],
-	)
+	);
+
+	for instruction in [
+		Instruction::CompareBranchZero {
+			source:    Register::R0,
+			immediate: 0x08000010,
+		},
+
+		Instruction::InclusiveOrNot {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Shifter::from_register(Register::R2),
+			s:           Sflag::Off,
+		},
+
+		Instruction::PreloadData {
+			address: Address::ImmediateOffset { base: Register::R0, source: 0x4, indexing: Indexing::PreIndexed },
+		},
+	] {
+		assert!(matches!(codec.encode_arm(instruction), Err(Error::IllegalInstruction { .. })));
+	}
+
+	codec.set_pre_armv6(true);
+
+	let result = codec.encode_arm(Instruction::Multiply {
+		predicate:   Predicate::Always,
+		destination: Register::R0,
+		base:        Register::R0,
+		source:      Register::R0,
+		s:           Sflag::Off,
+	});
+
+	assert!(matches!(result, Err(Error::IllegalRegister { .. })));
+
+	let result = codec.encode_arm(Instruction::Multiply {
+		predicate:   Predicate::Always,
+		destination: Register::R1,
+		base:        Register::R1,
+		source:      Register::R2,
+		s:           Sflag::Off,
+	});
+
+	assert!(matches!(result, Err(Error::IllegalRegister { .. })));
+
+	let result = codec.encode_arm(Instruction::SignedMultiplyAccumulateLong {
+		predicate:        Predicate::GreaterThanOrEqual,
+		destination_low:  Register::R4,
+		destination_high: Register::Sp,
+		base:             Register::R4,
+		source:           Register::R9,
+		s:                Sflag::On,
+	});
+
+	assert!(matches!(result, Err(Error::IllegalRegister { .. })));
+
+	codec.set_pre_armv6(false);
+
+	for opcode in [
+		0b11100000_00000000_00000001_10010000,
+		0b11100000_00100010_01000011_10010010,
+		0b11100000_10000001_00000010_10010000,
+	] {
+		let instruction = codec.decode_arm(opcode.into()).unwrap();
+		assert_eq!(codec.encode_arm(instruction).unwrap(), opcode);
+	}
+
+	for instruction in [
+		Instruction::BitFieldInsert {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      Register::R1,
+			lsb:         0x10,
+			width:       0x11,
+		},
+
+		Instruction::BitFieldInsert {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      Register::R1,
+			lsb:         0x4,
+			width:       0x0,
+		},
+
+		Instruction::SignedBitFieldExtract {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      Register::R1,
+			lsb:         0x20,
+			width:       0x1,
+		},
+
+		Instruction::UnsignedBitFieldExtract {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      Register::R1,
+			lsb:         0x1C,
+			width:       0x8,
+		},
+
+		Instruction::SignedSaturate {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			immediate:   0x0,
+			source:      Shifter::from_register(Register::R1),
+		},
+
+		Instruction::SignedSaturate {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			immediate:   0x21,
+			source:      Shifter::from_register(Register::R1),
+		},
+
+		Instruction::UnsignedSaturate {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			immediate:   0x20,
+			source:      Shifter::from_register(Register::R1),
+		},
+
+		Instruction::SignedSaturate16 {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			immediate:   0x0,
+			source:      Register::R1,
+		},
+
+		Instruction::SignedSaturate16 {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			immediate:   0x11,
+			source:      Register::R1,
+		},
+
+		Instruction::UnsignedSaturate16 {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			immediate:   0x10,
+			source:      Register::R1,
+		},
+	] {
+		assert!(matches!(codec.encode_arm(instruction), Err(Error::IllegalImmediate { .. })));
+	}
+
+	let result = codec.encode_arm(Instruction::SignedSaturate {
+		predicate:   Predicate::Always,
+		destination: Register::R0,
+		immediate:   0x8,
+		source:      Shifter::LogicalShiftRightImmediate { source: Register::R1, shift: 0x1 },
+	});
+
+	assert!(matches!(result, Err(Error::IllegalShifter { .. })));
 }
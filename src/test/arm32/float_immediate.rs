@@ -0,0 +1,48 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Pollex.
+//
+// Pollex is free software: you can redistribute it
+// and/or modify it under the terms of the GNU Af-
+// fero General Public License as published by the
+// Free Software Foundation, either version 3 of
+// the License, or (at your option) any later ver-
+// sion.
+//
+// Pollex is distributed in the hope that it will
+// be useful, but WITHOUT ANY WARRANTY; without
+// even the implied warranty of MERCHANTABILITY or
+// FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// Affero General Public License for more details.
+//
+// You should have received a copy of the GNU Af-
+// fero General Public License along with Pollex.
+// If not, see <https://www.gnu.org/licenses/>.
+
+use crate::arm32::FloatImmediate;
+
+#[test]
+fn test_float_immediate() {
+	let tree = [
+		(1.5_f64,   Some(0b01111000)),
+		(-2.0_f64,  Some(0b10000000)),
+		(0.125_f64, Some(0b01000000)),
+		(31.0_f64,  Some(0b00111111)),
+		(0.1_f64,   None),
+		(0.0_f64,   None),
+		(32.0_f64,  None),
+	];
+
+	for (value, encoding) in tree {
+		let single = FloatImmediate::from_f32(value as f32);
+		let double = FloatImmediate::from_f64(value);
+
+		assert_eq!(single.map(FloatImmediate::to_u8), encoding, "{value:?}");
+		assert_eq!(double.map(FloatImmediate::to_u8), encoding, "{value:?}");
+
+		if let Some(immediate) = double {
+			assert_eq!(immediate.to_f32().to_bits(), (value as f32).to_bits());
+			assert_eq!(immediate.to_f64().to_bits(), value.to_bits());
+		}
+	}
+}
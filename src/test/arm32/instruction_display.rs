@@ -20,11 +20,41 @@
 // If not, see <https://www.gnu.org/licenses/>.
 
 use crate::arm32::{
+	Address,
+	BarrierOption,
+	Bflag,
+	BlockMode,
+	Coprocessor,
+	CoprocessorRegister,
+	DoubleRegister,
+	Eflag,
+	ElementType,
+	Endianness,
+	FloatImmediate,
+	Indexing,
+	InterruptMask,
+	Lflag,
+	ParallelOperation,
+	ParallelPrefix,
 	Predicate,
+	ProcessorMode,
+	QuadRegister,
+	Rflag,
+	Scalar,
 	Sflag,
 	Instruction,
 	Register,
+	RegisterList,
 	Shifter,
+	Sign,
+	SingleRegister,
+	StatusFields,
+	StatusRegister,
+	StructureAddress,
+	Tflag,
+	VectorRegister,
+	VfpRegister,
+	Wflag,
 };
 
 use alloc::string::ToString;
@@ -61,7 +91,7 @@ fn test_instruction_display() {
 			destination: Register::R0,
 			base:        Register::Pc,
 			source:      Register::Pc,
-			shift:       Register::Lr,
+			accumulator: Register::Lr,
 			s:           Sflag::Off,
 		},
 
@@ -93,6 +123,545 @@ fn test_instruction_display() {
 			source:      Shifter::LogicalShiftLeftImmediate { source: Register::R0, shift: 0x0 },
 			s:           Sflag::On,
 		},
+
+		Instruction::Move {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      Shifter::RotateRightExtend { source: Register::R1 },
+			s:           Sflag::Off,
+		},
+
+		Instruction::Swap {
+			predicate: Predicate::Always,
+			register:  Register::R0,
+			source:    Register::R1,
+			address:   Address::ImmediateOffset { base: Register::R2, source: 0x0, indexing: Indexing::Offset },
+			b:         Bflag::On,
+		},
+
+		Instruction::Load {
+			predicate: Predicate::Equal,
+			register:  Register::R5,
+			address:   Address::ScaledRegisterOffset { base: Register::R6, source: Shifter::LogicalShiftLeftImmediate { source: Register::R7, shift: 0x2 }, sign: Sign::Positive, indexing: Indexing::Offset },
+			b:         Bflag::On,
+			t:         Tflag::Off,
+		},
+
+		Instruction::Load {
+			predicate: Predicate::Always,
+			register:  Register::R0,
+			address:   Address::ImmediateOffset { base: Register::R1, source: -0x4, indexing: Indexing::PostIndexed },
+			b:         Bflag::Off,
+			t:         Tflag::Off,
+		},
+
+		Instruction::Store {
+			predicate: Predicate::Always,
+			register:  Register::R0,
+			address:   Address::RegisterOffset { base: Register::R1, source: Register::R2, sign: Sign::Negative, indexing: Indexing::PreIndexed },
+			b:         Bflag::Off,
+			t:         Tflag::Off,
+		},
+
+		Instruction::LoadSignedHalfword {
+			predicate: Predicate::Equal,
+			register:  Register::R0,
+			address:   Address::ImmediateOffset { base: Register::R1, source: 0x2, indexing: Indexing::Offset },
+		},
+
+		Instruction::StoreDoubleword {
+			predicate: Predicate::Always,
+			first:     Register::R4,
+			second:    Register::R5,
+			address:   Address::ImmediateOffset { base: Register::Sp, source: -0x8, indexing: Indexing::PreIndexed },
+		},
+
+		Instruction::StoreMultiple {
+			predicate: Predicate::Always,
+			base:      Register::Sp,
+			registers: RegisterList::from_u16(0b01000000_11110000),
+			mode:      BlockMode::DecrementBefore,
+			w:         Wflag::On,
+			s:         Sflag::Off,
+		},
+
+		Instruction::LoadMultiple {
+			predicate: Predicate::NotEqual,
+			base:      Register::Sp,
+			registers: RegisterList::new().with(Register::R4).with(Register::Pc),
+			mode:      BlockMode::IncrementAfter,
+			w:         Wflag::On,
+			s:         Sflag::Off,
+		},
+
+		Instruction::LoadMultiple {
+			predicate: Predicate::Always,
+			base:      Register::R0,
+			registers: RegisterList::from_u16(0b10000000_00000011),
+			mode:      BlockMode::IncrementBefore,
+			w:         Wflag::Off,
+			s:         Sflag::On,
+		},
+
+		Instruction::StoreMultiple {
+			predicate: Predicate::Equal,
+			base:      Register::R1,
+			registers: RegisterList::from_u16(0b00000000_00001101),
+			mode:      BlockMode::DecrementAfter,
+			w:         Wflag::On,
+			s:         Sflag::Off,
+		},
+
+		Instruction::SignedMultiplyAccumulateLong {
+			predicate:        Predicate::NotEqual,
+			destination_low:  Register::R4,
+			destination_high: Register::R5,
+			base:             Register::R6,
+			source:           Register::R7,
+			s:                Sflag::On,
+		},
+
+		Instruction::MultiplySubtract {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Register::R2,
+			accumulator: Register::R3,
+		},
+
+		Instruction::MoveFromStatusRegister {
+			predicate:   Predicate::NotEqual,
+			destination: Register::R1,
+			source:      StatusRegister::Spsr,
+		},
+
+		Instruction::MoveToStatusRegister {
+			predicate:   Predicate::Always,
+			destination: StatusRegister::Cpsr,
+			fields:      StatusFields::CONTROL | StatusFields::FLAGS,
+			source:      Shifter::LogicalShiftLeftImmediate { source: Register::R0, shift: 0x0 },
+		},
+
+		Instruction::MoveToStatusRegister {
+			predicate:   Predicate::Always,
+			destination: StatusRegister::Spsr,
+			fields:      StatusFields::ALL,
+			source:      Shifter::Immediate(0x1F),
+		},
+
+		Instruction::MoveFromCoprocessor {
+			predicate:   Predicate::Always,
+			coprocessor: Coprocessor::P15,
+			opcode1:     0x0,
+			register:    Register::R0,
+			crn:         CoprocessorRegister::C1,
+			crm:         CoprocessorRegister::C0,
+			opcode2:     0x0,
+		},
+
+		Instruction::MoveToCoprocessorDouble {
+			predicate:   Predicate::Always,
+			coprocessor: Coprocessor::P15,
+			opcode1:     0x0,
+			first:       Register::R0,
+			second:      Register::R1,
+			crm:         CoprocessorRegister::C2,
+		},
+
+		Instruction::MoveFromCoprocessorDouble {
+			predicate:   Predicate::Equal,
+			coprocessor: Coprocessor::P15,
+			opcode1:     0x1,
+			first:       Register::R2,
+			second:      Register::R3,
+			crm:         CoprocessorRegister::C14,
+		},
+
+		Instruction::StoreCoprocessor {
+			predicate:   Predicate::Always,
+			coprocessor: Coprocessor::P6,
+			crd:         CoprocessorRegister::C2,
+			address:     Address::ImmediateOffset { base: Register::R3, source: -0x8, indexing: Indexing::PreIndexed },
+			l:           Lflag::On,
+		},
+
+		Instruction::LoadExclusiveByte {
+			predicate: Predicate::NotEqual,
+			register:  Register::R0,
+			address:   Address::ImmediateOffset { base: Register::R1, source: 0x0, indexing: Indexing::Offset },
+		},
+
+		Instruction::StoreExclusiveDoubleword {
+			predicate: Predicate::Always,
+			status:    Register::R1,
+			first:     Register::R2,
+			second:    Register::R3,
+			address:   Address::ImmediateOffset { base: Register::R4, source: 0x0, indexing: Indexing::Offset },
+		},
+
+		Instruction::ClearExclusive,
+
+		Instruction::DataSynchronisationBarrier {
+			option: BarrierOption::IshSt,
+		},
+
+		Instruction::SignExtendAddByte {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Register::R2,
+			rotation:    0x10,
+		},
+
+		Instruction::ZeroExtendByte16 {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      Register::R1,
+			rotation:    0x18,
+		},
+
+		Instruction::PackHalfword {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Shifter::LogicalShiftLeftImmediate { source: Register::R2, shift: 0x8 },
+		},
+
+		Instruction::PackHalfword {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Shifter::ArithmeticShiftRightImmediate { source: Register::R2, shift: 0x20 },
+		},
+
+		Instruction::UnsignedSumAbsoluteDifferencesAccumulate {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Register::R2,
+			accumulator: Register::R3,
+		},
+
+		Instruction::ParallelArithmetic {
+			predicate:   Predicate::Always,
+			prefix:      ParallelPrefix::SignedHalving,
+			operation:   ParallelOperation::Add16,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Register::R2,
+		},
+
+		Instruction::ParallelArithmetic {
+			predicate:   Predicate::Always,
+			prefix:      ParallelPrefix::UnsignedSaturating,
+			operation:   ParallelOperation::AddSubtractExchange,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Register::R2,
+		},
+
+		Instruction::MoveWide {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			immediate:   0x1234,
+		},
+
+		Instruction::BitFieldInsert {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      Register::R1,
+			lsb:         0x4,
+			width:       0x8,
+		},
+
+		Instruction::BitFieldClear {
+			predicate:   Predicate::Always,
+			destination: Register::R2,
+			lsb:         0x0,
+			width:       0x20,
+		},
+
+		Instruction::SignedBitFieldExtract {
+			predicate:   Predicate::Always,
+			destination: Register::R2,
+			source:      Register::R3,
+			lsb:         0x1F,
+			width:       0x1,
+		},
+
+		Instruction::ReversePackedHalfword {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      Register::R1,
+		},
+
+		Instruction::UnsignedDivide {
+			predicate:   Predicate::Always,
+			destination: Register::R3,
+			base:        Register::R4,
+			source:      Register::R5,
+		},
+
+		Instruction::FloatAdd {
+			predicate:   Predicate::NotEqual,
+			destination: VfpRegister::Single(SingleRegister::S0),
+			base:        VfpRegister::Single(SingleRegister::S1),
+			source:      VfpRegister::Single(SingleRegister::S2),
+		},
+
+		Instruction::FloatCompare {
+			predicate: Predicate::Always,
+			lhs:       VfpRegister::Double(DoubleRegister::D0),
+			rhs:       Some(VfpRegister::Double(DoubleRegister::D1)),
+			e:         Eflag::On,
+		},
+
+		Instruction::FloatCompare {
+			predicate: Predicate::Always,
+			lhs:       VfpRegister::Single(SingleRegister::S0),
+			rhs:       None,
+			e:         Eflag::Off,
+		},
+
+		Instruction::FloatConvert {
+			predicate:   Predicate::Always,
+			destination: VfpRegister::Single(SingleRegister::S1),
+			source:      VfpRegister::Double(DoubleRegister::D0),
+		},
+
+		Instruction::FloatToUnsigned {
+			predicate:   Predicate::Always,
+			destination: SingleRegister::S0,
+			source:      VfpRegister::Double(DoubleRegister::D1),
+			r:           Rflag::On,
+		},
+
+		Instruction::UnsignedToFloat {
+			predicate:   Predicate::Always,
+			destination: VfpRegister::Double(DoubleRegister::D0),
+			source:      SingleRegister::S1,
+		},
+
+		Instruction::FloatMoveImmediate {
+			predicate:   Predicate::Always,
+			destination: VfpRegister::Single(SingleRegister::S0),
+			immediate:   FloatImmediate::new(0b01111000),
+		},
+
+		Instruction::FloatMoveImmediate {
+			predicate:   Predicate::Always,
+			destination: VfpRegister::Double(DoubleRegister::D0),
+			immediate:   FloatImmediate::new(0b10000000),
+		},
+
+		Instruction::MoveFromFloatDouble {
+			predicate: Predicate::Always,
+			low:       Register::R2,
+			high:      Register::R3,
+			source:    DoubleRegister::D17,
+		},
+
+		Instruction::MoveFromFloatStatus {
+			predicate:   Predicate::Always,
+			destination: Register::Pc,
+		},
+
+		Instruction::FloatStore {
+			predicate: Predicate::Always,
+			register:  VfpRegister::Single(SingleRegister::S1),
+			address:   Address::ImmediateOffset { base: Register::Sp, source: -0x4, indexing: Indexing::Offset },
+		},
+
+		Instruction::FloatLoadMultiple {
+			predicate: Predicate::Always,
+			base:      Register::R0,
+			first:     VfpRegister::Double(DoubleRegister::D0),
+			count:     0x4,
+			mode:      BlockMode::IncrementAfter,
+			w:         Wflag::On,
+		},
+
+		Instruction::FloatStoreMultiple {
+			predicate: Predicate::Always,
+			base:      Register::Sp,
+			first:     VfpRegister::Double(DoubleRegister::D8),
+			count:     0x8,
+			mode:      BlockMode::DecrementBefore,
+			w:         Wflag::On,
+		},
+
+		Instruction::VectorAdd {
+			element:     ElementType::I64,
+			destination: VectorRegister::Quad(QuadRegister::Q0),
+			base:        VectorRegister::Quad(QuadRegister::Q1),
+			source:      VectorRegister::Quad(QuadRegister::Q2),
+		},
+
+		Instruction::VectorMultiply {
+			element:     ElementType::P8,
+			destination: VectorRegister::Double(DoubleRegister::D0),
+			base:        VectorRegister::Double(DoubleRegister::D1),
+			source:      VectorRegister::Double(DoubleRegister::D2),
+		},
+
+		Instruction::VectorInclusiveOr {
+			destination: VectorRegister::Quad(QuadRegister::Q0),
+			base:        VectorRegister::Quad(QuadRegister::Q1),
+			source:      VectorRegister::Quad(QuadRegister::Q1),
+		},
+
+		Instruction::VectorShiftRight {
+			element:     ElementType::U32,
+			destination: VectorRegister::Quad(QuadRegister::Q0),
+			source:      VectorRegister::Quad(QuadRegister::Q1),
+			shift:       0x1,
+		},
+
+		Instruction::VectorMultiplyScalar {
+			element:     ElementType::I16,
+			destination: VectorRegister::Double(DoubleRegister::D0),
+			base:        VectorRegister::Double(DoubleRegister::D1),
+			source:      Scalar::new(DoubleRegister::D2, 0x3),
+		},
+
+		Instruction::MoveToScalar {
+			predicate:   Predicate::Equal,
+			element:     ElementType::Untyped32,
+			destination: Scalar::new(DoubleRegister::D0, 0x0),
+			source:      Register::R0,
+		},
+
+		Instruction::MoveFromScalar {
+			predicate:   Predicate::Always,
+			element:     ElementType::S8,
+			destination: Register::R0,
+			source:      Scalar::new(DoubleRegister::D1, 0x7),
+		},
+
+		Instruction::VectorExtract {
+			destination: VectorRegister::Quad(QuadRegister::Q0),
+			base:        VectorRegister::Quad(QuadRegister::Q1),
+			source:      VectorRegister::Quad(QuadRegister::Q2),
+			index:       0xF,
+		},
+
+		Instruction::VectorLoadStructure {
+			interleave: 0x1,
+			element:    ElementType::Untyped32,
+			first:      DoubleRegister::D0,
+			count:      0x3,
+			spacing:    0x1,
+			address:    StructureAddress::RegisterPostIndexed { base: Register::R2, alignment: 0x0, source: Register::R3 },
+		},
+
+		Instruction::VectorLoadStructure {
+			interleave: 0x3,
+			element:    ElementType::Untyped16,
+			first:      DoubleRegister::D0,
+			count:      0x3,
+			spacing:    0x2,
+			address:    StructureAddress::Offset { base: Register::R0, alignment: 0x40 },
+		},
+
+		Instruction::VectorStoreStructure {
+			interleave: 0x4,
+			element:    ElementType::Untyped8,
+			first:      DoubleRegister::D0,
+			count:      0x4,
+			spacing:    0x1,
+			address:    StructureAddress::PostIndexed { base: Register::R0, alignment: 0x100 },
+		},
+
+		Instruction::NoOperation {
+			predicate: Predicate::Equal,
+		},
+
+		Instruction::WaitForInterrupt {
+			predicate: Predicate::Always,
+		},
+
+		Instruction::EnableInterrupts {
+			mask: InterruptMask::ALL,
+			mode: None,
+		},
+
+		Instruction::DisableInterrupts {
+			mask: InterruptMask::IRQ,
+			mode: None,
+		},
+
+		Instruction::EnableInterrupts {
+			mask: InterruptMask::FIQ,
+			mode: Some(ProcessorMode::Supervisor),
+		},
+
+		Instruction::ChangeProcessorMode {
+			mode: ProcessorMode::System,
+		},
+
+		Instruction::SetEndianness {
+			endianness: Endianness::Big,
+		},
+
+		Instruction::SignedSaturate {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			immediate:   0x20,
+			source:      Shifter::ArithmeticShiftRightImmediate { source: Register::R1, shift: 0x1 },
+		},
+
+		Instruction::UnsignedSaturate16 {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			immediate:   0xF,
+			source:      Register::R1,
+		},
+
+		Instruction::SaturatingDoubleSubtract {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Register::R2,
+		},
+
+		Instruction::IfThen {
+			predicate: Predicate::Equal,
+			mask:      0b1010,
+		},
+
+		Instruction::IfThen {
+			predicate: Predicate::Always,
+			mask:      0b1000,
+		},
+
+		Instruction::CompareBranchNonZero {
+			source:    Register::R7,
+			immediate: 0x100,
+		},
+
+		Instruction::TableBranchByte {
+			predicate: Predicate::Equal,
+			base:      Register::R0,
+			index:     Register::R1,
+		},
+
+		Instruction::TableBranchHalfword {
+			predicate: Predicate::Always,
+			base:      Register::Pc,
+			index:     Register::R10,
+		},
+
+		Instruction::PreloadData {
+			address: Address::ImmediateOffset { base: Register::R1, source: -0x8, indexing: Indexing::Offset },
+		},
+
+		Instruction::InclusiveOrNot {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Shifter::Immediate(0xFF),
+			s:           Sflag::On,
+		},
 	];
 
 	let mut displays = Vec::with_capacity(tree.len());
@@ -109,6 +678,90 @@ fn test_instruction_display() {
 			"NEGS r0, r0",
 			"LSRGTS r0, r7, #32",
 			"MOVS r0, r0",
+			"RRX r0, r1",
+			"SWPB r0, r1, [r2]",
+			"LDREQB r5, [r6, r7, LSL #2]",
+			"LDR r0, [r1], #-4",
+			"STR r0, [r1, -r2]!",
+			"LDREQSH r0, [r1, #2]",
+			"STRD r4, r5, [sp, #-8]!",
+			"PUSH {r4-r7, lr}",
+			"POPNE {r4, pc}",
+			"LDMIB r0, {r0, r1, pc}^",
+			"STMEQDA r1!, {r0, r2, r3}",
+			"SMLALNES r4, r5, r6, r7",
+			"MLS r0, r1, r2, r3",
+			"MRSNE r1, SPSR",
+			"MSR CPSR_fc, r0",
+			"MSR SPSR_fsxc, #31",
+			"MRC p15, 0, r0, c1, c0, 0 ; SCTLR",
+			"MCRR p15, 0, r0, r1, c2",
+			"MRRCEQ p15, 1, r2, r3, c14",
+			"STCL p6, c2, [r3, #-8]!",
+			"LDREXBNE r0, [r1]",
+			"STREXD r1, r2, r3, [r4]",
+			"CLREX",
+			"DSB ISHST",
+			"SXTAB r0, r1, r2, ROR #16",
+			"UXTB16 r0, r1, ROR #24",
+			"PKHBT r0, r1, r2, LSL #8",
+			"PKHTB r0, r1, r2, ASR #32",
+			"USADA8 r0, r1, r2, r3",
+			"SHADD16 r0, r1, r2",
+			"UQADDSUBX r0, r1, r2",
+			"MOVW r0, #4660",
+			"BFI r0, r1, #4, #8",
+			"BFC r2, #0, #32",
+			"SBFX r2, r3, #31, #1",
+			"REV16 r0, r1",
+			"UDIV r3, r4, r5",
+			"VADDNE.F32 s0, s1, s2",
+			"VCMPE.F64 d0, d1",
+			"VCMP.F32 s0, #0.0",
+			"VCVT.F32.F64 s1, d0",
+			"VCVTR.U32.F64 s0, d1",
+			"VCVT.F64.U32 d0, s1",
+			"VMOV.F32 s0, #1.5",
+			"VMOV.F64 d0, #-2.0",
+			"VMOV r2, r3, d17",
+			"VMRS APSR_nzcv, FPSCR",
+			"VSTR s1, [sp, #-4]",
+			"VLDMIA r0!, {d0-d3}",
+			"VPUSH {d8-d15}",
+			"VADD.I64 q0, q1, q2",
+			"VMUL.P8 d0, d1, d2",
+			"VMOV q0, q1",
+			"VSHR.U32 q0, q1, #1",
+			"VMUL.I16 d0, d1, d2[3]",
+			"VMOVEQ.32 d0[0], r0",
+			"VMOV.S8 r0, d1[7]",
+			"VEXT.8 q0, q1, q2, #15",
+			"VLD1.32 {d0, d1, d2}, [r2], r3",
+			"VLD3.16 {d0, d2, d4}, [r0:64]",
+			"VST4.8 {d0, d1, d2, d3}, [r0:256]!",
+			"NOPEQ",
+			"WFI",
+			"CPSIE aif",
+			"CPSID i",
+			"CPSIE f, #19",
+			"CPS #31",
+			"SETEND BE",
+			"SSAT r0, #32, r1, ASR #1",
+			"USAT16 r0, #15, r1",
+			"QDSUB r0, r1, r2",
+			"ITTE EQ",
+			"IT AL",
+			"CBNZ r7, #256",
+			"TBBEQ [r0, r1]",
+			"TBH [pc, r10, LSL #1]",
+			"PLD [r1, #-8]",
+			"ORNS r0, r1, #255",
 		],
 	);
+
+	// Every displayed instruction should parse back to
+	// itself.
+	for (instruction, display) in tree.into_iter().zip(displays) {
+		assert_eq!(display.parse::<Instruction>().unwrap(), instruction, "{display}");
+	}
 }
@@ -0,0 +1,256 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Pollex.
+//
+// Pollex is free software: you can redistribute it
+// and/or modify it under the terms of the GNU Af-
+// fero General Public License as published by the
+// Free Software Foundation, either version 3 of
+// the License, or (at your option) any later ver-
+// sion.
+//
+// Pollex is distributed in the hope that it will
+// be useful, but WITHOUT ANY WARRANTY; without
+// even the implied warranty of MERCHANTABILITY or
+// FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// Affero General Public License for more details.
+//
+// You should have received a copy of the GNU Af-
+// fero General Public License along with Pollex.
+// If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Error;
+use crate::arm32::{
+	Address,
+	BarrierOption,
+	Bflag,
+	BlockMode,
+	DoubleRegister,
+	ElementType,
+	Indexing,
+	Instruction,
+	ParallelOperation,
+	ParallelPrefix,
+	Predicate,
+	QuadRegister,
+	Register,
+	RegisterList,
+	Rflag,
+	Sflag,
+	Shifter,
+	Sign,
+	SingleRegister,
+	StatusFields,
+	StatusRegister,
+	StructureAddress,
+	Tflag,
+	VectorRegister,
+	VfpRegister,
+	Wflag,
+};
+
+use alloc::vec::Vec;
+
+#[test]
+fn test_instruction_from_str() {
+	let tree = [
+		"CPY r0, r1",
+		"adds r0, r1, #0x10",
+		"ADDSEQ r0, r1, r2, lsl r3",
+		"SUB r0, #0b1010",
+		"ORR r0, r1, LSL #2",
+		"LSLS r0, r1, #3",
+		"ADD.W r0, r1, r2",
+		"LDRBEQ r0, [r1, #-4]!",
+		"LDRSH r0, [r1], -r2",
+		"STMFD sp!, {r0-r3, lr}",
+		"LDMEA r0, {r1, r2}^",
+		"SVC #0x0",
+		"MSR APSR_nzcvq, r0",
+		"DMB",
+		"UASXNE r0, r1, r2",
+		"ITET GT",
+		"MRC p15, 0, r0, c13, c0, 3 ; TPIDRURO",
+		"VADD.F32 d0, d1, d2",
+		"VCVT.S32.F32 s0, s1",
+		"VDUP.16 q0, r1",
+		"VLD2.8 {d0-d3}, [r0]",
+	];
+
+	let mut programme = Vec::with_capacity(tree.len());
+	for s in tree { programme.push(s.parse::<Instruction>().unwrap()) }
+
+	assert_eq!(
+		programme,
+		[
+			Instruction::Move {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				source:      Shifter::from_register(Register::R1),
+				s:           Sflag::Off,
+			},
+
+			Instruction::Add {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				base:        Register::R1,
+				source:      Shifter::Immediate(0x10),
+				s:           Sflag::On,
+			},
+
+			Instruction::Add {
+				predicate:   Predicate::Equal,
+				destination: Register::R0,
+				base:        Register::R1,
+				source:      Shifter::LogicalShiftLeftRegister { source: Register::R2, shift: Register::R3 },
+				s:           Sflag::On,
+			},
+
+			Instruction::Subtract {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				base:        Register::R0,
+				source:      Shifter::Immediate(0xA),
+				s:           Sflag::Off,
+			},
+
+			Instruction::InclusiveOr {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				base:        Register::R0,
+				source:      Shifter::LogicalShiftLeftImmediate { source: Register::R1, shift: 0x2 },
+				s:           Sflag::Off,
+			},
+
+			Instruction::Move {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				source:      Shifter::LogicalShiftLeftImmediate { source: Register::R1, shift: 0x3 },
+				s:           Sflag::On,
+			},
+
+			Instruction::Add {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				base:        Register::R1,
+				source:      Shifter::from_register(Register::R2),
+				s:           Sflag::Off,
+			},
+
+			Instruction::Load {
+				predicate: Predicate::Equal,
+				register:  Register::R0,
+				address:   Address::ImmediateOffset { base: Register::R1, source: -0x4, indexing: Indexing::PreIndexed },
+				b:         Bflag::On,
+				t:         Tflag::Off,
+			},
+
+			Instruction::LoadSignedHalfword {
+				predicate: Predicate::Always,
+				register:  Register::R0,
+				address:   Address::RegisterOffset { base: Register::R1, source: Register::R2, sign: Sign::Negative, indexing: Indexing::PostIndexed },
+			},
+
+			Instruction::StoreMultiple {
+				predicate: Predicate::Always,
+				base:      Register::Sp,
+				registers: RegisterList::from_u16(0b01000000_00001111),
+				mode:      BlockMode::DecrementBefore,
+				w:         Wflag::On,
+				s:         Sflag::Off,
+			},
+
+			Instruction::LoadMultiple {
+				predicate: Predicate::Always,
+				base:      Register::R0,
+				registers: RegisterList::from_u16(0b00000000_00000110),
+				mode:      BlockMode::DecrementBefore,
+				w:         Wflag::Off,
+				s:         Sflag::On,
+			},
+
+			Instruction::SoftwareInterrupt {
+				predicate: Predicate::Always,
+				immediate: 0x0,
+			},
+
+			Instruction::MoveToStatusRegister {
+				predicate:   Predicate::Always,
+				destination: StatusRegister::Cpsr,
+				fields:      StatusFields::FLAGS,
+				source:      Shifter::from_register(Register::R0),
+			},
+
+			Instruction::DataMemoryBarrier {
+				option: BarrierOption::Sy,
+			},
+
+			Instruction::ParallelArithmetic {
+				predicate:   Predicate::NotEqual,
+				prefix:      ParallelPrefix::Unsigned,
+				operation:   ParallelOperation::AddSubtractExchange,
+				destination: Register::R0,
+				base:        Register::R1,
+				source:      Register::R2,
+			},
+
+			Instruction::IfThen {
+				predicate: Predicate::GreaterThan,
+				mask:      0b0110,
+			},
+
+			Instruction::MoveFromCoprocessor {
+				predicate:   Predicate::Always,
+				coprocessor: "p15".parse().unwrap(),
+				opcode1:     0x0,
+				register:    Register::R0,
+				crn:         "c13".parse().unwrap(),
+				crm:         "c0".parse().unwrap(),
+				opcode2:     0x3,
+			},
+
+			Instruction::VectorAdd {
+				element:     ElementType::F32,
+				destination: VectorRegister::Double(DoubleRegister::D0),
+				base:        VectorRegister::Double(DoubleRegister::D1),
+				source:      VectorRegister::Double(DoubleRegister::D2),
+			},
+
+			Instruction::FloatToSigned {
+				predicate:   Predicate::Always,
+				destination: SingleRegister::S0,
+				source:      VfpRegister::Single(SingleRegister::S1),
+				r:           Rflag::Off,
+			},
+
+			Instruction::VectorDuplicate {
+				predicate:   Predicate::Always,
+				element:     ElementType::Untyped16,
+				destination: VectorRegister::Quad(QuadRegister::Q0),
+				source:      Register::R1,
+			},
+
+			Instruction::VectorLoadStructure {
+				interleave: 0x2,
+				element:    ElementType::Untyped8,
+				first:      DoubleRegister::D0,
+				count:      0x4,
+				spacing:    0x1,
+				address:    StructureAddress::Offset { base: Register::R0, alignment: 0x0 },
+			},
+		],
+	);
+
+	assert!(matches!("FOO r0".parse::<Instruction>(), Err(Error::UnknownMnemonic(..))));
+	assert!(matches!("ADD.F32 r0, r1, r2".parse::<Instruction>(), Err(Error::UnknownMnemonic(..))));
+	assert!(matches!("MOV r16, r0".parse::<Instruction>(), Err(Error::UnknownRegister(..))));
+	assert!(matches!("VMRS r0, FPEXC".parse::<Instruction>(), Err(Error::UnknownRegister(..))));
+	assert!(matches!("MOV r0".parse::<Instruction>(), Err(Error::IllegalInstruction { .. })));
+	assert!(matches!("MOV r0, r1, r2".parse::<Instruction>(), Err(Error::IllegalInstruction { .. })));
+	assert!(matches!("LDR r0, [r1".parse::<Instruction>(), Err(Error::IllegalInstruction { .. })));
+	assert!(matches!("MOV r0, #0xZZ".parse::<Instruction>(), Err(Error::IllegalImmediate { .. })));
+	assert!(matches!("VMOV.F32 s0, #0.1".parse::<Instruction>(), Err(Error::IllegalImmediate { .. })));
+	assert!(matches!("CLREXEQ".parse::<Instruction>(), Err(Error::IllegalPredicate { .. })));
+	assert!(matches!("VCVTR.F32.S32 s0, s1".parse::<Instruction>(), Err(Error::IllegalFlag { .. })));
+	assert!(matches!("VADD.F64 d0, d1, s2".parse::<Instruction>(), Err(Error::IllegalRegister { .. })));
+}
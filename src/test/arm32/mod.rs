@@ -19,7 +19,12 @@
 // fero General Public License along with Pollex.
 // If not, see <https://www.gnu.org/licenses/>.
 
+mod arm_decode;
 mod arm_encode;
+mod float_immediate;
 mod instruction_display;
+mod instruction_from_str;
+mod register_list;
+mod shifter;
 mod thumb_decode;
 mod thumb_encode;
\ No newline at end of file
@@ -0,0 +1,99 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Pollex.
+//
+// Pollex is free software: you can redistribute it
+// and/or modify it under the terms of the GNU Af-
+// fero General Public License as published by the
+// Free Software Foundation, either version 3 of
+// the License, or (at your option) any later ver-
+// sion.
+//
+// Pollex is distributed in the hope that it will
+// be useful, but WITHOUT ANY WARRANTY; without
+// even the implied warranty of MERCHANTABILITY or
+// FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// Affero General Public License for more details.
+//
+// You should have received a copy of the GNU Af-
+// fero General Public License along with Pollex.
+// If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Error;
+use crate::arm32::{
+	BlockMode,
+	Instruction,
+	InstructionCodec,
+	Predicate,
+	Register,
+	RegisterList,
+	Sflag,
+	Wflag,
+};
+
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+#[test]
+fn test_register_list() {
+	let list: RegisterList = [Register::Lr, Register::R7, Register::R4, Register::R5, Register::R6].into_iter().collect();
+
+	assert_eq!(list.to_u16(), 0b01000000_11110000);
+	assert_eq!(list.len(), 0x5);
+	assert_eq!(list.lowest(), Some(Register::R4));
+	assert_eq!(list.highest(), Some(Register::Lr));
+	assert_eq!(list.iter().collect::<Vec<_>>(), [Register::R4, Register::R5, Register::R6, Register::R7, Register::Lr]);
+
+	let low = RegisterList::from_u16(0b00000000_11111111);
+
+	assert_eq!(list & low, RegisterList::from_u16(0b00000000_11110000));
+	assert_eq!(list - low, RegisterList::from(Register::Lr));
+	assert_eq!(list | low, RegisterList::from_u16(0b01000000_11111111));
+	assert!((list & low).is_subset(low));
+	assert!(!list.is_low());
+
+	let tree = [
+		(RegisterList::new(),                       "{}"),
+		(RegisterList::from_u16(0b01000000_11110000), "{r4-r7, lr}"),
+		(RegisterList::from_u16(0b00000000_00000011), "{r0, r1}"),
+		(RegisterList::from_u16(0b00010111_00000101), "{r0, r2, r8-r10, r12}"),
+		(RegisterList::from_u16(0b11111111_11111111), "{r0-r12, sp, lr, pc}"),
+	];
+
+	for (list, display) in tree { assert_eq!(list.to_string(), display) }
+
+	let mut codec = InstructionCodec::new();
+
+	let result = codec.encode_arm(Instruction::LoadMultiple {
+		predicate: Predicate::Always,
+		base:      Register::R0,
+		registers: RegisterList::new(),
+		mode:      BlockMode::IncrementAfter,
+		w:         Wflag::Off,
+		s:         Sflag::Off,
+	});
+
+	assert!(matches!(result, Err(Error::IllegalInstruction { .. })));
+
+	let result = codec.encode_arm(Instruction::LoadMultiple {
+		predicate: Predicate::Always,
+		base:      Register::R0,
+		registers: RegisterList::from_u16(0b00000000_00000011),
+		mode:      BlockMode::IncrementAfter,
+		w:         Wflag::On,
+		s:         Sflag::Off,
+	});
+
+	assert!(matches!(result, Err(Error::IllegalRegister { .. })));
+
+	let result = codec.encode_thumb(Instruction::StoreMultiple {
+		predicate: Predicate::Always,
+		base:      Register::Sp,
+		registers: RegisterList::from(Register::R8),
+		mode:      BlockMode::DecrementBefore,
+		w:         Wflag::On,
+		s:         Sflag::Off,
+	});
+
+	assert!(matches!(result, Err(Error::IllegalRegister { .. })));
+}
@@ -0,0 +1,70 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Pollex.
+//
+// Pollex is free software: you can redistribute it
+// and/or modify it under the terms of the GNU Af-
+// fero General Public License as published by the
+// Free Software Foundation, either version 3 of
+// the License, or (at your option) any later ver-
+// sion.
+//
+// Pollex is distributed in the hope that it will
+// be useful, but WITHOUT ANY WARRANTY; without
+// even the implied warranty of MERCHANTABILITY or
+// FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// Affero General Public License for more details.
+//
+// You should have received a copy of the GNU Af-
+// fero General Public License along with Pollex.
+// If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Error;
+use crate::arm32::{
+	Instruction,
+	InstructionCodec,
+	Predicate,
+	Register,
+	Sflag,
+	Shifter,
+};
+
+#[test]
+fn test_shifter_arm_encodable() {
+	let tree = [
+		(Shifter::Immediate(0x000000FF), true),
+		(Shifter::Immediate(0x00000FF0), true),
+		(Shifter::Immediate(0xF000000F), true),
+		(Shifter::Immediate(0x00000101), false),
+		(Shifter::Immediate(0xFFFFFFFF), false),
+		(Shifter::LogicalShiftLeftImmediate { source: Register::R0, shift: 0x1F }, true),
+		(Shifter::LogicalShiftLeftImmediate { source: Register::R0, shift: 0x20 }, false),
+		(Shifter::LogicalShiftRightImmediate { source: Register::R0, shift: 0x20 }, true),
+		(Shifter::RotateRightImmediate { source: Register::R0, shift: 0x20 }, false),
+		(Shifter::LogicalShiftLeftRegister { source: Register::R0, shift: Register::R1 }, true),
+		(Shifter::LogicalShiftLeftRegister { source: Register::R0, shift: Register::Pc }, false),
+	];
+
+	for (shifter, encodable) in tree { assert_eq!(shifter.is_arm_encodable(), encodable, "{shifter:?}") }
+
+	let mut codec = InstructionCodec::new();
+
+	let result = codec.encode_arm(Instruction::Move {
+		predicate:   Predicate::Always,
+		destination: Register::R0,
+		source:      Shifter::Immediate(0x00000101),
+		s:           Sflag::Off,
+	});
+
+	assert!(matches!(result, Err(Error::IllegalImmediate { .. })));
+
+	let result = codec.encode_arm(Instruction::Add {
+		predicate:   Predicate::Always,
+		destination: Register::Pc,
+		base:        Register::R0,
+		source:      Shifter::LogicalShiftLeftRegister { source: Register::R1, shift: Register::R2 },
+		s:           Sflag::Off,
+	});
+
+	assert!(matches!(result, Err(Error::IllegalShifter { .. })));
+}
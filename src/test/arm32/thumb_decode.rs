@@ -19,11 +19,42 @@
 // fero General Public License along with Pollex.
 // If not, see <https://www.gnu.org/licenses/>.
 
+use crate::Error;
 use crate::arm32::{
+	Address,
+	BarrierOption,
+	Bflag,
+	BlockMode,
+	Coprocessor,
+	CoprocessorRegister,
+	DoubleRegister,
+	ElementType,
+	Endianness,
+	Indexing,
 	Instruction,
 	InstructionCodec,
+	InterruptMask,
+	Lflag,
+	ParallelOperation,
+	ParallelPrefix,
 	Predicate,
+	ProcessorMode,
+	QuadRegister,
 	Register,
+	RegisterList,
+	Scalar,
+	Sflag,
+	Shifter,
+	Sign,
+	SingleRegister,
+	StatusFields,
+	StatusRegister,
+	StructureAddress,
+	Tflag,
+	ThumbOpcode,
+	VectorRegister,
+	VfpRegister,
+	Wflag,
 };
 
 use alloc::vec::Vec;
@@ -33,6 +64,12 @@ fn test_thumb_decode() {
 	let binary = [
 		0b11011111_10101010,
 		0b01000111_01110000,
+		0b01101000_01001000,
+		0b01011100_10001000,
+		0b10010111_00000001,
+		0b01001000_00000010,
+		0b10000000_01001000,
+		0b01011110_10001000,
 	];
 
 	let mut codec = InstructionCodec::new();
@@ -52,6 +89,1533 @@ fn test_thumb_decode() {
 				predicate: Predicate::Always,
 				source:    Register::Lr,
 			},
+
+			Instruction::Load {
+				predicate: Predicate::Always,
+				register:  Register::R0,
+				address:   Address::ImmediateOffset { base: Register::R1, source: 0x4, indexing: Indexing::Offset },
+				b:         Bflag::Off,
+				t:         Tflag::Off,
+			},
+
+			Instruction::Load {
+				predicate: Predicate::Always,
+				register:  Register::R0,
+				address:   Address::RegisterOffset { base: Register::R1, source: Register::R2, sign: Sign::Positive, indexing: Indexing::Offset },
+				b:         Bflag::On,
+				t:         Tflag::Off,
+			},
+
+			Instruction::Store {
+				predicate: Predicate::Always,
+				register:  Register::R7,
+				address:   Address::ImmediateOffset { base: Register::Sp, source: 0x4, indexing: Indexing::Offset },
+				b:         Bflag::Off,
+				t:         Tflag::Off,
+			},
+
+			Instruction::Load {
+				predicate: Predicate::Always,
+				register:  Register::R0,
+				address:   Address::ImmediateOffset { base: Register::Pc, source: 0x8, indexing: Indexing::Offset },
+				b:         Bflag::Off,
+				t:         Tflag::Off,
+			},
+
+			Instruction::StoreHalfword {
+				predicate: Predicate::Always,
+				register:  Register::R0,
+				address:   Address::ImmediateOffset { base: Register::R1, source: 0x2, indexing: Indexing::Offset },
+			},
+
+			Instruction::LoadSignedHalfword {
+				predicate: Predicate::Always,
+				register:  Register::R0,
+				address:   Address::RegisterOffset { base: Register::R1, source: Register::R2, sign: Sign::Positive, indexing: Indexing::Offset },
+			},
+		],
+	);
+}
+
+#[test]
+fn test_thumb_decode_complete() {
+	let binary = [
+		0b00000000_00001000,
+		0b00000111_11001000,
+		0b00001000_00011010,
+		0b00010000_01101100,
+		0b00011000_10001000,
+		0b00011111_11001000,
+		0b00100111_11111111,
+		0b00101011_00000100,
+		0b00110001_00000001,
+		0b00111010_11001000,
+		0b01000000_00001000,
+		0b01000000_01001000,
+		0b01000000_10001000,
+		0b01000000_11011010,
+		0b01000001_00101100,
+		0b01000001_01001000,
+		0b01000001_10001000,
+		0b01000001_11001000,
+		0b01000010_00001000,
+		0b01000010_01001000,
+		0b01000010_10001000,
+		0b01000010_11001000,
+		0b01000011_00001000,
+		0b01000011_01001000,
+		0b01000011_10001000,
+		0b01000011_11001000,
+		0b01000100_11110000,
+		0b01000101_01100000,
+		0b01000110_10000000,
+		0b01000111_01110000,
+		0b01000111_10011000,
+		0b01001000_11111111,
+		0b01010010_10001000,
+		0b01010110_10001000,
+		0b01010000_10001000,
+		0b01011100_10001000,
+		0b01110111_11001000,
+		0b01101111_11110111,
+		0b10001111_11001000,
+		0b10010000_11111111,
+		0b10100000_00000100,
+		0b10101001_11111111,
+		0b10110000_01111111,
+		0b10110000_10000001,
+		0b10110010_00001000,
+		0b10110010_01001000,
+		0b10110010_10001000,
+		0b10110010_11001000,
+		0b10110101_11110000,
+		0b10111101_00000001,
+		0b10110110_01011000,
+		0b10110110_01110011,
+		0b10110110_01100100,
+		0b10111010_00001000,
+		0b10111010_01001000,
+		0b10111010_11001000,
+		0b10111110_11111111,
+		0b10111111_00000000,
+		0b10111111_00010000,
+		0b10111111_00100000,
+		0b10111111_00110000,
+		0b10111111_01000000,
+		0b11000000_00000011,
+		0b11001001_00000110,
+		0b11001001_00000100,
+		0b11011111_10101011,
+		0b11010000_11111110,
+		0b11010001_01111111,
+		0b11100111_11111110,
+		0b11100011_11111111,
+		0b11100100_00000000,
+		0b10110011_11111010,
+		0b10111001_00000111,
+	];
+
+	let mut codec = InstructionCodec::new_at(0x08000000);
+
+	let mut programme = Vec::new();
+	for opcode in binary { programme.push(codec.decode_thumb(opcode.into()).unwrap()) }
+
+	assert_eq!(
+		programme,
+		[
+			Instruction::Move {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				source:      Shifter::from_register(Register::R1),
+				s:           Sflag::On,
+			},
+
+			Instruction::Move {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				source:      Shifter::LogicalShiftLeftImmediate { source: Register::R1, shift: 0x1F },
+				s:           Sflag::On,
+			},
+
+			Instruction::Move {
+				predicate:   Predicate::Always,
+				destination: Register::R2,
+				source:      Shifter::LogicalShiftRightImmediate { source: Register::R3, shift: 0x20 },
+				s:           Sflag::On,
+			},
+
+			Instruction::Move {
+				predicate:   Predicate::Always,
+				destination: Register::R4,
+				source:      Shifter::ArithmeticShiftRightImmediate { source: Register::R5, shift: 0x1 },
+				s:           Sflag::On,
+			},
+
+			Instruction::Add {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				base:        Register::R1,
+				source:      Shifter::from_register(Register::R2),
+				s:           Sflag::On,
+			},
+
+			Instruction::Subtract {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				base:        Register::R1,
+				source:      Shifter::Immediate(0x7),
+				s:           Sflag::On,
+			},
+
+			Instruction::Move {
+				predicate:   Predicate::Always,
+				destination: Register::R7,
+				source:      Shifter::Immediate(0xFF),
+				s:           Sflag::On,
+			},
+
+			Instruction::Compare {
+				predicate: Predicate::Always,
+				lhs:       Register::R3,
+				rhs:       Shifter::Immediate(0x4),
+			},
+
+			Instruction::Add {
+				predicate:   Predicate::Always,
+				destination: Register::R1,
+				base:        Register::R1,
+				source:      Shifter::Immediate(0x1),
+				s:           Sflag::On,
+			},
+
+			Instruction::Subtract {
+				predicate:   Predicate::Always,
+				destination: Register::R2,
+				base:        Register::R2,
+				source:      Shifter::Immediate(0xC8),
+				s:           Sflag::On,
+			},
+
+			Instruction::And {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				base:        Register::R0,
+				source:      Shifter::from_register(Register::R1),
+				s:           Sflag::On,
+			},
+
+			Instruction::ExclusiveOr {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				base:        Register::R0,
+				source:      Shifter::from_register(Register::R1),
+				s:           Sflag::On,
+			},
+
+			Instruction::Move {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				source:      Shifter::LogicalShiftLeftRegister { source: Register::R0, shift: Register::R1 },
+				s:           Sflag::On,
+			},
+
+			Instruction::Move {
+				predicate:   Predicate::Always,
+				destination: Register::R2,
+				source:      Shifter::LogicalShiftRightRegister { source: Register::R2, shift: Register::R3 },
+				s:           Sflag::On,
+			},
+
+			Instruction::Move {
+				predicate:   Predicate::Always,
+				destination: Register::R4,
+				source:      Shifter::ArithmeticShiftRightRegister { source: Register::R4, shift: Register::R5 },
+				s:           Sflag::On,
+			},
+
+			Instruction::AddCarry {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				base:        Register::R0,
+				source:      Shifter::from_register(Register::R1),
+				s:           Sflag::On,
+			},
+
+			Instruction::SubtractCarry {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				base:        Register::R0,
+				source:      Shifter::from_register(Register::R1),
+				s:           Sflag::On,
+			},
+
+			Instruction::Move {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				source:      Shifter::RotateRightRegister { source: Register::R0, shift: Register::R1 },
+				s:           Sflag::On,
+			},
+
+			Instruction::Test {
+				predicate: Predicate::Always,
+				lhs:       Register::R0,
+				rhs:       Shifter::from_register(Register::R1),
+			},
+
+			Instruction::ReverseSubtract {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				base:        Register::R1,
+				source:      Shifter::Immediate(0x0),
+				s:           Sflag::On,
+			},
+
+			Instruction::Compare {
+				predicate: Predicate::Always,
+				lhs:       Register::R0,
+				rhs:       Shifter::from_register(Register::R1),
+			},
+
+			Instruction::CompareNegated {
+				predicate: Predicate::Always,
+				lhs:       Register::R0,
+				rhs:       Shifter::from_register(Register::R1),
+			},
+
+			Instruction::InclusiveOr {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				base:        Register::R0,
+				source:      Shifter::from_register(Register::R1),
+				s:           Sflag::On,
+			},
+
+			Instruction::Multiply {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				base:        Register::R0,
+				source:      Register::R1,
+				s:           Sflag::On,
+			},
+
+			Instruction::BitClear {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				base:        Register::R0,
+				source:      Shifter::from_register(Register::R1),
+				s:           Sflag::On,
+			},
+
+			Instruction::MoveNot {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				source:      Shifter::from_register(Register::R1),
+				s:           Sflag::On,
+			},
+
+			Instruction::Add {
+				predicate:   Predicate::Always,
+				destination: Register::R8,
+				base:        Register::R8,
+				source:      Shifter::from_register(Register::Lr),
+				s:           Sflag::Off,
+			},
+
+			Instruction::Compare {
+				predicate: Predicate::Always,
+				lhs:       Register::R0,
+				rhs:       Shifter::from_register(Register::R12),
+			},
+
+			Instruction::Move {
+				predicate:   Predicate::Always,
+				destination: Register::R8,
+				source:      Shifter::from_register(Register::R0),
+				s:           Sflag::Off,
+			},
+
+			Instruction::BranchExchange {
+				predicate: Predicate::Always,
+				source:    Register::Lr,
+			},
+
+			Instruction::BranchLinkExchange {
+				predicate: Predicate::Always,
+				source:    Shifter::from_register(Register::R3),
+			},
+
+			Instruction::Load {
+				predicate: Predicate::Always,
+				register:  Register::R0,
+				address:   Address::ImmediateOffset { base: Register::Pc, source: 0x3FC, indexing: Indexing::Offset },
+				b:         Bflag::Off,
+				t:         Tflag::Off,
+			},
+
+			Instruction::StoreHalfword {
+				predicate: Predicate::Always,
+				register:  Register::R0,
+				address:   Address::RegisterOffset { base: Register::R1, source: Register::R2, sign: Sign::Positive, indexing: Indexing::Offset },
+			},
+
+			Instruction::LoadSignedByte {
+				predicate: Predicate::Always,
+				register:  Register::R0,
+				address:   Address::RegisterOffset { base: Register::R1, source: Register::R2, sign: Sign::Positive, indexing: Indexing::Offset },
+			},
+
+			Instruction::Store {
+				predicate: Predicate::Always,
+				register:  Register::R0,
+				address:   Address::RegisterOffset { base: Register::R1, source: Register::R2, sign: Sign::Positive, indexing: Indexing::Offset },
+				b:         Bflag::Off,
+				t:         Tflag::Off,
+			},
+
+			Instruction::Load {
+				predicate: Predicate::Always,
+				register:  Register::R0,
+				address:   Address::RegisterOffset { base: Register::R1, source: Register::R2, sign: Sign::Positive, indexing: Indexing::Offset },
+				b:         Bflag::On,
+				t:         Tflag::Off,
+			},
+
+			Instruction::Store {
+				predicate: Predicate::Always,
+				register:  Register::R0,
+				address:   Address::ImmediateOffset { base: Register::R1, source: 0x1F, indexing: Indexing::Offset },
+				b:         Bflag::On,
+				t:         Tflag::Off,
+			},
+
+			Instruction::Load {
+				predicate: Predicate::Always,
+				register:  Register::R7,
+				address:   Address::ImmediateOffset { base: Register::R6, source: 0x7C, indexing: Indexing::Offset },
+				b:         Bflag::Off,
+				t:         Tflag::Off,
+			},
+
+			Instruction::LoadHalfword {
+				predicate: Predicate::Always,
+				register:  Register::R0,
+				address:   Address::ImmediateOffset { base: Register::R1, source: 0x3E, indexing: Indexing::Offset },
+			},
+
+			Instruction::Store {
+				predicate: Predicate::Always,
+				register:  Register::R0,
+				address:   Address::ImmediateOffset { base: Register::Sp, source: 0x3FC, indexing: Indexing::Offset },
+				b:         Bflag::Off,
+				t:         Tflag::Off,
+			},
+
+			Instruction::Add {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				base:        Register::Pc,
+				source:      Shifter::Immediate(0x10),
+				s:           Sflag::Off,
+			},
+
+			Instruction::Add {
+				predicate:   Predicate::Always,
+				destination: Register::R1,
+				base:        Register::Sp,
+				source:      Shifter::Immediate(0x3FC),
+				s:           Sflag::Off,
+			},
+
+			Instruction::Add {
+				predicate:   Predicate::Always,
+				destination: Register::Sp,
+				base:        Register::Sp,
+				source:      Shifter::Immediate(0x1FC),
+				s:           Sflag::Off,
+			},
+
+			Instruction::Subtract {
+				predicate:   Predicate::Always,
+				destination: Register::Sp,
+				base:        Register::Sp,
+				source:      Shifter::Immediate(0x4),
+				s:           Sflag::Off,
+			},
+
+			Instruction::SignExtendHalfword {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				source:      Register::R1,
+				rotation:    0x0,
+			},
+
+			Instruction::SignExtendByte {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				source:      Register::R1,
+				rotation:    0x0,
+			},
+
+			Instruction::ZeroExtendHalfword {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				source:      Register::R1,
+				rotation:    0x0,
+			},
+
+			Instruction::ZeroExtendByte {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				source:      Register::R1,
+				rotation:    0x0,
+			},
+
+			Instruction::StoreMultiple {
+				predicate: Predicate::Always,
+				base:      Register::Sp,
+				registers: RegisterList::from_u16(0b01000000_11110000),
+				mode:      BlockMode::DecrementBefore,
+				w:         Wflag::On,
+				s:         Sflag::Off,
+			},
+
+			Instruction::LoadMultiple {
+				predicate: Predicate::Always,
+				base:      Register::Sp,
+				registers: RegisterList::from_u16(0b10000000_00000001),
+				mode:      BlockMode::IncrementAfter,
+				w:         Wflag::On,
+				s:         Sflag::Off,
+			},
+
+			Instruction::SetEndianness {
+				endianness: Endianness::Big,
+			},
+
+			Instruction::DisableInterrupts {
+				mask: InterruptMask::IRQ | InterruptMask::FIQ,
+				mode: None,
+			},
+
+			Instruction::EnableInterrupts {
+				mask: InterruptMask::ABORT,
+				mode: None,
+			},
+
+			Instruction::Reverse {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				source:      Register::R1,
+			},
+
+			Instruction::ReversePackedHalfword {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				source:      Register::R1,
+			},
+
+			Instruction::ReverseSignedHalfword {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				source:      Register::R1,
+			},
+
+			Instruction::Breakpoint {
+				immediate: 0xFF,
+			},
+
+			Instruction::NoOperation {
+				predicate: Predicate::Always,
+			},
+
+			Instruction::Yield {
+				predicate: Predicate::Always,
+			},
+
+			Instruction::WaitForEvent {
+				predicate: Predicate::Always,
+			},
+
+			Instruction::WaitForInterrupt {
+				predicate: Predicate::Always,
+			},
+
+			Instruction::SendEvent {
+				predicate: Predicate::Always,
+			},
+
+			Instruction::StoreMultiple {
+				predicate: Predicate::Always,
+				base:      Register::R0,
+				registers: RegisterList::from_u16(0b00000000_00000011),
+				mode:      BlockMode::IncrementAfter,
+				w:         Wflag::On,
+				s:         Sflag::Off,
+			},
+
+			Instruction::LoadMultiple {
+				predicate: Predicate::Always,
+				base:      Register::R1,
+				registers: RegisterList::from_u16(0b00000000_00000110),
+				mode:      BlockMode::IncrementAfter,
+				w:         Wflag::Off,
+				s:         Sflag::Off,
+			},
+
+			Instruction::LoadMultiple {
+				predicate: Predicate::Always,
+				base:      Register::R1,
+				registers: RegisterList::from_u16(0b00000000_00000100),
+				mode:      BlockMode::IncrementAfter,
+				w:         Wflag::On,
+				s:         Sflag::Off,
+			},
+
+			Instruction::SoftwareInterrupt {
+				predicate: Predicate::Always,
+				immediate: 0xAB,
+			},
+
+			Instruction::Branch {
+				predicate: Predicate::Equal,
+				immediate: 0x08000084,
+			},
+
+			Instruction::Branch {
+				predicate: Predicate::NotEqual,
+				immediate: 0x08000188,
+			},
+
+			Instruction::Branch {
+				predicate: Predicate::Always,
+				immediate: 0x08000088,
+			},
+
+			Instruction::Branch {
+				predicate: Predicate::Always,
+				immediate: 0x0800088C,
+			},
+
+			Instruction::Branch {
+				predicate: Predicate::Always,
+				immediate: 0x07FFF890,
+			},
+
+			Instruction::CompareBranchZero {
+				source:    Register::R2,
+				immediate: 0x08000110,
+			},
+
+			Instruction::CompareBranchNonZero {
+				source:    Register::R7,
+				immediate: 0x08000094,
+			},
+		],
+	);
+
+	for opcode in [
+		0b11011110_00000000_u16,
+		0b10111111_01010000,
+		0b10111111_11101100,
+		0b10111010_10000000,
+		0b11110000_00000000,
+		0b11101000_00000000,
+		0b10111100_00000000,
+		0b10110110_01100000,
+		0b01000111_00000001,
+		0b11000001_00000011,
+		0b01000101_00000001,
+	] {
+		assert!(matches!(codec.decode_thumb(opcode.into()), Err(Error::InvalidOpcode)));
+	}
+}
+
+#[test]
+fn test_thumb_decode_round_trip() {
+	let mut decoder = InstructionCodec::new_at(0x08000000);
+	let mut encoder = InstructionCodec::new_at(0x08000000);
+
+	for opcode in [
+		0b11000001_00001100_u16,
+		0b11001001_00000101,
+		0b11001001_00000110,
+		0b01000101_10000000,
+		0b01000110_00001000,
+		0b01000100_00001000,
+		0b10110101_00000001,
+		0b10111101_00000001,
+	] {
+		let instruction = decoder.decode_thumb(opcode.into()).unwrap();
+		assert_eq!(encoder.encode_thumb(instruction).unwrap(), (opcode.into(), None));
+	}
+}
+
+#[test]
+fn test_thumb_decode_wide() {
+	let binary = [
+		(0b11110100_00000000, 0b10010000_00000000),
+		(0b11110000_01111111, 0b10101111_11111111),
+		(0b11110000_00000000, 0b11111000_00000000),
+		(0b11110111_11111111, 0b11111111_11111110),
+		(0b11110000_00000001, 0b11101000_00000000),
+		(0b11110100_00000000, 0b11000000_00000000),
+		(0b11110000_00010000, 0b00101111_11111111),
+		(0b11101010_00001001, 0b00001000_00011010),
+		(0b11101010_01011111, 0b00010000_01100001),
+		(0b11110100_01111111, 0b00111000_00000000),
+		(0b11110100_01000001, 0b01110000_01111111),
+		(0b11110101_00011101, 0b01110000_10000000),
+		(0b11110110_00001111, 0b01110000_11111111),
+		(0b11110010_10101001, 0b01001000_11010010),
+		(0b11110110_01001011, 0b01101000_11101111),
+		(0b11110010_11000001, 0b00100000_00110100),
+		(0b11110011_00100001, 0b01110000_11011111),
+		(0b11110011_10101001, 0b00001000_00001111),
+		(0b11110011_01000001, 0b00000000_11011100),
+		(0b11110011_01101111, 0b01111000_11011111),
+		(0b11110011_01100001, 0b00100000_00010111),
+		(0b11110011_10000001, 0b01110000_11000000),
+		(0b11110011_11101111, 0b10001000_00000000),
+		(0b11110011_11111111, 0b10000000_00000000),
+		(0b11110011_10001001, 0b10001001_00000000),
+		(0b11110011_10101111, 0b10000000_00000000),
+		(0b11110011_10101111, 0b10000000_00000011),
+		(0b11110011_10101111, 0b10000111_11110001),
+		(0b11110011_10101111, 0b10000100_00100000),
+		(0b11110011_10101111, 0b10000001_00010000),
+		(0b11110011_10111111, 0b10001111_01011011),
+		(0b11110011_10111111, 0b10001111_01001111),
+		(0b11110011_10111111, 0b10001111_01101111),
+		(0b11110011_10111111, 0b10001111_00101111),
+		(0b11101000_10010000, 0b00000000_00000110),
+		(0b11101000_10111101, 0b10001111_11110000),
+		(0b11101001_01011111, 0b00000001_11111111),
+		(0b11101000_01011001, 0b10001111_11111111),
+		(0b11101000_01000010, 0b00010000_00000000),
+		(0b11101000_11000010, 0b00011111_01000000),
+		(0b11101000_11010010, 0b00000001_01111111),
+		(0b11101000_11000010, 0b00000001_01111000),
+		(0b11111000_01011111, 0b00001111_11111111),
+		(0b11111000_10011001, 0b10001111_11111111),
+		(0b11111000_00000001, 0b00001101_00000100),
+		(0b11111000_00110001, 0b00001011_11111111),
+		(0b11111000_00010001, 0b00001110_11111111),
+		(0b11111000_01001001, 0b10001110_00000000),
+		(0b11111000_01000001, 0b00000000_00100010),
+		(0b11111001_00010001, 0b00000000_00000010),
+		(0b11111001_00110001, 0b00001100_00000001),
+		(0b11111011_00000001, 0b11110000_00000010),
+		(0b11111011_00001001, 0b10111000_00001010),
+		(0b11111011_00000001, 0b00110000_00010010),
+		(0b11111011_11001010, 0b10001001_00001011),
+		(0b11111011_11100010, 0b00000001_01100011),
+		(0b11111011_10010001, 0b11110000_11110010),
+		(0b11111011_10111001, 0b11111000_11111010),
+		(0b11111011_01110001, 0b11110000_00000010),
+		(0b11111011_01110001, 0b00110000_00000010),
+		(0b11111010_01110001, 0b11110000_00000010),
+		(0b11111010_01001001, 0b11111000_10101010),
+		(0b11111010_00011111, 0b11110000_10000001),
+		(0b11111010_00110001, 0b11110000_10000010),
+		(0b11111010_00101111, 0b11110000_10010001),
+		(0b11111010_10000010, 0b11110000_10000001),
+		(0b11111010_10001010, 0b11111000_10111001),
+		(0b11111010_10010001, 0b11110000_10000001),
+		(0b11111010_10011001, 0b11111000_10101001),
+		(0b11111010_10100001, 0b11110000_10000010),
+		(0b11111010_10111001, 0b11111000_10001001),
+		(0b11111010_10010001, 0b11110000_00000010),
+		(0b11111010_11001001, 0b11111000_01011010),
+		(0b11111010_10100001, 0b11110000_00100010),
+		(0b11111010_11100001, 0b11110000_01100010),
+		(0b11101010_11000001, 0b00000000_00100010),
+		(0b11101010_11000001, 0b00010000_00000010),
+		(0b11101110_00110000, 0b00001010_10000001),
+		(0b11101111_00100010, 0b00001000_01000100),
+		(0b11111001_00100000, 0b00001010_10001101),
+		(0b11101110_00000111, 0b00001111_10010101),
+		(0b11101110_00110001, 0b00001011_00010000),
+		(0b11101101_10010000, 0b01011110_00000010),
+		(0b11101000_11010000, 0b11110000_00000001),
+		(0b11101000_11011111, 0b11110000_00011010),
+		(0b11111000_10010000, 0b11110000_00000000),
+		(0b11111000_00010001, 0b11111100_00001000),
+		(0b11111000_00011111, 0b11110000_00000100),
+		(0b11111000_00010000, 0b11110000_00100001),
+		(0b11101010_01100001, 0b00000000_00000010),
+		(0b11110000_01100100, 0b00000011_11111111),
+	];
+
+	let mut codec = InstructionCodec::new_at(0x08000000);
+
+	let mut programme = Vec::new();
+	for (opcode0, opcode1) in binary { programme.push(codec.decode_thumb_wide((opcode0.into(), opcode1.into())).unwrap()) }
+
+	assert_eq!(
+		programme,
+		[
+			Instruction::Branch {
+				predicate: Predicate::Always,
+				immediate: 0x07000004,
+			},
+
+			Instruction::Branch {
+				predicate: Predicate::NotEqual,
+				immediate: 0x08100006,
+			},
+
+			Instruction::BranchLink {
+				predicate: Predicate::Always,
+				source:    0x0800000C,
+			},
+
+			Instruction::BranchLink {
+				predicate: Predicate::Always,
+				source:    0x0800000C,
+			},
+
+			Instruction::BranchLinkExchange {
+				predicate: Predicate::Always,
+				source:    Shifter::Immediate(0x08001014),
+			},
+
+			Instruction::BranchLinkExchange {
+				predicate: Predicate::Always,
+				source:    Shifter::Immediate(0x07000018),
+			},
+
+			Instruction::Test {
+				predicate: Predicate::Always,
+				lhs:       Register::R0,
+				rhs:       Shifter::Immediate(0xFF00FF00),
+			},
+
+			Instruction::And {
+				predicate:   Predicate::Always,
+				destination: Register::R8,
+				base:        Register::R9,
+				source:      Shifter::LogicalShiftRightImmediate { source: Register::R10, shift: 0x20 },
+				s:           Sflag::Off,
+			},
+
+			Instruction::Move {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				source:      Shifter::ArithmeticShiftRightImmediate { source: Register::R1, shift: 0x5 },
+				s:           Sflag::On,
+			},
+
+			Instruction::MoveNot {
+				predicate:   Predicate::Always,
+				destination: Register::R8,
+				source:      Shifter::Immediate(0x20000),
+				s:           Sflag::On,
+			},
+
+			Instruction::InclusiveOr {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				base:        Register::R1,
+				source:      Shifter::Immediate(0x3FC),
+				s:           Sflag::Off,
+			},
+
+			Instruction::Add {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				base:        Register::Sp,
+				source:      Shifter::Immediate(0x100),
+				s:           Sflag::On,
+			},
+
+			Instruction::Add {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				base:        Register::Pc,
+				source:      Shifter::Immediate(0xFFF),
+				s:           Sflag::Off,
+			},
+
+			Instruction::Subtract {
+				predicate:   Predicate::Always,
+				destination: Register::R8,
+				base:        Register::R9,
+				source:      Shifter::Immediate(0x4D2),
+				s:           Sflag::Off,
+			},
+
+			Instruction::MoveWide {
+				predicate:   Predicate::Always,
+				destination: Register::R8,
+				immediate:   0xBEEF,
+			},
+
+			Instruction::MoveTop {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				immediate:   0x1234,
+			},
+
+			Instruction::SignedSaturate {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				immediate:   0x20,
+				source:      Shifter::ArithmeticShiftRightImmediate { source: Register::R1, shift: 0x1F },
+			},
+
+			Instruction::UnsignedSaturate16 {
+				predicate:   Predicate::Always,
+				destination: Register::R8,
+				immediate:   0xF,
+				source:      Register::R9,
+			},
+
+			Instruction::SignedBitFieldExtract {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				source:      Register::R1,
+				lsb:         0x3,
+				width:       0x1D,
+			},
+
+			Instruction::BitFieldClear {
+				predicate:   Predicate::Always,
+				destination: Register::R8,
+				lsb:         0x1F,
+				width:       0x1,
+			},
+
+			Instruction::BitFieldInsert {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				source:      Register::R1,
+				lsb:         0x8,
+				width:       0x10,
+			},
+
+			Instruction::UnsignedSaturate {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				immediate:   0x0,
+				source:      Shifter::LogicalShiftLeftImmediate { source: Register::R1, shift: 0x1F },
+			},
+
+			Instruction::MoveFromStatusRegister {
+				predicate:   Predicate::Always,
+				destination: Register::R8,
+				source:      StatusRegister::Cpsr,
+			},
+
+			Instruction::MoveFromStatusRegister {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				source:      StatusRegister::Spsr,
+			},
+
+			Instruction::MoveToStatusRegister {
+				predicate:   Predicate::Always,
+				destination: StatusRegister::Cpsr,
+				fields:      StatusFields::from_u8(0b1001),
+				source:      Shifter::from_register(Register::R9),
+			},
+
+			Instruction::NoOperation {
+				predicate: Predicate::Always,
+			},
+
+			Instruction::WaitForInterrupt {
+				predicate: Predicate::Always,
+			},
+
+			Instruction::DisableInterrupts {
+				mask: InterruptMask::ALL,
+				mode: Some(ProcessorMode::Fiq),
+			},
+
+			Instruction::EnableInterrupts {
+				mask: InterruptMask::FIQ,
+				mode: None,
+			},
+
+			Instruction::ChangeProcessorMode {
+				mode: ProcessorMode::User,
+			},
+
+			Instruction::DataMemoryBarrier {
+				option: BarrierOption::Ish,
+			},
+
+			Instruction::DataSynchronisationBarrier {
+				option: BarrierOption::Sy,
+			},
+
+			Instruction::InstructionSynchronisationBarrier {
+				option: BarrierOption::Sy,
+			},
+
+			Instruction::ClearExclusive,
+
+			Instruction::LoadMultiple {
+				predicate: Predicate::Always,
+				base:      Register::R0,
+				registers: RegisterList::from_u16(0b00000000_00000110),
+				mode:      BlockMode::IncrementAfter,
+				w:         Wflag::Off,
+				s:         Sflag::Off,
+			},
+
+			Instruction::LoadMultiple {
+				predicate: Predicate::Always,
+				base:      Register::Sp,
+				registers: RegisterList::from_u16(0b10001111_11110000),
+				mode:      BlockMode::IncrementAfter,
+				w:         Wflag::On,
+				s:         Sflag::Off,
+			},
+
+			Instruction::LoadDoubleword {
+				predicate: Predicate::Always,
+				first:     Register::R0,
+				second:    Register::R1,
+				address:   Address::ImmediateOffset { base: Register::Pc, source: -0x3FC, indexing: Indexing::Offset },
+			},
+
+			Instruction::LoadExclusive {
+				predicate: Predicate::Always,
+				register:  Register::R8,
+				address:   Address::ImmediateOffset { base: Register::R9, source: 0x3FC, indexing: Indexing::Offset },
+			},
+
+			Instruction::StoreExclusive {
+				predicate: Predicate::Always,
+				status:    Register::R0,
+				register:  Register::R1,
+				address:   Address::from_register(Register::R2),
+			},
+
+			Instruction::StoreExclusiveByte {
+				predicate: Predicate::Always,
+				status:    Register::R0,
+				register:  Register::R1,
+				address:   Address::from_register(Register::R2),
+			},
+
+			Instruction::LoadExclusiveDoubleword {
+				predicate: Predicate::Always,
+				first:     Register::R0,
+				second:    Register::R1,
+				address:   Address::from_register(Register::R2),
+			},
+
+			Instruction::StoreExclusiveDoubleword {
+				predicate: Predicate::Always,
+				status:    Register::R8,
+				first:     Register::R0,
+				second:    Register::R1,
+				address:   Address::from_register(Register::R2),
+			},
+
+			Instruction::Load {
+				predicate: Predicate::Always,
+				register:  Register::R0,
+				address:   Address::ImmediateOffset { base: Register::Pc, source: -0xFFF, indexing: Indexing::Offset },
+				b:         Bflag::Off,
+				t:         Tflag::Off,
+			},
+
+			Instruction::Load {
+				predicate: Predicate::Always,
+				register:  Register::R8,
+				address:   Address::ImmediateOffset { base: Register::R9, source: 0xFFF, indexing: Indexing::Offset },
+				b:         Bflag::On,
+				t:         Tflag::Off,
+			},
+
+			Instruction::Store {
+				predicate: Predicate::Always,
+				register:  Register::R0,
+				address:   Address::ImmediateOffset { base: Register::R1, source: -0x4, indexing: Indexing::PreIndexed },
+				b:         Bflag::On,
+				t:         Tflag::Off,
+			},
+
+			Instruction::LoadHalfword {
+				predicate: Predicate::Always,
+				register:  Register::R0,
+				address:   Address::ImmediateOffset { base: Register::R1, source: 0xFF, indexing: Indexing::PostIndexed },
+			},
+
+			Instruction::Load {
+				predicate: Predicate::Always,
+				register:  Register::R0,
+				address:   Address::ImmediateOffset { base: Register::R1, source: 0xFF, indexing: Indexing::Offset },
+				b:         Bflag::On,
+				t:         Tflag::On,
+			},
+
+			Instruction::Store {
+				predicate: Predicate::Always,
+				register:  Register::R8,
+				address:   Address::ImmediateOffset { base: Register::R9, source: 0x0, indexing: Indexing::Offset },
+				b:         Bflag::Off,
+				t:         Tflag::On,
+			},
+
+			Instruction::Store {
+				predicate: Predicate::Always,
+				register:  Register::R0,
+				address:   Address::ScaledRegisterOffset { base: Register::R1, source: Shifter::LogicalShiftLeftImmediate { source: Register::R2, shift: 0x2 }, sign: Sign::Positive, indexing: Indexing::Offset },
+				b:         Bflag::Off,
+				t:         Tflag::Off,
+			},
+
+			Instruction::LoadSignedByte {
+				predicate: Predicate::Always,
+				register:  Register::R0,
+				address:   Address::RegisterOffset { base: Register::R1, source: Register::R2, sign: Sign::Positive, indexing: Indexing::Offset },
+			},
+
+			Instruction::LoadSignedHalfword {
+				predicate: Predicate::Always,
+				register:  Register::R0,
+				address:   Address::ImmediateOffset { base: Register::R1, source: -0x1, indexing: Indexing::Offset },
+			},
+
+			Instruction::Multiply {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				base:        Register::R1,
+				source:      Register::R2,
+				s:           Sflag::Off,
+			},
+
+			Instruction::MultiplyAccumulate {
+				predicate:   Predicate::Always,
+				destination: Register::R8,
+				base:        Register::R9,
+				source:      Register::R10,
+				accumulator: Register::R11,
+				s:           Sflag::Off,
+			},
+
+			Instruction::MultiplySubtract {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				base:        Register::R1,
+				source:      Register::R2,
+				accumulator: Register::R3,
+			},
+
+			Instruction::SignedMultiplyAccumulateLong {
+				predicate:        Predicate::Always,
+				destination_low:  Register::R8,
+				destination_high: Register::R9,
+				base:             Register::R10,
+				source:           Register::R11,
+				s:                Sflag::Off,
+			},
+
+			Instruction::UnsignedMultiplyAccumulateAccumulateLong {
+				predicate:        Predicate::Always,
+				destination_low:  Register::R0,
+				destination_high: Register::R1,
+				base:             Register::R2,
+				source:           Register::R3,
+			},
+
+			Instruction::SignedDivide {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				base:        Register::R1,
+				source:      Register::R2,
+			},
+
+			Instruction::UnsignedDivide {
+				predicate:   Predicate::Always,
+				destination: Register::R8,
+				base:        Register::R9,
+				source:      Register::R10,
+			},
+
+			Instruction::UnsignedSumAbsoluteDifferences {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				base:        Register::R1,
+				source:      Register::R2,
+			},
+
+			Instruction::UnsignedSumAbsoluteDifferencesAccumulate {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				base:        Register::R1,
+				source:      Register::R2,
+				accumulator: Register::R3,
+			},
+
+			Instruction::Move {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				source:      Shifter::RotateRightRegister { source: Register::R1, shift: Register::R2 },
+				s:           Sflag::On,
+			},
+
+			Instruction::SignExtendAddByte {
+				predicate:   Predicate::Always,
+				destination: Register::R8,
+				base:        Register::R9,
+				source:      Register::R10,
+				rotation:    0x10,
+			},
+
+			Instruction::ZeroExtendHalfword {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				source:      Register::R1,
+				rotation:    0x0,
+			},
+
+			Instruction::ZeroExtendAddByte16 {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				base:        Register::R1,
+				source:      Register::R2,
+				rotation:    0x0,
+			},
+
+			Instruction::SignExtendByte16 {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				source:      Register::R1,
+				rotation:    0x8,
+			},
+
+			Instruction::SaturatingAdd {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				base:        Register::R1,
+				source:      Register::R2,
+			},
+
+			Instruction::SaturatingDoubleSubtract {
+				predicate:   Predicate::Always,
+				destination: Register::R8,
+				base:        Register::R9,
+				source:      Register::R10,
+			},
+
+			Instruction::Reverse {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				source:      Register::R1,
+			},
+
+			Instruction::ReverseBits {
+				predicate:   Predicate::Always,
+				destination: Register::R8,
+				source:      Register::R9,
+			},
+
+			Instruction::Select {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				base:        Register::R1,
+				source:      Register::R2,
+			},
+
+			Instruction::CountLeadingZeroes {
+				predicate:   Predicate::Always,
+				destination: Register::R8,
+				source:      Register::R9,
+			},
+
+			Instruction::ParallelArithmetic {
+				predicate:   Predicate::Always,
+				prefix:      ParallelPrefix::Signed,
+				operation:   ParallelOperation::Add16,
+				destination: Register::R0,
+				base:        Register::R1,
+				source:      Register::R2,
+			},
+
+			Instruction::ParallelArithmetic {
+				predicate:   Predicate::Always,
+				prefix:      ParallelPrefix::UnsignedSaturating,
+				operation:   ParallelOperation::Subtract8,
+				destination: Register::R8,
+				base:        Register::R9,
+				source:      Register::R10,
+			},
+
+			Instruction::ParallelArithmetic {
+				predicate:   Predicate::Always,
+				prefix:      ParallelPrefix::SignedHalving,
+				operation:   ParallelOperation::AddSubtractExchange,
+				destination: Register::R0,
+				base:        Register::R1,
+				source:      Register::R2,
+			},
+
+			Instruction::ParallelArithmetic {
+				predicate:   Predicate::Always,
+				prefix:      ParallelPrefix::UnsignedHalving,
+				operation:   ParallelOperation::SubtractAddExchange,
+				destination: Register::R0,
+				base:        Register::R1,
+				source:      Register::R2,
+			},
+
+			Instruction::PackHalfword {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				base:        Register::R1,
+				source:      Shifter::ArithmeticShiftRightImmediate { source: Register::R2, shift: 0x20 },
+			},
+
+			Instruction::PackHalfword {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				base:        Register::R1,
+				source:      Shifter::LogicalShiftLeftImmediate { source: Register::R2, shift: 0x4 },
+			},
+
+			Instruction::FloatAdd {
+				predicate:   Predicate::Always,
+				destination: VfpRegister::Single(SingleRegister::S0),
+				base:        VfpRegister::Single(SingleRegister::S1),
+				source:      VfpRegister::Single(SingleRegister::S2),
+			},
+
+			Instruction::VectorAdd {
+				element:     ElementType::I32,
+				destination: VectorRegister::Quad(QuadRegister::Q0),
+				base:        VectorRegister::Quad(QuadRegister::Q1),
+				source:      VectorRegister::Quad(QuadRegister::Q2),
+			},
+
+			Instruction::VectorLoadStructure {
+				interleave: 0x1,
+				element:    ElementType::Untyped32,
+				first:      DoubleRegister::D0,
+				count:      0x2,
+				spacing:    0x1,
+				address:    StructureAddress::PostIndexed { base: Register::R0, alignment: 0x0 },
+			},
+
+			Instruction::MoveToCoprocessor {
+				predicate:   Predicate::Always,
+				coprocessor: Coprocessor::P15,
+				opcode1:     0x0,
+				register:    Register::R0,
+				crn:         CoprocessorRegister::C7,
+				crm:         CoprocessorRegister::C5,
+				opcode2:     0x4,
+			},
+
+			Instruction::MoveFromScalar {
+				predicate:   Predicate::Always,
+				element:     ElementType::Untyped32,
+				destination: Register::R0,
+				source:      Scalar::new(DoubleRegister::D1, 0x1),
+			},
+
+			Instruction::LoadCoprocessor {
+				predicate:   Predicate::Always,
+				coprocessor: Coprocessor::P14,
+				crd:         CoprocessorRegister::C5,
+				address:     Address::ImmediateOffset { base: Register::R0, source: 0x8, indexing: Indexing::Offset },
+				l:           Lflag::Off,
+			},
+
+			Instruction::TableBranchByte {
+				predicate: Predicate::Always,
+				base:      Register::R0,
+				index:     Register::R1,
+			},
+
+			Instruction::TableBranchHalfword {
+				predicate: Predicate::Always,
+				base:      Register::Pc,
+				index:     Register::R10,
+			},
+
+			Instruction::PreloadData {
+				address: Address::ImmediateOffset { base: Register::R0, source: 0x0, indexing: Indexing::Offset },
+			},
+
+			Instruction::PreloadData {
+				address: Address::ImmediateOffset { base: Register::R1, source: -0x8, indexing: Indexing::Offset },
+			},
+
+			Instruction::PreloadData {
+				address: Address::ImmediateOffset { base: Register::Pc, source: -0x4, indexing: Indexing::Offset },
+			},
+
+			Instruction::PreloadData {
+				address: Address::ScaledRegisterOffset {
+					base:     Register::R0,
+					source:   Shifter::LogicalShiftLeftImmediate { source: Register::R1, shift: 0x2 },
+					sign:     Sign::Positive,
+					indexing: Indexing::Offset,
+				},
+			},
+
+			Instruction::InclusiveOrNot {
+				predicate:   Predicate::Always,
+				destination: Register::R0,
+				base:        Register::R1,
+				source:      Shifter::from_register(Register::R2),
+				s:           Sflag::Off,
+			},
+
+			Instruction::InclusiveOrNot {
+				predicate:   Predicate::Always,
+				destination: Register::R3,
+				base:        Register::R4,
+				source:      Shifter::Immediate(0xFF),
+				s:           Sflag::Off,
+			},
 		],
-	)
+	);
+
+	for opcode in [
+		(0b11101000_11010000_u16, 0b11110001_00000001_u16),
+		(0b11101010_01100001, 0b00001111_00000010),
+		(0b11111001_10010000, 0b11110000_00000000),
+		(0b11111011_00010001, 0b11110000_00000010),
+		(0b11101000_00001101, 0b11000000_00010000),
+		(0b11111000_00110001, 0b00001110_00000000),
+		(0b11110011_11100000, 0b10000000_00100000),
+		(0b11110011_11100011, 0b10001110_00000000),
+		(0b01000111_01110000, 0b00000000_00000000),
+		(0b11111000_01010111, 0b01111011_01101000),
+		(0b11101011_00001111, 0b00000000_00000000),
+		(0b11101000_10111101, 0b11000000_00000001),
+		(0b11110111_00100001, 0b01110101_11100110),
+		(0b11110111_10100110, 0b01101011_00000100),
+		(0b11110011_01101010, 0b00100100_00101001),
+		(0b11111010_10010101, 0b11111100_10001000),
+		(0b11110011_10111000, 0b10101111_01010010),
+		(0b11101000_01011010, 0b01101100_00010010),
+		(0b11101000_11010001, 0b00001111_01000000),
+	] {
+		assert!(matches!(codec.decode_thumb_wide((opcode.0.into(), opcode.1.into())), Err(Error::InvalidOpcode)));
+	}
+
+	assert!(ThumbOpcode::from_u16(0b11101000_00000000).is_wide_prefix());
+	assert!(ThumbOpcode::from_u16(0b11111111_11111111).is_wide_prefix());
+	assert!(!ThumbOpcode::from_u16(0b11100111_11111111).is_wide_prefix());
+}
+
+#[test]
+fn test_thumb_decode_wide_round_trip() {
+	let mut decoder = InstructionCodec::new_at(0x08000000);
+	let mut encoder = InstructionCodec::new_at(0x08000000);
+	encoder.set_thumb2(true);
+
+	for opcode in [
+		(0b11111010_00010110_u16, 0b11110110_00001100_u16),
+		(0b11111000_01010111, 0b00011011_00000100),
+		(0b11101011_00001101, 0b00000000_00000001),
+		(0b11101000_10111101, 0b01000000_00000011),
+	] {
+		let instruction = decoder.decode_thumb_wide((opcode.0.into(), opcode.1.into())).unwrap();
+		assert_eq!(encoder.encode_thumb(instruction).unwrap(), (opcode.0.into(), Some(opcode.1.into())));
+	}
+}
+
+#[test]
+fn test_thumb_if_then_decode() {
+	let binary = [
+		0b10111111_00000110_u16,
+		0b00011000_10001000,
+		0b01000110_11001000,
+		0b11100000_10000000,
+		0b10111111_11001000,
+		0b11110001_10110000,
+		0b00000000_00000001,
+		0b10111111_11100100,
+		0b01101000_00001000,
+		0b01000111_01110000,
+	];
+
+	let mut codec = InstructionCodec::new_at(0x08000000);
+
+	let mut programme = Vec::new();
+	let mut opcodes = binary.into_iter().map(ThumbOpcode::from_u16);
+	while let Some(opcode) = opcodes.next() {
+		let instruction = if opcode.is_wide_prefix() {
+			codec.decode_thumb_wide((opcode, opcodes.next().unwrap()))
+		} else {
+			codec.decode_thumb(opcode)
+		};
+
+		programme.push(instruction.unwrap());
+	}
+
+	assert_eq!(
+		programme,
+		[
+			Instruction::IfThen {
+				predicate: Predicate::Equal,
+				mask:      0b1010,
+			},
+			Instruction::Add {
+				predicate:   Predicate::Equal,
+				destination: Register::R0,
+				base:        Register::R1,
+				source:      Shifter::from_register(Register::R2),
+				s:           Sflag::Off,
+			},
+			Instruction::Move {
+				predicate:   Predicate::Equal,
+				destination: Register::R8,
+				source:      Shifter::from_register(Register::R9),
+				s:           Sflag::Off,
+			},
+			Instruction::Branch {
+				predicate: Predicate::NotEqual,
+				immediate: 0x0800010A,
+			},
+			Instruction::IfThen {
+				predicate: Predicate::GreaterThan,
+				mask:      0b1000,
+			},
+			Instruction::Subtract {
+				predicate:   Predicate::GreaterThan,
+				destination: Register::R0,
+				base:        Register::R0,
+				source:      Shifter::Immediate(0x1),
+				s:           Sflag::On,
+			},
+			Instruction::IfThen {
+				predicate: Predicate::Always,
+				mask:      0b1100,
+			},
+			Instruction::Load {
+				predicate: Predicate::Always,
+				register:  Register::R0,
+				address:   Address::from_register(Register::R1),
+				b:         Bflag::Off,
+				t:         Tflag::Off,
+			},
+			Instruction::BranchExchange {
+				predicate: Predicate::Always,
+				source:    Register::Lr,
+			},
+		],
+	);
+
+	assert_eq!(codec.if_then_predicate(), None);
+
+	codec.decode_thumb(0b10111111_00000100.into()).unwrap();
+
+	for opcode in [
+		0b10111111_00001000_u16,
+		0b11010000_00000000,
+		0b01000111_01110000,
+	] {
+		assert!(matches!(codec.decode_thumb(opcode.into()), Err(Error::InvalidOpcode)));
+	}
+
+	assert_eq!(codec.if_then_predicate(), Some(Predicate::Equal));
 }
@@ -19,13 +19,44 @@
 // fero General Public License along with Pollex.
 // If not, see <https://www.gnu.org/licenses/>.
 
+use crate::Error;
 use crate::arm32::{
+	Address,
+	BarrierOption,
+	Bflag,
+	BlockMode,
+	Coprocessor,
+	CoprocessorRegister,
+	DoubleRegister,
+	Eflag,
+	ElementType,
+	Endianness,
+	FloatImmediate,
+	Indexing,
 	Instruction,
 	InstructionCodec,
+	InterruptMask,
+	Lflag,
+	ParallelOperation,
+	ParallelPrefix,
 	Predicate,
+	ProcessorMode,
+	QuadRegister,
 	Register,
+	RegisterList,
+	Rflag,
 	Sflag,
 	Shifter,
+	Sign,
+	SingleRegister,
+	StatusFields,
+	StatusRegister,
+	StructureAddress,
+	Tflag,
+	ThumbOpcode,
+	VectorRegister,
+	VfpRegister,
+	Wflag,
 };
 
 use alloc::vec::Vec;
@@ -46,13 +77,1367 @@ fn test_thumb_encode() {
 			s:           Sflag::On,
 		},
 
+		// This branch is located at 0x08000006, so a zero
+		// offset (as expected below) targets 0x0800000A.
 		Instruction::Branch {
 			predicate: Predicate::Always,
-			immediate: 0x08000008,
+			immediate: 0x0800000A,
+		},
+
+		Instruction::Load {
+			predicate: Predicate::Always,
+			register:  Register::R0,
+			address:   Address::ImmediateOffset { base: Register::R1, source: 0x4, indexing: Indexing::Offset },
+			b:         Bflag::Off,
+			t:         Tflag::Off,
+		},
+
+		Instruction::Load {
+			predicate: Predicate::Always,
+			register:  Register::R0,
+			address:   Address::ImmediateOffset { base: Register::R1, source: 0x1F, indexing: Indexing::Offset },
+			b:         Bflag::On,
+			t:         Tflag::Off,
+		},
+
+		Instruction::Store {
+			predicate: Predicate::Always,
+			register:  Register::R0,
+			address:   Address::RegisterOffset { base: Register::R1, source: Register::R2, sign: Sign::Positive, indexing: Indexing::Offset },
+			b:         Bflag::On,
+			t:         Tflag::Off,
+		},
+
+		Instruction::Load {
+			predicate: Predicate::Always,
+			register:  Register::R0,
+			address:   Address::ImmediateOffset { base: Register::Sp, source: 0x3FC, indexing: Indexing::Offset },
+			b:         Bflag::Off,
+			t:         Tflag::Off,
+		},
+
+		Instruction::Store {
+			predicate: Predicate::Always,
+			register:  Register::R7,
+			address:   Address::ImmediateOffset { base: Register::Sp, source: 0x4, indexing: Indexing::Offset },
+			b:         Bflag::Off,
+			t:         Tflag::Off,
+		},
+
+		Instruction::Load {
+			predicate: Predicate::Always,
+			register:  Register::R0,
+			address:   Address::ImmediateOffset { base: Register::Pc, source: 0x8, indexing: Indexing::Offset },
+			b:         Bflag::Off,
+			t:         Tflag::Off,
+		},
+
+		Instruction::LoadHalfword {
+			predicate: Predicate::Always,
+			register:  Register::R0,
+			address:   Address::ImmediateOffset { base: Register::R1, source: 0x3E, indexing: Indexing::Offset },
+		},
+
+		Instruction::LoadSignedByte {
+			predicate: Predicate::Always,
+			register:  Register::R0,
+			address:   Address::RegisterOffset { base: Register::R1, source: Register::R2, sign: Sign::Positive, indexing: Indexing::Offset },
+		},
+
+		Instruction::StoreHalfword {
+			predicate: Predicate::Always,
+			register:  Register::R0,
+			address:   Address::RegisterOffset { base: Register::R1, source: Register::R2, sign: Sign::Positive, indexing: Indexing::Offset },
+		},
+
+		Instruction::StoreMultiple {
+			predicate: Predicate::Always,
+			base:      Register::Sp,
+			registers: RegisterList::from_u16(0b01000000_11110000),
+			mode:      BlockMode::DecrementBefore,
+			w:         Wflag::On,
+			s:         Sflag::Off,
+		},
+
+		Instruction::LoadMultiple {
+			predicate: Predicate::Always,
+			base:      Register::Sp,
+			registers: RegisterList::new().with(Register::R0).with(Register::Pc),
+			mode:      BlockMode::IncrementAfter,
+			w:         Wflag::On,
+			s:         Sflag::Off,
+		},
+
+		Instruction::LoadMultiple {
+			predicate: Predicate::Always,
+			base:      Register::R0,
+			registers: RegisterList::new().with(Register::R1).with(Register::R2),
+			mode:      BlockMode::IncrementAfter,
+			w:         Wflag::On,
+			s:         Sflag::Off,
+		},
+
+		Instruction::StoreMultiple {
+			predicate: Predicate::Always,
+			base:      Register::R0,
+			registers: RegisterList::from_u16(0b00000000_00000111),
+			mode:      BlockMode::IncrementAfter,
+			w:         Wflag::On,
+			s:         Sflag::Off,
+		},
+
+		Instruction::LoadMultiple {
+			predicate: Predicate::Always,
+			base:      Register::R0,
+			registers: RegisterList::new().with(Register::R0).with(Register::R1),
+			mode:      BlockMode::IncrementAfter,
+			w:         Wflag::Off,
+			s:         Sflag::Off,
+		},
+
+		Instruction::Multiply {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Register::R0,
+			s:           Sflag::On,
+		},
+
+		Instruction::LoadExclusive {
+			predicate: Predicate::Always,
+			register:  Register::R0,
+			address:   Address::ImmediateOffset { base: Register::R1, source: 0x4, indexing: Indexing::Offset },
+		},
+
+		Instruction::StoreExclusive {
+			predicate: Predicate::Always,
+			status:    Register::R2,
+			register:  Register::R3,
+			address:   Address::ImmediateOffset { base: Register::R4, source: 0x0, indexing: Indexing::Offset },
+		},
+
+		Instruction::LoadExclusiveByte {
+			predicate: Predicate::Always,
+			register:  Register::R0,
+			address:   Address::ImmediateOffset { base: Register::R1, source: 0x0, indexing: Indexing::Offset },
+		},
+
+		Instruction::StoreExclusiveHalfword {
+			predicate: Predicate::Always,
+			status:    Register::R0,
+			register:  Register::R1,
+			address:   Address::ImmediateOffset { base: Register::R2, source: 0x0, indexing: Indexing::Offset },
+		},
+
+		Instruction::LoadExclusiveDoubleword {
+			predicate: Predicate::Always,
+			first:     Register::R2,
+			second:    Register::R3,
+			address:   Address::ImmediateOffset { base: Register::R4, source: 0x0, indexing: Indexing::Offset },
+		},
+
+		Instruction::StoreExclusiveDoubleword {
+			predicate: Predicate::Always,
+			status:    Register::R1,
+			first:     Register::R2,
+			second:    Register::R3,
+			address:   Address::ImmediateOffset { base: Register::R4, source: 0x0, indexing: Indexing::Offset },
+		},
+
+		Instruction::ClearExclusive,
+
+		Instruction::DataMemoryBarrier {
+			option: BarrierOption::Sy,
+		},
+
+		Instruction::DataSynchronisationBarrier {
+			option: BarrierOption::IshSt,
+		},
+
+		Instruction::InstructionSynchronisationBarrier {
+			option: BarrierOption::Sy,
+		},
+
+		Instruction::MoveWide {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			immediate:   0x1234,
+		},
+
+		Instruction::MoveTop {
+			predicate:   Predicate::Always,
+			destination: Register::R1,
+			immediate:   0xFFFF,
+		},
+
+		Instruction::MoveWide {
+			predicate:   Predicate::Always,
+			destination: Register::R9,
+			immediate:   0xF0F,
+		},
+
+		Instruction::BitFieldInsert {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      Register::R1,
+			lsb:         0x4,
+			width:       0x8,
+		},
+
+		Instruction::BitFieldClear {
+			predicate:   Predicate::Always,
+			destination: Register::R2,
+			lsb:         0x0,
+			width:       0x20,
+		},
+
+		Instruction::UnsignedBitFieldExtract {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      Register::R1,
+			lsb:         0x3,
+			width:       0x5,
+		},
+
+		Instruction::SignedBitFieldExtract {
+			predicate:   Predicate::Always,
+			destination: Register::R2,
+			source:      Register::R3,
+			lsb:         0x1F,
+			width:       0x1,
+		},
+
+		Instruction::ReverseBits {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      Register::R1,
+		},
+
+		Instruction::Reverse {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      Register::R1,
+		},
+
+		Instruction::ReversePackedHalfword {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      Register::R1,
+		},
+
+		Instruction::ReverseSignedHalfword {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      Register::R1,
+		},
+
+		Instruction::SignedDivide {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Register::R2,
+		},
+
+		Instruction::UnsignedDivide {
+			predicate:   Predicate::Always,
+			destination: Register::R3,
+			base:        Register::R4,
+			source:      Register::R5,
+		},
+
+		Instruction::FloatSubtract {
+			predicate:   Predicate::Always,
+			destination: VfpRegister::Double(DoubleRegister::D16),
+			base:        VfpRegister::Double(DoubleRegister::D17),
+			source:      VfpRegister::Double(DoubleRegister::D31),
+		},
+
+		Instruction::FloatSquareRoot {
+			predicate:   Predicate::Always,
+			destination: VfpRegister::Single(SingleRegister::S0),
+			source:      VfpRegister::Single(SingleRegister::S1),
+		},
+
+		Instruction::FloatCompare {
+			predicate: Predicate::Always,
+			lhs:       VfpRegister::Double(DoubleRegister::D0),
+			rhs:       Some(VfpRegister::Double(DoubleRegister::D1)),
+			e:         Eflag::On,
+		},
+
+		Instruction::FloatConvert {
+			predicate:   Predicate::Always,
+			destination: VfpRegister::Single(SingleRegister::S1),
+			source:      VfpRegister::Double(DoubleRegister::D0),
+		},
+
+		Instruction::FloatToUnsigned {
+			predicate:   Predicate::Always,
+			destination: SingleRegister::S0,
+			source:      VfpRegister::Double(DoubleRegister::D1),
+			r:           Rflag::On,
+		},
+
+		Instruction::FloatMoveImmediate {
+			predicate:   Predicate::Always,
+			destination: VfpRegister::Single(SingleRegister::S0),
+			immediate:   FloatImmediate::new(0b01111000),
+		},
+
+		Instruction::MoveToFloatDouble {
+			predicate:   Predicate::Always,
+			destination: DoubleRegister::D0,
+			low:         Register::R0,
+			high:        Register::R1,
+		},
+
+		Instruction::MoveToFloatStatus {
+			predicate: Predicate::Always,
+			source:    Register::R1,
+		},
+
+		Instruction::FloatLoad {
+			predicate: Predicate::Always,
+			register:  VfpRegister::Double(DoubleRegister::D0),
+			address:   Address::ImmediateOffset { base: Register::R0, source: 0x8, indexing: Indexing::Offset },
+		},
+
+		Instruction::FloatStore {
+			predicate: Predicate::Always,
+			register:  VfpRegister::Single(SingleRegister::S1),
+			address:   Address::ImmediateOffset { base: Register::Sp, source: -0x4, indexing: Indexing::Offset },
+		},
+
+		Instruction::VectorAdd {
+			element:     ElementType::I8,
+			destination: VectorRegister::Double(DoubleRegister::D0),
+			base:        VectorRegister::Double(DoubleRegister::D1),
+			source:      VectorRegister::Double(DoubleRegister::D2),
+		},
+
+		Instruction::VectorSubtract {
+			element:     ElementType::I32,
+			destination: VectorRegister::Quad(QuadRegister::Q8),
+			base:        VectorRegister::Quad(QuadRegister::Q9),
+			source:      VectorRegister::Quad(QuadRegister::Q15),
+		},
+
+		Instruction::VectorStoreStructure {
+			interleave: 0x4,
+			element:    ElementType::Untyped8,
+			first:      DoubleRegister::D0,
+			count:      0x4,
+			spacing:    0x1,
+			address:    StructureAddress::PostIndexed { base: Register::R0, alignment: 0x100 },
+		},
+
+		Instruction::VectorDuplicate {
+			predicate:   Predicate::Always,
+			element:     ElementType::Untyped32,
+			destination: VectorRegister::Quad(QuadRegister::Q0),
+			source:      Register::R0,
+		},
+
+		Instruction::NoOperation {
+			predicate: Predicate::Always,
+		},
+
+		Instruction::Yield {
+			predicate: Predicate::Always,
+		},
+
+		Instruction::WaitForEvent {
+			predicate: Predicate::Always,
+		},
+
+		Instruction::WaitForInterrupt {
+			predicate: Predicate::Always,
+		},
+
+		Instruction::SendEvent {
+			predicate: Predicate::Always,
+		},
+
+		Instruction::EnableInterrupts {
+			mask: InterruptMask::ALL,
+			mode: None,
+		},
+
+		Instruction::DisableInterrupts {
+			mask: InterruptMask::IRQ,
+			mode: None,
+		},
+
+		Instruction::EnableInterrupts {
+			mask: InterruptMask::FIQ,
+			mode: Some(ProcessorMode::Supervisor),
+		},
+
+		Instruction::ChangeProcessorMode {
+			mode: ProcessorMode::System,
+		},
+
+		Instruction::SetEndianness {
+			endianness: Endianness::Big,
+		},
+
+		Instruction::SetEndianness {
+			endianness: Endianness::Little,
+		},
+
+		Instruction::UnsignedSaturate {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			immediate:   0x0,
+			source:      Shifter::from_register(Register::R1),
+		},
+
+		Instruction::UnsignedSaturate {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			immediate:   0x1F,
+			source:      Shifter::LogicalShiftLeftImmediate { source: Register::R1, shift: 0x3 },
+		},
+
+		Instruction::UnsignedSaturate {
+			predicate:   Predicate::Always,
+			destination: Register::R2,
+			immediate:   0x7,
+			source:      Shifter::ArithmeticShiftRightImmediate { source: Register::R3, shift: 0x1F },
+		},
+
+		Instruction::SignedSaturate {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			immediate:   0x1,
+			source:      Shifter::from_register(Register::R1),
+		},
+
+		Instruction::SignedSaturate {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			immediate:   0x20,
+			source:      Shifter::ArithmeticShiftRightImmediate { source: Register::R1, shift: 0x1 },
+		},
+
+		Instruction::SignedSaturate16 {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			immediate:   0x1,
+			source:      Register::R1,
+		},
+
+		Instruction::SignedSaturate16 {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			immediate:   0x10,
+			source:      Register::R1,
+		},
+
+		Instruction::UnsignedSaturate16 {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			immediate:   0x0,
+			source:      Register::R1,
+		},
+
+		Instruction::UnsignedSaturate16 {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			immediate:   0xF,
+			source:      Register::R1,
+		},
+
+		Instruction::SaturatingAdd {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Register::R2,
+		},
+
+		Instruction::SaturatingSubtract {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Register::R2,
+		},
+
+		Instruction::SaturatingDoubleAdd {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Register::R2,
+		},
+
+		Instruction::SaturatingDoubleSubtract {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Register::R2,
+		},
+		Instruction::PackHalfword {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Shifter::ArithmeticShiftRightImmediate { source: Register::R2, shift: 0x8 },
+		},
+
+		Instruction::ParallelArithmetic {
+			predicate:   Predicate::Always,
+			prefix:      ParallelPrefix::UnsignedSaturating,
+			operation:   ParallelOperation::Add16,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Register::R2,
+		},
+
+		Instruction::Select {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Register::R2,
+		},
+
+		Instruction::UnsignedSumAbsoluteDifferences {
+			predicate:   Predicate::Always,
+			destination: Register::R3,
+			base:        Register::R4,
+			source:      Register::R5,
+		},
+
+		Instruction::UnsignedSumAbsoluteDifferencesAccumulate {
+			predicate:   Predicate::Always,
+			destination: Register::R3,
+			base:        Register::R4,
+			source:      Register::R5,
+			accumulator: Register::R6,
+		},
+
+		Instruction::MoveFromCoprocessor {
+			predicate:   Predicate::Always,
+			coprocessor: Coprocessor::P15,
+			opcode1:     0x0,
+			register:    Register::R0,
+			crn:         CoprocessorRegister::C1,
+			crm:         CoprocessorRegister::C0,
+			opcode2:     0x0,
+		},
+
+		Instruction::LoadCoprocessor {
+			predicate:   Predicate::Always,
+			coprocessor: Coprocessor::P6,
+			crd:         CoprocessorRegister::C2,
+			address:     Address::ImmediateOffset { base: Register::R3, source: -0x8, indexing: Indexing::PreIndexed },
+			l:           Lflag::On,
+		},
+
+		Instruction::MoveFromStatusRegister {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      StatusRegister::Cpsr,
+		},
+
+		Instruction::MoveToStatusRegister {
+			predicate:   Predicate::Always,
+			destination: StatusRegister::Cpsr,
+			fields:      StatusFields::CONTROL | StatusFields::FLAGS,
+			source:      Shifter::from_register(Register::R1),
+		},
+
+		Instruction::Move {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      Shifter::from_register(Register::R9),
+			s:           Sflag::Off,
+		},
+
+		Instruction::Move {
+			predicate:   Predicate::Always,
+			destination: Register::R9,
+			source:      Shifter::from_register(Register::R0),
+			s:           Sflag::Off,
+		},
+
+		Instruction::CompareBranchZero {
+			source:    Register::R2,
+			immediate: 0x080001AC,
+		},
+
+		Instruction::CompareBranchNonZero {
+			source:    Register::R7,
+			immediate: 0x08000130,
+		},
+
+		Instruction::TableBranchByte {
+			predicate: Predicate::Always,
+			base:      Register::R0,
+			index:     Register::R1,
+		},
+
+		Instruction::TableBranchHalfword {
+			predicate: Predicate::Always,
+			base:      Register::Pc,
+			index:     Register::R10,
+		},
+
+		Instruction::InclusiveOrNot {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Shifter::from_register(Register::R2),
+			s:           Sflag::Off,
+		},
+
+		Instruction::PreloadData {
+			address: Address::from_register(Register::R0),
+		},
+
+		Instruction::PreloadData {
+			address: Address::ImmediateOffset { base: Register::R1, source: -0x8, indexing: Indexing::Offset },
+		},
+	];
+
+	let mut codec = InstructionCodec::new_at(0x08000000);
+
+	let mut opcodes = Vec::new();
+	for instruction in programme {
+		let opcode = codec.encode_thumb(instruction).unwrap();
+
+		opcodes.push(opcode.0);
+		if let Some(opcode) = opcode.1 { opcodes.push(opcode) };
+	}
+
+	assert_eq!(
+		opcodes,
+		[
+			0b11110000_00000000,
+			0b11111000_00100000,
+			0b01000000_00111000,
+			0b11100000_00000000,
+			0b01101000_01001000,
+			0b01111111_11001000,
+			0b01010100_10001000,
+			0b10011000_11111111,
+			0b10010111_00000001,
+			0b01001000_00000010,
+			0b10001111_11001000,
+			0b01010110_10001000,
+			0b01010010_10001000,
+			0b10110101_11110000,
+			0b10111101_00000001,
+			0b11001000_00000110,
+			0b11000000_00000111,
+			0b11001000_00000011,
+			0b01000011_01001000,
+			0b11101000_01010001,
+			0b00001111_00000001,
+			0b11101000_01000100,
+			0b00110010_00000000,
+			0b11101000_11010001,
+			0b00001111_01001111,
+			0b11101000_11000010,
+			0b00011111_01010000,
+			0b11101000_11010100,
+			0b00100011_01111111,
+			0b11101000_11000100,
+			0b00100011_01110001,
+			0b11110011_10111111,
+			0b10001111_00101111,
+			0b11110011_10111111,
+			0b10001111_01011111,
+			0b11110011_10111111,
+			0b10001111_01001010,
+			0b11110011_10111111,
+			0b10001111_01101111,
+			0b11110010_01000001,
+			0b00100000_00110100,
+			0b11110110_11001111,
+			0b01110001_11111111,
+			0b11110110_01000000,
+			0b01111001_00001111,
+			0b11110011_01100001,
+			0b00010000_00001011,
+			0b11110011_01101111,
+			0b00000010_00011111,
+			0b11110011_11000001,
+			0b00000000_11000100,
+			0b11110011_01000011,
+			0b01110010_11000000,
+			0b11111010_10010001,
+			0b11110000_10100001,
+			0b10111010_00001000,
+			0b10111010_01001000,
+			0b10111010_11001000,
+			0b11111011_10010001,
+			0b11110000_11110010,
+			0b11111011_10110100,
+			0b11110011_11110101,
+			0b11101110_01110001,
+			0b00001011_11101111,
+			0b11101110_10110001,
+			0b00001010_11100000,
+			0b11101110_10110100,
+			0b00001011_11000001,
+			0b11101110_11110111,
+			0b00001011_11000000,
+			0b11101110_10111100,
+			0b00001011_01000001,
+			0b11101110_10110111,
+			0b00001010_00001000,
+			0b11101100_01000001,
+			0b00001011_00010000,
+			0b11101110_11100001,
+			0b00011010_00010000,
+			0b11101101_10010000,
+			0b00001011_00000010,
+			0b11101101_01001101,
+			0b00001010_00000001,
+			0b11101111_00000001,
+			0b00001000_00000010,
+			0b11111111_01100010,
+			0b00001000_11101110,
+			0b11111001_00000000,
+			0b00000000_00111101,
+			0b11101110_10100000,
+			0b00001011_00010000,
+			0b10111111_00000000,
+			0b10111111_00010000,
+			0b10111111_00100000,
+			0b10111111_00110000,
+			0b10111111_01000000,
+			0b10110110_01100111,
+			0b10110110_01110010,
+			0b11110011_10101111,
+			0b10000101_00110011,
+			0b11110011_10101111,
+			0b10000001_00011111,
+			0b10110110_01011000,
+			0b10110110_01010000,
+			0b11110011_10000001,
+			0b00000000_00000000,
+			0b11110011_10000001,
+			0b00000000_11011111,
+			0b11110011_10100011,
+			0b01110010_11000111,
+			0b11110011_00000001,
+			0b00000000_00000000,
+			0b11110011_00100001,
+			0b00000000_01011111,
+			0b11110011_00100001,
+			0b00000000_00000000,
+			0b11110011_00100001,
+			0b00000000_00001111,
+			0b11110011_10100001,
+			0b00000000_00000000,
+			0b11110011_10100001,
+			0b00000000_00001111,
+			0b11111010_10000010,
+			0b11110000_10000001,
+			0b11111010_10000010,
+			0b11110000_10100001,
+			0b11111010_10000010,
+			0b11110000_10010001,
+			0b11111010_10000010,
+			0b11110000_10110001,
+			0b11101010_11000001,
+			0b00100000_00100010,
+			0b11111010_10010001,
+			0b11110000_01010010,
+			0b11111010_10100001,
+			0b11110000_10000010,
+			0b11111011_01110100,
+			0b11110011_00000101,
+			0b11111011_01110100,
+			0b01100011_00000101,
+			0b11101110_00010001,
+			0b00001111_00010000,
+			0b11101101_01110011,
+			0b00100110_00000010,
+			0b11110011_11101111,
+			0b10000000_00000000,
+			0b11110011_10000001,
+			0b10001001_00000000,
+			0b01000110_01001000,
+			0b01000110_10000001,
+			0b10110011_11111010,
+			0b10111001_00000111,
+			0b11101000_11010000,
+			0b11110000_00000001,
+			0b11101000_11011111,
+			0b11110000_00011010,
+			0b11101010_01100001,
+			0b00000000_00000010,
+			0b11111000_10010000,
+			0b11110000_00000000,
+			0b11111000_00010001,
+			0b11111100_00001000,
+		],
+	);
+
+	for instruction in [
+		Instruction::CompareBranchZero {
+			source:    Register::R0,
+			immediate: 0x08000000,
+		},
+
+		Instruction::CompareBranchNonZero {
+			source:    Register::R0,
+			immediate: 0x08000200,
+		},
+	] {
+		assert!(matches!(codec.encode_thumb(instruction), Err(Error::IllegalImmediate { .. })));
+	}
+}
+
+#[test]
+fn test_thumb2_encode() {
+	let programme = [
+		Instruction::Add {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Shifter::Immediate(0x7),
+			s:           Sflag::On,
+		},
+
+		Instruction::Add {
+			predicate:   Predicate::Always,
+			destination: Register::R1,
+			base:        Register::R1,
+			source:      Shifter::Immediate(0xFF),
+			s:           Sflag::On,
+		},
+
+		Instruction::Subtract {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Shifter::from_register(Register::R2),
+			s:           Sflag::On,
+		},
+
+		Instruction::Add {
+			predicate:   Predicate::Always,
+			destination: Register::R8,
+			base:        Register::R8,
+			source:      Shifter::from_register(Register::R9),
+			s:           Sflag::Off,
+		},
+
+		Instruction::Add {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::Sp,
+			source:      Shifter::Immediate(0x3FC),
+			s:           Sflag::Off,
+		},
+
+		Instruction::Add {
+			predicate:   Predicate::Always,
+			destination: Register::Sp,
+			base:        Register::Sp,
+			source:      Shifter::Immediate(0x1FC),
+			s:           Sflag::Off,
+		},
+
+		Instruction::Subtract {
+			predicate:   Predicate::Always,
+			destination: Register::Sp,
+			base:        Register::Sp,
+			source:      Shifter::Immediate(0x4),
+			s:           Sflag::Off,
+		},
+
+		Instruction::Add {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::Pc,
+			source:      Shifter::Immediate(0x10),
+			s:           Sflag::Off,
+		},
+
+		Instruction::Compare {
+			predicate: Predicate::Always,
+			lhs:       Register::R0,
+			rhs:       Shifter::Immediate(0xFF),
+		},
+
+		Instruction::Compare {
+			predicate: Predicate::Always,
+			lhs:       Register::R8,
+			rhs:       Shifter::from_register(Register::R1),
+		},
+
+		Instruction::CompareNegated {
+			predicate: Predicate::Always,
+			lhs:       Register::R0,
+			rhs:       Shifter::from_register(Register::R1),
+		},
+
+		Instruction::Test {
+			predicate: Predicate::Always,
+			lhs:       Register::R0,
+			rhs:       Shifter::from_register(Register::R1),
+		},
+
+		Instruction::ReverseSubtract {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Shifter::Immediate(0x0),
+			s:           Sflag::On,
+		},
+
+		Instruction::SignExtendByte {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      Register::R1,
+			rotation:    0x0,
+		},
+
+		Instruction::ZeroExtendHalfword {
+			predicate:   Predicate::Always,
+			destination: Register::R2,
+			source:      Register::R3,
+			rotation:    0x0,
+		},
+
+		Instruction::Move {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      Shifter::LogicalShiftLeftImmediate { source: Register::R1, shift: 0x3 },
+			s:           Sflag::On,
+		},
+
+		Instruction::Add {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Shifter::from_register(Register::R8),
+			s:           Sflag::Off,
+		},
+
+		Instruction::Add {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Shifter::Immediate(0xFF00FF00),
+			s:           Sflag::Off,
+		},
+
+		Instruction::Add {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Shifter::Immediate(0xFFF),
+			s:           Sflag::Off,
+		},
+
+		Instruction::Subtract {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::Sp,
+			source:      Shifter::Immediate(0x1),
+			s:           Sflag::Off,
+		},
+
+		Instruction::Add {
+			predicate:   Predicate::Always,
+			destination: Register::R8,
+			base:        Register::R9,
+			source:      Shifter::LogicalShiftLeftImmediate { source: Register::R10, shift: 0x3 },
+			s:           Sflag::On,
+		},
+
+		Instruction::And {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Shifter::from_register(Register::R2),
+			s:           Sflag::Off,
+		},
+
+		Instruction::BitClear {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Shifter::Immediate(0xFF),
+			s:           Sflag::On,
+		},
+
+		Instruction::InclusiveOr {
+			predicate:   Predicate::Always,
+			destination: Register::R8,
+			base:        Register::R8,
+			source:      Shifter::RotateRightImmediate { source: Register::R9, shift: 0x4 },
+			s:           Sflag::Off,
+		},
+
+		Instruction::ExclusiveOr {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Shifter::RotateRightExtend { source: Register::R2 },
+			s:           Sflag::Off,
+		},
+
+		Instruction::ReverseSubtract {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Shifter::Immediate(0x80000000),
+			s:           Sflag::Off,
+		},
+
+		Instruction::AddCarry {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Shifter::from_register(Register::R2),
+			s:           Sflag::Off,
+		},
+
+		Instruction::SubtractCarry {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Shifter::ArithmeticShiftRightImmediate { source: Register::R2, shift: 0x20 },
+			s:           Sflag::Off,
+		},
+
+		Instruction::Compare {
+			predicate: Predicate::Always,
+			lhs:       Register::R0,
+			rhs:       Shifter::Immediate(0x1000),
+		},
+
+		Instruction::CompareNegated {
+			predicate: Predicate::Always,
+			lhs:       Register::R8,
+			rhs:       Shifter::from_register(Register::R9),
+		},
+
+		Instruction::Test {
+			predicate: Predicate::Always,
+			lhs:       Register::R0,
+			rhs:       Shifter::Immediate(0x1000000),
+		},
+
+		Instruction::TestEquivalence {
+			predicate: Predicate::Always,
+			lhs:       Register::R0,
+			rhs:       Shifter::from_register(Register::R1),
+		},
+
+		Instruction::Move {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      Shifter::Immediate(0xAB00AB00),
+			s:           Sflag::Off,
+		},
+
+		Instruction::Move {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      Shifter::Immediate(0xABCD),
+			s:           Sflag::Off,
+		},
+
+		Instruction::Move {
+			predicate:   Predicate::Always,
+			destination: Register::R8,
+			source:      Shifter::LogicalShiftRightImmediate { source: Register::R9, shift: 0x1 },
+			s:           Sflag::On,
+		},
+
+		Instruction::MoveNot {
+			predicate:   Predicate::Always,
+			destination: Register::R8,
+			source:      Shifter::from_register(Register::R9),
+			s:           Sflag::Off,
+		},
+
+		Instruction::Move {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      Shifter::LogicalShiftLeftRegister { source: Register::R1, shift: Register::R2 },
+			s:           Sflag::Off,
+		},
+
+		Instruction::Move {
+			predicate:   Predicate::Always,
+			destination: Register::R8,
+			source:      Shifter::ArithmeticShiftRightRegister { source: Register::R9, shift: Register::R10 },
+			s:           Sflag::On,
+		},
+
+		Instruction::Multiply {
+			predicate:   Predicate::Always,
+			destination: Register::R8,
+			base:        Register::R9,
+			source:      Register::R10,
+			s:           Sflag::Off,
+		},
+
+		Instruction::MultiplyAccumulate {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Register::R2,
+			accumulator: Register::R3,
+			s:           Sflag::Off,
+		},
+
+		Instruction::MultiplySubtract {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Register::R2,
+			accumulator: Register::R3,
+		},
+
+		Instruction::SignedMultiplyLong {
+			predicate:        Predicate::Always,
+			destination_low:  Register::R0,
+			destination_high: Register::R1,
+			base:             Register::R2,
+			source:           Register::R3,
+			s:                Sflag::Off,
+		},
+
+		Instruction::UnsignedMultiplyLong {
+			predicate:        Predicate::Always,
+			destination_low:  Register::R0,
+			destination_high: Register::R1,
+			base:             Register::R2,
+			source:           Register::R3,
+			s:                Sflag::Off,
+		},
+
+		Instruction::SignedMultiplyAccumulateLong {
+			predicate:        Predicate::Always,
+			destination_low:  Register::R0,
+			destination_high: Register::R1,
+			base:             Register::R2,
+			source:           Register::R3,
+			s:                Sflag::Off,
+		},
+
+		Instruction::UnsignedMultiplyAccumulateLong {
+			predicate:        Predicate::Always,
+			destination_low:  Register::R0,
+			destination_high: Register::R1,
+			base:             Register::R2,
+			source:           Register::R3,
+			s:                Sflag::Off,
+		},
+
+		Instruction::UnsignedMultiplyAccumulateAccumulateLong {
+			predicate:        Predicate::Always,
+			destination_low:  Register::R0,
+			destination_high: Register::R1,
+			base:             Register::R2,
+			source:           Register::R3,
+		},
+
+		Instruction::Load {
+			predicate: Predicate::Always,
+			register:  Register::R8,
+			address:   Address::ImmediateOffset { base: Register::R9, source: 0xFFF, indexing: Indexing::Offset },
+			b:         Bflag::Off,
+			t:         Tflag::Off,
+		},
+
+		Instruction::Store {
+			predicate: Predicate::Always,
+			register:  Register::R0,
+			address:   Address::ImmediateOffset { base: Register::R1, source: -0xFF, indexing: Indexing::Offset },
+			b:         Bflag::Off,
+			t:         Tflag::Off,
+		},
+
+		Instruction::Load {
+			predicate: Predicate::Always,
+			register:  Register::R0,
+			address:   Address::ImmediateOffset { base: Register::R1, source: 0x1, indexing: Indexing::PreIndexed },
+			b:         Bflag::On,
+			t:         Tflag::Off,
+		},
+
+		Instruction::StoreHalfword {
+			predicate: Predicate::Always,
+			register:  Register::R0,
+			address:   Address::ImmediateOffset { base: Register::R1, source: -0x2, indexing: Indexing::PostIndexed },
+		},
+
+		Instruction::LoadSignedHalfword {
+			predicate: Predicate::Always,
+			register:  Register::R0,
+			address:   Address::ScaledRegisterOffset { base: Register::R1, source: Shifter::LogicalShiftLeftImmediate { source: Register::R2, shift: 0x3 }, sign: Sign::Positive, indexing: Indexing::Offset },
+		},
+
+		Instruction::LoadSignedByte {
+			predicate: Predicate::Always,
+			register:  Register::R0,
+			address:   Address::ImmediateOffset { base: Register::Pc, source: -0x4, indexing: Indexing::Offset },
+		},
+
+		Instruction::Load {
+			predicate: Predicate::Always,
+			register:  Register::Pc,
+			address:   Address::ImmediateOffset { base: Register::Sp, source: 0x4, indexing: Indexing::PostIndexed },
+			b:         Bflag::Off,
+			t:         Tflag::Off,
+		},
+
+		Instruction::Load {
+			predicate: Predicate::Always,
+			register:  Register::R0,
+			address:   Address::ImmediateOffset { base: Register::R1, source: 0x8, indexing: Indexing::Offset },
+			b:         Bflag::Off,
+			t:         Tflag::On,
+		},
+
+		Instruction::LoadDoubleword {
+			predicate: Predicate::Always,
+			first:     Register::R0,
+			second:    Register::R1,
+			address:   Address::ImmediateOffset { base: Register::R2, source: -0x3FC, indexing: Indexing::PreIndexed },
+		},
+
+		Instruction::StoreDoubleword {
+			predicate: Predicate::Always,
+			first:     Register::R8,
+			second:    Register::R9,
+			address:   Address::ImmediateOffset { base: Register::Sp, source: 0x8, indexing: Indexing::PostIndexed },
+		},
+
+		Instruction::LoadMultiple {
+			predicate: Predicate::Always,
+			base:      Register::R8,
+			registers: RegisterList::from_u16(0b01000010_00000001),
+			mode:      BlockMode::IncrementAfter,
+			w:         Wflag::On,
+			s:         Sflag::Off,
+		},
+
+		Instruction::StoreMultiple {
+			predicate: Predicate::Always,
+			base:      Register::Sp,
+			registers: RegisterList::from_u16(0b01001111_11110000),
+			mode:      BlockMode::DecrementBefore,
+			w:         Wflag::On,
+			s:         Sflag::Off,
+		},
+
+		Instruction::LoadMultiple {
+			predicate: Predicate::Always,
+			base:      Register::R0,
+			registers: RegisterList::from_u16(0b00000000_00000110),
+			mode:      BlockMode::DecrementBefore,
+			w:         Wflag::Off,
+			s:         Sflag::Off,
+		},
+
+		Instruction::CountLeadingZeroes {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      Register::R1,
+		},
+
+		Instruction::Reverse {
+			predicate:   Predicate::Always,
+			destination: Register::R8,
+			source:      Register::R9,
+		},
+
+		Instruction::ReversePackedHalfword {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      Register::R8,
+		},
+
+		Instruction::ReverseSignedHalfword {
+			predicate:   Predicate::Always,
+			destination: Register::R8,
+			source:      Register::R0,
+		},
+
+		Instruction::SignExtendAddByte {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Register::R2,
+			rotation:    0x8,
+		},
+
+		Instruction::ZeroExtendByte16 {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      Register::R1,
+			rotation:    0x18,
+		},
+
+		Instruction::SignExtendHalfword {
+			predicate:   Predicate::Always,
+			destination: Register::R8,
+			source:      Register::R9,
+			rotation:    0x0,
+		},
+
+		Instruction::Branch {
+			predicate: Predicate::Always,
+			immediate: 0x070000EC,
+		},
+
+		Instruction::Branch {
+			predicate: Predicate::Always,
+			immediate: 0x090000EE,
+		},
+
+		Instruction::Branch {
+			predicate: Predicate::Always,
+			immediate: 0x080010F4,
+		},
+
+		Instruction::Branch {
+			predicate: Predicate::Equal,
+			immediate: 0x07F000F8,
+		},
+
+		Instruction::Branch {
+			predicate: Predicate::NotEqual,
+			immediate: 0x081000FA,
+		},
+
+		Instruction::Branch {
+			predicate: Predicate::GreaterThan,
+			immediate: 0x07FFFFFC,
+		},
+
+		Instruction::BranchLink {
+			predicate: Predicate::Always,
+			source:    0x07000104,
+		},
+
+		Instruction::BranchLink {
+			predicate: Predicate::Always,
+			source:    0x08800108,
+		},
+
+		Instruction::BranchLinkExchange {
+			predicate: Predicate::Always,
+			source:    Shifter::Immediate(0x0700010C),
+		},
+
+		Instruction::BranchLinkExchange {
+			predicate: Predicate::Always,
+			source:    Shifter::Immediate(0x08001110),
 		},
 	];
 
 	let mut codec = InstructionCodec::new_at(0x08000000);
+	codec.set_thumb2(true);
 
 	let mut opcodes = Vec::new();
 	for instruction in programme {
@@ -65,10 +1450,709 @@ fn test_thumb_encode() {
 	assert_eq!(
 		opcodes,
 		[
+			0b00011101_11001000,
+			0b00110001_11111111,
+			0b00011010_10001000,
+			0b01000100_11001000,
+			0b10101000_11111111,
+			0b10110000_01111111,
+			0b10110000_10000001,
+			0b10100000_00000100,
+			0b00101000_11111111,
+			0b01000101_10001000,
+			0b01000010_11001000,
+			0b01000010_00001000,
+			0b01000010_01001000,
+			0b10110010_01001000,
+			0b10110010_10011010,
+			0b00000000_11001000,
+			0b11101011_00000001,
+			0b00000000_00001000,
+			0b11110001_00000001,
+			0b00100000_11111111,
+			0b11110110_00000001,
+			0b01110000_11111111,
+			0b11110001_10101101,
+			0b00000000_00000001,
+			0b11101011_00011001,
+			0b00001000_11001010,
+			0b11101010_00000001,
+			0b00000000_00000010,
+			0b11110000_00110001,
+			0b00000000_11111111,
+			0b11101010_01001000,
+			0b00011000_00111001,
+			0b11101010_10000001,
+			0b00000000_00110010,
+			0b11110001_11000001,
+			0b01000000_00000000,
+			0b11101011_01000001,
+			0b00000000_00000010,
+			0b11101011_01100001,
+			0b00000000_00100010,
+			0b11110101_10110000,
+			0b01011111_10000000,
+			0b11101011_00011000,
+			0b00001111_00001001,
+			0b11110000_00010000,
+			0b01111111_10000000,
+			0b11101010_10010000,
+			0b00001111_00000001,
+			0b11110000_01001111,
+			0b00100000_10101011,
+			0b11110110_01001010,
+			0b00110000_11001101,
+			0b11101010_01011111,
+			0b00001000_01011001,
+			0b11101010_01101111,
+			0b00001000_00001001,
+			0b11111010_00000001,
+			0b11110000_00000010,
+			0b11111010_01011001,
+			0b11111000_00001010,
+			0b11111011_00001001,
+			0b11111000_00001010,
+			0b11111011_00000001,
+			0b00110000_00000010,
+			0b11111011_00000001,
+			0b00110000_00010010,
+			0b11111011_10000010,
+			0b00000001_00000011,
+			0b11111011_10100010,
+			0b00000001_00000011,
+			0b11111011_11000010,
+			0b00000001_00000011,
+			0b11111011_11100010,
+			0b00000001_00000011,
+			0b11111011_11100010,
+			0b00000001_01100011,
+			0b11111000_11011001,
+			0b10001111_11111111,
+			0b11111000_01000001,
+			0b00001100_11111111,
+			0b11111000_00010001,
+			0b00001111_00000001,
+			0b11111000_00100001,
+			0b00001001_00000010,
+			0b11111001_00110001,
+			0b00000000_00110010,
+			0b11111001_00011111,
+			0b00000000_00000100,
+			0b11111000_01011101,
+			0b11111011_00000100,
+			0b11111000_01010001,
+			0b00001110_00001000,
+			0b11101001_01110010,
+			0b00000001_11111111,
+			0b11101000_11101101,
+			0b10001001_00000010,
+			0b11101000_10111000,
+			0b01000010_00000001,
+			0b11101001_00101101,
+			0b01001111_11110000,
+			0b11101001_00010000,
+			0b00000000_00000110,
+			0b11111010_10110001,
+			0b11110000_10000001,
+			0b11111010_10011001,
+			0b11111000_10001001,
+			0b11111010_10011000,
+			0b11110000_10011000,
+			0b11111010_10010000,
+			0b11111000_10110000,
+			0b11111010_01000001,
+			0b11110000_10010010,
+			0b11111010_00111111,
+			0b11110000_10110001,
+			0b11111010_00001111,
+			0b11111000_10001001,
+			0b11110100_00000000,
+			0b10010000_00000000,
+			0b11110011_11111111,
+			0b10010111_11111111,
+			0b11110000_00000001,
+			0b10111000_00000000,
+			0b11110100_00000000,
+			0b10000000_00000000,
+			0b11110000_01111111,
+			0b10101111_11111111,
+			0b11110111_00111111,
+			0b10101111_01111110,
+			0b11110100_00000000,
+			0b11010000_00000000,
 			0b11110000_00000000,
-			0b11111000_00100000,
-			0b01000000_00111000,
-			0b11100000_00000000,
+			0b11011000_00000000,
+			0b11110100_00000000,
+			0b11000000_00000000,
+			0b11110000_00000001,
+			0b11101000_00000000,
+		],
+	);
+
+	let instruction = Instruction::And {
+		predicate:   Predicate::Always,
+		destination: Register::R0,
+		base:        Register::R0,
+		source:      Shifter::from_register(Register::R1),
+		s:           Sflag::Off,
+	};
+
+	let mut codec = InstructionCodec::new();
+	assert!(matches!(codec.encode_thumb(instruction), Err(Error::IllegalFlag { .. })));
+
+	codec.set_thumb2(true);
+	assert_eq!(codec.encode_thumb(instruction).unwrap(), (0b11101010_00000000.into(), Some(0b00000000_00000001.into())));
+
+	let opcode = codec.encode_thumb(Instruction::Move {
+		predicate:   Predicate::Always,
+		destination: Register::R0,
+		source:      Shifter::Immediate(0x00010001),
+		s:           Sflag::On,
+	}).unwrap();
+
+	assert_eq!(opcode, (0b11110000_01011111.into(), Some(0b00010000_00000001.into())));
+
+	let result = codec.encode_thumb(Instruction::Move {
+		predicate:   Predicate::Always,
+		destination: Register::R0,
+		source:      Shifter::Immediate(0x00000101),
+		s:           Sflag::On,
+	});
+
+	assert!(matches!(result, Err(Error::IllegalImmediate { .. })));
+
+	for instruction in [
+		Instruction::BitFieldInsert {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      Register::R1,
+			lsb:         0x10,
+			width:       0x11,
+		},
+
+		Instruction::BitFieldInsert {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      Register::R1,
+			lsb:         0x4,
+			width:       0x0,
+		},
+
+		Instruction::SignedBitFieldExtract {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      Register::R1,
+			lsb:         0x20,
+			width:       0x1,
+		},
+
+		Instruction::UnsignedBitFieldExtract {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			source:      Register::R1,
+			lsb:         0x1C,
+			width:       0x8,
+		},
+
+		Instruction::SignedSaturate {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			immediate:   0x0,
+			source:      Shifter::from_register(Register::R1),
+		},
+
+		Instruction::SignedSaturate {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			immediate:   0x21,
+			source:      Shifter::from_register(Register::R1),
+		},
+
+		Instruction::UnsignedSaturate {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			immediate:   0x20,
+			source:      Shifter::from_register(Register::R1),
+		},
+
+		Instruction::SignedSaturate16 {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			immediate:   0x0,
+			source:      Register::R1,
+		},
+
+		Instruction::SignedSaturate16 {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			immediate:   0x11,
+			source:      Register::R1,
+		},
+
+		Instruction::UnsignedSaturate16 {
+			predicate:   Predicate::Always,
+			destination: Register::R0,
+			immediate:   0x10,
+			source:      Register::R1,
+		},
+	] {
+		assert!(matches!(codec.encode_thumb(instruction), Err(Error::IllegalImmediate { .. })));
+	}
+
+	let result = codec.encode_thumb(Instruction::SignedSaturate {
+		predicate:   Predicate::Always,
+		destination: Register::R0,
+		immediate:   0x8,
+		source:      Shifter::LogicalShiftRightImmediate { source: Register::R1, shift: 0x1 },
+	});
+
+	assert!(matches!(result, Err(Error::IllegalShifter { .. })));
+
+	for instruction in [
+		Instruction::Add {
+			predicate:   Predicate::Always,
+			destination: Register::Sp,
+			base:        Register::R1,
+			source:      Shifter::Immediate(0x100),
+			s:           Sflag::Off,
+		},
+
+		Instruction::Subtract {
+			predicate:   Predicate::Always,
+			destination: Register::Sp,
+			base:        Register::R8,
+			source:      Shifter::Immediate(0x4),
+			s:           Sflag::Off,
+		},
+
+		Instruction::LoadDoubleword {
+			predicate: Predicate::Always,
+			first:     Register::R2,
+			second:    Register::R2,
+			address:   Address::ImmediateOffset { base: Register::R0, source: 0x0, indexing: Indexing::Offset },
+		},
+
+		Instruction::Load {
+			predicate: Predicate::Always,
+			register:  Register::Sp,
+			address:   Address::ImmediateOffset { base: Register::R8, source: 0x4, indexing: Indexing::Offset },
+			b:         Bflag::Off,
+			t:         Tflag::Off,
+		},
+
+		Instruction::StoreHalfword {
+			predicate: Predicate::Always,
+			register:  Register::Sp,
+			address:   Address::ImmediateOffset { base: Register::R0, source: 0x2, indexing: Indexing::Offset },
+		},
+	] {
+		assert!(matches!(codec.encode_thumb(instruction), Err(Error::IllegalRegister { .. })));
+	}
+}
+
+#[test]
+fn test_thumb_if_then_encode() {
+	let programme = [
+		Instruction::IfThen {
+			predicate: Predicate::Equal,
+			mask:      0b1010,
+		},
+
+		Instruction::Add {
+			predicate:   Predicate::Equal,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Shifter::from_register(Register::R2),
+			s:           Sflag::Off,
+		},
+
+		Instruction::Move {
+			predicate:   Predicate::Equal,
+			destination: Register::R8,
+			source:      Shifter::from_register(Register::R9),
+			s:           Sflag::Off,
+		},
+
+		Instruction::Branch {
+			predicate: Predicate::NotEqual,
+			immediate: 0x0800010A,
+		},
+
+		Instruction::IfThen {
+			predicate: Predicate::GreaterThan,
+			mask:      0b1000,
+		},
+
+		Instruction::Subtract {
+			predicate:   Predicate::GreaterThan,
+			destination: Register::R0,
+			base:        Register::R0,
+			source:      Shifter::Immediate(0x1),
+			s:           Sflag::On,
+		},
+
+		Instruction::IfThen {
+			predicate: Predicate::Always,
+			mask:      0b1100,
+		},
+
+		Instruction::Load {
+			predicate: Predicate::Always,
+			register:  Register::R0,
+			address:   Address::from_register(Register::R1),
+			b:         Bflag::Off,
+			t:         Tflag::Off,
+		},
+
+		Instruction::BranchExchange {
+			predicate: Predicate::Always,
+			source:    Register::Lr,
+		},
+	];
+
+	let mut codec = InstructionCodec::new_at(0x08000000);
+	codec.set_thumb2(true);
+
+	let mut opcodes = Vec::new();
+	for instruction in programme {
+		let opcode = codec.encode_thumb(instruction).unwrap();
+
+		opcodes.push(opcode.0);
+		if let Some(opcode) = opcode.1 { opcodes.push(opcode) };
+	}
+
+	assert_eq!(
+		opcodes,
+		[
+			0b10111111_00000110,
+			0b00011000_10001000,
+			0b01000110_11001000,
+			0b11100000_10000000,
+			0b10111111_11001000,
+			0b11110001_10110000,
+			0b00000000_00000001,
+			0b10111111_11100100,
+			0b01101000_00001000,
+			0b01000111_01110000,
 		],
-	)
+	);
+
+	assert_eq!(codec.if_then_predicate(), None);
+
+	let mut codec = InstructionCodec::new();
+
+	assert!(matches!(codec.encode_thumb(Instruction::IfThen { predicate: Predicate::Always, mask: 0b0100 }), Err(Error::IllegalPredicate { .. })));
+	assert!(matches!(codec.encode_thumb(Instruction::IfThen { predicate: Predicate::Equal, mask: 0b0000 }), Err(Error::IllegalImmediate { .. })));
+
+	codec.encode_thumb(Instruction::IfThen { predicate: Predicate::Equal, mask: 0b1100 }).unwrap();
+	assert_eq!(codec.if_then_predicate(), Some(Predicate::Equal));
+
+	let branch = Instruction::BranchExchange { predicate: Predicate::Equal, source: Register::Lr };
+	let copy = |s| Instruction::Move {
+		predicate:   Predicate::Equal,
+		destination: Register::R0,
+		source:      Shifter::from_register(Register::R1),
+		s,
+	};
+
+	assert!(matches!(codec.encode_thumb(Instruction::IfThen { predicate: Predicate::Equal, mask: 0b1000 }), Err(Error::IllegalInstruction { .. })));
+	assert!(matches!(codec.encode_thumb(Instruction::NoOperation { predicate: Predicate::NotEqual }), Err(Error::IllegalPredicate { .. })));
+	assert!(matches!(codec.encode_thumb(branch), Err(Error::IllegalInstruction { .. })));
+	assert!(matches!(codec.encode_thumb(copy(Sflag::On)), Err(Error::IllegalFlag { .. })));
+	assert!(matches!(codec.encode_thumb(Instruction::CompareBranchZero { source: Register::R0, immediate: 0x10 }), Err(Error::IllegalInstruction { .. })));
+
+	for instruction in [
+		Instruction::Subtract {
+			predicate:   Predicate::Equal,
+			destination: Register::Pc,
+			base:        Register::Lr,
+			source:      Shifter::Immediate(0x4),
+			s:           Sflag::Off,
+		},
+
+		Instruction::MoveNot {
+			predicate:   Predicate::Equal,
+			destination: Register::Pc,
+			source:      Shifter::from_register(Register::R0),
+			s:           Sflag::Off,
+		},
+
+		Instruction::Load {
+			predicate: Predicate::Equal,
+			register:  Register::Pc,
+			address:   Address::ImmediateOffset { base: Register::Sp, source: 0x4, indexing: Indexing::PostIndexed },
+			b:         Bflag::Off,
+			t:         Tflag::Off,
+		},
+
+		Instruction::LoadHalfword {
+			predicate: Predicate::Equal,
+			register:  Register::Pc,
+			address:   Address::ImmediateOffset { base: Register::R0, source: 0x0, indexing: Indexing::Offset },
+		},
+	] {
+		assert!(matches!(codec.encode_thumb(instruction), Err(Error::IllegalInstruction { .. })));
+	}
+
+	assert_eq!(codec.encode_thumb(copy(Sflag::Off)).unwrap(), (0b01000110_00001000.into(), None));
+	assert_eq!(codec.encode_thumb(branch).unwrap(), (0b01000111_01110000.into(), None));
+	assert_eq!(codec.if_then_predicate(), None);
+}
+
+#[test]
+fn test_thumb_encode_sequence() {
+	let programme = [
+		Instruction::Move {
+			predicate:   Predicate::Equal,
+			destination: Register::R0,
+			source:      Shifter::from_register(Register::R1),
+			s:           Sflag::Off,
+		},
+
+		Instruction::Add {
+			predicate:   Predicate::NotEqual,
+			destination: Register::R0,
+			base:        Register::R0,
+			source:      Shifter::Immediate(0x1),
+			s:           Sflag::Off,
+		},
+
+		Instruction::Move {
+			predicate:   Predicate::Always,
+			destination: Register::R2,
+			source:      Shifter::from_register(Register::R3),
+			s:           Sflag::Off,
+		},
+
+		Instruction::Subtract {
+			predicate:   Predicate::GreaterThan,
+			destination: Register::R0,
+			base:        Register::R1,
+			source:      Shifter::from_register(Register::R2),
+			s:           Sflag::Off,
+		},
+
+		Instruction::Subtract {
+			predicate:   Predicate::GreaterThan,
+			destination: Register::R3,
+			base:        Register::R3,
+			source:      Shifter::Immediate(0x2),
+			s:           Sflag::Off,
+		},
+
+		Instruction::Add {
+			predicate:   Predicate::LessThanOrEqual,
+			destination: Register::R0,
+			base:        Register::R0,
+			source:      Shifter::from_register(Register::R1),
+			s:           Sflag::Off,
+		},
+
+		Instruction::Move {
+			predicate:   Predicate::GreaterThan,
+			destination: Register::R4,
+			source:      Shifter::from_register(Register::R5),
+			s:           Sflag::Off,
+		},
+
+		Instruction::Move {
+			predicate:   Predicate::GreaterThan,
+			destination: Register::R4,
+			source:      Shifter::from_register(Register::R0),
+			s:           Sflag::Off,
+		},
+
+		Instruction::BranchExchange {
+			predicate: Predicate::GreaterThan,
+			source:    Register::Lr,
+		},
+
+		Instruction::Branch {
+			predicate: Predicate::Equal,
+			immediate: 0x08000008,
+		},
+
+		Instruction::BranchExchange {
+			predicate: Predicate::NotEqual,
+			source:    Register::Lr,
+		},
+
+		Instruction::Move {
+			predicate:   Predicate::NotEqual,
+			destination: Register::R0,
+			source:      Shifter::Immediate(0xFF),
+			s:           Sflag::Off,
+		},
+	];
+
+	let mut codec = InstructionCodec::new_at(0x08000000);
+	let opcodes = codec.encode_thumb_sequence(&programme).unwrap();
+
+	assert_eq!(
+		opcodes,
+		[
+			0b10111111_00001100,
+			0b01000110_00001000,
+			0b00110000_00000001,
+			0b01000110_00011010,
+			0b10111111_11000101,
+			0b00011010_10001000,
+			0b00111011_00000010,
+			0b01000100_00001000,
+			0b01000110_00101100,
+			0b10111111_11000100,
+			0b01000110_00000100,
+			0b01000111_01110000,
+			0b11010000_11110110,
+			0b10111111_00011000,
+			0b01000111_01110000,
+			0b10111111_00011000,
+			0b00100000_11111111,
+		].map(ThumbOpcode::from_u16),
+	);
+}
+
+#[test]
+fn test_thumb_round_trip() {
+	let programme = [
+		Instruction::BranchLink {
+			predicate: Predicate::Always,
+			source:    0x07FFF000,
+		},
+
+		Instruction::And {
+			predicate:   Predicate::Always,
+			destination: Register::R2,
+			base:        Register::R2,
+			source:      Shifter::from_register(Register::R5),
+			s:           Sflag::On,
+		},
+
+		Instruction::ExclusiveOr {
+			predicate:   Predicate::Always,
+			destination: Register::R2,
+			base:        Register::R2,
+			source:      Shifter::from_register(Register::R5),
+			s:           Sflag::On,
+		},
+
+		Instruction::Move {
+			predicate:   Predicate::Always,
+			destination: Register::R2,
+			source:      Shifter::LogicalShiftLeftRegister { source: Register::R2, shift: Register::R5 },
+			s:           Sflag::On,
+		},
+
+		Instruction::Move {
+			predicate:   Predicate::Always,
+			destination: Register::R2,
+			source:      Shifter::LogicalShiftRightRegister { source: Register::R2, shift: Register::R5 },
+			s:           Sflag::On,
+		},
+
+		Instruction::Move {
+			predicate:   Predicate::Always,
+			destination: Register::R2,
+			source:      Shifter::ArithmeticShiftRightRegister { source: Register::R2, shift: Register::R5 },
+			s:           Sflag::On,
+		},
+
+		Instruction::AddCarry {
+			predicate:   Predicate::Always,
+			destination: Register::R2,
+			base:        Register::R2,
+			source:      Shifter::from_register(Register::R5),
+			s:           Sflag::On,
+		},
+
+		Instruction::SubtractCarry {
+			predicate:   Predicate::Always,
+			destination: Register::R2,
+			base:        Register::R2,
+			source:      Shifter::from_register(Register::R5),
+			s:           Sflag::On,
+		},
+
+		Instruction::Move {
+			predicate:   Predicate::Always,
+			destination: Register::R2,
+			source:      Shifter::RotateRightRegister { source: Register::R2, shift: Register::R5 },
+			s:           Sflag::On,
+		},
+
+		Instruction::Test {
+			predicate: Predicate::Always,
+			lhs:       Register::R2,
+			rhs:       Shifter::from_register(Register::R5),
+		},
+
+		Instruction::ReverseSubtract {
+			predicate:   Predicate::Always,
+			destination: Register::R2,
+			base:        Register::R5,
+			source:      Shifter::Immediate(0x0),
+			s:           Sflag::On,
+		},
+
+		Instruction::Compare {
+			predicate: Predicate::Always,
+			lhs:       Register::R2,
+			rhs:       Shifter::from_register(Register::R5),
+		},
+
+		Instruction::CompareNegated {
+			predicate: Predicate::Always,
+			lhs:       Register::R2,
+			rhs:       Shifter::from_register(Register::R5),
+		},
+
+		Instruction::InclusiveOr {
+			predicate:   Predicate::Always,
+			destination: Register::R2,
+			base:        Register::R2,
+			source:      Shifter::from_register(Register::R5),
+			s:           Sflag::On,
+		},
+
+		Instruction::Multiply {
+			predicate:   Predicate::Always,
+			destination: Register::R2,
+			base:        Register::R2,
+			source:      Register::R5,
+			s:           Sflag::On,
+		},
+
+		Instruction::BitClear {
+			predicate:   Predicate::Always,
+			destination: Register::R2,
+			base:        Register::R2,
+			source:      Shifter::from_register(Register::R5),
+			s:           Sflag::On,
+		},
+
+		Instruction::MoveNot {
+			predicate:   Predicate::Always,
+			destination: Register::R2,
+			source:      Shifter::from_register(Register::R5),
+			s:           Sflag::On,
+		},
+	];
+
+	let mut encoder = InstructionCodec::new_at(0x08000000);
+	let mut decoder = InstructionCodec::new_at(0x08000000);
+
+	for instruction in programme {
+		let opcode = encoder.encode_thumb(instruction).unwrap();
+
+		let result = if let Some(opcode1) = opcode.1 {
+			decoder.decode_thumb_wide((opcode.0, opcode1)).unwrap()
+		} else {
+			decoder.decode_thumb(opcode.0).unwrap()
+		};
+
+		assert_eq!(result, instruction);
+	}
+
+	let opcode = InstructionCodec::new_at(0x08000000).encode_thumb(programme[0x0]).unwrap();
+	assert_eq!(opcode, (0b11110111_11111110.into(), Some(0b11111111_11111110.into())));
 }